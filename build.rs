@@ -2,17 +2,18 @@
 //!
 //! This script fetches enum values from the OpenFIGI API and generates corresponding
 //! Rust enums with proper documentation and serialization attributes. It includes
-//! intelligent caching to avoid unnecessary API calls during development.
+//! intelligent caching to avoid unnecessary API calls during development, and a vendored mode
+//! generating the enums from the committed snapshots without network access.
 
 use serde::Deserialize;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     env,
     fmt::{self, Display, Write},
     fs,
     path::{Path, PathBuf},
     thread,
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 // ============================================================================================
@@ -22,9 +23,25 @@ use std::{
 /// Environment variable to force rebuilding of all enums regardless of cache status
 const FORCE_REBUILD_ENV_VAR: &str = "OPENFIGI_FORCE_REBUILD";
 
+/// Environment variable set by Cargo when the `full-enums` feature is enabled
+const FULL_ENUMS_FEATURE_ENV_VAR: &str = "CARGO_FEATURE_FULL_ENUMS";
+
+/// Environment variable to generate enums from the committed snapshots only, like the
+/// `vendored-enums` feature
+const VENDORED_ENUMS_ENV_VAR: &str = "OPENFIGI_VENDORED_ENUMS";
+
+/// Environment variable set by Cargo when the `vendored-enums` feature is enabled
+const VENDORED_ENUMS_FEATURE_ENV_VAR: &str = "CARGO_FEATURE_VENDORED_ENUMS";
+
 /// Base URL for OpenFIGI mapping values API
 const OPENFIGI_BASE_URL: &str = "https://api.openfigi.com/v3/mapping/values";
 
+/// Unix timestamps of when each cache file was last fetched from the API, keyed by file name
+const FETCHED_AT_PATH: &str = "resources/cached_data/fetched_at.json";
+
+/// Curated mapping from detailed security types to the coarser security types 2
+const SECURITY_TYPE_MAPPING_CSV: &str = "resources/others/security_type_to_security_type2.csv";
+
 // ============================================================================================
 // ERROR HANDLING
 // ============================================================================================
@@ -114,6 +131,16 @@ struct EndpointConfig {
     doc_comment: &'static str,
     /// Optional path to CSV file containing additional documentation
     docs_csv_path: Option<&'static str>,
+    /// Optional list of major values kept when the `full-enums` feature is disabled
+    ///
+    /// Enums with such a list must be open, so that code compiles the same way with and
+    /// without the feature.
+    slim_values: Option<&'static [&'static str]>,
+    /// Whether the enum gets an `Other(String)` variant holding any value that is not a variant
+    ///
+    /// Enums read from responses are open, so that values the API introduces after the build
+    /// still deserialize. Open enums cannot derive `Copy`.
+    open: bool,
 }
 
 impl EndpointConfig {
@@ -132,6 +159,32 @@ impl EndpointConfig {
         self.docs_csv_path
             .map(|path| Path::new(manifest_dir).join("resources/docs").join(path))
     }
+
+    /// Whether the generated enum has an `Other(String)` variant for values without a variant
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Whether `FromStr` and `parse_lenient()` return `Other` instead of failing on unknown values
+    ///
+    /// Only enums with slim values do, as their variants depend on the `full-enums` feature.
+    /// Other open enums only fall back when deserializing, so that typos in requests are still
+    /// reported.
+    fn parses_unknown_values(&self) -> bool {
+        self.slim_values.is_some()
+    }
+
+    /// Selects the values to generate variants for, honoring the `full-enums` feature
+    fn variant_values(&self, values: &[String]) -> Vec<String> {
+        match self.slim_values {
+            Some(slim) if env::var_os(FULL_ENUMS_FEATURE_ENV_VAR).is_none() => values
+                .iter()
+                .filter(|value| slim.contains(&value.as_str()))
+                .cloned()
+                .collect(),
+            _ => values.to_vec(),
+        }
+    }
 }
 
 /// API response structure for mapping values
@@ -153,6 +206,9 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "currency_enum.rs",
         doc_comment: "/// Enum for all supported currency codes.",
         docs_csv_path: None,
+        slim_values: None,
+        // Only sent in requests, never read from responses, so it stays closed and `Copy`
+        open: false,
     },
     EndpointConfig {
         name: "ExchCode",
@@ -161,6 +217,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "exch_code_enum.rs",
         doc_comment: "/// Enum for all supported exchange codes.",
         docs_csv_path: None,
+        slim_values: Some(MAJOR_EXCH_CODES),
+        open: true,
     },
     EndpointConfig {
         name: "IdType",
@@ -169,6 +227,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "id_type_enum.rs",
         doc_comment: "/// Enum for all supported ID types.",
         docs_csv_path: Some("id_type_enum_docs.csv"),
+        slim_values: None,
+        open: false,
     },
     EndpointConfig {
         name: "MarketSecDesc",
@@ -177,6 +237,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "market_sec_desc_enum.rs",
         doc_comment: "/// Enum for all supported market sector descriptions.",
         docs_csv_path: Some("market_sec_desc_enum_docs.csv"),
+        slim_values: None,
+        open: true,
     },
     EndpointConfig {
         name: "MicCode",
@@ -185,6 +247,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "mic_code_enum.rs",
         doc_comment: "/// Enum for all supported market identifiers codes.",
         docs_csv_path: None,
+        slim_values: Some(MAJOR_MIC_CODES),
+        open: true,
     },
     EndpointConfig {
         name: "SecurityType",
@@ -193,6 +257,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "security_type_enum.rs",
         doc_comment: "/// Enum for all supported security types.",
         docs_csv_path: Some("security_type_enum_docs.csv"),
+        slim_values: None,
+        open: true,
     },
     EndpointConfig {
         name: "SecurityType2",
@@ -201,6 +267,8 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "security_type2_enum.rs",
         doc_comment: "/// Enum for all supported security types 2.",
         docs_csv_path: None,
+        slim_values: None,
+        open: true,
     },
     EndpointConfig {
         name: "StateCode",
@@ -209,9 +277,26 @@ const ENDPOINTS: &[EndpointConfig] = &[
         enum_filename: "state_code_enum.rs",
         doc_comment: "/// Enum for all supported state codes.",
         docs_csv_path: None,
+        slim_values: None,
+        open: false,
     },
 ];
 
+/// Exchange codes of major venues, kept when the `full-enums` feature is disabled
+const MAJOR_EXCH_CODES: &[&str] = &[
+    "A0", "AT", "AU", "BB", "BZ", "CG", "CH", "CN", "CS", "CT", "CV", "DC", "FH", "FP", "GF", "GR",
+    "GY", "HK", "IB", "IM", "IN", "IS", "JP", "JT", "KS", "LI", "LN", "MM", "NA", "NO", "SE", "SJ",
+    "SM", "SP", "SQ", "SS", "SW", "UA", "UF", "UN", "UP", "UQ", "UR", "US", "UV", "UW", "VX",
+];
+
+/// Market identifier codes of major venues, kept when the `full-enums` feature is disabled
+const MAJOR_MIC_CODES: &[&str] = &[
+    "ARCX", "BATS", "BVMF", "IFEU", "XAMS", "XASE", "XASX", "XBOM", "XBRU", "XCBO", "XCBT", "XCME",
+    "XCSE", "XETR", "XEUR", "XFRA", "XHEL", "XHKG", "XICE", "XJSE", "XKRX", "XLON", "XMAD", "XMEX",
+    "XNAS", "XNSE", "XNYM", "XNYS", "XOSE", "XOSL", "XPAR", "XSES", "XSHE", "XSHG", "XSTO", "XSWX",
+    "XTKS", "XTSE",
+];
+
 // ============================================================================================
 // MAIN ENTRY POINT
 // ============================================================================================
@@ -233,15 +318,28 @@ fn main() -> BuildResult<()> {
     // Register all documentation CSV files for rebuild tracking
     register_doc_files_for_rebuild(&manifest_dir);
 
+    // Vendored builds read the committed snapshots only, so they must rebuild when those change
+    println!("cargo:rerun-if-env-changed={VENDORED_ENUMS_ENV_VAR}");
+    let vendored = is_vendored_build();
+    if vendored {
+        register_cache_files_for_rebuild(&manifest_dir);
+    }
+
     // Determine if we need to fetch fresh data
-    let should_fetch_fresh_data = should_rebuild_enums();
+    let should_fetch_fresh_data = !vendored && should_rebuild_enums();
 
     // Process each endpoint configuration
     println!("Processing {} OpenFIGI enums...", ENDPOINTS.len());
+    let mut enum_values = HashMap::new();
     for config in ENDPOINTS {
-        process_endpoint_config(config, &manifest_dir, should_fetch_fresh_data)?;
+        let values =
+            process_endpoint_config(config, &manifest_dir, should_fetch_fresh_data, vendored)?;
+        enum_values.insert(config.name, values);
     }
 
+    // Generate the conversions between security type classifications
+    generate_security_type_mapping(&manifest_dir, &enum_values)?;
+
     println!("Successfully generated all OpenFIGI enums");
     Ok(())
 }
@@ -253,6 +351,28 @@ fn register_doc_files_for_rebuild(manifest_dir: &str) {
             println!("cargo:rerun-if-changed={}", docs_path.display());
         }
     }
+    println!(
+        "cargo:rerun-if-changed={}",
+        Path::new(manifest_dir)
+            .join(SECURITY_TYPE_MAPPING_CSV)
+            .display()
+    );
+}
+
+/// Register the cached enum values for Cargo rebuild tracking
+fn register_cache_files_for_rebuild(manifest_dir: &str) {
+    for endpoint in ENDPOINTS {
+        println!(
+            "cargo:rerun-if-changed={}",
+            Path::new(manifest_dir)
+                .join(endpoint.cache_path())
+                .display()
+        );
+    }
+    println!(
+        "cargo:rerun-if-changed={}",
+        Path::new(manifest_dir).join(FETCHED_AT_PATH).display()
+    );
 }
 
 /// Validates endpoint configurations for consistency
@@ -295,6 +415,13 @@ fn validate_endpoint_configs() {
             "Invalid enum name: {}",
             config.name
         );
+
+        // Slimmed enums need `Other` for the values dropped without the `full-enums` feature
+        assert!(
+            config.slim_values.is_none() || config.open,
+            "Enum with slim values must be open: {}",
+            config.name
+        );
     }
 
     println!("Validated {} endpoint configurations", ENDPOINTS.len());
@@ -305,15 +432,27 @@ fn validate_endpoint_configs() {
 // ============================================================================================
 
 /// Processes a single endpoint configuration to generate its enum
+///
+/// Returns the enum values the enum was generated from. Vendored builds fail instead of
+/// falling back to the API if the cache cannot be loaded.
 fn process_endpoint_config(
     config: &EndpointConfig,
     manifest_dir: &str,
     should_fetch_fresh: bool,
-) -> BuildResult<()> {
+    vendored: bool,
+) -> BuildResult<Vec<String>> {
     println!("Processing {} enum...", config.name);
 
     // Fetch the enum values (either from cache or API)
-    let enum_values = if should_fetch_fresh {
+    let enum_values = if vendored {
+        println!("  → Loading {} from vendored snapshot", config.name);
+        load_cached_data(config).map_err(|cache_err| {
+            BuildError::InvalidData(format!(
+                "Vendored snapshot {} could not be loaded: {cache_err}",
+                config.cache_path()
+            ))
+        })?
+    } else if should_fetch_fresh {
         println!("  → Fetching fresh {} data from API", config.name);
         fetch_fresh_data_from_api(config).or_else(|api_err| {
             println!("  → API fetch failed ({api_err}), falling back to cache");
@@ -337,14 +476,35 @@ fn process_endpoint_config(
 
     // Generate the enum file
     let output_path = get_enum_output_path(config)?;
-    generate_enum_file(config, &enum_values, &documentation_map, &output_path)?;
+    let fetched_at = load_fetched_at(config)?;
+    let variant_values = config.variant_values(&enum_values);
+    generate_enum_file(
+        config,
+        &variant_values,
+        &documentation_map,
+        fetched_at,
+        &output_path,
+    )?;
 
     println!(
         "  ✓ Generated {} enum with {} variants",
         config.name,
-        enum_values.len()
+        variant_values.len()
     );
-    Ok(())
+    Ok(enum_values)
+}
+
+/// Determines if enums are generated from the committed snapshots without network access
+fn is_vendored_build() -> bool {
+    if env::var_os(VENDORED_ENUMS_FEATURE_ENV_VAR).is_some() {
+        println!("Using vendored data: vendored-enums feature enabled");
+        return true;
+    }
+    if env::var_os(VENDORED_ENUMS_ENV_VAR).is_some() {
+        println!("Using vendored data: {VENDORED_ENUMS_ENV_VAR} environment variable set");
+        return true;
+    }
+    false
 }
 
 /// Determines if we should rebuild/refresh enum data
@@ -416,14 +576,52 @@ fn fetch_fresh_data_from_api(config: &EndpointConfig) -> BuildResult<Vec<String>
     // Cache the fetched data
     let cache_path = config.cache_path();
     fs::write(&cache_path, serde_json::to_string(&values)?)?;
+    record_fetched_at(config)?;
     println!("  → Updated cached file {cache_path}");
 
     // Delay to avoid hitting API rate limits
-    thread::sleep(Duration::from_millis(5000));
+    thread::sleep(Duration::from_secs(5));
 
     Ok(values)
 }
 
+/// Loads the Unix timestamp of when the cached values were fetched from the API
+///
+/// Falls back to the modification time of the cache file if no timestamp was recorded.
+fn load_fetched_at(config: &EndpointConfig) -> BuildResult<u64> {
+    let recorded = fs::read_to_string(FETCHED_AT_PATH)
+        .ok()
+        .and_then(|data| serde_json::from_str::<HashMap<String, u64>>(&data).ok())
+        .and_then(|timestamps| timestamps.get(config.cache_filename).copied());
+    if let Some(timestamp) = recorded {
+        return Ok(timestamp);
+    }
+
+    let modified = fs::metadata(config.cache_path())?.modified()?;
+    Ok(unix_timestamp(modified))
+}
+
+/// Records the current time as the fetch timestamp of the cache file
+fn record_fetched_at(config: &EndpointConfig) -> BuildResult<()> {
+    let mut timestamps: BTreeMap<String, u64> = fs::read_to_string(FETCHED_AT_PATH)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default();
+    timestamps.insert(
+        config.cache_filename.to_string(),
+        unix_timestamp(SystemTime::now()),
+    );
+    fs::write(FETCHED_AT_PATH, serde_json::to_string(&timestamps)?)?;
+    Ok(())
+}
+
+/// Converts a system time to seconds since the Unix epoch
+fn unix_timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
 // ============================================================================================
 // ENUM GENERATION
 // ============================================================================================
@@ -439,6 +637,7 @@ fn generate_enum_file(
     config: &EndpointConfig,
     values: &[String],
     docs_map: &HashMap<String, String>,
+    fetched_at: u64,
     output_path: &Path,
 ) -> BuildResult<()> {
     let mut content = String::with_capacity(values.len() * 50); // Pre-allocate roughly
@@ -448,10 +647,18 @@ fn generate_enum_file(
     writeln!(&mut content, "#[allow(missing_docs)]")?;
     writeln!(&mut content, "#[allow(non_camel_case_types)]")?;
     writeln!(&mut content, "#[non_exhaustive]")?;
-    writeln!(
-        &mut content,
-        "#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]"
-    )?;
+    // `Other(String)` rules out `Copy` for open enums
+    if config.is_open() {
+        writeln!(
+            &mut content,
+            "#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+        )?;
+    } else {
+        writeln!(
+            &mut content,
+            "#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+        )?;
+    }
     writeln!(&mut content, "pub enum {} {{", config.name)?;
 
     // Generate variants
@@ -463,12 +670,37 @@ fn generate_enum_file(
             writeln!(&mut content, "    /// {doc_comment}")?;
         }
 
-        writeln!(&mut content, "    #[serde(rename = \"{value}\")]")?;
+        // Make the API value searchable in rustdoc when it differs from the variant name
+        if is_valid_doc_alias(value) && value != variant_name.trim_start_matches("r#") {
+            writeln!(&mut content, "    #[doc(alias = {value:?})]")?;
+        }
+
         writeln!(&mut content, "    {variant_name},")?;
     }
 
+    if config.is_open() {
+        let example = if config.slim_values.is_some() {
+            "a venue only included with the `full-enums` feature or a value added to the API after this crate was built"
+        } else {
+            "a value added to the API after this crate was built"
+        };
+        writeln!(
+            &mut content,
+            "    /// Any value without a dedicated variant, e.g. {example}."
+        )?;
+        writeln!(&mut content, "    Other(String),")?;
+    }
+
     writeln!(&mut content, "}}")?;
 
+    // Generate provenance constants, string conversions and serde implementations
+    write_provenance(&mut content, config, fetched_at)?;
+    write_string_conversions(&mut content, config, values)?;
+    if config.is_open() {
+        write_fallback_detection(&mut content, config.name)?;
+    }
+    write_serde_impls(&mut content, config.name, config.is_open())?;
+
     // Write the file
     fs::write(output_path, content)?;
     println!("  → Generated enum file: {}", output_path.display());
@@ -476,6 +708,451 @@ fn generate_enum_file(
     Ok(())
 }
 
+/// Checks whether rustdoc accepts a value as `#[doc(alias)]`
+///
+/// Aliases must not be empty, contain quotes or whitespace other than spaces, or start or end
+/// with a space.
+fn is_valid_doc_alias(value: &str) -> bool {
+    !value.is_empty()
+        && value.trim() == value
+        && !value
+            .chars()
+            .any(|c| c == '"' || c == '\'' || (c.is_whitespace() && c != ' '))
+}
+
+/// Writes the `SOURCE` and `GENERATED_AT` constants describing where the values came from
+fn write_provenance(
+    content: &mut String,
+    config: &EndpointConfig,
+    fetched_at: u64,
+) -> BuildResult<()> {
+    write!(
+        content,
+        r"
+impl {name} {{
+    /// URL of the OpenFIGI API endpoint the variants of this enum were generated from.
+    pub const SOURCE: &'static str = {source:?};
+
+    /// Time the variants of this enum were fetched from [`Self::SOURCE`], in seconds since the Unix epoch.
+    ///
+    /// Builds that use the bundled cache report the time the cache was last refreshed.
+    #[allow(clippy::unreadable_literal)]
+    pub const GENERATED_AT: u64 = {fetched_at};
+}}
+",
+        name = config.name,
+        source = config.api_url(),
+    )?;
+    Ok(())
+}
+
+/// Writes `VARIANTS`, `as_str()`, `parse_lenient()` and the `FromStr` and `Display` implementations of an enum
+///
+/// The list of variants is not called `ALL`, which is the Albanian lek variant of `Currency`.
+/// `FromStr` only accepts the exact API values, and the deprecated values replaced by them, and
+/// suggests the value matching leniently in its error. `parse_lenient()` additionally matches the
+/// [`lenient_key`] of the API values and variant names, skipping keys shared by several variants.
+/// For enums with slim values both fall back to the `Other` variant instead of failing.
+fn write_string_conversions(
+    content: &mut String,
+    config: &EndpointConfig,
+    values: &[String],
+) -> BuildResult<()> {
+    let (name, open) = (config.name, config.is_open());
+    let variants: Vec<(String, &str)> = values
+        .iter()
+        .map(|value| (sanitize_identifier(value), value.as_str()))
+        .collect();
+
+    let mut all_items = String::new();
+    let mut as_str_arms = String::new();
+    let mut from_str_arms = String::new();
+    for (variant, value) in &variants {
+        writeln!(all_items, "        Self::{variant},")?;
+        writeln!(as_str_arms, "            Self::{variant} => {value:?},")?;
+        writeln!(
+            from_str_arms,
+            "            {value:?} => Ok(Self::{variant}),"
+        )?;
+    }
+    let mut lenient_arms = String::new();
+    for (variant, keys) in collect_lenient_keys(&variants) {
+        let patterns: Vec<String> = keys.iter().map(|key| format!("{key:?}")).collect();
+        writeln!(
+            lenient_arms,
+            "            {} => Some(Self::{variant}),",
+            patterns.join(" | ")
+        )?;
+    }
+
+    let all_doc = if open {
+        "Every variant except `Other`, in the order of the API values."
+    } else {
+        "Every variant, in the order of the API values."
+    };
+
+    let as_str_type = if open {
+        as_str_arms.push_str("            Self::Other(value) => value,\n");
+        "&str"
+    } else {
+        "&'static str"
+    };
+
+    let (exact_guard, fallback, exact_fallback, errors_doc) = parse_fallbacks(config);
+
+    write!(
+        content,
+        r"
+impl {name} {{
+    /// {all_doc}
+    ///
+    /// Use [`Self::as_str`] to list the API values, e.g. to offer them in a user interface.
+    #[allow(clippy::large_stack_arrays)]
+    pub const VARIANTS: &'static [Self] = &[
+{all_items}    ];
+
+    /// Returns the value of this variant as used by the OpenFIGI API.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn as_str(&self) -> {as_str_type} {{
+        match self {{
+{as_str_arms}        }}
+    }}
+
+    /// Parses a value leniently, ignoring case, whitespace and punctuation.
+    ///
+    /// Exact API values are always accepted. Otherwise the input is matched against
+    /// the API values and variant names after normalization, as long as the match is
+    /// unambiguous. Intended for ingesting human-edited files.
+    ///
+    /// # Errors
+    ///
+    /// {errors_doc}
+    #[allow(clippy::too_many_lines)]
+    pub fn parse_lenient(value: &str) -> crate::error::Result<Self> {{
+        {exact_guard} {{
+            return Ok(parsed);
+        }}
+        match Self::from_lenient_key(value) {{
+            Some(parsed) => Ok(parsed),
+            None => {fallback},
+        }}
+    }}
+
+    /// Returns the variant whose normalized API value or name matches the normalized `value`.
+    #[allow(clippy::too_many_lines)]
+    fn from_lenient_key(value: &str) -> Option<Self> {{
+        match crate::model::enums::lenient_key(value).as_str() {{
+{lenient_arms}            _ => None,
+        }}
+    }}
+}}
+
+impl ::std::str::FromStr for {name} {{
+    type Err = crate::error::OpenFIGIError;
+
+    #[allow(clippy::too_many_lines)]
+    fn from_str(value: &str) -> crate::error::Result<Self> {{
+        match value {{
+{from_str_arms}            _ => match crate::model::enums::replacement_value({name:?}, value) {{
+                Some(replacement) => replacement.parse(),
+                None => {exact_fallback},
+            }},
+        }}
+    }}
+}}
+
+impl ::std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+"
+    )?;
+
+    Ok(())
+}
+
+/// Returns the guard of exact matches in `parse_lenient()`, the fallbacks of `parse_lenient()`
+/// and `FromStr` for unknown values, and the documentation of their errors
+fn parse_fallbacks(config: &EndpointConfig) -> (&'static str, String, String, &'static str) {
+    let name = config.name;
+    if config.parses_unknown_values() {
+        let fallback = "Ok(Self::Other(value.to_owned()))".to_string();
+        (
+            "if let Ok(parsed) = value.parse::<Self>()\n            && !matches!(parsed, Self::Other(_))",
+            fallback.clone(),
+            fallback,
+            "Never fails; values that do not match exactly one variant are returned as `Other`.",
+        )
+    } else {
+        (
+            "if let Ok(parsed) = value.parse()",
+            format!("Err(crate::model::enums::unknown_value({name:?}, value))"),
+            format!(
+                "Err(crate::model::enums::unknown_value_suggesting(\n                    {name:?},\n                    value,\n                    Self::from_lenient_key(value).as_ref().map(Self::as_str),\n                ))"
+            ),
+            "Returns a validation error if the value does not match exactly one variant.",
+        )
+    }
+}
+
+/// Writes `from_raw()` and `is_known()` of an open enum, creating and detecting `Other` values
+fn write_fallback_detection(content: &mut String, name: &str) -> BuildResult<()> {
+    write!(
+        content,
+        r"
+impl {name} {{
+    /// Parses an API value like `FromStr`, holding values without a variant in `Other`.
+    ///
+    /// This is how responses are deserialized, so that values the API introduced after this
+    /// crate was built do not fail the whole response. Use it to send such a value explicitly.
+    #[must_use]
+    pub fn from_raw(value: &str) -> Self {{
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Other(value.to_owned()))
+    }}
+
+    /// Returns whether this value has a dedicated variant rather than falling back to `Other`.
+    ///
+    /// Values the API introduced after this crate was built deserialize as `Other`, so this
+    /// detects responses containing values the crate does not know about yet.
+    #[must_use]
+    pub fn is_known(&self) -> bool {{
+        !matches!(self, Self::Other(_))
+    }}
+}}
+"
+    )?;
+    Ok(())
+}
+
+/// Writes `Serialize` and `Deserialize` implementations delegating to `as_str()` and `FromStr`
+///
+/// Serialization writes the replacement of deprecated values. Reusing the string conversion tables keeps the generated code much smaller than derived
+/// implementations, which emit a second set of tables for every enum.
+fn write_serde_impls(content: &mut String, name: &str, open: bool) -> BuildResult<()> {
+    let visitor = if open {
+        format!("crate::model::enums::FromStrVisitor::with_fallback({name:?}, Self::from_raw)")
+    } else {
+        format!("crate::model::enums::FromStrVisitor::new({name:?})")
+    };
+    write!(
+        content,
+        r"
+impl ::serde::Serialize for {name} {{
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        serializer.serialize_str(&crate::model::enums::serialized_value({name:?}, self.as_str()))
+    }}
+}}
+
+impl<'de> ::serde::Deserialize<'de> for {name} {{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        deserializer.deserialize_str({visitor})
+    }}
+}}
+"
+    )?;
+    Ok(())
+}
+
+/// Groups the lenient keys of the API values and variant names by variant
+///
+/// Keys shared by several variants are dropped, as they cannot be resolved unambiguously.
+/// Variants keep their order; variants without any remaining key are omitted.
+fn collect_lenient_keys(variants: &[(String, &str)]) -> Vec<(String, Vec<String>)> {
+    let mut owners: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for (variant, value) in variants {
+        for key in [
+            lenient_key(value),
+            lenient_key(variant.trim_start_matches("r#")),
+        ] {
+            let entry = owners.entry(key).or_default();
+            if !entry.contains(&variant.as_str()) {
+                entry.push(variant);
+            }
+        }
+    }
+
+    let mut keys_by_variant: HashMap<&str, Vec<String>> = HashMap::new();
+    for (key, owners) in owners {
+        if let [variant] = owners[..]
+            && !key.is_empty()
+        {
+            keys_by_variant.entry(variant).or_default().push(key);
+        }
+    }
+
+    variants
+        .iter()
+        .filter_map(|(variant, _)| {
+            keys_by_variant
+                .remove(variant.as_str())
+                .map(|keys| (variant.clone(), keys))
+        })
+        .collect()
+}
+
+/// Normalizes a value for lenient parsing by keeping only lower-cased alphanumeric characters
+///
+/// Must match `crate::model::enums::lenient_key` of the library.
+fn lenient_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// ============================================================================================
+// SECURITY TYPE MAPPING GENERATION
+// ============================================================================================
+
+/// Generates `SecurityType::coarse()` and `SecurityType2::detailed()` from the mapping CSV
+///
+/// Entries referring to values that are not part of the generated enums (e.g. after a refresh
+/// from the API removed them) are skipped so that the build never breaks on stale mappings.
+fn generate_security_type_mapping(
+    manifest_dir: &str,
+    enum_values: &HashMap<&str, Vec<String>>,
+) -> BuildResult<()> {
+    println!("Processing security type mapping...");
+    let csv_path = Path::new(manifest_dir).join(SECURITY_TYPE_MAPPING_CSV);
+    let mapping = load_documentation_from_csv(&csv_path)?;
+
+    let known = |name: &str, value: &str| {
+        enum_values
+            .get(name)
+            .is_some_and(|values| values.iter().any(|v| v == value))
+    };
+
+    // Keep the order of the detailed enum for deterministic output
+    let mut pairs = Vec::new();
+    for security_type in enum_values.get("SecurityType").into_iter().flatten() {
+        if let Some(security_type2) = mapping.get(security_type) {
+            if known("SecurityType2", security_type2) {
+                pairs.push((security_type.as_str(), security_type2.as_str()));
+            } else {
+                println!("  → Skipping mapping to unknown security type 2 '{security_type2}'");
+            }
+        }
+    }
+    for security_type in mapping.keys() {
+        if !known("SecurityType", security_type) {
+            println!("  → Skipping mapping of unknown security type '{security_type}'");
+        }
+    }
+
+    // Group the detailed types by coarse type, in the order of the coarse enum
+    let groups: Vec<(&str, Vec<String>)> = enum_values
+        .get("SecurityType2")
+        .into_iter()
+        .flatten()
+        .map(|security_type2| {
+            let detailed = pairs
+                .iter()
+                .filter(|(_, coarse)| coarse == security_type2)
+                .map(|(security_type, _)| sanitize_identifier(security_type))
+                .collect::<Vec<_>>();
+            (security_type2.as_str(), detailed)
+        })
+        .filter(|(_, detailed)| !detailed.is_empty())
+        .collect();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    // Detailed to coarse
+    let arms: Vec<String> = groups
+        .iter()
+        .map(|(security_type2, detailed)| {
+            let patterns: Vec<String> = detailed.iter().map(|v| format!("Self::{v}")).collect();
+            format!(
+                "{} => Some(SecurityType2::{})",
+                patterns.join(" | "),
+                sanitize_identifier(security_type2)
+            )
+        })
+        .collect();
+    write_match_impl(
+        &out_dir.join("security_type_coarse.rs"),
+        "SecurityType",
+        &[
+            "Returns the coarser [`SecurityType2`] classification of this security type.",
+            "",
+            "Returns `None` if the security type has no unambiguous coarse classification.",
+        ],
+        "coarse(&self) -> Option<SecurityType2>",
+        &arms,
+        "None",
+    )?;
+
+    // Coarse to detailed
+    let arms: Vec<String> = groups
+        .iter()
+        .map(|(security_type2, detailed)| {
+            let values: Vec<String> = detailed
+                .iter()
+                .map(|v| format!("SecurityType::{v}"))
+                .collect();
+            format!(
+                "Self::{} => &[{}]",
+                sanitize_identifier(security_type2),
+                values.join(", ")
+            )
+        })
+        .collect();
+    write_match_impl(
+        &out_dir.join("security_type2_detailed.rs"),
+        "SecurityType2",
+        &[
+            "Returns all detailed [`SecurityType`] values classified as this security type 2.",
+            "",
+            "This is the inverse of [`SecurityType::coarse()`].",
+        ],
+        "detailed(&self) -> &'static [SecurityType]",
+        &arms,
+        "&[]",
+    )?;
+
+    println!(
+        "  ✓ Generated security type mapping with {} entries",
+        pairs.len()
+    );
+    Ok(())
+}
+
+/// Writes an `impl` block with a single method consisting of a `match` on `self`
+fn write_match_impl(
+    output_path: &Path,
+    enum_name: &str,
+    docs: &[&str],
+    signature: &str,
+    arms: &[String],
+    fallback: &str,
+) -> BuildResult<()> {
+    let mut content = String::with_capacity(arms.iter().map(String::len).sum::<usize>() * 2);
+
+    writeln!(&mut content, "impl {enum_name} {{")?;
+    for line in docs {
+        writeln!(&mut content, "{}", format!("    /// {line}").trim_end())?;
+    }
+    writeln!(&mut content, "    #[must_use]")?;
+    writeln!(&mut content, "    pub fn {signature} {{")?;
+    writeln!(&mut content, "        match self {{")?;
+    for arm in arms {
+        writeln!(&mut content, "            {arm},")?;
+    }
+    writeln!(&mut content, "            _ => {fallback},")?;
+    writeln!(&mut content, "        }}")?;
+    writeln!(&mut content, "    }}")?;
+    writeln!(&mut content, "}}")?;
+
+    fs::write(output_path, content)?;
+    println!("  → Generated mapping file: {}", output_path.display());
+    Ok(())
+}
+
 // ============================================================================================
 // IDENTIFIER SANITIZATION
 // ============================================================================================
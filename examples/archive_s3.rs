@@ -0,0 +1,131 @@
+//! # Archiving Requests to S3
+//!
+//! Keeps a copy of every request and response in an S3-compatible bucket, as market data
+//! usage agreements may require. Each request is stored as two objects below
+//! `openfigi/<date>/`: the request metadata as JSON and the response body as received.
+//!
+//! Set `OPENFIGI_ARCHIVE_URL` to the URL of a bucket accepting `PUT` requests, e.g. a bucket
+//! of a self-hosted store with an upload policy or a gateway signing requests on your behalf. Without it, the
+//! objects are uploaded to a local mock bucket. A production archiver writing to AWS S3 would
+//! sign its uploads with the AWS SDK instead.
+//!
+//! ```bash
+//! cargo run --example archive_s3
+//! OPENFIGI_ARCHIVE_URL=http://localhost:9000/records cargo run --example archive_s3
+//! ```
+
+mod common;
+
+use openfigi_rs::archive::{Archiver, RequestPlan};
+use openfigi_rs::error::OpenFIGIError;
+use openfigi_rs::model::enums::IdType;
+use serde_json::json;
+use std::sync::atomic::{AtomicU64, Ordering};
+use wiremock::{Mock, MockServer, ResponseTemplate, matchers::method};
+
+/// Uploads every request and response to a bucket.
+struct S3Archiver {
+    http: reqwest::Client,
+    bucket_url: String,
+    sequence: AtomicU64,
+}
+
+impl S3Archiver {
+    fn new(bucket_url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            bucket_url: bucket_url.into().trim_end_matches('/').to_string(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    async fn put(&self, key: &str, content_type: &str, body: Vec<u8>) -> Result<(), OpenFIGIError> {
+        self.http
+            .put(format!("{}/{key}", self.bucket_url))
+            .header("content-type", content_type)
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Archiver for S3Archiver {
+    async fn archive(
+        &self,
+        plan: &RequestPlan,
+        response_bytes: &[u8],
+    ) -> Result<(), OpenFIGIError> {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+        let prefix = format!(
+            "openfigi/{}/{}-{sequence:06}-{}",
+            plan.sent_at.format("%Y-%m-%d"),
+            plan.sent_at.format("%Y%m%dT%H%M%S%.6fZ"),
+            plan.endpoint
+        );
+        let metadata = json!({
+            "endpoint": plan.endpoint,
+            "url": plan.url.as_str(),
+            "method": plan.method.as_str(),
+            "batchSize": plan.batch_size,
+            "body": plan.body,
+            "sentAt": plan.sent_at,
+            "receivedAt": plan.received_at,
+            "status": plan.status.as_u16(),
+        });
+        self.put(
+            &format!("{prefix}.response"),
+            "application/octet-stream",
+            response_bytes.to_vec(),
+        )
+        .await?;
+        self.put(
+            &format!("{prefix}.json"),
+            "application/json",
+            serde_json::to_vec(&metadata)?,
+        )
+        .await
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let env = common::setup().await?;
+
+    // Stand in for the bucket unless a real one is configured
+    let (bucket_url, mock_bucket) = if let Ok(url) = std::env::var("OPENFIGI_ARCHIVE_URL") {
+        (url, None)
+    } else {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        (format!("{}/records", server.uri()), Some(server))
+    };
+
+    let client = env
+        .client
+        .clone()
+        .with_archiver(S3Archiver::new(&bucket_url));
+    let data = client
+        .mapping(IdType::ID_ISIN, "US4592001014")
+        .send()
+        .await?;
+    println!(
+        "Mapped US4592001014 to {} results using the {} API",
+        data.data().len(),
+        if env.live { "live" } else { "mock" }
+    );
+
+    if let Some(server) = mock_bucket {
+        for request in server.received_requests().await.unwrap_or_default() {
+            println!("Archived {}", request.url.path());
+        }
+    } else {
+        println!("Archived to {bucket_url}");
+    }
+    Ok(())
+}
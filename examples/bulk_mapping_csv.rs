@@ -0,0 +1,107 @@
+//! # Bulk Mapping from CSV
+//!
+//! Maps every identifier listed in a CSV file to FIGIs and writes the results as CSV to stdout.
+//! The input needs `id_type` and `id_value` columns, where `id_type` is an OpenFIGI identifier
+//! type such as `ID_ISIN` or `TICKER`.
+//!
+//! Requests are sent in batches sized to the limit of the client (100 jobs with an API key,
+//! 5 without), and repeated identifiers within a batch are only sent once.
+//!
+//! ```bash
+//! cargo run --example bulk_mapping_csv
+//! cargo run --example bulk_mapping_csv -- path/to/identifiers.csv
+//! ```
+
+mod common;
+
+use openfigi_rs::model::{enums::IdType, request::MappingRequest};
+use serde::Deserialize;
+use std::{io, time::Duration};
+
+/// Identifier file used when no path is given.
+const DEFAULT_INPUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/examples/fixtures/identifiers.csv"
+);
+
+/// A row of the input file.
+#[derive(Deserialize)]
+struct Row {
+    id_type: String,
+    id_value: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let env = common::setup().await?;
+    let input = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_INPUT.to_string());
+
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    writer.write_record(["id_type", "id_value", "figi", "ticker", "name", "error"])?;
+
+    let mut requests = Vec::new();
+    for row in csv::Reader::from_path(&input)?.deserialize() {
+        let row: Row = row?;
+        let id_type: IdType = serde_json::from_value(row.id_type.as_str().into())?;
+        let request = MappingRequest::new(id_type, row.id_value.clone());
+        // Report invalid requests, e.g. identifiers with a wrong check digit, without sending them
+        if let Err(err) = request.validate() {
+            writer.write_record([&row.id_type, &row.id_value, "", "", "", &err.to_string()])?;
+            continue;
+        }
+        requests.push(request);
+    }
+    eprintln!("Read {} valid identifiers from {input}", requests.len());
+
+    let batch_size = if env.client.has_api_key() { 100 } else { 5 };
+
+    for (batch, chunk) in requests.chunks(batch_size).enumerate() {
+        // Stay well within the live rate limit of 25 mapping requests per minute
+        if env.live && batch > 0 {
+            tokio::time::sleep(Duration::from_millis(2500)).await;
+        }
+
+        let results = env
+            .client
+            .bulk_mapping()
+            .add_requests(chunk)
+            .send_deduplicated()
+            .await?;
+        eprintln!(
+            "Batch {}: sent {} of {} jobs",
+            batch + 1,
+            results.unique_len(),
+            results.len()
+        );
+
+        for (index, result) in results.iter() {
+            let request = &chunk[index];
+            let id_type = serde_json::to_value(request.id_type)?;
+            let id_type = id_type.as_str().unwrap_or_default();
+            let id_value = request.id_value.as_str().unwrap_or_default();
+
+            match result {
+                Ok(data) => {
+                    for figi in data.data() {
+                        writer.write_record([
+                            id_type,
+                            id_value,
+                            &figi.figi,
+                            figi.ticker.as_deref().unwrap_or_default(),
+                            figi.name.as_deref().unwrap_or_default(),
+                            "",
+                        ])?;
+                    }
+                }
+                Err(err) => {
+                    writer.write_record([id_type, id_value, "", "", "", &err.to_string()])?;
+                }
+            }
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Shared setup for the examples.
+//!
+//! By default every example talks to a local mock server that replays the fixtures in
+//! `examples/fixtures`, so no API key or network access is required. Set `OPENFIGI_LIVE=1`
+//! to run against the real OpenFIGI API instead (the `OPENFIGI_API_KEY` environment
+//! variable is picked up as usual).
+
+use openfigi_rs::client::OpenFIGIClient;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use wiremock::{
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+    matchers::{method, path},
+};
+
+/// A client together with the mock server backing it, if any.
+pub struct ExampleEnv {
+    /// Client connected to either the mock server or the live API
+    pub client: OpenFIGIClient,
+    /// Whether the client talks to the live API
+    pub live: bool,
+    // Keeps the mock server running for as long as the environment is alive
+    _server: Option<MockServer>,
+}
+
+/// Creates the client used by the examples.
+pub async fn setup() -> anyhow::Result<ExampleEnv> {
+    if std::env::var_os("OPENFIGI_LIVE").is_some() {
+        return Ok(ExampleEnv {
+            client: OpenFIGIClient::new(),
+            live: true,
+            _server: None,
+        });
+    }
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v3/mapping"))
+        .respond_with(MappingResponder(serde_json::from_str(include_str!(
+            "../fixtures/mapping.json"
+        ))?))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v3/filter"))
+        .respond_with(FilterResponder(serde_json::from_str(include_str!(
+            "../fixtures/filter_pages.json"
+        ))?))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v3/search"))
+        .respond_with(SearchResponder(serde_json::from_str(include_str!(
+            "../fixtures/search.json"
+        ))?))
+        .mount(&server)
+        .await;
+
+    let client = OpenFIGIClient::builder()
+        .base_url(format!("{}/v3/", server.uri()))
+        .build()?;
+    eprintln!(
+        "Using mock server at {} (set OPENFIGI_LIVE=1 to use the live API)",
+        server.uri()
+    );
+
+    Ok(ExampleEnv {
+        client,
+        live: false,
+        _server: Some(server),
+    })
+}
+
+/// Answers each mapping job with the fixture data for its `idValue`.
+struct MappingResponder(HashMap<String, Value>);
+
+impl Respond for MappingResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let jobs: Vec<Value> = serde_json::from_slice(&request.body).unwrap_or_default();
+        let results: Vec<Value> = jobs
+            .iter()
+            .map(|job| {
+                job.get("idValue")
+                    .and_then(Value::as_str)
+                    .and_then(|id_value| self.0.get(id_value))
+                    .map_or_else(
+                        || json!({ "error": "Invalid idValue format." }),
+                        |data| json!({ "data": data }),
+                    )
+            })
+            .collect();
+        ResponseTemplate::new(200).set_body_json(results)
+    }
+}
+
+/// Serves the fixture pages, where the `start` token `page-N` selects page `N`.
+struct FilterResponder(Vec<Value>);
+
+impl Respond for FilterResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: Value = serde_json::from_slice(&request.body).unwrap_or_default();
+        let page = body
+            .get("start")
+            .and_then(Value::as_str)
+            .and_then(|start| start.strip_prefix("page-"))
+            .and_then(|index| index.parse::<usize>().ok())
+            .unwrap_or_default();
+        match self.0.get(page) {
+            Some(page) => ResponseTemplate::new(200).set_body_json(page),
+            None => ResponseTemplate::new(400).set_body_string("Invalid start token"),
+        }
+    }
+}
+
+/// Answers searches with the fixture results for the lower-cased query.
+struct SearchResponder(HashMap<String, Value>);
+
+impl Respond for SearchResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: Value = serde_json::from_slice(&request.body).unwrap_or_default();
+        let data = body
+            .get("query")
+            .and_then(Value::as_str)
+            .and_then(|query| self.0.get(&query.trim().to_lowercase()))
+            .cloned()
+            .unwrap_or_else(|| json!([]));
+        ResponseTemplate::new(200).set_body_json(json!({ "data": data }))
+    }
+}
@@ -0,0 +1,112 @@
+//! # Paginated Filter Scan to Parquet
+//!
+//! Scans all pages of a `/filter` query and writes the instruments to a Parquet file,
+//! one row group per page. Upcoming pages are prefetched while the current page is written.
+//!
+//! ```bash
+//! cargo run --example filter_scan_parquet
+//! cargo run --example filter_scan_parquet -- ibm /tmp/ibm.parquet
+//! ```
+
+mod common;
+
+use openfigi_rs::model::{enums::SecurityType2, request::HasFilters, response::FilterData};
+use parquet::{
+    data_type::{ByteArray, ByteArrayType},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+use serde::Serialize;
+use std::{fs::File, path::PathBuf, sync::Arc};
+
+/// Parquet schema of the written file.
+const SCHEMA: &str = "
+    message figi {
+        REQUIRED BYTE_ARRAY figi (UTF8);
+        OPTIONAL BYTE_ARRAY ticker (UTF8);
+        OPTIONAL BYTE_ARRAY name (UTF8);
+        OPTIONAL BYTE_ARRAY exch_code (UTF8);
+        OPTIONAL BYTE_ARRAY security_type (UTF8);
+    }
+";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let env = common::setup().await?;
+    let mut args = std::env::args().skip(1);
+    let query = args.next().unwrap_or_else(|| "ibm".to_string());
+    let output = args
+        .next()
+        .map_or_else(|| std::env::temp_dir().join("figis.parquet"), PathBuf::from);
+
+    let mut paginator = env
+        .client
+        .filter()
+        .query(&query)
+        .security_type2(SecurityType2::CommonStock)
+        .paginator()?
+        .prefetch(2);
+
+    let schema = Arc::new(parse_message_type(SCHEMA)?);
+    let properties = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(File::create(&output)?, schema, properties)?;
+
+    while let Some(page) = paginator.next_page().await {
+        let page = page?;
+        write_page(&mut writer, &page)?;
+        eprintln!(
+            "Wrote {} of {} instruments{}",
+            paginator.fetched(),
+            paginator
+                .total()
+                .map_or_else(|| "?".to_string(), |total| total.to_string()),
+            if env.live { " (live)" } else { "" }
+        );
+    }
+
+    writer.close()?;
+    println!("{}", output.display());
+    Ok(())
+}
+
+/// Writes a page of results as a single row group.
+fn write_page(writer: &mut SerializedFileWriter<File>, page: &FilterData) -> anyhow::Result<()> {
+    let results = page.data();
+    let columns: [Vec<Option<String>>; 5] = [
+        results.iter().map(|r| Some(r.figi.to_string())).collect(),
+        results.iter().map(|r| r.ticker.clone()).collect(),
+        results.iter().map(|r| r.name.clone()).collect(),
+        results
+            .iter()
+            .map(|r| enum_value(r.exch_code.as_ref()))
+            .collect(),
+        results
+            .iter()
+            .map(|r| enum_value(r.security_type.as_ref()))
+            .collect(),
+    ];
+
+    let mut row_group = writer.next_row_group()?;
+    for (index, column) in columns.iter().enumerate() {
+        let Some(mut column_writer) = row_group.next_column()? else {
+            anyhow::bail!("Schema has fewer columns than written");
+        };
+        let values: Vec<ByteArray> = column.iter().flatten().map(|v| v.as_str().into()).collect();
+        // The first column is required and therefore has no definition levels
+        let levels: Vec<i16> = column.iter().map(|v| i16::from(v.is_some())).collect();
+        let levels = (index > 0).then_some(levels.as_slice());
+        column_writer
+            .typed::<ByteArrayType>()
+            .write_batch(&values, levels, None)?;
+        column_writer.close()?;
+    }
+    row_group.close()?;
+    Ok(())
+}
+
+/// Returns the API value of an enum, e.g. `"Common Stock"`.
+fn enum_value<T: Serialize>(value: Option<&T>) -> Option<String> {
+    serde_json::to_value(value?)
+        .ok()
+        .and_then(|value| value.as_str().map(ToString::to_string))
+}
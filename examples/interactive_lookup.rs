@@ -0,0 +1,70 @@
+//! # Rate-Limited Interactive Lookup
+//!
+//! Reads search queries from stdin, one per line, and prints the top matches for each.
+//! Queries are paced to stay within the `/search` rate limit of the live API
+//! (5 requests per minute without an API key, 20 with one).
+//!
+//! ```bash
+//! cargo run --example interactive_lookup
+//! printf 'ibm\napple\n' | cargo run --example interactive_lookup
+//! ```
+
+mod common;
+
+use std::{
+    io::{IsTerminal, Write},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    time::{MissedTickBehavior, interval},
+};
+
+/// Number of results printed per query.
+const TOP_RESULTS: usize = 5;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let env = common::setup().await?;
+    let interactive = std::io::stdin().is_terminal();
+
+    let period = match (env.live, env.client.has_api_key()) {
+        (true, true) => Duration::from_secs(3),
+        (true, false) => Duration::from_secs(12),
+        (false, _) => Duration::from_millis(200),
+    };
+    let mut limiter = interval(period);
+    limiter.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    loop {
+        if interactive {
+            print!("query> ");
+            std::io::stdout().flush()?;
+        }
+        let Some(line) = lines.next_line().await? else {
+            break;
+        };
+        let query = line.trim();
+        if query.is_empty() {
+            continue;
+        }
+
+        limiter.tick().await;
+        match env.client.search(query).send().await {
+            Ok(results) if results.data().is_empty() => println!("No results for '{query}'"),
+            Ok(results) => {
+                for result in results.data().iter().take(TOP_RESULTS) {
+                    println!(
+                        "{:<14} {:<20} {}",
+                        result.figi,
+                        result.ticker.as_deref().unwrap_or("-"),
+                        result.display_name()
+                    );
+                }
+            }
+            Err(err) => eprintln!("Lookup for '{query}' failed: {err}"),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,454 @@
+//! # Request Archival
+//!
+//! A hook receiving every request sent through a client together with the raw bytes of its
+//! response, to keep the records that market data usage agreements require.
+//!
+//! An [`Archiver`](crate::archive::Archiver) registered with
+//! [`OpenFIGIClientBuilder::archiver`](crate::client_builder::OpenFIGIClientBuilder::archiver)
+//! is called once the body of each response was received, before the response is parsed, with
+//! a [`RequestPlan`](crate::archive::RequestPlan) describing the request and the body exactly as
+//! sent by the API. Each page of a paginated lookup and each batch of a bulk mapping is archived
+//! on its own. Responses served from a replay store (see [`crate::replay`]) are not archived, as
+//! they were archived when they were recorded.
+//!
+//! Archival fails closed: if the archiver returns an error, the request fails with that error
+//! instead of returning a response that was not archived.
+//!
+//! [`FileArchiver`](crate::archive::FileArchiver) writes every request and response to a
+//! directory. Other storage, e.g. an S3 bucket, is added by implementing the trait; see
+//! `examples/archive_s3.rs`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::archive::FileArchiver;
+//! use openfigi_rs::client::OpenFIGIClient;
+//!
+//! let client = OpenFIGIClient::builder()
+//!     .archiver(FileArchiver::new(std::env::temp_dir().join("openfigi-archive")))
+//!     .build()?;
+//! assert!(client.archiver().is_some());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::error::{OpenFIGIError, OtherErrorKind, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Method, ResponseBuilderExt, StatusCode};
+use serde_json::json;
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+use url::Url;
+
+/// A request passed to an [`Archiver`], with the status of its response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestPlan {
+    /// Endpoint path of the request relative to the base URL, e.g. `mapping`.
+    pub endpoint: String,
+    /// Full URL the request was sent to.
+    pub url: Url,
+    /// HTTP method of the request.
+    pub method: Method,
+    /// JSON body of the request, if any.
+    pub body: Option<serde_json::Value>,
+    /// Number of jobs in the body of the request, `1` for a single query and `0` without a body.
+    pub batch_size: usize,
+    /// When the request was sent.
+    pub sent_at: DateTime<Utc>,
+    /// When the body of the response was received.
+    pub received_at: DateTime<Utc>,
+    /// HTTP status of the response.
+    pub status: StatusCode,
+}
+
+/// Storage receiving every request sent through a client together with its response.
+///
+/// Implementations are shared between clones of a client and called concurrently for requests
+/// in flight at the same time.
+#[async_trait::async_trait]
+pub trait Archiver: Send + Sync {
+    /// Archives the request `plan` and the raw bytes of its response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be archived, which fails the request.
+    async fn archive(&self, plan: &RequestPlan, response_bytes: &[u8]) -> Result<()>;
+}
+
+/// An [`Archiver`] writing every request and response to files below a directory.
+///
+/// Each request is stored in a subdirectory named after the UTC date it was sent, as two files
+/// sharing a name made up of the time it was sent, the id of the process, a sequence number and
+/// the endpoint, e.g. `2024-05-01/20240501T093000.123456Z-4242-000001-mapping`:
+///
+/// - `.json` holds the method, URL, body and batch size of the request, the times it was sent
+///   and received, and the status of the response
+/// - `.response` holds the body of the response exactly as received
+///
+/// Files are never overwritten, also not by other processes or a restarted process archiving to
+/// the same directory. Within a Tokio runtime they are written on its blocking thread pool,
+/// otherwise synchronously.
+pub struct FileArchiver {
+    dir: PathBuf,
+    sequence: AtomicU64,
+}
+
+impl FileArchiver {
+    /// Creates an archiver writing below `dir`, which is created on first use if missing.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the directory the archiver writes to.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Names the files of `plan` and serializes its metadata, without touching the file system.
+    fn record(&self, plan: &RequestPlan) -> Result<ArchiveRecord> {
+        let dir = self.dir.join(plan.sent_at.format("%Y-%m-%d").to_string());
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+        let endpoint: String = plan
+            .endpoint
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let stem = format!(
+            "{}-{}-{sequence:06}-{endpoint}",
+            plan.sent_at.format("%Y%m%dT%H%M%S%.6fZ"),
+            std::process::id()
+        );
+
+        let metadata = json!({
+            "endpoint": plan.endpoint,
+            "url": plan.url.as_str(),
+            "method": plan.method.as_str(),
+            "batchSize": plan.batch_size,
+            "body": plan.body,
+            "sentAt": plan.sent_at,
+            "receivedAt": plan.received_at,
+            "status": plan.status.as_u16(),
+        });
+        Ok(ArchiveRecord {
+            dir,
+            stem,
+            metadata: serde_json::to_vec_pretty(&metadata)?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Archiver for FileArchiver {
+    async fn archive(&self, plan: &RequestPlan, response_bytes: &[u8]) -> Result<()> {
+        let record = self.record(plan)?;
+        if tokio::runtime::Handle::try_current().is_err() {
+            return record.write(response_bytes).map(|_| ());
+        }
+        let response_bytes = response_bytes.to_vec();
+        tokio::task::spawn_blocking(move || record.write(&response_bytes))
+            .await
+            .map_err(|error| {
+                OpenFIGIError::other_error(
+                    OtherErrorKind::Other,
+                    format!("Archive task failed: {error}"),
+                )
+            })?
+            .map(|_| ())
+    }
+}
+
+/// The files of one request of a [`FileArchiver`], ready to be written.
+struct ArchiveRecord {
+    dir: PathBuf,
+    stem: String,
+    metadata: Vec<u8>,
+}
+
+impl ArchiveRecord {
+    /// Writes the response and the metadata, returning the path of the `.json` file.
+    fn write(&self, response_bytes: &[u8]) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let metadata_path = self.dir.join(format!("{}.json", self.stem));
+        write_new(
+            &self.dir.join(format!("{}.response", self.stem)),
+            response_bytes,
+        )?;
+        write_new(&metadata_path, &self.metadata)?;
+        Ok(metadata_path)
+    }
+}
+
+impl fmt::Debug for FileArchiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileArchiver")
+            .field("dir", &self.dir)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Writes `contents` to a new file at `path`, failing if the file already exists.
+fn write_new(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+/// The archiver of a client, shared with its clones.
+#[derive(Clone)]
+pub(crate) struct SharedArchiver(Arc<dyn Archiver>);
+
+impl SharedArchiver {
+    pub(crate) fn new(archiver: impl Archiver + 'static) -> Self {
+        Self(Arc::new(archiver))
+    }
+
+    pub(crate) fn get(&self) -> &dyn Archiver {
+        self.0.as_ref()
+    }
+
+    /// Reads the body of `response`, archives it with `plan`, and returns an equivalent
+    /// response with the same URL, status, headers and extensions.
+    pub(crate) async fn archive(
+        &self,
+        mut plan: RequestPlan,
+        mut response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let url = response.url().clone();
+        let status = response.status();
+        let version = response.version();
+        let headers = std::mem::take(response.headers_mut());
+        let extensions = std::mem::take(response.extensions_mut());
+        let bytes = response.bytes().await?;
+
+        plan.received_at = Utc::now();
+        plan.status = status;
+        self.0.archive(&plan, &bytes).await?;
+
+        let mut archived = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(url)
+            .body(bytes)
+            .map_err(|error| {
+                OpenFIGIError::other_error(OtherErrorKind::Other, error.to_string())
+            })?;
+        *archived.headers_mut() = headers;
+        archived.extensions_mut().extend(extensions);
+        Ok(reqwest::Response::from(archived))
+    }
+}
+
+impl fmt::Debug for SharedArchiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedArchiver").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::enums::IdType, test_utils::mock_client_builder};
+    use std::sync::Mutex;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    #[derive(Default)]
+    struct Recorder {
+        archived: Mutex<Vec<(RequestPlan, Vec<u8>)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Archiver for Arc<Recorder> {
+        async fn archive(&self, plan: &RequestPlan, response_bytes: &[u8]) -> Result<()> {
+            self.archived
+                .lock()
+                .expect("Lock should not be poisoned")
+                .push((plan.clone(), response_bytes.to_vec()));
+            Ok(())
+        }
+    }
+
+    struct Failing;
+
+    #[async_trait::async_trait]
+    impl Archiver for Failing {
+        async fn archive(&self, _plan: &RequestPlan, _response_bytes: &[u8]) -> Result<()> {
+            Err(std::io::Error::other("archive unavailable").into())
+        }
+    }
+
+    fn plan() -> RequestPlan {
+        let sent_at = DateTime::parse_from_rfc3339("2024-05-01T09:30:00Z")
+            .expect("Timestamp should be valid")
+            .with_timezone(&Utc);
+        RequestPlan {
+            endpoint: "mapping".to_string(),
+            url: Url::parse("https://api.openfigi.com/v3/mapping").expect("URL should be valid"),
+            method: Method::POST,
+            body: Some(json!([{ "idType": "TICKER", "idValue": "IBM" }])),
+            batch_size: 1,
+            sent_at,
+            received_at: sent_at,
+            status: StatusCode::OK,
+        }
+    }
+
+    #[test]
+    fn test_file_archiver_writes_request_and_response() {
+        let dir = std::env::temp_dir().join(format!("openfigi-archive-{}", std::process::id()));
+        let archiver = FileArchiver::new(&dir);
+
+        let metadata_path = archiver
+            .record(&plan())
+            .and_then(|record| record.write(br#"[{"data":[]}]"#))
+            .expect("Request should be archived");
+        assert_eq!(
+            metadata_path,
+            dir.join(format!(
+                "2024-05-01/20240501T093000.000000Z-{}-000001-mapping.json",
+                std::process::id()
+            ))
+        );
+        let metadata: serde_json::Value =
+            serde_json::from_slice(&fs::read(&metadata_path).expect("Metadata should be written"))
+                .expect("Metadata should be JSON");
+        assert_eq!(metadata["method"], "POST");
+        assert_eq!(metadata["status"], 200);
+        assert_eq!(metadata["body"][0]["idValue"], "IBM");
+        assert_eq!(
+            fs::read(metadata_path.with_extension("response")).expect("Response should be written"),
+            br#"[{"data":[]}]"#
+        );
+
+        let second = archiver
+            .record(&plan())
+            .and_then(|record| record.write(b"[]"))
+            .expect("Request should be archived");
+        assert_ne!(second, metadata_path);
+        fs::remove_dir_all(&dir).expect("Archive should be removed");
+    }
+
+    #[tokio::test]
+    async fn test_file_archiver_archives_within_runtime() {
+        let dir =
+            std::env::temp_dir().join(format!("openfigi-archive-runtime-{}", std::process::id()));
+        let archiver = FileArchiver::new(&dir);
+
+        archiver
+            .archive(&plan(), b"[]")
+            .await
+            .expect("Request should be archived");
+        let day = dir.join("2024-05-01");
+        let files = fs::read_dir(&day)
+            .expect("Archive should be written")
+            .count();
+        assert_eq!(files, 2);
+        fs::remove_dir_all(&dir).expect("Archive should be removed");
+    }
+
+    #[tokio::test]
+    async fn test_archiver_receives_every_request() {
+        let server = MockServer::start().await;
+        let body = r#"[{"data":[{"figi":"BBG000BLNNH6"}]}]"#;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/json")
+                    .set_body_string(body),
+            )
+            .mount(&server)
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let client = mock_client_builder(&server)
+            .archiver(Arc::clone(&recorder))
+            .build()
+            .expect("Failed to create test client");
+
+        let response = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect("Mapping should succeed");
+        assert_eq!(response.data()[0].figi, "BBG000BLNNH6");
+
+        let archived = recorder
+            .archived
+            .lock()
+            .expect("Lock should not be poisoned");
+        assert_eq!(archived.len(), 1);
+        let (plan, bytes) = &archived[0];
+        assert_eq!(plan.endpoint, "mapping");
+        assert_eq!(plan.method, Method::POST);
+        assert_eq!(plan.batch_size, 1);
+        assert_eq!(plan.status, StatusCode::OK);
+        assert_eq!(plan.url.path(), "/v3/mapping");
+        assert!(plan.received_at >= plan.sent_at);
+        assert_eq!(bytes.as_slice(), body.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_archived_error_reports_the_request_url() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Invalid idType."))
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .archiver(Arc::new(Recorder::default()))
+            .build()
+            .expect("Failed to create test client");
+
+        let err = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect_err("Bad request should fail");
+        let message = err.to_string();
+        assert!(
+            message.contains(&format!("{}/v3/mapping", server.uri())),
+            "{message}"
+        );
+        assert!(!message.contains("no.url.provided.local"), "{message}");
+    }
+
+    #[tokio::test]
+    async fn test_archive_failure_fails_the_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .archiver(Failing)
+            .build()
+            .expect("Failed to create test client");
+
+        let err = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect_err("Archive failure should fail the request");
+        assert!(err.to_string().contains("archive unavailable"));
+    }
+}
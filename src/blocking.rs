@@ -0,0 +1,612 @@
+//! # Blocking Client
+//!
+//! A synchronous variant of [`crate::client::OpenFIGIClient`] for scripts and codebases
+//! without an async runtime, similar to `reqwest::blocking`.
+//!
+//! [`crate::blocking::OpenFIGIClient`] offers the mapping, search and filter request builders
+//! of the async client with the same setters, including the filter setters of
+//! [`crate::model::request::HasFilters`], but their `send()` methods block the current thread
+//! until the response is parsed. The requests are driven by the runtime shared with
+//! [`crate::client::OpenFIGIClient::block_on`], so no runtime needs to be set up, and the
+//! configuration of the wrapped async client, e.g. its API key, rate limit or policy, applies
+//! unchanged.
+//!
+//! Requires the `blocking` feature. The blocking methods must not be called from within an
+//! async runtime, where they return an error instead of stalling the runtime.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::blocking::OpenFIGIClient;
+//! use openfigi_rs::model::enums::{ExchCode, IdType};
+//! use openfigi_rs::model::request::HasFilters;
+//!
+//! let client = OpenFIGIClient::new();
+//!
+//! let ibm = client
+//!     .mapping(IdType::TICKER, "IBM")
+//!     .exch_code(ExchCode::US)
+//!     .send()?;
+//! println!("{:?}", ibm.data().first());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    client,
+    endpoint::{
+        FilterableRequestBuilder, dedup::Deduplicated, filter, mapping, search, values::MappingKey,
+    },
+    error::Result,
+    model::{
+        enums::{IdType, Level},
+        request::{
+            HasFilters, IdValue, MappingRequest, MappingRequestBuilder, RequestFilters,
+            SearchRequest,
+        },
+        response::{
+            FigiResult, FilterData, IsinMapping, MappingData, MappingResponses, SearchData,
+        },
+    },
+    validation::ValidationRule,
+};
+use std::{ops::RangeInclusive, path::Path, time::Duration};
+
+/// Synchronous client for the OpenFIGI API.
+///
+/// Wraps a [`crate::client::OpenFIGIClient`], so cloning is cheap and clones share the
+/// connection pool. Configure it with [`crate::client::OpenFIGIClient::builder`] and convert the
+/// built client with [`From`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::blocking;
+/// use openfigi_rs::client::OpenFIGIClient;
+///
+/// let client: blocking::OpenFIGIClient = OpenFIGIClient::builder()
+///     .api_key("your-api-key")
+///     .build()?
+///     .into();
+/// assert!(client.as_async().has_api_key());
+/// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OpenFIGIClient {
+    inner: client::OpenFIGIClient,
+}
+
+impl OpenFIGIClient {
+    /// Creates a client with the default configuration, see
+    /// [`crate::client::OpenFIGIClient::new`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the wrapped async client.
+    #[must_use]
+    pub fn as_async(&self) -> &client::OpenFIGIClient {
+        &self.inner
+    }
+
+    /// Creates a mapping request builder, see [`crate::client::OpenFIGIClient::mapping`].
+    #[must_use]
+    pub fn mapping<T: Into<IdValue>>(
+        &self,
+        id_type: IdType,
+        id_value: T,
+    ) -> SingleMappingRequestBuilder {
+        SingleMappingRequestBuilder {
+            inner: self.inner.mapping(id_type, id_value),
+        }
+    }
+
+    /// Creates a mapping request builder for a FIGI of `level`, see
+    /// [`crate::client::OpenFIGIClient::mapping_figi`].
+    #[must_use]
+    pub fn mapping_figi(
+        &self,
+        level: Level,
+        figi: impl Into<String>,
+    ) -> SingleMappingRequestBuilder {
+        SingleMappingRequestBuilder {
+            inner: self.inner.mapping_figi(level, figi),
+        }
+    }
+
+    /// Creates a bulk mapping request builder, see
+    /// [`crate::client::OpenFIGIClient::bulk_mapping`].
+    #[must_use]
+    pub fn bulk_mapping(&self) -> BulkMappingRequestBuilder {
+        BulkMappingRequestBuilder {
+            inner: self.inner.bulk_mapping(),
+        }
+    }
+
+    /// Creates a search request builder, see [`crate::client::OpenFIGIClient::search`].
+    #[must_use]
+    pub fn search(&self, query: &str) -> SingleSearchRequestBuilder {
+        SingleSearchRequestBuilder {
+            inner: self.inner.search(query),
+        }
+    }
+
+    /// Creates a bulk search request builder, see
+    /// [`crate::client::OpenFIGIClient::bulk_search`].
+    #[must_use]
+    pub fn bulk_search(&self) -> BulkSearchRequestBuilder {
+        BulkSearchRequestBuilder {
+            inner: self.inner.bulk_search(),
+        }
+    }
+
+    /// Creates a filter request builder, see [`crate::client::OpenFIGIClient::filter`].
+    #[must_use]
+    pub fn filter(&self) -> SingleFilterRequestBuilder {
+        SingleFilterRequestBuilder {
+            inner: self.inner.filter(),
+        }
+    }
+
+    /// Fetches the current values of the mapping request property `key`, see
+    /// [`crate::client::OpenFIGIClient::mapping_values`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the HTTP request fails, if the response
+    /// cannot be parsed, or if called from within an async runtime.
+    pub fn mapping_values(&self, key: MappingKey) -> Result<Vec<String>> {
+        client::OpenFIGIClient::block_on(self.inner.mapping_values(key))
+    }
+
+    /// Maps a list of ISINs to their FIGI results or errors, keyed by ISIN, see
+    /// [`crate::client::OpenFIGIClient::map_isins`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if every batch failed as a whole, or if called
+    /// from within an async runtime. Other failures are reported per ISIN.
+    pub fn map_isins<'a>(&self, isins: impl IntoIterator<Item = &'a str>) -> Result<IsinMapping> {
+        client::OpenFIGIClient::block_on(self.inner.map_isins(isins))
+    }
+}
+
+impl From<client::OpenFIGIClient> for OpenFIGIClient {
+    fn from(inner: client::OpenFIGIClient) -> Self {
+        Self { inner }
+    }
+}
+
+/// Implements the setters shared by all request builders by delegating to the async builder.
+macro_rules! impl_common_setters {
+    ($builder:ty) => {
+        impl $builder {
+            /// Sets the accepted range for the number of results returned by `send()`.
+            #[must_use]
+            pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+                self.inner = self.inner.expect_results(range);
+                self
+            }
+
+            /// Adds a custom validation rule run for this request only, after the rules of the
+            /// client.
+            #[must_use]
+            pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+                self.inner = self.inner.validation_rule(rule);
+                self
+            }
+
+            /// Attaches a typed value to this request, passed to the middleware of the client.
+            #[must_use]
+            pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+                self.inner = self.inner.extension(value);
+                self
+            }
+        }
+    };
+}
+
+/// Implements the filter setters and [`FilterableRequestBuilder`] for a single request builder.
+macro_rules! impl_filterable {
+    ($builder:ty) => {
+        impl_common_setters!($builder);
+
+        impl HasFilters for $builder {
+            fn filters_mut(&mut self) -> &mut RequestFilters {
+                self.inner.filters_mut()
+            }
+        }
+
+        impl FilterableRequestBuilder for $builder {
+            fn expect_results(self, range: RangeInclusive<usize>) -> Self {
+                <$builder>::expect_results(self, range)
+            }
+
+            fn validation_rule(self, rule: impl ValidationRule + 'static) -> Self {
+                <$builder>::validation_rule(self, rule)
+            }
+
+            fn extension<T: Clone + Send + Sync + 'static>(self, value: T) -> Self {
+                <$builder>::extension(self, value)
+            }
+        }
+    };
+}
+
+/// Blocking variant of [`crate::endpoint::mapping::SingleMappingRequestBuilder`].
+pub struct SingleMappingRequestBuilder {
+    inner: mapping::SingleMappingRequestBuilder,
+}
+
+impl_filterable!(SingleMappingRequestBuilder);
+
+impl SingleMappingRequestBuilder {
+    /// Sets the identifier type.
+    #[must_use]
+    pub fn id_type(mut self, id_type: IdType) -> Self {
+        self.inner = self.inner.id_type(id_type);
+        self
+    }
+
+    /// Sets the identifier value.
+    #[must_use]
+    pub fn id_value<T: Into<IdValue>>(mut self, id_value: T) -> Self {
+        self.inner = self.inner.id_value(id_value);
+        self
+    }
+
+    /// Keeps only the results on `level` of the FIGI hierarchy, see
+    /// [`crate::endpoint::mapping::SingleMappingRequestBuilder::level`].
+    #[must_use]
+    pub fn level(mut self, level: Level) -> Self {
+        self.inner = self.inner.level(level);
+        self
+    }
+
+    /// Sends the mapping request and blocks until the results are parsed, see
+    /// [`crate::endpoint::mapping::SingleMappingRequestBuilder::send`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send(self) -> Result<MappingData> {
+        client::OpenFIGIClient::block_on(self.inner.send())
+    }
+
+    /// Sends the mapping request and writes the FIGIs to the file at `path` as
+    /// newline-delimited JSON, see
+    /// [`crate::endpoint::mapping::SingleMappingRequestBuilder::send_to_ndjson`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        client::OpenFIGIClient::block_on(self.inner.send_to_ndjson(path))
+    }
+}
+
+/// Blocking variant of [`crate::endpoint::mapping::BulkMappingRequestBuilder`].
+pub struct BulkMappingRequestBuilder {
+    inner: mapping::BulkMappingRequestBuilder,
+}
+
+impl_common_setters!(BulkMappingRequestBuilder);
+
+impl BulkMappingRequestBuilder {
+    /// Adds a mapping request to the batch.
+    #[must_use]
+    pub fn add_request(mut self, request: MappingRequest) -> Self {
+        self.inner = self.inner.add_request(request);
+        self
+    }
+
+    /// Adds several mapping requests to the batch.
+    #[must_use]
+    pub fn add_requests(
+        mut self,
+        requests: impl IntoIterator<Item = impl Into<MappingRequest>>,
+    ) -> Self {
+        self.inner = self.inner.add_requests(requests);
+        self
+    }
+
+    /// Adds a mapping request configured with a closure, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::add_request_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the configured request cannot be built.
+    pub fn add_request_with<F>(mut self, config: F) -> Result<Self>
+    where
+        F: FnOnce(MappingRequestBuilder) -> MappingRequestBuilder,
+    {
+        self.inner = self.inner.add_request_with(config)?;
+        Ok(self)
+    }
+
+    /// Sets filters applied to every request of the batch that does not set them itself.
+    #[must_use]
+    pub fn default_filters(mut self, filters: RequestFilters) -> Self {
+        self.inner = self.inner.default_filters(filters);
+        self
+    }
+
+    /// Sets how often a batch rejected with `413 Payload Too Large` is split, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::max_split_depth`].
+    #[must_use]
+    pub fn max_split_depth(mut self, depth: usize) -> Self {
+        self.inner = self.inner.max_split_depth(depth);
+        self
+    }
+
+    /// Sets how many batches [`Self::send_chunked`] sends at the same time.
+    #[must_use]
+    pub fn chunk_concurrency(mut self, concurrency: usize) -> Self {
+        self.inner = self.inner.chunk_concurrency(concurrency);
+        self
+    }
+
+    /// Sets the delay between starting two batches of [`Self::send_chunked`].
+    #[must_use]
+    pub fn chunk_delay(mut self, delay: Duration) -> Self {
+        self.inner = self.inner.chunk_delay(delay);
+        self
+    }
+
+    /// Sends the batch and blocks until the results are parsed, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::send`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send(self) -> Result<MappingResponses> {
+        client::OpenFIGIClient::block_on(self.inner.send())
+    }
+
+    /// Sends the batch and returns each result paired with the request that produced it, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::send_with_requests`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_with_requests(self) -> Result<Vec<(MappingRequest, Result<MappingData>)>> {
+        client::OpenFIGIClient::block_on(self.inner.send_with_requests())
+    }
+
+    /// Sends the requests in batches of at most the job limit, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::send_chunked`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_chunked(self) -> Result<MappingResponses> {
+        client::OpenFIGIClient::block_on(self.inner.send_chunked())
+    }
+
+    /// Sends each distinct request once, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::send_deduplicated`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_deduplicated(self) -> Result<Deduplicated<MappingData>> {
+        client::OpenFIGIClient::block_on(self.inner.send_deduplicated())
+    }
+}
+
+/// Blocking variant of [`crate::endpoint::search::SingleSearchRequestBuilder`].
+#[derive(Clone)]
+pub struct SingleSearchRequestBuilder {
+    inner: search::SingleSearchRequestBuilder,
+}
+
+impl_filterable!(SingleSearchRequestBuilder);
+
+impl SingleSearchRequestBuilder {
+    /// Sets the search query.
+    #[must_use]
+    pub fn query(mut self, query: &str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the pagination token of the page to fetch.
+    #[must_use]
+    pub fn start(mut self, start: &str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sends the search request and blocks until the results are parsed, see
+    /// [`crate::endpoint::search::SingleSearchRequestBuilder::send`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send(self) -> Result<SearchData> {
+        client::OpenFIGIClient::block_on(self.inner.send())
+    }
+
+    /// Sends the search request and writes the results to the file at `path` as
+    /// newline-delimited JSON, see
+    /// [`crate::endpoint::search::SingleSearchRequestBuilder::send_to_ndjson`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        client::OpenFIGIClient::block_on(self.inner.send_to_ndjson(path))
+    }
+}
+
+/// Blocking variant of [`crate::endpoint::search::BulkSearchRequestBuilder`].
+pub struct BulkSearchRequestBuilder {
+    inner: search::BulkSearchRequestBuilder,
+}
+
+impl_common_setters!(BulkSearchRequestBuilder);
+
+impl BulkSearchRequestBuilder {
+    /// Adds a search request.
+    #[must_use]
+    pub fn add_request(mut self, request: SearchRequest) -> Self {
+        self.inner = self.inner.add_request(request);
+        self
+    }
+
+    /// Adds multiple search requests.
+    #[must_use]
+    pub fn add_requests(mut self, requests: Vec<SearchRequest>) -> Self {
+        self.inner = self.inner.add_requests(requests);
+        self
+    }
+
+    /// Adds a search request for `query` without filters.
+    #[must_use]
+    pub fn add_query(mut self, query: impl Into<String>) -> Self {
+        self.inner = self.inner.add_query(query);
+        self
+    }
+
+    /// Sets how many requests are sent at the same time, see
+    /// [`crate::endpoint::search::BulkSearchRequestBuilder::concurrency`].
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner = self.inner.concurrency(concurrency);
+        self
+    }
+
+    /// Sends the search requests and blocks until the result of every request is parsed, see
+    /// [`crate::endpoint::search::BulkSearchRequestBuilder::send`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send(self) -> Result<Vec<Result<SearchData>>> {
+        client::OpenFIGIClient::block_on(self.inner.send())
+    }
+
+    /// Sends each distinct search request once, see
+    /// [`crate::endpoint::search::BulkSearchRequestBuilder::send_deduplicated`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_deduplicated(self) -> Result<Deduplicated<SearchData>> {
+        client::OpenFIGIClient::block_on(self.inner.send_deduplicated())
+    }
+}
+
+/// Blocking variant of [`crate::endpoint::filter::SingleFilterRequestBuilder`].
+#[derive(Clone)]
+pub struct SingleFilterRequestBuilder {
+    inner: filter::SingleFilterRequestBuilder,
+}
+
+impl_filterable!(SingleFilterRequestBuilder);
+
+impl SingleFilterRequestBuilder {
+    /// Sets the filter query.
+    #[must_use]
+    pub fn query(mut self, query: &str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the pagination token of the page to fetch.
+    #[must_use]
+    pub fn start(mut self, start: &str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sends the filter request and blocks until the results are parsed, see
+    /// [`crate::endpoint::filter::SingleFilterRequestBuilder::send`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send(self) -> Result<FilterData> {
+        client::OpenFIGIClient::block_on(self.inner.send())
+    }
+
+    /// Follows the `next` token across all result pages and returns the collected results, see
+    /// [`crate::endpoint::filter::SingleFilterRequestBuilder::paginate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn paginate(self, max_results: Option<usize>) -> Result<Vec<FigiResult>> {
+        client::OpenFIGIClient::block_on(self.inner.paginate(max_results))
+    }
+
+    /// Follows the `next` token across all result pages and writes the results to the file at
+    /// `path` as newline-delimited JSON, see
+    /// [`crate::endpoint::filter::SingleFilterRequestBuilder::send_to_ndjson`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as the async
+    /// method, or if called from within an async runtime.
+    pub fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        client::OpenFIGIClient::block_on(self.inner.send_to_ndjson(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::enums::Currency;
+    use crate::test_utils::mock_client;
+
+    #[test]
+    fn test_blocking_mapping() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        let runtime = tokio::runtime::Runtime::new().expect("Should start runtime");
+        let server = runtime.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/v3/mapping"))
+                .and(body_partial_json(
+                    json!([{ "idValue": "IBM", "currency": "USD" }]),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!([{ "data": [{ "figi": "BBG000BLNNH6" }] }])),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+            server
+        });
+
+        let client: OpenFIGIClient = mock_client(&server).into();
+
+        let data = client
+            .mapping(IdType::TICKER, "IBM")
+            .currency(Currency::USD)
+            .expect_results(1..=1)
+            .send()
+            .expect("Blocking mapping should succeed");
+        assert_eq!(data.data()[0].figi, "BBG000BLNNH6");
+
+        runtime.block_on(server.verify());
+    }
+}
@@ -0,0 +1,262 @@
+//! # Request Budgets
+//!
+//! Hard caps on the number of requests a client sends per calendar day or month, protecting
+//! contractual request volumes against runaway loops.
+//!
+//! A [`RequestBudget`](crate::budget::RequestBudget) attached to a client counts every HTTP
+//! request sent through it, including each page of a paginated lookup. Once a cap is reached,
+//! requests fail fast with
+//! [`OpenFIGIError::BudgetExceeded`](crate::error::OpenFIGIError::BudgetExceeded) until the
+//! period ends. Periods are UTC calendar days and months. Retries performed by middleware of the
+//! HTTP client are not counted separately.
+//!
+//! If the `tracing` feature is enabled, every refused request emits a `tracing::warn!` event with
+//! the `period` and `limit` of the exhausted cap, which can be turned into a metric by a
+//! subscriber.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::budget::{BudgetPeriod, RequestBudget};
+//! use openfigi_rs::client::OpenFIGIClient;
+//!
+//! let budget = RequestBudget::new().per_day(10_000).per_month(200_000);
+//! let client = OpenFIGIClient::builder().budget(budget.clone()).build()?;
+//!
+//! assert_eq!(budget.remaining(BudgetPeriod::Day), Some(10_000));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::error::{OpenFIGIError, Result};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Calendar period of a [`RequestBudget`] cap, in UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BudgetPeriod {
+    /// A calendar day.
+    Day,
+    /// A calendar month.
+    Month,
+}
+
+impl BudgetPeriod {
+    /// Returns the first day of the period containing `now`.
+    fn start(self, now: DateTime<Utc>) -> NaiveDate {
+        let today = now.date_naive();
+        match self {
+            Self::Day => today,
+            Self::Month => today.with_day(1).unwrap_or(today),
+        }
+    }
+}
+
+impl fmt::Display for BudgetPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Day => "day",
+            Self::Month => "month",
+        })
+    }
+}
+
+/// A cap on the number of requests per period.
+#[derive(Debug)]
+struct Cap {
+    period: BudgetPeriod,
+    limit: u64,
+    /// First day of the period `used` refers to.
+    start: Option<NaiveDate>,
+    used: u64,
+}
+
+impl Cap {
+    /// Returns the number of requests counted in the period containing `now`.
+    fn used_at(&self, now: DateTime<Utc>) -> u64 {
+        if self.start == Some(self.period.start(now)) {
+            self.used
+        } else {
+            0
+        }
+    }
+}
+
+/// A shared count of requests, capped per day and/or month.
+///
+/// Enable a budget for a client via
+/// [`OpenFIGIClient::with_budget`](crate::client::OpenFIGIClient::with_budget) or
+/// [`crate::client_builder::OpenFIGIClientBuilder::budget`]. Clones share the same counts, so
+/// one budget can cap several clients together. A budget without caps never refuses requests.
+#[derive(Clone, Debug, Default)]
+pub struct RequestBudget {
+    caps: Arc<Mutex<Vec<Cap>>>,
+}
+
+impl RequestBudget {
+    /// Creates a new budget without caps.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caps the requests per UTC calendar day at `limit`, replacing any previous daily cap.
+    #[must_use]
+    pub fn per_day(self, limit: u64) -> Self {
+        self.cap(BudgetPeriod::Day, limit)
+    }
+
+    /// Caps the requests per UTC calendar month at `limit`, replacing any previous monthly cap.
+    #[must_use]
+    pub fn per_month(self, limit: u64) -> Self {
+        self.cap(BudgetPeriod::Month, limit)
+    }
+
+    /// Returns the cap for `period`, if any.
+    #[must_use]
+    pub fn limit(&self, period: BudgetPeriod) -> Option<u64> {
+        self.lock()
+            .iter()
+            .find(|cap| cap.period == period)
+            .map(|cap| cap.limit)
+    }
+
+    /// Returns the number of requests counted in the current `period`.
+    ///
+    /// Requests are only counted for periods with a cap, so this is `0` for other periods.
+    #[must_use]
+    pub fn used(&self, period: BudgetPeriod) -> u64 {
+        let now = Utc::now();
+        self.lock()
+            .iter()
+            .find(|cap| cap.period == period)
+            .map_or(0, |cap| cap.used_at(now))
+    }
+
+    /// Returns the number of requests left in the current `period`, or `None` if it has no cap.
+    #[must_use]
+    pub fn remaining(&self, period: BudgetPeriod) -> Option<u64> {
+        let now = Utc::now();
+        self.lock()
+            .iter()
+            .find(|cap| cap.period == period)
+            .map(|cap| cap.limit.saturating_sub(cap.used_at(now)))
+    }
+
+    /// Counts a request against every cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenFIGIError::BudgetExceeded`] without counting the request if any cap is
+    /// exhausted.
+    pub(crate) fn acquire(&self) -> Result<()> {
+        self.acquire_at(Utc::now())
+    }
+
+    fn acquire_at(&self, now: DateTime<Utc>) -> Result<()> {
+        let mut caps = self.lock();
+        if let Some(cap) = caps.iter().find(|cap| cap.used_at(now) >= cap.limit) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                period = %cap.period,
+                limit = cap.limit,
+                "OpenFIGI request budget exceeded"
+            );
+            return Err(OpenFIGIError::BudgetExceeded {
+                period: cap.period,
+                limit: cap.limit,
+            });
+        }
+        for cap in caps.iter_mut() {
+            let start = cap.period.start(now);
+            if cap.start != Some(start) {
+                cap.start = Some(start);
+                cap.used = 0;
+            }
+            cap.used += 1;
+        }
+        Ok(())
+    }
+
+    fn cap(self, period: BudgetPeriod, limit: u64) -> Self {
+        {
+            let mut caps = self.lock();
+            caps.retain(|cap| cap.period != period);
+            caps.push(Cap {
+                period,
+                limit,
+                start: None,
+                used: 0,
+            });
+        }
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Cap>> {
+        self.caps.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 12, 0, 0)
+            .single()
+            .expect("Should be a valid date")
+    }
+
+    #[test]
+    fn test_daily_cap_resets_next_day() {
+        let budget = RequestBudget::new().per_day(2);
+        let today = at(2024, 3, 15);
+
+        assert!(budget.acquire_at(today).is_ok());
+        assert!(budget.acquire_at(today).is_ok());
+        let err = budget
+            .acquire_at(today)
+            .expect_err("Third request should exceed the daily cap");
+        assert!(matches!(
+            err,
+            OpenFIGIError::BudgetExceeded {
+                period: BudgetPeriod::Day,
+                limit: 2
+            }
+        ));
+
+        assert!(budget.acquire_at(at(2024, 3, 16)).is_ok());
+    }
+
+    #[test]
+    fn test_refused_requests_are_not_counted() {
+        let budget = RequestBudget::new().per_day(10).per_month(1);
+        let today = at(2024, 3, 15);
+
+        assert!(budget.acquire_at(today).is_ok());
+        assert!(budget.acquire_at(at(2024, 3, 20)).is_err());
+        assert!(budget.acquire_at(at(2024, 4, 1)).is_ok());
+        assert_eq!(budget.lock()[0].used_at(at(2024, 4, 1)), 1);
+    }
+
+    #[test]
+    fn test_budget_without_caps() {
+        let budget = RequestBudget::new();
+        assert!(budget.acquire().is_ok());
+        assert_eq!(budget.remaining(BudgetPeriod::Day), None);
+        assert_eq!(budget.used(BudgetPeriod::Day), 0);
+    }
+
+    #[test]
+    fn test_clones_share_counts() {
+        let budget = RequestBudget::new().per_day(5);
+        let clone = budget.clone();
+        assert!(clone.acquire().is_ok());
+        assert_eq!(budget.used(BudgetPeriod::Day), 1);
+        assert_eq!(budget.remaining(BudgetPeriod::Day), Some(4));
+        assert_eq!(budget.limit(BudgetPeriod::Day), Some(5));
+    }
+}
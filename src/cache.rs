@@ -0,0 +1,292 @@
+//! # Response Caching
+//!
+//! Caching facilities that reduce the number of full responses transferred from the OpenFIGI API.
+//!
+//! ## Key Features
+//!
+//! - **Conditional requests**: `GET` responses carrying an `ETag` header are stored, and subsequent
+//!   `GET` requests to the same URL send an `If-None-Match` header
+//! - **Transparent revalidation**: A `304 Not Modified` answer is replaced by the stored response,
+//!   so callers always observe a complete `200 OK` response
+//! - **Shared storage**: The cache is cheap to clone and can be shared between multiple clients
+//!
+//! Refreshing metadata that rarely changes, such as the allowed values of an enum, therefore
+//! costs a round trip without a response body.
+//!
+//! A [`MappingCache`](crate::cache::MappingCache) additionally stores the outcomes of bulk
+//! mapping jobs, so identifiers mapped before are not sent again until their entry expires.
+//! Identifiers the API found no instrument for are cached with a separate, shorter TTL, since
+//! re-querying known-bad identifiers wastes quota but they may be listed later.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::cache::ETagCache;
+//! use openfigi_rs::client::OpenFIGIClient;
+//!
+//! let cache = ETagCache::new();
+//! let client = OpenFIGIClient::builder()
+//!     .etag_cache(cache.clone())
+//!     .build()?;
+//!
+//! assert!(cache.is_empty());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    error::{OpenFIGIError, Result},
+    model::{
+        request::{MappingJob, MappingRequest},
+        response::MappingData,
+    },
+    parse::API_WARNING_PREFIX,
+};
+use reqwest::{
+    StatusCode,
+    header::{HeaderMap, HeaderValue},
+};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+use url::Url;
+
+/// A response stored for conditional revalidation.
+#[derive(Clone, Debug)]
+pub(crate) struct CachedResponse {
+    /// The entity tag returned with the response
+    pub(crate) etag: HeaderValue,
+    /// The headers of the original response
+    pub(crate) headers: HeaderMap,
+    /// The raw body of the original response
+    pub(crate) body: Vec<u8>,
+}
+
+/// In-memory store of `ETag`-validated responses, keyed by request URL.
+///
+/// Only successful `GET` responses with an `ETag` header are stored. Enable the cache for a
+/// client via [`crate::client_builder::OpenFIGIClientBuilder::etag_cache`]. Clones share the
+/// same underlying storage.
+#[derive(Clone, Debug, Default)]
+pub struct ETagCache {
+    entries: Arc<Mutex<HashMap<Url, CachedResponse>>>,
+}
+
+impl ETagCache {
+    /// Creates a new, empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached responses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no responses are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the stored entity tag for the given URL, if any.
+    #[must_use]
+    pub fn etag(&self, url: &Url) -> Option<String> {
+        self.lock()
+            .get(url)
+            .and_then(|entry| entry.etag.to_str().ok())
+            .map(ToString::to_string)
+    }
+
+    /// Removes the cached response for the given URL, forcing a full refresh on the next request.
+    pub fn invalidate(&self, url: &Url) {
+        self.lock().remove(url);
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns a copy of the cached response for the given URL.
+    pub(crate) fn get(&self, url: &Url) -> Option<CachedResponse> {
+        self.lock().get(url).cloned()
+    }
+
+    /// Stores a response for the given URL, replacing any previous entry.
+    pub(crate) fn insert(&self, url: Url, response: CachedResponse) {
+        self.lock().insert(url, response);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Url, CachedResponse>> {
+        // The map stays consistent even if a holder panicked, so recover from poisoning
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Outcome of a mapping job stored in a [`MappingCache`].
+#[derive(Clone, Debug)]
+enum CachedOutcome {
+    /// The job succeeded with the given data
+    Found(MappingData),
+    /// The API found no instrument, with the message of its soft error
+    NotFound(String),
+}
+
+#[derive(Clone, Debug)]
+struct CachedMapping {
+    outcome: CachedOutcome,
+    expires_at: Instant,
+}
+
+/// In-memory store of bulk mapping job outcomes, keyed by request.
+///
+/// Successful jobs and jobs the API found no instrument for are stored, each with its own TTL;
+/// other errors are never cached. Requests are equal when they serialize to the same JSON, as
+/// in [`crate::endpoint::dedup`]. Enable the cache for a client via
+/// [`crate::client_builder::OpenFIGIClientBuilder::mapping_cache`]. Clones share the same
+/// underlying storage.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::cache::MappingCache;
+/// use openfigi_rs::client::OpenFIGIClient;
+/// use std::time::Duration;
+///
+/// let cache = MappingCache::new()
+///     .ttl(Duration::from_hours(12))
+///     .negative_ttl(Duration::from_mins(30));
+/// let client = OpenFIGIClient::builder()
+///     .mapping_cache(cache.clone())
+///     .build()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct MappingCache {
+    entries: Arc<Mutex<HashMap<String, CachedMapping>>>,
+    ttl: Duration,
+    negative_ttl: Duration,
+}
+
+impl Default for MappingCache {
+    /// An empty cache keeping results for 24 hours and not-found outcomes for one hour.
+    fn default() -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl: Duration::from_hours(24),
+            negative_ttl: Duration::from_hours(1),
+        }
+    }
+}
+
+impl MappingCache {
+    /// Creates a new, empty cache with the default TTLs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long successful job outcomes are kept.
+    #[must_use]
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how long outcomes the API found no instrument for are kept. `Duration::ZERO`
+    /// disables caching them.
+    #[must_use]
+    pub fn negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = ttl;
+        self
+    }
+
+    /// Returns the number of cached outcomes that have not expired.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let now = Instant::now();
+        self.lock()
+            .values()
+            .filter(|entry| entry.expires_at > now)
+            .count()
+    }
+
+    /// Returns true if no unexpired outcomes are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the cached outcome of `request`, forcing it to be sent on the next lookup.
+    pub fn invalidate(&self, request: &MappingRequest) {
+        if let Some(key) = Self::key(request) {
+            self.lock().remove(&key);
+        }
+    }
+
+    /// Removes all cached outcomes.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the cached outcome of `job`, evicting it if it has expired.
+    pub(crate) fn get(&self, job: &MappingJob<'_>) -> Option<Result<MappingData>> {
+        let key = Self::key(job)?;
+        let mut entries = self.lock();
+        let entry = entries.get(&key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.remove(&key);
+            return None;
+        }
+        Some(match &entry.outcome {
+            CachedOutcome::Found(data) => Ok(data.clone()),
+            CachedOutcome::NotFound(message) => Err(OpenFIGIError::response_error(
+                StatusCode::OK,
+                format!("{API_WARNING_PREFIX}{message}"),
+                String::new(),
+            )),
+        })
+    }
+
+    /// Stores the outcome of `job` if it succeeded or found no instrument.
+    pub(crate) fn insert(&self, job: &MappingJob<'_>, result: &Result<MappingData>) {
+        let (outcome, ttl) = match result {
+            Ok(data) => (CachedOutcome::Found(data.clone()), self.ttl),
+            Err(err) => match err.not_found_message() {
+                Some(message) => (
+                    CachedOutcome::NotFound(message.to_string()),
+                    self.negative_ttl,
+                ),
+                None => return,
+            },
+        };
+        let (Some(key), Some(expires_at)) = (Self::key(job), Instant::now().checked_add(ttl))
+        else {
+            return;
+        };
+        if !ttl.is_zero() {
+            self.lock().insert(
+                key,
+                CachedMapping {
+                    outcome,
+                    expires_at,
+                },
+            );
+        }
+    }
+
+    /// Returns the key of a request, its JSON as sent to the API.
+    fn key(request: &impl Serialize) -> Option<String> {
+        serde_json::to_string(request).ok()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedMapping>> {
+        // The map stays consistent even if a holder panicked, so recover from poisoning
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -49,15 +49,53 @@
 //! and examples refer to [`crate::client_builder::OpenFIGIClientBuilder`].
 
 use crate::{
-    API_KEY, DEFAULT_BASE_URL,
+    DEFAULT_BASE_URL,
+    archive::{Archiver, SharedArchiver},
+    budget::RequestBudget,
+    cache::{ETagCache, MappingCache},
     client_builder::OpenFIGIClientBuilder,
-    error::{OpenFIGIError, Result},
-    model::response::ResponseResult,
+    disambiguation::{Disambiguator, Venue},
+    error::{OpenFIGIError, ReasonCode, Result},
+    maintenance::MaintenanceSchedule,
+    model::response::{FigiResult, ResponseResult},
+    observer::{RequestObserver, RequestObservers},
+    parse,
+    policy::Policy,
+    rate_limit::{RateLimitPolicy, RateLimitStatus},
+    replay::ReplayMode,
     request_builder::OpenFIGIRequestBuilder,
+    trace::ResolutionTrace,
+    validation::{ValidationRule, ValidationRules},
 };
+use reqwest::header::{HeaderMap, HeaderName};
 use reqwest_middleware::ClientWithMiddleware;
 use serde::de::DeserializeOwned;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex, OnceLock, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use url::Url;
+#[cfg(feature = "tokio-runtime")]
+use {
+    crate::error::OtherErrorKind,
+    std::{io, sync::LazyLock},
+    tokio::runtime::Runtime,
+};
+
+/// Runtime driving the futures passed to [`OpenFIGIClient::block_on`].
+#[cfg(feature = "tokio-runtime")]
+static BLOCKING_RUNTIME: LazyLock<io::Result<Runtime>> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("openfigi-block-on")
+        .enable_all()
+        .build()
+});
 
 /// HTTP client for making requests to the OpenFIGI API.
 ///
@@ -102,8 +140,43 @@ pub struct OpenFIGIClient {
     client: ClientWithMiddleware,
     base_url: Url,
     api_key: Option<String>,
+    etag_cache: Option<ETagCache>,
+    mapping_cache: Option<MappingCache>,
+    validation_rules: ValidationRules,
+    observers: RequestObservers,
+    policy: Policy,
+    exchange_preferences: Vec<Venue>,
+    captured_headers: Vec<HeaderName>,
+    status_reasons: HashMap<reqwest::StatusCode, ReasonCode>,
+    resolution_trace: Option<ResolutionTrace>,
+    paused: Arc<AtomicBool>,
+    budget: Option<RequestBudget>,
+    maintenance: Option<MaintenanceSchedule>,
+    rate_limit: Option<RateLimitPolicy>,
+    /// Policy pacing the convenience calls of a client without a rate limit, created on first
+    /// use and shared by its clones.
+    auto_rate_limit: Arc<OnceLock<RateLimitPolicy>>,
+    rate_limit_status: Arc<Mutex<Option<RateLimitStatus>>>,
+    in_flight: Option<InFlightLimit>,
+    replay: Option<ReplayMode>,
+    archiver: Option<SharedArchiver>,
 }
 
+/// Shared limit on the number of requests of a client and its clones in flight at once.
+#[derive(Clone, Debug)]
+struct InFlightLimit {
+    max: usize,
+    permits: Arc<Semaphore>,
+}
+
+/// Slot of a request counted against the in-flight limit, released once dropped.
+///
+/// Stored in the extensions of the response, so the slot is held until its body is consumed.
+#[derive(Clone, Debug)]
+pub(crate) struct InFlightPermit(
+    #[expect(dead_code, reason = "held until dropped")] Arc<OwnedSemaphorePermit>,
+);
+
 impl Default for OpenFIGIClient {
     /// Create a new [`crate::client::OpenFIGIClient`] with default settings.
     ///
@@ -113,11 +186,29 @@ impl Default for OpenFIGIClient {
     /// Uses a default `ClientWithMiddleware` and attempts to read the API key
     /// from the `OPENFIGI_API_KEY` environment variable.
     fn default() -> Self {
-        let api_key = API_KEY.as_ref().map(std::string::ToString::to_string);
+        let api_key = crate::env_api_key();
         Self {
             client: ClientWithMiddleware::default(),
             base_url: DEFAULT_BASE_URL.clone(),
             api_key,
+            etag_cache: None,
+            mapping_cache: None,
+            validation_rules: ValidationRules::new(),
+            observers: RequestObservers::default(),
+            policy: Policy::new(),
+            exchange_preferences: Vec::new(),
+            captured_headers: Vec::new(),
+            status_reasons: HashMap::new(),
+            resolution_trace: None,
+            paused: Arc::default(),
+            budget: None,
+            maintenance: None,
+            rate_limit: None,
+            auto_rate_limit: Arc::default(),
+            rate_limit_status: Arc::default(),
+            in_flight: None,
+            replay: None,
+            archiver: None,
         }
     }
 }
@@ -143,6 +234,47 @@ impl OpenFIGIClient {
         Self::default()
     }
 
+    /// Create a new [`crate::client::OpenFIGIClient`] with recommended production defaults.
+    ///
+    /// Like [`Self::new`], reads the API key from the `OPENFIGI_API_KEY` environment variable,
+    /// and additionally
+    ///
+    /// - times out requests after 60 seconds and connection attempts after 10 seconds,
+    /// - retries requests rejected with `429` or `503` after the delay announced by the API,
+    ///   see [`crate::middleware::RateLimitRetry`],
+    /// - delays requests to stay within the published rate limits of the key tier, see
+    ///   [`RateLimitPolicy::auto`].
+    ///
+    /// If the `tracing` feature is enabled, every request is traced as described in the
+    /// feature table of the crate documentation. Use [`Self::builder`] to pick the settings
+    /// individually.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::quickstart()?;
+    /// assert!(client.rate_limit().is_some());
+    /// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpenFIGIError`] if the HTTP client cannot be initialized, e.g. because
+    /// the TLS backend is unavailable.
+    pub fn quickstart() -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_mins(1))
+            .connect_timeout(Duration::from_secs(10))
+            .build()?;
+        Self::builder()
+            .reqwest_client(http)
+            .with_default_retries()
+            .rate_limit(RateLimitPolicy::auto())
+            .build()
+    }
+
     /// Create a new [`crate::client::OpenFIGIClient`] from individual components.
     ///
     /// Provides maximum control over client configuration. Primarily used
@@ -177,6 +309,24 @@ impl OpenFIGIClient {
             client,
             base_url,
             api_key,
+            etag_cache: None,
+            mapping_cache: None,
+            validation_rules: ValidationRules::new(),
+            observers: RequestObservers::default(),
+            policy: Policy::new(),
+            exchange_preferences: Vec::new(),
+            captured_headers: Vec::new(),
+            status_reasons: HashMap::new(),
+            resolution_trace: None,
+            paused: Arc::default(),
+            budget: None,
+            maintenance: None,
+            rate_limit: None,
+            auto_rate_limit: Arc::default(),
+            rate_limit_status: Arc::default(),
+            in_flight: None,
+            replay: None,
+            archiver: None,
         }
     }
 
@@ -281,6 +431,696 @@ impl OpenFIGIClient {
         self.api_key.is_some()
     }
 
+    /// Returns the `ETag` cache used for conditional `GET` requests, if enabled.
+    ///
+    /// See [`crate::cache::ETagCache`] for details.
+    #[must_use]
+    pub fn etag_cache(&self) -> Option<&ETagCache> {
+        self.etag_cache.as_ref()
+    }
+
+    /// Returns a new client that uses the given `ETag` cache for conditional `GET` requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::cache::ETagCache;
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new().with_etag_cache(ETagCache::new());
+    /// assert!(client.etag_cache().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_etag_cache(mut self, cache: ETagCache) -> Self {
+        self.etag_cache = Some(cache);
+        self
+    }
+
+    /// Returns the cache of bulk mapping job outcomes, if enabled.
+    ///
+    /// See [`crate::cache::MappingCache`] for details.
+    #[must_use]
+    pub fn mapping_cache(&self) -> Option<&MappingCache> {
+        self.mapping_cache.as_ref()
+    }
+
+    /// Returns a new client that serves bulk mapping jobs from the given cache while their
+    /// outcomes have not expired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::cache::MappingCache;
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new().with_mapping_cache(MappingCache::new());
+    /// assert!(client.mapping_cache().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_mapping_cache(mut self, cache: MappingCache) -> Self {
+        self.mapping_cache = Some(cache);
+        self
+    }
+
+    /// Returns the custom validation rules run for every request sent through the endpoint builders.
+    ///
+    /// See [`crate::validation`] for details.
+    #[must_use]
+    pub fn validation_rules(&self) -> &ValidationRules {
+        &self.validation_rules
+    }
+
+    /// Returns a new client that additionally runs `rule` for every request sent through the
+    /// endpoint builders.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::request::RequestFilters;
+    ///
+    /// let client = OpenFIGIClient::new().with_validation_rule(|_: &RequestFilters| Ok(()));
+    /// assert_eq!(client.validation_rules().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
+    }
+
+    /// Returns a new client that runs the given set of custom validation rules.
+    #[must_use]
+    pub(crate) fn with_validation_rules(mut self, rules: ValidationRules) -> Self {
+        self.validation_rules = rules;
+        self
+    }
+
+    /// Returns the number of observers notified about every request sent through this client.
+    ///
+    /// See [`crate::observer`] for details.
+    #[must_use]
+    pub fn observer_count(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Returns a new client that additionally notifies `observer` about every request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::observer::{RequestInfo, RequestObserver};
+    ///
+    /// struct Log;
+    ///
+    /// impl RequestObserver for Log {
+    ///     fn on_request(&self, request: &RequestInfo) {
+    ///         println!("{} {}", request.method, request.endpoint);
+    ///     }
+    /// }
+    ///
+    /// let client = OpenFIGIClient::new().with_observer(Log);
+    /// assert_eq!(client.observer_count(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Returns a new client that notifies the given observers.
+    #[must_use]
+    pub(crate) fn with_observers(mut self, observers: RequestObservers) -> Self {
+        self.observers = observers;
+        self
+    }
+
+    /// Returns the observers notified about every request sent through this client.
+    pub(crate) fn observers(&self) -> &RequestObservers {
+        &self.observers
+    }
+
+    /// Returns the compliance policy applied to the results of every request sent through the
+    /// endpoint builders.
+    ///
+    /// See [`crate::policy`] for details.
+    #[must_use]
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Returns a new client applying `policy` to the results of every request sent through the
+    /// endpoint builders, replacing any previously set policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::ExchCode;
+    /// use openfigi_rs::policy::Policy;
+    ///
+    /// let client = OpenFIGIClient::new().with_policy(Policy::new().deny_exch_codes([ExchCode::US]));
+    /// assert!(!client.policy().is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the venues preferred by [`disambiguator`](Self::disambiguator) and
+    /// [`best_match`](Self::best_match), earlier venues over later ones.
+    #[must_use]
+    pub fn exchange_preferences(&self) -> &[Venue] {
+        &self.exchange_preferences
+    }
+
+    /// Returns a new client preferring the given venues when disambiguating results, replacing
+    /// any previously set preferences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::disambiguation::Venue;
+    /// use openfigi_rs::model::enums::{ExchCode, MicCode};
+    ///
+    /// let client = OpenFIGIClient::new()
+    ///     .with_exchange_preferences([Venue::Exch(ExchCode::US), Venue::Mic(MicCode::XNYS)]);
+    /// assert_eq!(client.exchange_preferences().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_exchange_preferences<V: Into<Venue>>(
+        mut self,
+        venues: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.exchange_preferences = venues.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the names of the response headers captured into errors.
+    #[must_use]
+    pub fn captured_headers(&self) -> &[HeaderName] {
+        &self.captured_headers
+    }
+
+    /// Returns a new client capturing the given response headers into errors, replacing any
+    /// previously set headers.
+    ///
+    /// Captured headers are available via [`OpenFIGIError::headers`] on errors created from a
+    /// response, e.g. request ids added by gateways for support tickets with the data vendor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::exports::HeaderName;
+    ///
+    /// let client = OpenFIGIClient::new().with_captured_headers([
+    ///     HeaderName::from_static("x-request-id"),
+    ///     HeaderName::from_static("ratelimit-reset"),
+    /// ]);
+    /// assert_eq!(client.captured_headers().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_captured_headers(mut self, names: impl IntoIterator<Item = HeaderName>) -> Self {
+        self.captured_headers = names.into_iter().collect();
+        self
+    }
+
+    /// Returns the reasons registered for response statuses, see [`Self::with_status_reason`].
+    #[must_use]
+    pub fn status_reasons(&self) -> &HashMap<reqwest::StatusCode, ReasonCode> {
+        &self.status_reasons
+    }
+
+    /// Returns a new client classifying error responses with `status` as `reason`.
+    ///
+    /// The reason is attached to errors created from such responses and returned by
+    /// [`OpenFIGIError::reason_code`] instead of the built-in classification, e.g. for gateways
+    /// answering blocked requests with a custom status. Registering a status again replaces its
+    /// reason.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::error::ReasonCode;
+    /// use openfigi_rs::exports::StatusCode;
+    ///
+    /// let client = OpenFIGIClient::new()
+    ///     .with_status_reason(StatusCode::CONFLICT, ReasonCode::RateLimited);
+    /// assert_eq!(
+    ///     client.status_reasons().get(&StatusCode::CONFLICT),
+    ///     Some(&ReasonCode::RateLimited)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_status_reason(mut self, status: reqwest::StatusCode, reason: ReasonCode) -> Self {
+        self.status_reasons.insert(status, reason);
+        self
+    }
+
+    /// Returns a [`Disambiguator`] preferring the client's
+    /// [`exchange_preferences`](Self::exchange_preferences).
+    ///
+    /// Further rules can be added to the returned disambiguator; they apply after the
+    /// exchange preferences. If the client has a [resolution trace](Self::resolution_trace),
+    /// the disambiguator records its selections in it.
+    #[must_use]
+    pub fn disambiguator(&self) -> Disambiguator {
+        let disambiguator =
+            Disambiguator::new().prefer_venues(self.exchange_preferences.iter().cloned());
+        match &self.resolution_trace {
+            Some(trace) => disambiguator.with_trace(trace.clone()),
+            None => disambiguator,
+        }
+    }
+
+    /// Returns the trace recording the requests sent through this client, if enabled.
+    ///
+    /// See [`crate::trace`] for details.
+    #[must_use]
+    pub fn resolution_trace(&self) -> Option<&ResolutionTrace> {
+        self.resolution_trace.as_ref()
+    }
+
+    /// Returns a new client recording its requests and the selections of its
+    /// [disambiguators](Self::disambiguator) in `trace`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::trace::ResolutionTrace;
+    ///
+    /// let trace = ResolutionTrace::new();
+    /// let client = OpenFIGIClient::new().with_resolution_trace(trace.clone());
+    /// assert!(client.resolution_trace().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_resolution_trace(mut self, trace: ResolutionTrace) -> Self {
+        self.resolution_trace = Some(trace);
+        self
+    }
+
+    /// Pauses the client, refusing all requests of this client and its clones.
+    ///
+    /// Intended as a kill switch to stop an application's OpenFIGI traffic centrally, e.g. when
+    /// quota abuse or upstream issues are detected. While paused, every request fails fast with
+    /// [`OpenFIGIError::ClientPaused`] without
+    /// network traffic. Requests already in flight are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let worker = client.clone();
+    ///
+    /// client.pause();
+    /// assert!(worker.is_paused());
+    ///
+    /// client.resume();
+    /// assert!(!worker.is_paused());
+    /// ```
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resumes a client paused with [`Self::pause`].
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns true if the client is paused, see [`Self::pause`].
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Returns the budget capping the requests sent through this client, if enabled.
+    ///
+    /// See [`crate::budget`] for details.
+    #[must_use]
+    pub fn budget(&self) -> Option<&RequestBudget> {
+        self.budget.as_ref()
+    }
+
+    /// Returns a new client counting its requests against `budget`, refusing requests with
+    /// [`OpenFIGIError::BudgetExceeded`] once a cap is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::budget::RequestBudget;
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new().with_budget(RequestBudget::new().per_day(10_000));
+    /// assert!(client.budget().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_budget(mut self, budget: RequestBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Returns the maintenance windows during which this client defers or refuses requests, if
+    /// any.
+    ///
+    /// See [`crate::maintenance`] for details.
+    #[must_use]
+    pub fn maintenance(&self) -> Option<&MaintenanceSchedule> {
+        self.maintenance.as_ref()
+    }
+
+    /// Returns a new client deferring or refusing its requests during the windows of `schedule`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::maintenance::MaintenanceSchedule;
+    ///
+    /// let schedule = MaintenanceSchedule::new().window("sun 02:00 2h".parse()?);
+    /// let client = OpenFIGIClient::new().with_maintenance(schedule);
+    /// assert_eq!(client.maintenance().map(|s| s.windows().len()), Some(1));
+    /// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+    /// ```
+    #[must_use]
+    pub fn with_maintenance(mut self, schedule: MaintenanceSchedule) -> Self {
+        self.maintenance = Some(schedule);
+        self
+    }
+
+    /// Returns the policy limiting the request rate of this client, if enabled.
+    ///
+    /// See [`crate::rate_limit`] for details.
+    #[must_use]
+    pub fn rate_limit(&self) -> Option<&RateLimitPolicy> {
+        self.rate_limit.as_ref()
+    }
+
+    /// Returns a new client delaying its requests to stay within the limits of `policy`.
+    ///
+    /// The limits of a [`RateLimitPolicy::auto`] policy are chosen depending on whether this
+    /// client has an API key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::rate_limit::RateLimitPolicy;
+    ///
+    /// let client = OpenFIGIClient::new().with_rate_limit(RateLimitPolicy::auto());
+    /// assert!(client.rate_limit().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_rate_limit(mut self, policy: RateLimitPolicy) -> Self {
+        policy.resolve(self.has_api_key());
+        self.rate_limit = Some(policy);
+        self
+    }
+
+    /// Returns the rate limit of this client, or else the [`RateLimitPolicy::auto`] policy it
+    /// and its clones share for calls pacing their requests by default.
+    pub(crate) fn rate_limit_or_auto(&self) -> RateLimitPolicy {
+        self.rate_limit.clone().unwrap_or_else(|| {
+            self.auto_rate_limit
+                .get_or_init(|| {
+                    let policy = RateLimitPolicy::auto();
+                    policy.resolve(self.has_api_key());
+                    policy
+                })
+                .clone()
+        })
+    }
+
+    /// Returns the rate limit state announced by the API in the last response received by this
+    /// client or its clones, if any response carried rate limit headers.
+    ///
+    /// See [`crate::rate_limit`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// client.search("ibm").send().await?;
+    /// if let Some(status) = client.rate_limit_status()
+    ///     && status.is_exhausted()
+    /// {
+    ///     println!("Rate limit exhausted, resets in {:?}", status.reset());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limit_status
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Captures the rate limit headers of a response, keeping the previous state without them.
+    pub(crate) fn update_rate_limit_status(&self, headers: &HeaderMap) {
+        if let Some(status) = RateLimitStatus::from_headers(headers) {
+            *self
+                .rate_limit_status
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(status);
+        }
+    }
+
+    /// Returns the maximum number of requests in flight at once, if limited.
+    #[must_use]
+    pub fn max_concurrent_requests(&self) -> Option<usize> {
+        self.in_flight.as_ref().map(|limit| limit.max)
+    }
+
+    /// Returns a new client sending at most `max` requests at once; `0` is treated as `1`.
+    ///
+    /// Further requests wait until a request in flight completes, which protects small proxies
+    /// in front of the API and bounds the memory of large responses received at the same time.
+    /// A request is in flight from sending it until its response body is read, or until the
+    /// response is dropped. The limit is shared with clones of the returned client and applies
+    /// independently of a rate limit (see [`crate::rate_limit`]); responses served from a replay
+    /// store do not count against it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new().with_max_concurrent_requests(4);
+    /// assert_eq!(client.max_concurrent_requests(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        let max = max.max(1);
+        self.in_flight = Some(InFlightLimit {
+            max,
+            permits: Arc::new(Semaphore::new(max)),
+        });
+        self
+    }
+
+    /// Waits until fewer than the maximum number of requests are in flight, then returns the
+    /// slot of a new request, if the number of requests in flight is limited.
+    pub(crate) async fn acquire_in_flight(&self) -> Option<InFlightPermit> {
+        let limit = self.in_flight.as_ref()?;
+        let permit = Arc::clone(&limit.permits).acquire_owned().await.ok()?;
+        Some(InFlightPermit(Arc::new(permit)))
+    }
+
+    /// Returns whether this client records or replays responses, if enabled.
+    ///
+    /// See [`crate::replay`] for details.
+    #[must_use]
+    pub fn replay(&self) -> Option<&ReplayMode> {
+        self.replay.as_ref()
+    }
+
+    /// Returns a new client recording responses into, or replaying responses from, a store.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::replay::{ReplayMode, ReplayStore};
+    ///
+    /// let client = OpenFIGIClient::new().with_replay(ReplayMode::Record(ReplayStore::new()));
+    /// assert!(client.replay().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_replay(mut self, mode: ReplayMode) -> Self {
+        self.replay = Some(mode);
+        self
+    }
+
+    /// Returns the archiver receiving every request and response, if enabled.
+    ///
+    /// See [`crate::archive`] for details.
+    #[must_use]
+    pub fn archiver(&self) -> Option<&dyn Archiver> {
+        self.archiver.as_ref().map(SharedArchiver::get)
+    }
+
+    /// Returns a new client passing every request and its response to `archiver`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::archive::FileArchiver;
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new().with_archiver(FileArchiver::new("archive"));
+    /// assert!(client.archiver().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_archiver(mut self, archiver: impl Archiver + 'static) -> Self {
+        self.archiver = Some(SharedArchiver::new(archiver));
+        self
+    }
+
+    /// Returns a new client passing every request and its response to a shared archiver.
+    pub(crate) fn with_shared_archiver(mut self, archiver: SharedArchiver) -> Self {
+        self.archiver = Some(archiver);
+        self
+    }
+
+    /// Returns the archiver of this client and its clones, if enabled.
+    pub(crate) fn shared_archiver(&self) -> Option<&SharedArchiver> {
+        self.archiver.as_ref()
+    }
+
+    /// Returns the result listed on the most preferred venue of the client's
+    /// [`exchange_preferences`](Self::exchange_preferences), or the first result if none is
+    /// listed on a preferred venue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::ExchCode;
+    /// use openfigi_rs::model::response::FigiResult;
+    ///
+    /// let results: Vec<FigiResult> = serde_json::from_str(
+    ///     r#"[{"figi": "BBG000BLNQ16", "exchCode": "UN"}, {"figi": "BBG000BLNNH6", "exchCode": "US"}]"#,
+    /// )?;
+    /// let client = OpenFIGIClient::new().with_exchange_preferences([ExchCode::US]);
+    ///
+    /// let best = client.best_match(&results).expect("non-empty results");
+    /// assert_eq!(best.figi, "BBG000BLNNH6");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn best_match<'a>(&self, results: &'a [FigiResult]) -> Option<&'a FigiResult> {
+        self.disambiguator().best_match(results)
+    }
+
+    /// Sends a raw request to an arbitrary API path and returns the unprocessed response.
+    ///
+    /// This is a low-level escape hatch for calling endpoints that the crate does not
+    /// (yet) support. The request is sent through the same HTTP client and middleware
+    /// stack as all other requests, and the `X-OPENFIGI-APIKEY` header is added if an
+    /// API key is configured. The path is resolved against [`Self::base_url`].
+    ///
+    /// The response status is not checked and the body is not parsed; both are left to
+    /// the caller. For typed access prefer implementing [`crate::endpoint::Endpoint`]
+    /// and calling [`Self::execute`].
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method to use for the request
+    /// * `path` - The endpoint path relative to the base URL (e.g., "mapping/values/exchCode")
+    /// * `json_body` - An optional JSON request body
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the URL cannot be constructed or if
+    /// the HTTP request fails to be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use reqwest::Method;
+    /// use serde_json::json;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let response = client
+    ///     .raw_request(
+    ///         Method::POST,
+    ///         "mapping",
+    ///         Some(json!([{"idType": "TICKER", "idValue": "IBM"}])),
+    ///     )
+    ///     .await?;
+    /// println!("Status: {}", response.status());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn raw_request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        json_body: Option<serde_json::Value>,
+    ) -> Result<reqwest::Response> {
+        let request = self.request(path, method);
+        match json_body {
+            Some(body) => request.body(&body).send().await,
+            None => request.send().await,
+        }
+    }
+
+    /// Runs a future of this crate to completion from synchronous code.
+    ///
+    /// Intended for synchronous code paths that only need a few lookups, such as scripts or
+    /// worker threads of a thread pool, without setting up a runtime. The future is driven by a
+    /// runtime shared by all callers, which is started on first use, so pooled connections are
+    /// reused across calls. Calls from several threads may run concurrently.
+    ///
+    /// Requires the `tokio-runtime` feature, which is enabled by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the future, or an [`OpenFIGIError::OtherError`] if called from
+    /// within an async runtime, where blocking would stall the runtime's worker thread, or
+    /// if the shared runtime cannot be started.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let results = OpenFIGIClient::block_on(client.search("apple").send())?;
+    /// println!("Found {} results", results.data().len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "tokio-runtime")]
+    pub fn block_on<T>(future: impl Future<Output = Result<T>>) -> Result<T> {
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Other,
+                "OpenFIGIClient::block_on cannot be called from within an async runtime; await the future instead",
+            ));
+        }
+
+        match &*BLOCKING_RUNTIME {
+            Ok(runtime) => runtime.block_on(future),
+            Err(err) => Err(OpenFIGIError::other_error(
+                OtherErrorKind::Other,
+                format!("Failed to start runtime for OpenFIGIClient::block_on: {err}"),
+            )),
+        }
+    }
+
     #[doc(hidden)]
     /// Creates a request builder for the specified endpoint path and HTTP method.
     ///
@@ -300,40 +1140,40 @@ impl OpenFIGIClient {
         OpenFIGIRequestBuilder::new(self.clone(), method, path)
     }
 
-    #[doc(hidden)]
-    /// Parses single HTTP responses with comprehensive OpenFIGI-specific error handling.
+    /// Parses the response of a request returning a single result, e.g. from `send_raw()`.
     ///
-    /// This internal method processes HTTP responses from the OpenFIGI API, providing
-    /// detailed error context and automatic rate limit detection. It converts successful
-    /// responses to the expected type and transforms error responses into meaningful
-    /// [`crate::error::OpenFIGIError`] instances.
+    /// Applies the same handling as the `send()` methods of the request builders, so callers
+    /// using `send_raw()` for access to headers or status codes can reuse it instead of
+    /// reimplementing it. Successful responses are deserialized into `T`, with byte order
+    /// marks stripped and invalid UTF-8 decoded lossily, and error responses are converted
+    /// into an [`OpenFIGIError`] carrying the status, the error body, the captured headers and,
+    /// for `429 Too Many Requests`, the rate limit headers (`ratelimit-policy`,
+    /// `ratelimit-reset`) in its message. The client policy and the warning log of the
+    /// `send()` methods are not applied.
     ///
-    /// ## Error Handling by Status Code
-    ///
-    /// - **200 OK**: Successfully deserializes response to type `T`
-    /// - **400 Bad Request**: Invalid request format or missing required fields
-    /// - **401 Unauthorized**: Missing or invalid API key
-    /// - **404 Not Found**: Requested resource not found
-    /// - **405 Method Not Allowed**: HTTP method not supported for endpoint
-    /// - **406 Not Acceptable**: Unsupported Accept header
-    /// - **413 Payload Too Large**: Too many requests in batch (max 100 with API key, 5 without)
-    /// - **429 Too Many Requests**: Rate limit exceeded, includes retry timing
-    /// - **500 Internal Server Error**: Resend the request later with an exponential backoff strategy
-    /// - **503**: Service temporarily unavailable
-    ///
-    /// ## Rate Limiting
+    /// # Examples
     ///
-    /// When rate limit headers are present (`X-RateLimit-Remaining`, `X-RateLimit-Reset`),
-    /// they are automatically parsed and included in error messages to help with retry logic.
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::response::SearchData;
     ///
-    /// # Arguments
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let response = client.search("ibm").send_raw().await?;
+    /// let remaining = response.headers().get("ratelimit-remaining").cloned();
     ///
-    /// * `response` - The HTTP response from the OpenFIGI API
+    /// let data: SearchData = client.parse_single_response(response).await?;
+    /// println!("{} results, rate limit remaining: {remaining:?}", data.data().len());
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `Ok(T)` for successful responses or `Err(OpenFIGIError)` with detailed context.
-    pub(crate) async fn parse_single_response<T: DeserializeOwned>(
+    /// Returns an [`OpenFIGIError`] if the response has an error status, if its body cannot be
+    /// read or deserialized into `T`, or if the body is an `{"error": "..."}` or
+    /// `{"warning": "..."}` payload.
+    pub async fn parse_single_response<T: DeserializeOwned>(
         &self,
         response: reqwest::Response,
     ) -> Result<T> {
@@ -341,49 +1181,60 @@ impl OpenFIGIClient {
 
         // Early return for success case to optimize the common path
         if status.is_success() {
-            // Deserialize the response body into the expected type `T`
-            let parsed_response: ResponseResult<T> =
-                response.json().await.map_err(OpenFIGIError::from)?;
-
-            // Check if the response contains an error
-            match parsed_response {
-                ResponseResult::Success(data) => return Ok(data),
-                ResponseResult::Error(err) => {
-                    // If the response contains an error, convert it to OpenFIGIError
-                    return Err(OpenFIGIError::response_error(
-                        status,
-                        format!("OpenFIGI API error: {}", err.error),
-                        String::new(),
-                    ));
-                }
-            }
+            let headers = self.capture_headers(response.headers());
+
+            // Deserialize the response body into the expected type `T`, converting an error
+            // payload into an OpenFIGIError
+            return Self::read_json(response)
+                .await
+                .and_then(|parsed_response: ResponseResult<T>| {
+                    parse::into_result(status, parsed_response)
+                })
+                .map_err(|err| err.with_headers(headers));
         }
 
         return Err(self.handle_error_response(response).await);
     }
 
-    #[doc(hidden)]
-    /// Parses a list of HTTP responses from the OpenFIGI API, handling both successes and errors for batch mapping requests.
+    /// Parses the response of a bulk request returning one result per job, e.g. from the
+    /// `send_raw()` method of a bulk mapping.
     ///
-    /// This internal method processes HTTP responses that return a list of results (such as batch mapping requests).
-    /// Each item in the returned vector is a `Result<T, OpenFIGIError>`, where:
-    /// - `Ok(T)` contains a successfully parsed mapping result
-    /// - `Err(OpenFIGIError)` contains a detailed error for a failed mapping request
+    /// Applies the same handling as [`Self::parse_single_response`] to the response, and
+    /// converts every job into its own result, so jobs reported as errors or warnings by the
+    /// API do not fail the whole batch. The results are in the order of the jobs.
     ///
-    /// # Arguments
-    ///
-    /// * `response` - The HTTP response from the OpenFIGI API
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::IdType;
+    /// use openfigi_rs::model::request::MappingRequest;
+    /// use openfigi_rs::model::response::MappingData;
     ///
-    /// Returns `Ok(Vec<Result<T, OpenFIGIError>>)` for successful HTTP responses, where each item is either a success or error for the corresponding batch request.
-    /// Returns `Err(OpenFIGIError)` if the HTTP response itself is an error (e.g., network failure, invalid status code).
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let response = client
+    ///     .bulk_mapping()
+    ///     .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+    ///     .send_raw()
+    ///     .await?;
+    ///
+    /// let results: Vec<_> = client.parse_list_response::<MappingData>(response).await?;
+    /// for result in results {
+    ///     match result {
+    ///         Ok(data) => println!("{} FIGIs", data.data().len()),
+    ///         Err(err) => println!("Job failed: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// # Error Handling
+    /// # Errors
     ///
-    /// - Each batch item is parsed and mapped to either a success or error variant.
-    /// - If the HTTP response is not successful, a single `OpenFIGIError` is returned for the entire batch.
-    pub(crate) async fn parse_list_response<T: DeserializeOwned>(
+    /// Returns an [`OpenFIGIError`] for the whole batch if the response has an error status,
+    /// or if its body cannot be read or is not a list of job results.
+    pub async fn parse_list_response<T: DeserializeOwned>(
         &self,
         response: reqwest::Response,
     ) -> Result<Vec<Result<T>>> {
@@ -391,21 +1242,17 @@ impl OpenFIGIClient {
 
         // Early return for success case to optimize the common path
         if response.status().is_success() {
+            let headers = self.capture_headers(response.headers());
+
             // Deserialize the response body into the expected type `T`
-            let parsed_list: Vec<ResponseResult<T>> =
-                response.json().await.map_err(OpenFIGIError::from)?;
+            let parsed_list: Vec<ResponseResult<T>> = Self::read_json(response)
+                .await
+                .map_err(|err| err.with_headers(headers))?;
 
             // Transform the parsed list into a `Result<T, OpenFIGIError>`.
             let results: Vec<Result<T>> = parsed_list
                 .into_iter()
-                .map(|item| match item {
-                    ResponseResult::Success(data) => Ok(data),
-                    ResponseResult::Error(err) => Err(OpenFIGIError::response_error(
-                        status,
-                        format!("OpenFIGI API error: {}", err.error),
-                        String::new(),
-                    )),
-                })
+                .map(|item| parse::into_result(status, item))
                 .collect();
 
             return Ok(results);
@@ -418,6 +1265,7 @@ impl OpenFIGIClient {
     async fn handle_error_response(&self, response: reqwest::Response) -> OpenFIGIError {
         let status = response.status();
         let url = response.url().clone();
+        let headers = self.capture_headers(response.headers());
 
         // Rate-Limit-Info nur bei 429 extrahieren
         let rate_limit_info = if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
@@ -426,13 +1274,60 @@ impl OpenFIGIClient {
             None
         };
 
+        let reason = self.status_reasons.get(&status).copied();
+
         // Handle different HTTP status codes with OpenFIGI-specific context
-        let error_message = Self::format_error_message(status, &url, rate_limit_info);
+        let error_message = Self::format_error_message(status, &url, rate_limit_info, reason);
 
-        // Use `unwrap_or_default` to avoid panics if text cannot be read
-        let resp_text = response.text().await.unwrap_or_default();
+        let content_type = Self::content_type(&response);
+
+        // Use `unwrap_or_default` to avoid panics if the body cannot be read
+        let bytes = response.bytes().await.unwrap_or_default();
+        let (resp_text, lossy) = parse::decode_body(&content_type, &bytes);
+
+        // HTML error pages originate from intermediaries rather than the OpenFIGI API
+        if parse::is_html(&content_type, &resp_text) {
+            return OpenFIGIError::unexpected_content_type(status, content_type, &resp_text)
+                .with_headers(headers)
+                .with_reason(reason);
+        }
 
         OpenFIGIError::response_error(status, error_message, resp_text)
+            .with_lossy_body(lossy)
+            .with_headers(headers)
+            .with_reason(reason)
+    }
+
+    /// Returns the headers of a response listed in [`Self::captured_headers`].
+    fn capture_headers(&self, headers: &HeaderMap) -> HeaderMap {
+        let mut captured = HeaderMap::new();
+        for name in &self.captured_headers {
+            for value in headers.get_all(name) {
+                captured.append(name.clone(), value.clone());
+            }
+        }
+        captured
+    }
+
+    /// Reads the response body and deserializes it from JSON.
+    ///
+    /// See [`crate::parse`] for the applied decoding and content type handling.
+    async fn read_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+        let status = response.status();
+        let content_type = Self::content_type(&response);
+        let bytes = response.bytes().await?;
+
+        parse::parse_json(status, &content_type, &bytes)
+    }
+
+    /// Returns the `Content-Type` header of the response, or an empty string if absent.
+    fn content_type(response: &reqwest::Response) -> String {
+        response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string()
     }
 
     /// Extracts rate limit information from HTTP response headers.
@@ -479,7 +1374,7 @@ impl OpenFIGIClient {
     /// * `status` - HTTP status code from the response
     /// * `url` - The URL that was requested
     /// * `rate_limit_info` - Optional rate limit information from headers
-    /// * `resp_text` - Raw response body text
+    /// * `reason` - Reason registered for the status with [`Self::with_status_reason`]
     ///
     /// # Returns
     ///
@@ -488,7 +1383,11 @@ impl OpenFIGIClient {
         status: reqwest::StatusCode,
         url: &Url,
         rate_limit_info: Option<String>,
+        reason: Option<ReasonCode>,
     ) -> String {
+        if let Some(reason) = reason {
+            return format!("HTTP status {} from {url}: {reason}.", status.as_u16());
+        }
         match status {
             reqwest::StatusCode::BAD_REQUEST => {
                 format!("Bad request to {url}: Invalid request body or parameters.")
@@ -505,6 +1404,15 @@ impl OpenFIGIClient {
             reqwest::StatusCode::NOT_ACCEPTABLE => {
                 format!("Not acceptable request to {url}: Unsupported Accept header type.")
             }
+            reqwest::StatusCode::CONFLICT => format!(
+                "Conflict for {url}: The request conflicts with the state of the API or a gateway, e.g. a duplicate in-flight request."
+            ),
+            reqwest::StatusCode::UNPROCESSABLE_ENTITY => format!(
+                "Unprocessable request to {url}: The request was understood but rejected, possibly by a gateway inspecting its content."
+            ),
+            reqwest::StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS => format!(
+                "Unavailable for legal reasons from {url}: Access was blocked by policy, typically by a corporate gateway."
+            ),
             reqwest::StatusCode::PAYLOAD_TOO_LARGE => format!(
                 "Payload too large for {url}: Too many mapping requests in request (max 100 with API key, 5 without)."
             ),
@@ -528,6 +1436,7 @@ impl OpenFIGIClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::mock_client_builder;
 
     #[test]
     fn test_client_new() {
@@ -548,4 +1457,325 @@ mod tests {
         assert_eq!(openfigi_client.api_key(), api_key.as_deref());
         assert!(openfigi_client.has_api_key());
     }
+
+    #[test]
+    #[cfg(feature = "tokio-runtime")]
+    fn test_block_on() {
+        assert_eq!(OpenFIGIClient::block_on(async { Ok(42) }).ok(), Some(42));
+        assert!(OpenFIGIClient::block_on(async { Ok(()) }).is_ok());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tokio-runtime")]
+    async fn test_block_on_inside_runtime() {
+        let err = OpenFIGIClient::block_on(async { Ok(()) })
+            .expect_err("block_on should refuse to run inside a runtime");
+        assert!(err.to_string().contains("within an async runtime"));
+    }
+
+    #[test]
+    fn test_auto_rate_limit_is_shared_by_clones() {
+        let client = OpenFIGIClient::new();
+        let clone = client.clone();
+        assert!(clone.auto_rate_limit.get().is_none());
+
+        client.rate_limit_or_auto();
+        assert!(clone.auto_rate_limit.get().is_some());
+        assert!(client.rate_limit().is_none());
+
+        // A configured rate limit takes precedence and leaves the shared policy alone
+        let limited = OpenFIGIClient::new().with_rate_limit(RateLimitPolicy::new());
+        limited.rate_limit_or_auto();
+        assert!(limited.auto_rate_limit.get().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_paused_client_refuses_requests() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .paused(true)
+            .build()
+            .expect("Failed to create test client");
+        let worker = client.clone();
+
+        let err = worker
+            .search("ibm")
+            .send()
+            .await
+            .expect_err("Paused client should refuse requests");
+        assert!(err.is_client_paused());
+
+        client.resume();
+        worker
+            .search("ibm")
+            .send()
+            .await
+            .expect("Resumed client should send requests");
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests() {
+        use serde_json::json;
+        use std::time::{Duration, Instant};
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": [] }))
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .max_concurrent_requests(1)
+            .build()
+            .expect("Failed to create test client");
+
+        let start = Instant::now();
+        let first = tokio::spawn(client.search("ibm").send());
+        let second = tokio::spawn(client.search("aapl").send());
+        for task in [first, second] {
+            task.await
+                .expect("Task should not panic")
+                .expect("Search should succeed");
+        }
+        // The second request waits for the response of the first one
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_sends_body_and_api_key() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/custom"))
+            .and(header("X-OPENFIGI-APIKEY", "test_key"))
+            .and(body_json(json!({ "figi": "BBG000BLNNH6" })))
+            .respond_with(ResponseTemplate::new(418))
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .api_key("test_key")
+            .build()
+            .expect("Failed to create test client");
+
+        let response = client
+            .raw_request(
+                reqwest::Method::POST,
+                "custom",
+                Some(json!({ "figi": "BBG000BLNNH6" })),
+            )
+            .await
+            .expect("Request should be sent");
+        // The status is returned unchecked
+        assert_eq!(response.status().as_u16(), 418);
+    }
+
+    async fn mock_response(template: wiremock::ResponseTemplate) -> reqwest::Response {
+        use wiremock::{Mock, MockServer, matchers::any};
+
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(template)
+            .mount(&server)
+            .await;
+        reqwest::get(server.uri())
+            .await
+            .expect("Mock server should respond")
+    }
+
+    #[tokio::test]
+    async fn test_parse_single_response_strips_bom() {
+        let mut body = b"\xEF\xBB\xBF".to_vec();
+        body.extend_from_slice(br#"{"data": [{"figi": "BBG000BLNNH6"}]}"#);
+        let response = mock_response(
+            wiremock::ResponseTemplate::new(200).set_body_raw(body, "application/json"),
+        )
+        .await;
+
+        let data: crate::model::response::MappingData = OpenFIGIClient::new()
+            .parse_single_response(response)
+            .await
+            .expect("BOM-prefixed body should parse");
+        assert_eq!(data.data()[0].figi, "BBG000BLNNH6");
+    }
+
+    #[tokio::test]
+    async fn test_error_response_with_declared_charset() {
+        // "Zugriff verweigert für Benutzer" encoded as ISO-8859-1
+        let body = b"Zugriff verweigert f\xFCr Benutzer".to_vec();
+        let response = mock_response(
+            wiremock::ResponseTemplate::new(403)
+                .set_body_raw(body, "text/plain; charset=ISO-8859-1"),
+        )
+        .await;
+
+        let err = OpenFIGIClient::new()
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("403 should be an error");
+        assert!(!err.is_lossy_body());
+        assert!(err.to_string().contains("Zugriff verweigert für Benutzer"));
+    }
+
+    #[tokio::test]
+    async fn test_error_response_with_invalid_utf8_is_lossy() {
+        let body = b"Bad gateway \xFF\xFE proxy".to_vec();
+        let response =
+            mock_response(wiremock::ResponseTemplate::new(502).set_body_raw(body, "text/plain"))
+                .await;
+
+        let err = OpenFIGIClient::new()
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("502 should be an error");
+        assert!(err.is_lossy_body());
+        assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(err.to_string().contains("Bad gateway"));
+    }
+
+    #[tokio::test]
+    async fn test_captured_headers_are_attached_to_errors() {
+        let response = mock_response(
+            wiremock::ResponseTemplate::new(503)
+                .insert_header("x-request-id", "req-42")
+                .insert_header("x-other", "ignored")
+                .set_body_raw("Service unavailable", "text/plain"),
+        )
+        .await;
+
+        let client =
+            OpenFIGIClient::new().with_captured_headers([HeaderName::from_static("x-request-id")]);
+        let err = client
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("503 should be rejected");
+        let headers = err.headers().expect("Response errors should have headers");
+        assert_eq!(headers.len(), 1);
+        assert_eq!(
+            headers.get("x-request-id").and_then(|v| v.to_str().ok()),
+            Some("req-42")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_reasons_classify_gateway_errors() {
+        let client = OpenFIGIClient::new()
+            .with_status_reason(reqwest::StatusCode::CONFLICT, ReasonCode::RateLimited);
+
+        let response = mock_response(wiremock::ResponseTemplate::new(409)).await;
+        let err = client
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("409 should be rejected");
+        assert_eq!(err.reason_code(), Some(ReasonCode::RateLimited));
+        assert!(err.to_string().contains("rate limited"));
+
+        let response = mock_response(wiremock::ResponseTemplate::new(422)).await;
+        let err = client
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("422 should be rejected");
+        assert_eq!(err.reason_code(), Some(ReasonCode::Unprocessable));
+        assert!(err.to_string().contains("Unprocessable request"));
+    }
+
+    #[tokio::test]
+    async fn test_bad_request_is_classified_by_api_message() {
+        for (body, reason) in [
+            ("Invalid idValue format.", ReasonCode::InvalidIdValue),
+            ("No identifier found.", ReasonCode::NotFound),
+        ] {
+            let response =
+                mock_response(wiremock::ResponseTemplate::new(400).set_body_string(body)).await;
+            let err = OpenFIGIClient::new()
+                .parse_single_response::<serde_json::Value>(response)
+                .await
+                .expect_err("400 should be rejected");
+            assert_eq!(err.reason_code(), Some(reason), "{body}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_html_success_response_is_unexpected_content_type() {
+        let response = mock_response(wiremock::ResponseTemplate::new(200).set_body_raw(
+            "<!DOCTYPE html>\n<html>\n  <title>Corporate Login</title>\n</html>",
+            "text/html; charset=utf-8",
+        ))
+        .await;
+
+        let err = OpenFIGIClient::new()
+            .parse_single_response::<serde_json::Value>(response)
+            .await
+            .expect_err("HTML body should be rejected");
+        assert!(err.is_unexpected_content_type());
+        assert_eq!(err.status(), Some(reqwest::StatusCode::OK));
+        if let OpenFIGIError::UnexpectedContentType {
+            content_type,
+            snippet,
+            ..
+        } = err
+        {
+            assert_eq!(content_type, "text/html; charset=utf-8");
+            assert_eq!(
+                snippet,
+                "<!DOCTYPE html> <html> <title>Corporate Login</title> </html>"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unlabelled_html_error_page_is_detected() {
+        let response = mock_response(
+            wiremock::ResponseTemplate::new(502)
+                .set_body_raw("<html><body>Bad Gateway</body></html>", "text/plain"),
+        )
+        .await;
+
+        let err = OpenFIGIClient::new()
+            .parse_list_response::<serde_json::Value>(response)
+            .await
+            .expect_err("502 should be an error");
+        assert!(err.is_unexpected_content_type());
+        assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_GATEWAY));
+    }
+
+    #[test]
+    fn test_unexpected_content_type_snippet_is_truncated() {
+        let body = "word ".repeat(100);
+        let err =
+            OpenFIGIError::unexpected_content_type(reqwest::StatusCode::OK, "text/plain", &body);
+        if let OpenFIGIError::UnexpectedContentType { snippet, .. } = err {
+            assert_eq!(snippet.chars().count(), 201);
+            assert!(snippet.ends_with('…'));
+        } else {
+            panic!("Expected UnexpectedContentType error");
+        }
+    }
 }
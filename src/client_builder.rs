@@ -85,12 +85,26 @@
 //! ```
 
 use crate::{
-    API_KEY, DEFAULT_BASE_URL,
+    DEFAULT_BASE_URL,
+    archive::{Archiver, SharedArchiver},
+    budget::RequestBudget,
+    cache::{ETagCache, MappingCache},
     client::OpenFIGIClient,
-    error::{OpenFIGIError, Result},
+    disambiguation::Venue,
+    environment::Environment,
+    error::{OpenFIGIError, OtherErrorKind, ReasonCode, Result},
+    maintenance::MaintenanceSchedule,
+    middleware::RateLimitRetry,
+    observer::{RequestObserver, RequestObservers},
+    policy::Policy,
+    rate_limit::RateLimitPolicy,
+    replay::ReplayMode,
+    trace::ResolutionTrace,
+    validation::{ValidationRule, ValidationRules},
 };
-use reqwest::Client as ReqwestClient;
+use reqwest::{Client as ReqwestClient, StatusCode, header::HeaderName};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use std::collections::HashMap;
 use url::Url;
 
 /// Builder for configuring [`crate::client::OpenFIGIClient`] instances with custom settings.
@@ -123,6 +137,24 @@ pub struct OpenFIGIClientBuilder {
     middleware_client: Option<ClientWithMiddleware>,
     base_url: Option<String>,
     api_key: Option<String>,
+    env_api_key: bool,
+    etag_cache: Option<ETagCache>,
+    mapping_cache: Option<MappingCache>,
+    validation_rules: ValidationRules,
+    observers: RequestObservers,
+    policy: Policy,
+    exchange_preferences: Vec<Venue>,
+    captured_headers: Vec<HeaderName>,
+    status_reasons: HashMap<StatusCode, ReasonCode>,
+    resolution_trace: Option<ResolutionTrace>,
+    paused: bool,
+    budget: Option<RequestBudget>,
+    maintenance: Option<MaintenanceSchedule>,
+    rate_limit: Option<RateLimitPolicy>,
+    max_concurrent_requests: Option<usize>,
+    replay: Option<ReplayMode>,
+    archiver: Option<SharedArchiver>,
+    rate_limit_retry: Option<RateLimitRetry>,
 }
 
 impl Default for OpenFIGIClientBuilder {
@@ -136,6 +168,24 @@ impl Default for OpenFIGIClientBuilder {
             middleware_client: None,
             base_url: None,
             api_key: None,
+            env_api_key: true,
+            etag_cache: None,
+            mapping_cache: None,
+            validation_rules: ValidationRules::new(),
+            observers: RequestObservers::default(),
+            policy: Policy::new(),
+            exchange_preferences: Vec::new(),
+            captured_headers: Vec::new(),
+            status_reasons: HashMap::new(),
+            resolution_trace: None,
+            paused: false,
+            budget: None,
+            maintenance: None,
+            rate_limit: None,
+            max_concurrent_requests: None,
+            replay: None,
+            archiver: None,
+            rate_limit_retry: None,
         }
     }
 }
@@ -159,6 +209,27 @@ impl OpenFIGIClientBuilder {
         Self::default()
     }
 
+    /// Create a new builder after reloading the `OPENFIGI_API_KEY` environment variable.
+    ///
+    /// Equivalent to [`crate::init`] followed by [`Self::new`], so clients built from it use the
+    /// current value of the variable rather than the value read when the first client was
+    /// created. An API key set with [`Self::api_key`] still takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::from_env().build()?;
+    /// assert_eq!(client.has_api_key(), std::env::var("OPENFIGI_API_KEY").is_ok());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn from_env() -> Self {
+        crate::init();
+        Self::new()
+    }
+
     /// Set a custom base URL for the OpenFIGI API.
     ///
     /// Overrides the default URL (`https://api.openfigi.com/v3/`). Useful for testing
@@ -184,10 +255,36 @@ impl OpenFIGIClientBuilder {
         self
     }
 
+    /// Set the base URL from a named [`Environment`].
+    ///
+    /// Equivalent to [`Self::base_url`] with the base URL of the environment, for deployments
+    /// selecting the target of the API by name in their configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::environment::Environment;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .environment(Environment::Production)
+    ///     .build()?;
+    /// assert_eq!(client.base_url().as_str(), "https://api.openfigi.com/v3/");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.base_url = Some(match environment {
+            Environment::Production => DEFAULT_BASE_URL.to_string(),
+            Environment::Custom(url) => url.into(),
+        });
+        self
+    }
+
     /// Set the API key for authenticating requests.
     ///
     /// If not explicitly provided, the builder attempts to use the `OPENFIGI_API_KEY`
-    /// environment variable. Without an API key, the client operates with rate limits
+    /// environment variable, unless disabled with [`Self::no_env_api_key`]. Without an API key, the client operates with rate limits
     /// but can still access public endpoints.
     ///
     /// # Arguments
@@ -210,6 +307,27 @@ impl OpenFIGIClientBuilder {
         self
     }
 
+    /// Ignore the `OPENFIGI_API_KEY` environment variable.
+    ///
+    /// Clients built without an API key set with [`Self::api_key`] are then guaranteed to send
+    /// unauthenticated requests, e.g. for services that must stay on the anonymous tier or tests
+    /// of its rate limits, regardless of the environment they run in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new().no_env_api_key().build()?;
+    /// assert!(!client.has_api_key());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn no_env_api_key(mut self) -> Self {
+        self.env_api_key = false;
+        self
+    }
+
     /// Use a custom reqwest client for HTTP operations.
     ///
     /// The provided client will be automatically wrapped with default middleware.
@@ -277,6 +395,413 @@ impl OpenFIGIClientBuilder {
         self
     }
 
+    /// Enable conditional `GET` requests backed by the given `ETag` cache.
+    ///
+    /// Successful `GET` responses carrying an `ETag` header are stored in the cache.
+    /// Later requests to the same URL are sent with `If-None-Match`, and a
+    /// `304 Not Modified` answer is served from the cache. This keeps refreshes of
+    /// rarely changing metadata cheap and rate-limit friendly. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache to store responses in, which may be shared between clients
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::cache::ETagCache;
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .etag_cache(ETagCache::new())
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn etag_cache(mut self, cache: ETagCache) -> Self {
+        self.etag_cache = Some(cache);
+        self
+    }
+
+    /// Enable caching the outcomes of bulk mapping jobs in the given cache.
+    ///
+    /// Jobs whose outcome is cached and has not expired are answered from the cache instead of
+    /// being sent, so repeated lookups of the same identifiers spare the job and rate limits.
+    /// Identifiers the API found no instrument for expire after the shorter
+    /// [`MappingCache::negative_ttl`]. Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::cache::MappingCache;
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .mapping_cache(MappingCache::new().negative_ttl(Duration::from_mins(15)))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn mapping_cache(mut self, cache: MappingCache) -> Self {
+        self.mapping_cache = Some(cache);
+        self
+    }
+
+    /// Add a custom validation rule run for every request sent through the endpoint builders.
+    ///
+    /// Rules run in the order they were added, after the built-in validation succeeded.
+    /// See [`crate::validation`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::error::{OpenFIGIError, OtherErrorKind};
+    /// use openfigi_rs::model::request::RequestFilters;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .validation_rule(|filters: &RequestFilters| {
+    ///         if filters.mic_code.is_none() {
+    ///             return Err(OpenFIGIError::OtherError {
+    ///                 kind: OtherErrorKind::Validation,
+    ///                 message: "micCode is required".into(),
+    ///             });
+    ///         }
+    ///         Ok(())
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
+    }
+
+    /// Add an observer notified about every request, e.g. to record metrics.
+    ///
+    /// Observers are notified in the order they were added. See [`crate::observer`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::exports::StatusCode;
+    /// use openfigi_rs::observer::{RequestInfo, RequestObserver};
+    /// use std::time::Duration;
+    ///
+    /// struct LatencyLog;
+    ///
+    /// impl RequestObserver for LatencyLog {
+    ///     fn on_response(&self, request: &RequestInfo, status: StatusCode, elapsed: Duration) {
+    ///         println!("{} answered {status} after {elapsed:?}", request.endpoint);
+    ///     }
+    /// }
+    ///
+    /// let client = OpenFIGIClientBuilder::new().observer(LatencyLog).build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Set the compliance policy applied to the results of every request.
+    ///
+    /// See [`crate::policy`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::model::enums::ExchCode;
+    /// use openfigi_rs::policy::Policy;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .policy(Policy::new().deny_exch_codes([ExchCode::US]))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the venues preferred when disambiguating results, earlier venues over later ones.
+    ///
+    /// See [`OpenFIGIClient::best_match`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::model::enums::ExchCode;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .exchange_preferences([ExchCode::US, ExchCode::LN])
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn exchange_preferences<V: Into<Venue>>(
+        mut self,
+        venues: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.exchange_preferences = venues.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the response headers captured into errors, e.g. request ids added by gateways.
+    ///
+    /// See [`OpenFIGIClient::with_captured_headers`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::exports::HeaderName;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .capture_headers([HeaderName::from_static("x-request-id")])
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn capture_headers(mut self, names: impl IntoIterator<Item = HeaderName>) -> Self {
+        self.captured_headers = names.into_iter().collect();
+        self
+    }
+
+    /// Classify error responses with `status` as `reason`, e.g. a custom status of a gateway.
+    ///
+    /// See [`OpenFIGIClient::with_status_reason`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::error::ReasonCode;
+    /// use openfigi_rs::exports::StatusCode;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .status_reason(StatusCode::CONFLICT, ReasonCode::RateLimited)
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn status_reason(mut self, status: StatusCode, reason: ReasonCode) -> Self {
+        self.status_reasons.insert(status, reason);
+        self
+    }
+
+    /// Enable recording requests and selection decisions in `trace`.
+    ///
+    /// See [`crate::trace`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::trace::ResolutionTrace;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .resolution_trace(ResolutionTrace::new())
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn resolution_trace(mut self, trace: ResolutionTrace) -> Self {
+        self.resolution_trace = Some(trace);
+        self
+    }
+
+    /// Start the client paused, refusing all requests until
+    /// [`OpenFIGIClient::resume`] is called.
+    ///
+    /// See [`OpenFIGIClient::pause`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new().paused(true).build()?;
+    /// assert!(client.is_paused());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Enable capping the requests of the client with `budget`.
+    ///
+    /// See [`crate::budget`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::budget::RequestBudget;
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .budget(RequestBudget::new().per_month(200_000))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn budget(mut self, budget: RequestBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Enable deferring or refusing requests during the maintenance windows of `schedule`.
+    ///
+    /// See [`crate::maintenance`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::maintenance::{MaintenanceAction, MaintenanceSchedule};
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .maintenance(
+    ///         MaintenanceSchedule::new()
+    ///             .window("sat 22:00 4h".parse()?)
+    ///             .action(MaintenanceAction::Defer),
+    ///     )
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn maintenance(mut self, schedule: MaintenanceSchedule) -> Self {
+        self.maintenance = Some(schedule);
+        self
+    }
+
+    /// Enable retrying requests rejected with `429` or `503` after the delay announced by the API.
+    ///
+    /// Adds `retry` to the middleware stack of the HTTP client built from [`Self::reqwest_client`]
+    /// or the default client. See [`crate::middleware`] for details.
+    ///
+    /// **Note**: Cannot be combined with [`Self::middleware_client`]; add [`RateLimitRetry`] to
+    /// that middleware stack instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::middleware::RateLimitRetry;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .rate_limit_retry(RateLimitRetry::new().max_retries(5))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn rate_limit_retry(mut self, retry: RateLimitRetry) -> Self {
+        self.rate_limit_retry = Some(retry);
+        self
+    }
+
+    /// Enable retrying rate limited requests with the default [`RateLimitRetry`] policy.
+    ///
+    /// Shorthand for [`Self::rate_limit_retry`] with [`RateLimitRetry::default`].
+    #[must_use]
+    pub fn with_default_retries(self) -> Self {
+        self.rate_limit_retry(RateLimitRetry::default())
+    }
+
+    /// Enable delaying the requests of the client to stay within the limits of `policy`.
+    ///
+    /// See [`crate::rate_limit`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::rate_limit::RateLimitPolicy;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .rate_limit(RateLimitPolicy::auto())
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn rate_limit(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limit = Some(policy);
+        self
+    }
+
+    /// Limit the number of requests of the client in flight at once to `max`.
+    ///
+    /// See [`OpenFIGIClient::with_max_concurrent_requests`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .max_concurrent_requests(4)
+    ///     .build()?;
+    /// assert_eq!(client.max_concurrent_requests(), Some(4));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max);
+        self
+    }
+
+    /// Enable recording responses into, or replaying responses from, a store.
+    ///
+    /// See [`crate::replay`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    /// use openfigi_rs::replay::{ReplayMode, ReplayStore};
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .replay(ReplayMode::Replay(ReplayStore::new()))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn replay(mut self, mode: ReplayMode) -> Self {
+        self.replay = Some(mode);
+        self
+    }
+
+    /// Pass every request and the raw bytes of its response to `archiver`, e.g. to keep the
+    /// records required for market data usage.
+    ///
+    /// See [`crate::archive`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::archive::FileArchiver;
+    /// use openfigi_rs::client_builder::OpenFIGIClientBuilder;
+    ///
+    /// let client = OpenFIGIClientBuilder::new()
+    ///     .archiver(FileArchiver::new("archive"))
+    ///     .build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn archiver(mut self, archiver: impl Archiver + 'static) -> Self {
+        self.archiver = Some(SharedArchiver::new(archiver));
+        self
+    }
+
     /// Build the [`OpenFIGIClient`] with the configured settings.
     ///
     /// Creates the final client instance using the configured options. Missing settings
@@ -292,7 +817,8 @@ impl OpenFIGIClientBuilder {
     /// ## Default Values
     ///
     /// - **Base URL**: `https://api.openfigi.com/v3/`
-    /// - **API Key**: Value from `OPENFIGI_API_KEY` environment variable (if set)
+    /// - **API Key**: Value from `OPENFIGI_API_KEY` environment variable (if set and not
+    ///   disabled with [`Self::no_env_api_key`])
     /// - **HTTP Client**: Default reqwest client with standard middleware
     ///
     /// # Errors
@@ -300,6 +826,7 @@ impl OpenFIGIClientBuilder {
     /// Returns an error if:
     /// - The base URL cannot be parsed as a valid URL
     /// - The underlying HTTP client cannot be created
+    /// - Rate limit retries are combined with a middleware client, see [`Self::rate_limit_retry`]
     ///
     /// # Examples
     ///
@@ -319,9 +846,20 @@ impl OpenFIGIClientBuilder {
     pub fn build(self) -> Result<OpenFIGIClient> {
         // Determine the HTTP client to use (middleware_client takes precedence)
         let client = match (self.middleware_client, self.reqwest_client) {
+            (Some(_), _) if self.rate_limit_retry.is_some() => {
+                return Err(OpenFIGIError::other_error(
+                    OtherErrorKind::Validation,
+                    "Rate limit retries cannot be added to a middleware client; add RateLimitRetry to its middleware stack instead",
+                ));
+            }
             (Some(middleware_client), _) => middleware_client,
-            (None, Some(reqwest_client)) => ClientBuilder::new(reqwest_client).build(),
-            (None, None) => ClientBuilder::new(ReqwestClient::default()).build(),
+            (None, reqwest_client) => {
+                let builder = ClientBuilder::new(reqwest_client.unwrap_or_default());
+                match self.rate_limit_retry {
+                    Some(retry) => builder.with(retry).build(),
+                    None => builder.build(),
+                }
+            }
         };
 
         // Parse base URL or use default
@@ -330,12 +868,65 @@ impl OpenFIGIClientBuilder {
             None => DEFAULT_BASE_URL.clone(),
         };
 
-        // Use provided API key or try environment variable (only if not set)
-        let api_key = self.api_key.or(API_KEY.clone());
+        // Use provided API key or try environment variable (only if not set or disabled)
+        let api_key = match self.api_key {
+            Some(key) => Some(key),
+            None if self.env_api_key => crate::env_api_key(),
+            None => None,
+        };
+
+        let client = OpenFIGIClient::new_with_components(client, base_url, api_key)
+            .with_validation_rules(self.validation_rules)
+            .with_observers(self.observers)
+            .with_policy(self.policy)
+            .with_exchange_preferences(self.exchange_preferences)
+            .with_captured_headers(self.captured_headers);
+        let client = self
+            .status_reasons
+            .into_iter()
+            .fold(client, |client, (status, reason)| {
+                client.with_status_reason(status, reason)
+            });
 
-        Ok(OpenFIGIClient::new_with_components(
-            client, base_url, api_key,
-        ))
+        let client = match self.resolution_trace {
+            Some(trace) => client.with_resolution_trace(trace),
+            None => client,
+        };
+        let client = match self.budget {
+            Some(budget) => client.with_budget(budget),
+            None => client,
+        };
+        let client = match self.mapping_cache {
+            Some(cache) => client.with_mapping_cache(cache),
+            None => client,
+        };
+        let client = match self.maintenance {
+            Some(schedule) => client.with_maintenance(schedule),
+            None => client,
+        };
+        let client = match self.rate_limit {
+            Some(policy) => client.with_rate_limit(policy),
+            None => client,
+        };
+        let client = match self.max_concurrent_requests {
+            Some(max) => client.with_max_concurrent_requests(max),
+            None => client,
+        };
+        let client = match self.replay {
+            Some(mode) => client.with_replay(mode),
+            None => client,
+        };
+        let client = match self.archiver {
+            Some(archiver) => client.with_shared_archiver(archiver),
+            None => client,
+        };
+        if self.paused {
+            client.pause();
+        }
+        Ok(match self.etag_cache {
+            Some(cache) => client.with_etag_cache(cache),
+            None => client,
+        })
     }
 }
 
@@ -346,6 +937,33 @@ mod tests {
     use reqwest_middleware::ClientBuilder;
     use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
 
+    #[test]
+    fn test_builder_from_env() {
+        let client = OpenFIGIClientBuilder::from_env()
+            .build()
+            .expect("Failed to build client");
+        assert_eq!(
+            client.api_key(),
+            std::env::var("OPENFIGI_API_KEY").ok().as_deref()
+        );
+    }
+
+    #[test]
+    fn test_builder_no_env_api_key() {
+        let client = OpenFIGIClientBuilder::new()
+            .no_env_api_key()
+            .build()
+            .expect("Failed to build client");
+        assert!(!client.has_api_key());
+
+        let client = OpenFIGIClientBuilder::new()
+            .no_env_api_key()
+            .api_key("explicit-key")
+            .build()
+            .expect("Failed to build client");
+        assert_eq!(client.api_key(), Some("explicit-key"));
+    }
+
     #[test]
     fn test_builder_basic() {
         let client = OpenFIGIClientBuilder::new()
@@ -0,0 +1,383 @@
+//! # Result Disambiguation
+//!
+//! Post-processing narrowing the FIGI results of a request down to a single instrument, as
+//! needed by entity-mastering pipelines that map each identifier to exactly one FIGI.
+//!
+//! A [`Disambiguator`](crate::disambiguation::Disambiguator) applies its rules in the order they were added. Each rule keeps only the
+//! candidates it prefers, or all candidates if it prefers none of them, so later rules break ties
+//! left by earlier ones. The remaining candidates are reported as a [`Resolution`](crate::disambiguation::Resolution): a unique
+//! result, or a typed [`Ambiguous`](crate::disambiguation::Resolution::Ambiguous) outcome listing the remaining candidates.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::disambiguation::{Disambiguator, Resolution};
+//! use openfigi_rs::model::enums::ExchCode;
+//! use openfigi_rs::model::response::MappingResponses;
+//!
+//! let json = r#"[{"data": [
+//!     {"figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6", "exchCode": "US"},
+//!     {"figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6", "exchCode": "UN"},
+//!     {"figi": "BBG000BLNRM1", "compositeFIGI": "BBG000BLNRM1", "exchCode": "LN"}
+//! ]}]"#;
+//! let responses: MappingResponses = serde_json::from_str(json)?;
+//!
+//! let disambiguator = Disambiguator::new()
+//!     .prefer_composite()
+//!     .prefer_exch_codes([ExchCode::US]);
+//!
+//! for (index, resolution) in disambiguator.resolve_batch(&responses) {
+//!     match resolution {
+//!         Resolution::Unique(result) => println!("{index}: {}", result.figi),
+//!         Resolution::Ambiguous(candidates) => println!("{index}: {} candidates", candidates.len()),
+//!         Resolution::Empty => println!("{index}: no results"),
+//!     }
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    model::{
+        enums::{ExchCode, MIC_EXCH_CODES, MicCode},
+        response::{FigiResult, MappingResponses},
+    },
+    trace::{ResolutionTrace, TraceEvent},
+};
+
+/// A trading venue identified by its exchange code or MIC code.
+///
+/// MIC codes match results listed under the exchange code the bundled
+/// `mapping_mic_figi-exchange-codes.csv` table assigns to them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Venue {
+    /// A venue identified by its OpenFIGI exchange code.
+    Exch(ExchCode),
+    /// A venue identified by its ISO 10383 MIC code.
+    Mic(MicCode),
+}
+
+impl Venue {
+    /// Returns true if results listed under `exch_code` trade on this venue.
+    #[must_use]
+    pub fn matches(&self, exch_code: &ExchCode) -> bool {
+        match self {
+            Self::Exch(code) => code == exch_code,
+            Self::Mic(code) => MIC_EXCH_CODES.get(code.as_str()) == Some(&exch_code.as_str()),
+        }
+    }
+}
+
+impl From<ExchCode> for Venue {
+    fn from(code: ExchCode) -> Self {
+        Self::Exch(code)
+    }
+}
+
+impl From<MicCode> for Venue {
+    fn from(code: MicCode) -> Self {
+        Self::Mic(code)
+    }
+}
+
+/// A single preference applied by a [`Disambiguator`].
+#[derive(Clone, Debug, PartialEq)]
+enum Rule {
+    Composite,
+    Venues(Vec<Venue>),
+    SecurityDescription(String),
+}
+
+impl Rule {
+    /// Returns the rank of `result` under this rule, lower is better, `None` if not preferred.
+    fn rank(&self, result: &FigiResult) -> Option<usize> {
+        match self {
+            Self::Composite => result
+                .composite_figi
+                .as_ref()
+                .is_some_and(|composite| *composite == result.figi)
+                .then_some(0),
+            Self::Venues(venues) => {
+                let exch_code = result.exch_code.as_ref()?;
+                venues.iter().position(|venue| venue.matches(exch_code))
+            }
+            Self::SecurityDescription(description) => result
+                .security_description
+                .as_ref()
+                .is_some_and(|actual| actual.eq_ignore_ascii_case(description))
+                .then_some(0),
+        }
+    }
+}
+
+/// Outcome of disambiguating the results of a single request.
+#[derive(Clone, Debug, PartialEq)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "resolutions are short-lived and usually unique"
+)]
+pub enum Resolution {
+    /// Exactly one candidate remained.
+    Unique(FigiResult),
+    /// Several candidates remained after applying all rules.
+    Ambiguous(Vec<FigiResult>),
+    /// The request returned no results.
+    Empty,
+}
+
+impl Resolution {
+    /// Returns the result if it was resolved uniquely.
+    #[must_use]
+    pub fn unique(&self) -> Option<&FigiResult> {
+        match self {
+            Self::Unique(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Returns true if several candidates remained.
+    #[must_use]
+    pub fn is_ambiguous(&self) -> bool {
+        matches!(self, Self::Ambiguous(_))
+    }
+}
+
+/// An ordered set of rules resolving FIGI results to a single instrument.
+///
+/// See the [module documentation](crate::disambiguation) for how rules are applied.
+#[derive(Clone, Debug, Default)]
+pub struct Disambiguator {
+    rules: Vec<Rule>,
+    trace: Option<ResolutionTrace>,
+}
+
+impl Disambiguator {
+    /// Creates a disambiguator without rules, which only resolves single results.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefers the composite listing, i.e. results whose FIGI is their own composite FIGI,
+    /// over venue-level listings, e.g. `US` over `UN` or `UW`.
+    #[must_use]
+    pub fn prefer_composite(mut self) -> Self {
+        self.rules.push(Rule::Composite);
+        self
+    }
+
+    /// Prefers results listed on the given exchanges, earlier codes over later ones.
+    #[must_use]
+    pub fn prefer_exch_codes(self, codes: impl IntoIterator<Item = ExchCode>) -> Self {
+        self.prefer_venues(codes)
+    }
+
+    /// Prefers results listed on the venues of the given MIC codes, earlier codes over later
+    /// ones.
+    #[must_use]
+    pub fn prefer_mic_codes(self, codes: impl IntoIterator<Item = MicCode>) -> Self {
+        self.prefer_venues(codes)
+    }
+
+    /// Prefers results listed on the given venues, earlier venues over later ones.
+    ///
+    /// Adds no rule if `venues` is empty.
+    #[must_use]
+    pub fn prefer_venues<V: Into<Venue>>(mut self, venues: impl IntoIterator<Item = V>) -> Self {
+        let venues: Vec<Venue> = venues.into_iter().map(Into::into).collect();
+        if !venues.is_empty() {
+            self.rules.push(Rule::Venues(venues));
+        }
+        self
+    }
+
+    /// Prefers results whose `securityDescription` equals `description`, ignoring ASCII case.
+    #[must_use]
+    pub fn prefer_security_description(mut self, description: impl Into<String>) -> Self {
+        self.rules
+            .push(Rule::SecurityDescription(description.into()));
+        self
+    }
+
+    /// Records every selection in `trace`.
+    ///
+    /// See [`crate::trace`] for details.
+    #[must_use]
+    pub fn with_trace(mut self, trace: ResolutionTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    /// Returns `true` if no rules are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Resolves the results of a single request.
+    #[must_use]
+    pub fn resolve(&self, results: &[FigiResult]) -> Resolution {
+        let candidates = self.candidates(results);
+        let selected = match candidates.as_slice() {
+            [result] => Some(*result),
+            _ => None,
+        };
+        self.record(results, &candidates, selected);
+
+        match candidates.as_slice() {
+            [] => Resolution::Empty,
+            [result] => Resolution::Unique((*result).clone()),
+            _ => Resolution::Ambiguous(candidates.into_iter().cloned().collect()),
+        }
+    }
+
+    /// Returns the preferred result of a single request, falling back to the first remaining
+    /// candidate in response order if the rules leave several.
+    #[must_use]
+    pub fn best_match<'a>(&self, results: &'a [FigiResult]) -> Option<&'a FigiResult> {
+        let candidates = self.candidates(results);
+        let selected = candidates.first().copied();
+        self.record(results, &candidates, selected);
+        selected
+    }
+
+    /// Resolves the results of every request of a batch that matched at least one instrument,
+    /// with the index of the request.
+    pub fn resolve_batch<'a>(
+        &'a self,
+        responses: &'a MappingResponses,
+    ) -> impl Iterator<Item = (usize, Resolution)> + 'a {
+        responses
+            .successes()
+            .map(|(index, data)| (index, self.resolve(data.data())))
+    }
+
+    /// Records a selection in the trace, if any.
+    fn record(
+        &self,
+        results: &[FigiResult],
+        remaining: &[&FigiResult],
+        selected: Option<&FigiResult>,
+    ) {
+        if let Some(trace) = &self.trace {
+            trace.record(TraceEvent::Selection {
+                candidates: results
+                    .iter()
+                    .map(|result| result.figi.to_string())
+                    .collect(),
+                remaining: remaining
+                    .iter()
+                    .map(|result| result.figi.to_string())
+                    .collect(),
+                selected: selected.map(|result| result.figi.to_string()),
+            });
+        }
+    }
+
+    /// Returns the candidates remaining after applying all rules, in response order.
+    fn candidates<'a>(&self, results: &'a [FigiResult]) -> Vec<&'a FigiResult> {
+        let mut candidates: Vec<&FigiResult> = results.iter().collect();
+
+        for rule in &self.rules {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let best = candidates
+                .iter()
+                .filter_map(|result| rule.rank(result))
+                .min();
+            if let Some(best) = best {
+                candidates.retain(|result| rule.rank(result) == Some(best));
+            }
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: &str = "BBG000000016";
+    const B: &str = "BBG000000025";
+    const C: &str = "BBG000000034";
+
+    fn result(figi: &str, composite: &str, exch_code: &str) -> FigiResult {
+        serde_json::from_value(serde_json::json!({
+            "figi": figi,
+            "compositeFIGI": composite,
+            "exchCode": exch_code,
+        }))
+        .expect("valid FIGI result")
+    }
+
+    #[test]
+    fn test_resolve_without_rules() {
+        let disambiguator = Disambiguator::new();
+        assert_eq!(disambiguator.resolve(&[]), Resolution::Empty);
+
+        let single = [result(A, A, "US")];
+        assert_eq!(
+            disambiguator
+                .resolve(&single)
+                .unique()
+                .map(|r| r.figi.as_str()),
+            Some(A)
+        );
+
+        let several = [result(A, A, "US"), result(B, A, "UN")];
+        assert!(disambiguator.resolve(&several).is_ambiguous());
+    }
+
+    #[test]
+    fn test_prefer_composite() {
+        let results = [result(B, A, "UN"), result(A, A, "US"), result(C, C, "LN")];
+
+        let resolution = Disambiguator::new().prefer_composite().resolve(&results);
+        assert!(matches!(&resolution, Resolution::Ambiguous(c) if c.len() == 2));
+
+        let resolution = Disambiguator::new()
+            .prefer_composite()
+            .prefer_exch_codes([ExchCode::LN, ExchCode::US])
+            .resolve(&results);
+        assert_eq!(resolution.unique().map(|r| r.figi.as_str()), Some(C));
+    }
+
+    #[test]
+    fn test_unmatched_rule_keeps_candidates() {
+        let results = [result(B, A, "UN"), result(A, A, "US")];
+        let resolution = Disambiguator::new()
+            .prefer_exch_codes([ExchCode::LN])
+            .prefer_composite()
+            .resolve(&results);
+        assert_eq!(resolution.unique().map(|r| r.figi.as_str()), Some(A));
+    }
+
+    #[test]
+    fn test_prefer_mic_codes_and_best_match() {
+        let results = [result(A, A, "US"), result(B, A, "UN"), result(C, A, "UW")];
+        let disambiguator = Disambiguator::new()
+            .prefer_venues([Venue::Mic(MicCode::XNYS), Venue::Exch(ExchCode::US)]);
+        assert_eq!(
+            disambiguator.best_match(&results).map(|r| r.figi.as_str()),
+            Some(B)
+        );
+        assert_eq!(
+            Disambiguator::new()
+                .best_match(&results)
+                .map(|r| r.figi.as_str()),
+            Some(A)
+        );
+        assert!(Disambiguator::new().prefer_venues::<Venue>([]).is_empty());
+    }
+
+    #[test]
+    fn test_prefer_security_description() {
+        let mut results = [result(A, A, "US"), result(B, B, "US")];
+        results[1].security_description = Some("IBM".to_string());
+
+        let resolution = Disambiguator::new()
+            .prefer_security_description("ibm")
+            .resolve(&results);
+        assert_eq!(resolution.unique().map(|r| r.figi.as_str()), Some(B));
+    }
+}
@@ -0,0 +1,62 @@
+//! # Concurrent Dispatch
+//!
+//! Bounded concurrency for builders sending many independent requests, like
+//! [`crate::endpoint::search::BulkSearchRequestBuilder`] and
+//! [`crate::endpoint::filter::FilterBatch`]. Each request still counts against the rate limit of
+//! the client, so concurrent requests queue up behind the limit instead of bursting past it.
+
+use crate::error::{OpenFIGIError, OtherErrorKind, Result};
+use tokio::{runtime::Handle, task::JoinSet};
+
+/// Runs `task` for every item with at most `concurrency` tasks in flight and returns their
+/// results in the order of the items.
+///
+/// Tasks are spawned on the current Tokio runtime, and run one after another outside of one or
+/// with a `concurrency` of one. A panicked task is reported as an error of its item.
+pub(crate) async fn run_concurrently<I, T, F, Fut>(
+    items: Vec<I>,
+    concurrency: usize,
+    task: F,
+) -> Vec<Result<T>>
+where
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    if concurrency <= 1 || Handle::try_current().is_err() {
+        let mut results = Vec::with_capacity(items.len());
+        for item in items {
+            results.push(task(item).await);
+        }
+        return results;
+    }
+
+    let mut results: Vec<Option<Result<T>>> =
+        std::iter::repeat_with(|| None).take(items.len()).collect();
+    let mut tasks = JoinSet::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if tasks.len() >= concurrency
+            && let Some(Ok((finished, result))) = tasks.join_next().await
+        {
+            results[finished] = Some(result);
+        }
+        let future = task(item);
+        tasks.spawn(async move { (index, future.await) });
+    }
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((finished, result)) = joined {
+            results[finished] = Some(result);
+        }
+    }
+    results
+        .into_iter()
+        .map(|result| {
+            result.unwrap_or_else(|| {
+                Err(OpenFIGIError::other_error(
+                    OtherErrorKind::Other,
+                    "Task failed without a result",
+                ))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,176 @@
+//! # Request Deduplication
+//!
+//! Helpers for detecting duplicate requests before they are dispatched in bulk.
+//! Repeated inputs (for example the same identifier listed twice in an input file) would
+//! otherwise each consume a slot of the per-request job limit and count against the rate limit.
+//!
+//! ## Key Features
+//!
+//! - **Structural comparison**: Requests are considered equal when they serialize to the same
+//!   JSON, so identical identifier and filter combinations are merged regardless of how they
+//!   were constructed
+//! - **Shared results**: Each caller position maps to the result of the single dispatched request
+//! - **Order preserving**: Unique requests are dispatched in order of first appearance
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::IdType;
+//! use openfigi_rs::model::request::MappingRequest;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let results = client
+//!     .bulk_mapping()
+//!     .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+//!     .add_request(MappingRequest::new(IdType::TICKER, "AAPL"))
+//!     .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+//!     .send_deduplicated()
+//!     .await?;
+//!
+//! assert_eq!(results.len(), 3);
+//! assert_eq!(results.unique_len(), 2);
+//! for (index, result) in results.iter() {
+//!     println!("Request {index}: {:?}", result.is_ok());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Results of a deduplicated bulk request, addressable by the caller's original positions.
+///
+/// Only unique requests are sent to the API. Each original request position maps to the
+/// shared result of its unique counterpart, so duplicates resolve to the same result.
+#[derive(Debug)]
+pub struct Deduplicated<T> {
+    results: Vec<Result<T>>,
+    indices: Vec<usize>,
+}
+
+impl<T> Deduplicated<T> {
+    #[doc(hidden)]
+    /// Creates a new `Deduplicated` from the results of the unique requests and the
+    /// mapping from original positions to unique result indices.
+    pub(crate) fn new(results: Vec<Result<T>>, indices: Vec<usize>) -> Self {
+        Self { results, indices }
+    }
+
+    /// Returns the shared result for the request at the caller's original `index`.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&Result<T>> {
+        self.indices.get(index).and_then(|&i| self.results.get(i))
+    }
+
+    /// Returns an iterator over `(original index, result)` pairs in the caller's order.
+    ///
+    /// Duplicate requests yield references to the same result.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Result<T>)> {
+        self.indices
+            .iter()
+            .enumerate()
+            .filter_map(|(index, &i)| self.results.get(i).map(|result| (index, result)))
+    }
+
+    /// Returns the number of requests originally submitted, including duplicates.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if no requests were submitted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the number of unique requests that were actually sent.
+    #[must_use]
+    pub fn unique_len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns the number of submitted requests that duplicated an earlier one.
+    #[must_use]
+    pub fn duplicates(&self) -> usize {
+        self.len() - self.unique_len()
+    }
+
+    /// Returns the results of the unique requests, in order of first appearance.
+    pub fn unique_results(&self) -> &[Result<T>] {
+        &self.results
+    }
+}
+
+/// Removes structurally duplicate requests, preserving the order of first appearance.
+///
+/// Returns the unique requests together with, for each original request, the index of
+/// its unique counterpart.
+pub(crate) fn dedup_requests<R: Serialize>(requests: Vec<R>) -> (Vec<R>, Vec<usize>) {
+    let mut seen: HashMap<String, usize> = HashMap::with_capacity(requests.len());
+    let mut unique = Vec::with_capacity(requests.len());
+    let mut indices = Vec::with_capacity(requests.len());
+
+    for request in requests {
+        // Requests that cannot be serialized are never merged; sending them will surface the error
+        let Ok(key) = serde_json::to_string(&request) else {
+            indices.push(unique.len());
+            unique.push(request);
+            continue;
+        };
+        let index = *seen.entry(key).or_insert_with(|| {
+            unique.push(request);
+            unique.len() - 1
+        });
+        indices.push(index);
+    }
+
+    (unique, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        enums::{ExchCode, IdType},
+        request::MappingRequest,
+    };
+
+    #[test]
+    fn test_dedup_requests_merges_identical_requests() {
+        let mut with_filter = MappingRequest::new(IdType::TICKER, "IBM");
+        with_filter.filters.exch_code = Some(ExchCode::US);
+
+        let (unique, indices) = dedup_requests(vec![
+            MappingRequest::new(IdType::TICKER, "IBM"),
+            MappingRequest::new(IdType::TICKER, "AAPL"),
+            with_filter.clone(),
+            MappingRequest::new(IdType::TICKER, "IBM"),
+            with_filter,
+        ]);
+
+        assert_eq!(unique.len(), 3);
+        assert_eq!(indices, [0, 1, 2, 0, 2]);
+    }
+
+    #[test]
+    fn test_deduplicated_maps_original_positions() {
+        let results = Deduplicated::new(vec![Ok(1), Ok(2)], vec![0, 1, 0]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.unique_len(), 2);
+        assert_eq!(results.duplicates(), 1);
+        assert!(matches!(results.get(2), Some(Ok(1))));
+        assert!(results.get(3).is_none());
+        let values: Vec<_> = results
+            .iter()
+            .map(|(i, r)| (i, *r.as_ref().expect("Should be Ok")))
+            .collect();
+        assert_eq!(values, [(0, 1), (1, 2), (2, 1)]);
+    }
+}
@@ -6,6 +6,7 @@
 //! ## Key Features
 //!
 //! - **Single Filter**: Build and send individual filter requests
+//! - **Filter Batch**: Run several filter queries concurrently and merge their results by FIGI
 //! - **Fluent API**: Chainable method calls for easy configuration
 //! - **Validation**: Automatic validation of request limits and API key requirements
 //!
@@ -16,6 +17,7 @@
 //! ```rust
 //! use openfigi_rs::client::OpenFIGIClient;
 //! use openfigi_rs::model::enums::{Currency, ExchCode};
+//! use openfigi_rs::model::request::HasFilters;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = OpenFIGIClient::new();
@@ -30,23 +32,61 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ### Filter Batch
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::SecurityType;
+//! use openfigi_rs::model::request::HasFilters;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let universe = client
+//!     .filter_batch()
+//!     .add_filter(client.filter().query("ibm").security_type(SecurityType::CommonStock))
+//!     .add_filter(client.filter().query("ibm").security_type(SecurityType::ADR))
+//!     .send()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
 
+use crate::validation::{ValidationRule, ValidationRules};
 use crate::{
     DEFAULT_ENDPOINT_FILTER,
     client::OpenFIGIClient,
-    error::Result,
-    impl_filter_builder,
+    endpoint::pagination::FilterPaginator,
+    endpoint::{Endpoint, FilterableRequestBuilder, Sendable, concurrent::run_concurrently},
+    error::{OpenFIGIError, OtherErrorKind, Result},
+    export,
     model::{
-        enums::{
-            Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
-            StateCode,
-        },
-        request::{FilterRequestBuilder, RequestFilters},
-        response::FilterData,
+        request::{FilterRequest, FilterRequestBuilder, HasFilters, RequestFilters},
+        response::{FigiResult, FilterData},
     },
+    parse,
 };
-use chrono::NaiveDate;
+use http::Extensions;
 use reqwest::Method;
+use std::{collections::HashSet, fs::File, io::BufWriter, ops::RangeInclusive, path::Path};
+
+/// Default number of queries of a [`FilterBatch`] run at the same time.
+pub const DEFAULT_FILTER_CONCURRENCY: usize = 4;
+
+/// Marker type describing the `/filter` endpoint for use with [`crate::endpoint::Endpoint`].
+pub struct FilterEndpoint;
+
+impl Endpoint for FilterEndpoint {
+    const PATH: &'static str = DEFAULT_ENDPOINT_FILTER;
+    const METHOD: Method = Method::POST;
+    type Request = FilterRequest;
+    type Response = FilterData;
+
+    fn validate(request: &Self::Request) -> Result<()> {
+        request.validate()
+    }
+}
 
 /// Builder for constructing single filter requests to the `/filter` endpoint.
 ///
@@ -58,6 +98,7 @@ use reqwest::Method;
 /// ```rust
 /// use openfigi_rs::client::OpenFIGIClient;
 /// use openfigi_rs::model::enums::Currency;
+/// use openfigi_rs::model::request::HasFilters;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = OpenFIGIClient::new();
@@ -71,9 +112,13 @@ use reqwest::Method;
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct SingleFilterRequestBuilder {
     client: OpenFIGIClient,
     request_builder: FilterRequestBuilder,
+    expected_results: Option<RangeInclusive<usize>>,
+    validation_rules: ValidationRules,
+    extensions: Extensions,
 }
 
 impl SingleFilterRequestBuilder {
@@ -91,13 +136,39 @@ impl SingleFilterRequestBuilder {
         self
     }
 
-    /// Mutable access to the request filters, delegating to the inner `FilterRequestBuilder`.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        self.request_builder.filters_mut()
+    /// Sets the accepted range for the number of results matched by [`Self::send`].
+    ///
+    /// The check uses the `total` reported by the API, falling back to the number of
+    /// results on the page if it is absent. A count outside `range` is turned into an
+    /// [`crate::error::OpenFIGIError::UnexpectedResultCount`] error. The range does not
+    /// apply to [`Self::paginator`].
+    #[must_use]
+    pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+        self.expected_results = Some(range);
+        self
+    }
+
+    /// Adds a custom validation rule run for this request only, after the rules of the client.
+    ///
+    /// See [`crate::validation`] for details.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
     }
 
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
+    /// Attaches a typed value to this request, e.g. a job or portfolio id for correlation.
+    ///
+    /// The value is passed to every middleware of the client (see
+    /// [`crate::client_builder::OpenFIGIClientBuilder::middleware_client`]) in its
+    /// [`http::Extensions`], and is returned in the extensions of the response of
+    /// [`Self::send_raw`], together with the values added by middleware. A value of the same type
+    /// replaces the previous one.
+    #[must_use]
+    pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
 
     /// Sends the filter request to `/filter` endpoint and returns the raw HTTP response.
     ///
@@ -109,9 +180,11 @@ impl SingleFilterRequestBuilder {
     /// Returns an [`crate::error::OpenFIGIError`] if the filter request is invalid or if the HTTP request fails.
     pub async fn send_raw(self) -> Result<reqwest::Response> {
         let request = self.request_builder.build()?;
+        self.validation_rules.validate(&request.filters)?;
         self.client
             .request(DEFAULT_ENDPOINT_FILTER, Method::POST)
             .body(&request)
+            .extensions(self.extensions)
             .send()
             .await
     }
@@ -121,12 +194,255 @@ impl SingleFilterRequestBuilder {
     /// # Errors
     ///
     /// Returns an [`crate::error::OpenFIGIError`] if the filter request is invalid, if the HTTP request fails,
-    /// or if the response cannot be parsed.
-    pub async fn send(self) -> Result<FilterData> {
+    /// if the response cannot be parsed, or if the number of results is outside the range set with
+    /// [`Self::expect_results`].
+    pub async fn send(mut self) -> Result<FilterData> {
         let client = self.client.clone();
+        let expected_results = self.expected_results.take();
+        let filters = self.request_builder.filters_mut().clone();
         let raw_response = self.send_raw().await?;
 
-        client.parse_single_response(raw_response).await
+        let mut data: FilterData = client.parse_single_response(raw_response).await?;
+        if let Some(warning) = data.warning() {
+            parse::log_warning(warning);
+        }
+        let count = data.total.map_or(data.data().len(), |total| {
+            usize::try_from(total).unwrap_or(usize::MAX)
+        });
+        client.policy().apply(&filters, &mut data.data);
+        OpenFIGIError::check_result_count(expected_results.as_ref(), count)?;
+        Ok(data)
+    }
+
+    /// Creates a [`FilterPaginator`] that follows the `next` token across all result pages.
+    ///
+    /// The request is validated immediately. See [`FilterPaginator`] for details on
+    /// total-aware termination and background prefetching.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the filter request is invalid.
+    pub fn paginator(self) -> Result<FilterPaginator> {
+        let request = self.request_builder.build()?;
+        self.validation_rules.validate(&request.filters)?;
+        Ok(FilterPaginator::new(self.client, request))
+    }
+
+    /// Follows the `next` token across all result pages and returns the collected results.
+    ///
+    /// Stops after `max_results` results, if set. This is a shorthand for
+    /// [`Self::paginator`] with [`FilterPaginator::max_results`] and [`FilterPaginator::drain`];
+    /// use these directly to process pages as they arrive, or to resume after a failed page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the filter request is invalid or if a page
+    /// request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::SecurityType;
+    /// use openfigi_rs::model::request::HasFilters;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    ///
+    /// let results = client
+    ///     .filter()
+    ///     .query("ibm")
+    ///     .security_type(SecurityType::CommonStock)
+    ///     .paginate(Some(500))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn paginate(self, max_results: Option<usize>) -> Result<Vec<FigiResult>> {
+        let mut paginator = self.paginator()?;
+        if let Some(max) = max_results {
+            paginator = paginator.max_results(max);
+        }
+        paginator
+            .drain()
+            .await
+            .map_err(|drain| drain.into_parts().0)
+    }
+
+    /// Follows the `next` token across all result pages and writes the results to the file at
+    /// `path` as newline-delimited JSON, see [`crate::export`].
+    ///
+    /// The file is created or truncated before the first request, and each page is written as
+    /// it arrives, so exports of large universes do not hold all results in memory. Returns
+    /// the number of results written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the filter request is invalid, if a page
+    /// request fails, or if the file cannot be written. The pages written before the error
+    /// remain in the file.
+    pub async fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        let mut paginator = self.paginator()?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut count = 0;
+        while let Some(page) = paginator.next_page().await {
+            count += export::to_ndjson(&mut writer, page?.data())?;
+        }
+        Ok(count)
+    }
+}
+
+impl HasFilters for SingleFilterRequestBuilder {
+    /// Delegates to the inner `FilterRequestBuilder`.
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        self.request_builder.filters_mut()
+    }
+}
+
+impl FilterableRequestBuilder for SingleFilterRequestBuilder {
+    fn expect_results(self, range: RangeInclusive<usize>) -> Self {
+        SingleFilterRequestBuilder::expect_results(self, range)
+    }
+
+    fn validation_rule(self, rule: impl ValidationRule + 'static) -> Self {
+        SingleFilterRequestBuilder::validation_rule(self, rule)
+    }
+
+    fn extension<T: Clone + Send + Sync + 'static>(self, value: T) -> Self {
+        SingleFilterRequestBuilder::extension(self, value)
+    }
+}
+
+impl Sendable for SingleFilterRequestBuilder {
+    type Output = FilterData;
+
+    fn send(self) -> impl Future<Output = Result<FilterData>> + Send {
+        SingleFilterRequestBuilder::send(self)
+    }
+
+    fn send_raw(self) -> impl Future<Output = Result<reqwest::Response>> + Send {
+        SingleFilterRequestBuilder::send_raw(self)
+    }
+}
+
+/// Runs several filter queries concurrently and merges their results, deduplicated on FIGI.
+///
+/// Every query follows the `next` token across all of its result pages, like
+/// [`SingleFilterRequestBuilder::paginate`], with at most [`Self::concurrency`] queries running
+/// at once. Each page counts against the rate limit of the client. Created via
+/// [`OpenFIGIClient::filter_batch`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use openfigi_rs::client::OpenFIGIClient;
+/// use openfigi_rs::model::enums::{ExchCode, SecurityType};
+/// use openfigi_rs::model::request::HasFilters;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OpenFIGIClient::new();
+///
+/// let us = || client.filter().exch_code(ExchCode::US);
+/// let universe = client
+///     .filter_batch()
+///     .add_filter(us().security_type(SecurityType::CommonStock))
+///     .add_filter(us().security_type(SecurityType::ADR))
+///     .max_results(1_000)
+///     .send()
+///     .await?;
+/// println!("{} securities", universe.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct FilterBatch {
+    queries: Vec<SingleFilterRequestBuilder>,
+    max_results: Option<usize>,
+    concurrency: usize,
+}
+
+impl FilterBatch {
+    /// Adds a filter query created with [`OpenFIGIClient::filter`].
+    ///
+    /// The query is sent with the client it was created from, and its
+    /// [`SingleFilterRequestBuilder::expect_results`] range does not apply.
+    #[must_use]
+    pub fn add_filter(mut self, query: SingleFilterRequestBuilder) -> Self {
+        self.queries.push(query);
+        self
+    }
+
+    /// Limits the number of results fetched per query.
+    #[must_use]
+    pub fn max_results(mut self, max: usize) -> Self {
+        self.max_results = Some(max);
+        self
+    }
+
+    /// Sets how many queries run at the same time.
+    ///
+    /// Defaults to [`DEFAULT_FILTER_CONCURRENCY`]; `0` is treated as `1`. Concurrent queries
+    /// run on background tasks of the current Tokio runtime, and one after another outside of
+    /// one.
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Runs the queries and returns the results of each query in the order of the queries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the batch is empty or a query is invalid,
+    /// before any request is sent. Errors of individual queries, e.g. a failed page request,
+    /// are returned in their position of the result.
+    pub async fn send_each(self) -> Result<Vec<Result<Vec<FigiResult>>>> {
+        if self.queries.is_empty() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "No filter queries to send",
+            ));
+        }
+        let paginators = self
+            .queries
+            .into_iter()
+            .map(|query| {
+                let paginator = query.paginator()?;
+                Ok(match self.max_results {
+                    Some(max) => paginator.max_results(max),
+                    None => paginator,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(
+            run_concurrently(paginators, self.concurrency, |paginator| async move {
+                paginator
+                    .drain()
+                    .await
+                    .map_err(|drain| drain.into_parts().0)
+            })
+            .await,
+        )
+    }
+
+    /// Runs the queries and returns the results of all queries, keeping the first result of
+    /// every FIGI in the order of the queries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as
+    /// [`Self::send_each`], or the error of the first failed query.
+    pub async fn send(self) -> Result<Vec<FigiResult>> {
+        let mut seen = HashSet::new();
+        let mut merged = Vec::new();
+        for results in self.send_each().await? {
+            merged.extend(
+                results?
+                    .into_iter()
+                    .filter(|result| seen.insert(result.figi.clone())),
+            );
+        }
+        Ok(merged)
     }
 }
 
@@ -146,6 +462,31 @@ impl OpenFIGIClient {
         SingleFilterRequestBuilder {
             client: self.clone(),
             request_builder: FilterRequestBuilder::new(),
+            expected_results: None,
+            validation_rules: self.validation_rules().clone(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Creates a new [`FilterBatch`] for running several filter queries concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let batch = client
+    ///     .filter_batch()
+    ///     .add_filter(client.filter().query("ibm"))
+    ///     .add_filter(client.filter().query("apple"));
+    /// ```
+    #[must_use]
+    pub fn filter_batch(&self) -> FilterBatch {
+        FilterBatch {
+            queries: Vec::new(),
+            max_results: None,
+            concurrency: DEFAULT_FILTER_CONCURRENCY,
         }
     }
 }
@@ -154,6 +495,13 @@ impl OpenFIGIClient {
 mod tests {
     use super::*;
     use crate::client::OpenFIGIClient;
+    use crate::model::enums::{
+        Currency, ExchCode, MarketSecDesc, OptionType, SecurityType, StateCode,
+    };
+    use crate::model::response::Figi;
+    use crate::test_utils::{mock_client, mount_filter_pages};
+    use chrono::NaiveDate;
+    use wiremock::MockServer;
 
     fn create_test_client() -> OpenFIGIClient {
         OpenFIGIClient::new()
@@ -273,4 +621,106 @@ mod tests {
         // Verify client reference is preserved
         assert_eq!(builder.client.base_url(), client.base_url());
     }
+
+    #[tokio::test]
+    async fn test_filter_batch_merges_results() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/filter"))
+            .and(body_partial_json(json!({ "securityType": "Common Stock" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "figi": "BBG000000016" }, { "figi": "BBG000000025" }],
+                "total": 2
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/filter"))
+            .and(body_partial_json(json!({ "securityType": "ADR" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "figi": "BBG000000025" }, { "figi": "BBG000000034" }],
+                "total": 2
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let results = client
+            .filter_batch()
+            .add_filter(client.filter().security_type(SecurityType::CommonStock))
+            .add_filter(client.filter().security_type(SecurityType::ADR))
+            .concurrency(2)
+            .send()
+            .await
+            .expect("Filter batch should succeed");
+        let figis: Vec<_> = results.into_iter().map(|r| r.figi).collect();
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+
+        let err = client
+            .filter_batch()
+            .send()
+            .await
+            .expect_err("Empty filter batch should be rejected");
+        assert!(err.to_string().contains("No filter queries"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_at_max_results() {
+        let server = MockServer::start().await;
+        mount_filter_pages(&server).await;
+        let client = mock_client(&server);
+
+        let results = client
+            .filter()
+            .query("ibm")
+            .paginate(Some(1))
+            .await
+            .expect("Pagination should succeed");
+        assert_eq!(results.len(), 1);
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(1));
+
+        let results = client
+            .filter()
+            .query("ibm")
+            .paginate(None)
+            .await
+            .expect("Pagination should succeed");
+        let figis: Vec<_> = results.into_iter().map(|r| r.figi).collect();
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+    }
+
+    #[tokio::test]
+    async fn test_send_to_ndjson_writes_all_pages() {
+        let server = MockServer::start().await;
+        mount_filter_pages(&server).await;
+        let path =
+            std::env::temp_dir().join(format!("openfigi-export-{}.ndjson", std::process::id()));
+
+        let written = mock_client(&server)
+            .filter()
+            .query("ibm")
+            .send_to_ndjson(&path)
+            .await
+            .expect("Export should succeed");
+        let contents = std::fs::read_to_string(&path).expect("Export file should exist");
+        std::fs::remove_file(&path).expect("Export file should be removed");
+
+        assert_eq!(written, 3);
+        let figis: Vec<Figi> = contents
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<FigiResult>(line)
+                    .expect("Each line should be a result")
+                    .figi
+            })
+            .collect();
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+    }
 }
@@ -9,6 +9,10 @@
 //! - **Bulk Mapping**: Batch multiple mapping requests in a single request
 //! - **Fluent API**: Chainable method calls for easy configuration
 //! - **Validation**: Automatic validation of request limits and API key requirements
+//! - **Payload splitting**: Bulk batches rejected with `413 Payload Too Large` are split in
+//!   half and retried
+//! - **Chunking**: Bulk requests beyond the job limit are sent as several batches with
+//!   `send_chunked()`
 //!
 //! ## Examples
 //!
@@ -17,13 +21,13 @@
 //! ```rust
 //! use openfigi_rs::client::OpenFIGIClient;
 //! use openfigi_rs::model::enums::{IdType, Currency, ExchCode};
-//! use serde_json::json;
+//! use openfigi_rs::model::request::HasFilters;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = OpenFIGIClient::new();
 //!
 //! let result = client
-//!     .mapping(IdType::ID_ISIN, json!("US4592001014"))
+//!     .mapping(IdType::ID_ISIN, "US4592001014")
 //!     .currency(Currency::USD)
 //!     .exch_code(ExchCode::US)
 //!     .send()
@@ -38,7 +42,6 @@
 //! use openfigi_rs::client::OpenFIGIClient;
 //! use openfigi_rs::model::request::MappingRequest;
 //! use openfigi_rs::model::enums::IdType;
-//! use serde_json::json;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = OpenFIGIClient::new();
@@ -46,11 +49,11 @@
 //! let requests = vec![
 //!     MappingRequest::builder()
 //!         .id_type(IdType::ID_ISIN)
-//!         .id_value(json!("US4592001014"))
+//!         .id_value("US4592001014")
 //!         .build()?,
 //!     MappingRequest::builder()
 //!         .id_type(IdType::TICKER)
-//!         .id_value(json!("AAPL"))
+//!         .id_value("AAPL")
 //!         .build()?,
 //! ];
 //!
@@ -63,22 +66,70 @@
 //! # }
 //! ```
 
+use crate::validation::{self, ValidationRule, ValidationRules};
 use crate::{
     DEFAULT_ENDPOINT_MAPPING,
     client::OpenFIGIClient,
+    endpoint::{
+        Endpoint, FilterableRequestBuilder, Sendable,
+        concurrent::run_concurrently,
+        dedup::{Deduplicated, dedup_requests},
+    },
     error::{OpenFIGIError, OtherErrorKind, Result},
-    impl_filter_builder,
+    export,
     model::{
-        enums::{
-            Currency, ExchCode, IdType, MarketSecDesc, MicCode, OptionType, SecurityType,
-            SecurityType2, StateCode,
+        enums::{IdType, Level},
+        request::{
+            FilterField, HasFilters, IdValue, MappingJob, MappingJobs, MappingRequest,
+            MappingRequestBuilder, RequestFilters, SerializedFilters,
         },
-        request::{MappingRequest, MappingRequestBuilder, RequestFilters},
-        response::{MappingData, MappingResponses},
+        response::{CompositeExpansion, IsinMapping, JobOutcome, MappingData, MappingResponses},
     },
+    parse,
+};
+use futures_timer::Delay;
+use http::Extensions;
+use reqwest::{Method, StatusCode};
+use std::{
+    collections::HashSet,
+    ops::{Range, RangeInclusive},
+    path::Path,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
 };
-use chrono::NaiveDate;
-use reqwest::Method;
+
+/// Default number of times a bulk mapping batch rejected with `413 Payload Too Large` is split.
+pub const DEFAULT_MAX_SPLIT_DEPTH: usize = 3;
+
+/// Marker type describing the `/mapping` endpoint for use with [`crate::endpoint::Endpoint`].
+///
+/// The request body is the list of mapping jobs and the response is the batch of
+/// per-job results. Validation checks each job and the documented maximum of 100 jobs;
+/// the stricter limit for clients without an API key is enforced by
+/// [`BulkMappingRequestBuilder`].
+pub struct MappingEndpoint;
+
+impl Endpoint for MappingEndpoint {
+    const PATH: &'static str = DEFAULT_ENDPOINT_MAPPING;
+    const METHOD: Method = Method::POST;
+    type Request = Vec<MappingRequest>;
+    type Response = MappingResponses;
+
+    fn validate(request: &Self::Request) -> Result<()> {
+        if request.is_empty() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "No requests to send",
+            ));
+        } else if request.len() > 100 {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "Bulk mapping request cannot exceed 100 requests",
+            ));
+        }
+        request.iter().try_for_each(MappingRequest::validate)
+    }
+}
 
 /// Builder for constructing single mapping requests to the `/mapping` endpoint.
 ///
@@ -90,13 +141,13 @@ use reqwest::Method;
 /// ```rust
 /// use openfigi_rs::client::OpenFIGIClient;
 /// use openfigi_rs::model::enums::{IdType, Currency};
-/// use serde_json::json;
+/// use openfigi_rs::model::request::HasFilters;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = OpenFIGIClient::new();
 ///
 /// let response = client
-///     .mapping(IdType::ID_ISIN, json!("US4592001014"))
+///     .mapping(IdType::ID_ISIN, "US4592001014")
 ///     .currency(Currency::USD)
 ///     .send()
 ///     .await?;
@@ -106,11 +157,16 @@ use reqwest::Method;
 pub struct SingleMappingRequestBuilder {
     client: OpenFIGIClient,
     request_builder: MappingRequestBuilder,
+    expected_results: Option<RangeInclusive<usize>>,
+    level: Option<Level>,
+    validation_rules: ValidationRules,
+    extensions: Extensions,
 }
 
 impl SingleMappingRequestBuilder {
     /// Sets the required ID type for the mapping request.
     #[must_use]
+    #[doc(alias = "idType")]
     pub fn id_type(mut self, id_type: IdType) -> Self {
         self.request_builder = self.request_builder.id_type(id_type);
         self
@@ -118,18 +174,87 @@ impl SingleMappingRequestBuilder {
 
     /// Sets the required ID value for the mapping request.
     #[must_use]
-    pub fn id_value<T: Into<serde_json::Value>>(mut self, id_value: T) -> Self {
+    #[doc(alias = "idValue")]
+    pub fn id_value<T: Into<IdValue>>(mut self, id_value: T) -> Self {
         self.request_builder = self.request_builder.id_value(id_value);
         self
     }
 
-    /// Mutable access to the request filters, delegating to the inner `MappingRequestBuilder`.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        self.request_builder.filters_mut()
+    /// Sets the required ID value for the mapping request to a string.
+    #[must_use]
+    pub fn id_value_str(mut self, id_value: &str) -> Self {
+        self.request_builder = self.request_builder.id_value_str(id_value);
+        self
+    }
+
+    /// Sets the required ID value for the mapping request to a number.
+    #[must_use]
+    pub fn id_value_num(mut self, id_value: u64) -> Self {
+        self.request_builder = self.request_builder.id_value_num(id_value);
+        self
     }
 
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
+    /// Sets the accepted range for the number of FIGIs returned by [`Self::send`].
+    ///
+    /// A response with a result count outside `range` is turned into an
+    /// [`crate::error::OpenFIGIError::UnexpectedResultCount`] error, e.g. `1..=1` for an
+    /// identifier that must map to exactly one instrument.
+    #[must_use]
+    pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+        self.expected_results = Some(range);
+        self
+    }
+
+    /// Keeps only the results of [`Self::send`] on `level` of the FIGI hierarchy.
+    ///
+    /// Results are classified with [`Level::of`] after the client policy was applied, and
+    /// before the result count is checked against [`Self::expect_results`]. For example, a
+    /// composite FIGI mapped with [`OpenFIGIClient::mapping_figi`] returns the composite record
+    /// together with its listings, of which [`Level::Listing`] keeps the listings only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::Level;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let listings = client
+    ///     .mapping_figi(Level::Composite, "BBG000BLNNH6")
+    ///     .level(Level::Listing)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Adds a custom validation rule run for this request only, after the rules of the client.
+    ///
+    /// See [`crate::validation`] for details.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
+    }
+
+    /// Attaches a typed value to this request, e.g. a job or portfolio id for correlation.
+    ///
+    /// The value is passed to every middleware of the client (see
+    /// [`crate::client_builder::OpenFIGIClientBuilder::middleware_client`]) in its
+    /// [`http::Extensions`], and is returned in the extensions of the response of
+    /// [`Self::send_raw`], together with the values added by middleware. A value of the same type
+    /// replaces the previous one.
+    #[must_use]
+    pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
 
     /// Sends the mapping request to `/mapping` endpoint and returns the raw HTTP response.
     ///
@@ -141,10 +266,12 @@ impl SingleMappingRequestBuilder {
     /// Returns an [`crate::error::OpenFIGIError`] if the mapping request is invalid or if the HTTP request fails.
     pub async fn send_raw(self) -> Result<reqwest::Response> {
         let request = self.request_builder.build()?;
+        self.validation_rules.validate(&request.filters)?;
         let requests = vec![request];
         self.client
             .request(DEFAULT_ENDPOINT_MAPPING, Method::POST)
             .body(&requests)
+            .extensions(self.extensions)
             .send()
             .await
     }
@@ -154,10 +281,14 @@ impl SingleMappingRequestBuilder {
     /// # Errors
     ///
     /// Returns an [`crate::error::OpenFIGIError`] if the mapping request is invalid, if the HTTP request fails,
-    /// or if the response cannot be parsed.
+    /// if the response cannot be parsed, or if the number of FIGIs is outside the range set with
+    /// [`Self::expect_results`].
     #[expect(clippy::missing_panics_doc)]
-    pub async fn send(self) -> Result<MappingData> {
+    pub async fn send(mut self) -> Result<MappingData> {
         let client = self.client.clone();
+        let expected_results = self.expected_results.take();
+        let level = self.level;
+        let filters = self.request_builder.filters_mut().clone();
         let raw_response = self.send_raw().await?;
 
         let mut results = client.parse_list_response(raw_response).await?;
@@ -165,7 +296,16 @@ impl SingleMappingRequestBuilder {
         // Take the first element, ensuring the iterator is consumed and the Vec is empty.
         if results.len() == 1 {
             // The unwrap is safe due to the length check.
-            results.pop().unwrap()
+            let mut data: MappingData = results.pop().unwrap()?;
+            if let Some(warning) = data.warning() {
+                parse::log_warning(warning);
+            }
+            client.policy().apply(&filters, &mut data.data);
+            if let Some(level) = level {
+                data.data.retain(|result| Level::of(result) == level);
+            }
+            OpenFIGIError::check_result_count(expected_results.as_ref(), data.data().len())?;
+            Ok(data)
         } else {
             Err(OpenFIGIError::other_error(
                 OtherErrorKind::UnexpectedApiResponse,
@@ -176,6 +316,54 @@ impl SingleMappingRequestBuilder {
             ))
         }
     }
+
+    /// Sends the mapping request and writes the FIGIs to the file at `path` as
+    /// newline-delimited JSON, see [`crate::export`].
+    ///
+    /// The file is created or truncated once the response has been parsed. Returns the number
+    /// of FIGIs written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as [`Self::send`],
+    /// or if the file cannot be written.
+    pub async fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        let data = self.send().await?;
+        export::to_ndjson_file(path, data.data())
+    }
+}
+
+impl HasFilters for SingleMappingRequestBuilder {
+    /// Delegates to the inner `MappingRequestBuilder`.
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        self.request_builder.filters_mut()
+    }
+}
+
+impl FilterableRequestBuilder for SingleMappingRequestBuilder {
+    fn expect_results(self, range: RangeInclusive<usize>) -> Self {
+        SingleMappingRequestBuilder::expect_results(self, range)
+    }
+
+    fn validation_rule(self, rule: impl ValidationRule + 'static) -> Self {
+        SingleMappingRequestBuilder::validation_rule(self, rule)
+    }
+
+    fn extension<T: Clone + Send + Sync + 'static>(self, value: T) -> Self {
+        SingleMappingRequestBuilder::extension(self, value)
+    }
+}
+
+impl Sendable for SingleMappingRequestBuilder {
+    type Output = MappingData;
+
+    fn send(self) -> impl Future<Output = Result<MappingData>> + Send {
+        SingleMappingRequestBuilder::send(self)
+    }
+
+    fn send_raw(self) -> impl Future<Output = Result<reqwest::Response>> + Send {
+        SingleMappingRequestBuilder::send_raw(self)
+    }
 }
 
 /// Builder for bulk mapping requests to the `/mapping` endpoint.
@@ -194,13 +382,12 @@ impl SingleMappingRequestBuilder {
 /// use openfigi_rs::client::OpenFIGIClient;
 /// use openfigi_rs::model::request::MappingRequest;
 /// use openfigi_rs::model::enums::IdType;
-/// use serde_json::json;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = OpenFIGIClient::new();
 /// let requests = vec![
-///     MappingRequest::new(IdType::ID_ISIN, json!("US4592001014")),
-///     MappingRequest::new(IdType::TICKER, json!("AAPL")),
+///     MappingRequest::new(IdType::ID_ISIN, "US4592001014"),
+///     MappingRequest::new(IdType::TICKER, "AAPL"),
 /// ];
 ///
 /// let result = client
@@ -214,6 +401,13 @@ impl SingleMappingRequestBuilder {
 pub struct BulkMappingRequestBuilder {
     client: OpenFIGIClient,
     requests: Vec<MappingRequest>,
+    default_filters: RequestFilters,
+    expected_results: Option<RangeInclusive<usize>>,
+    validation_rules: ValidationRules,
+    extensions: Extensions,
+    max_split_depth: usize,
+    chunk_concurrency: usize,
+    chunk_delay: Duration,
 }
 
 impl BulkMappingRequestBuilder {
@@ -224,10 +418,122 @@ impl BulkMappingRequestBuilder {
         self
     }
 
+    /// Sets the accepted range for the number of FIGIs returned per mapping request.
+    ///
+    /// Each successful result with a count outside `range` is replaced by an
+    /// [`crate::error::OpenFIGIError::UnexpectedResultCount`] error for that request only,
+    /// so the remaining results of the batch are unaffected.
+    #[must_use]
+    pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+        self.expected_results = Some(range);
+        self
+    }
+
+    /// Adds a custom validation rule run for this request only, after the rules of the client.
+    ///
+    /// See [`crate::validation`] for details.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
+    }
+
+    /// Attaches a typed value to this request, e.g. a job or portfolio id for correlation.
+    ///
+    /// The value is passed to every middleware of the client (see
+    /// [`crate::client_builder::OpenFIGIClientBuilder::middleware_client`]) in its
+    /// [`http::Extensions`], and is returned in the extensions of the response of
+    /// [`Self::send_raw`], together with the values added by middleware. A value of the same type
+    /// replaces the previous one.
+    #[must_use]
+    pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
+
+    /// Sets how often a batch rejected with `413 Payload Too Large` is split in half and retried.
+    ///
+    /// Proxies between the client and the OpenFIGI API may cap payloads below the documented
+    /// job limit. On a `413` response, [`Self::send`] and [`Self::send_deduplicated`] send both
+    /// halves of the rejected batch separately, splitting them again if needed, until `depth`
+    /// splits were made. The results are returned in the order of the requests either way.
+    /// Defaults to [`DEFAULT_MAX_SPLIT_DEPTH`]; `0` disables splitting. [`Self::send_raw`]
+    /// never splits.
+    #[must_use]
+    pub fn max_split_depth(mut self, depth: usize) -> Self {
+        self.max_split_depth = depth;
+        self
+    }
+
+    /// Sets how many batches [`Self::send_chunked`] sends at the same time.
+    ///
+    /// Defaults to `1`, which sends the batches one after another; `0` is treated as `1`.
+    /// Concurrent batches are sent on background tasks of the current Tokio runtime, and one
+    /// after another outside of one.
+    #[must_use]
+    pub fn chunk_concurrency(mut self, concurrency: usize) -> Self {
+        self.chunk_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets the delay between starting two batches of [`Self::send_chunked`].
+    ///
+    /// Defaults to no delay. The delay applies in addition to a rate limit of the client, see
+    /// [`crate::rate_limit`].
+    #[must_use]
+    pub fn chunk_delay(mut self, delay: Duration) -> Self {
+        self.chunk_delay = delay;
+        self
+    }
+
     /// Adds multiple mapping requests to the bulk request.
+    ///
+    /// Accepts owned requests as well as references to requests, which are cloned.
     #[must_use]
-    pub fn add_requests(mut self, requests: Vec<MappingRequest>) -> Self {
-        self.requests.extend(requests);
+    pub fn add_requests(
+        mut self,
+        requests: impl IntoIterator<Item = impl Into<MappingRequest>>,
+    ) -> Self {
+        self.requests.extend(requests.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets filters shared by all mapping requests of the batch.
+    ///
+    /// The filters are stored and serialized once instead of on every request, and are merged
+    /// into each request as the batch is serialized, like [`RequestFilters::with_defaults`], so
+    /// filters set on a request take precedence. The requests themselves are left unchanged.
+    /// Requests are validated as merged with the defaults; validation rules added with
+    /// [`Self::validation_rule`] or on the client receive a merged copy of the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use openfigi_rs::client::OpenFIGIClient;
+    /// # use openfigi_rs::model::enums::{Currency, ExchCode, IdType};
+    /// # use openfigi_rs::model::request::{MappingRequest, RequestFilters};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = OpenFIGIClient::new();
+    /// let mut defaults = RequestFilters::default();
+    /// defaults.exch_code = Some(ExchCode::US);
+    /// defaults.currency = Some(Currency::USD);
+    ///
+    /// let result = client
+    ///     .bulk_mapping()
+    ///     .default_filters(defaults)
+    ///     .add_requests(vec![
+    ///         MappingRequest::new(IdType::TICKER, "IBM"),
+    ///         MappingRequest::new(IdType::TICKER, "AAPL"),
+    ///     ])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn default_filters(mut self, filters: RequestFilters) -> Self {
+        self.default_filters = filters;
         self
     }
 
@@ -247,7 +553,7 @@ impl BulkMappingRequestBuilder {
     /// ```rust,no_run
     /// # use openfigi_rs::client::OpenFIGIClient;
     /// # use openfigi_rs::model::enums::{IdType, Currency, ExchCode};
-    /// # use serde_json::json;
+    /// # use openfigi_rs::model::request::HasFilters;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = OpenFIGIClient::new();
@@ -287,14 +593,37 @@ impl BulkMappingRequestBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an [`crate::error::OpenFIGIError`] if the bulk mapping request is invalid or if the HTTP request fails.
-    pub async fn send_raw(self) -> Result<reqwest::Response> {
-        if self.requests.is_empty() {
-            return Err(OpenFIGIError::other_error(
-                OtherErrorKind::Validation,
-                "No requests to send",
-            ));
-        } else if !self.client.has_api_key() && self.requests.len() > 5 {
+    /// Returns an [`crate::error::OpenFIGIError`] if the bulk mapping request is invalid, including
+    /// a request whose identifier has an invalid check digit, or if the HTTP request fails.
+    pub async fn send_raw(mut self) -> Result<reqwest::Response> {
+        let defaults = self.serialize_default_filters()?;
+        self.validate()?;
+        // The raw response cannot report invalid identifiers per job
+        for request in &self.requests {
+            validation::validate_id_value(&request.id_type, &request.id_value)?;
+        }
+        let extensions = std::mem::take(&mut self.extensions);
+        let requests: Vec<&MappingRequest> = self.requests.iter().collect();
+        dispatch(&self.client, &requests, &defaults, extensions).await
+    }
+
+    /// Validates the default filters and serializes them once for all requests of the batch.
+    fn serialize_default_filters(&self) -> Result<SerializedFilters> {
+        if self.default_filters.is_empty() {
+            return Ok(SerializedFilters::default());
+        }
+        self.default_filters.validate()?;
+        SerializedFilters::new(&self.default_filters)
+    }
+
+    /// Returns the maximum number of requests per batch accepted by the API.
+    fn job_limit(&self) -> usize {
+        if self.client.has_api_key() { 100 } else { 5 }
+    }
+
+    /// Checks the batch against the job limits and the validation rules.
+    fn validate(&self) -> Result<()> {
+        if !self.client.has_api_key() && self.requests.len() > 5 {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
                 "Bulk mapping request cannot exceed 5 requests without an API key",
@@ -305,28 +634,424 @@ impl BulkMappingRequestBuilder {
                 "Bulk mapping request cannot exceed 100 requests",
             ));
         }
+        self.validate_requests()
+    }
 
-        self.client
-            .request(DEFAULT_ENDPOINT_MAPPING, Method::POST)
-            .body(&self.requests)
-            .send()
-            .await
+    /// Checks that the batch is not empty and runs the validation rules on every request.
+    ///
+    /// With default filters, the requests are validated as merged with them. Identifier values
+    /// are checked per job when the batch is sent, see [`dispatch_jobs`].
+    fn validate_requests(&self) -> Result<()> {
+        if self.requests.is_empty() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "No requests to send",
+            ));
+        }
+        let defaults = &self.default_filters;
+        for request in &self.requests {
+            if defaults.is_empty() {
+                self.validation_rules.validate(&request.filters)?;
+            } else {
+                request.validate_filters(defaults)?;
+                if !self.validation_rules.is_empty() {
+                    let merged = request.filters.clone().with_defaults(defaults);
+                    self.validation_rules.validate(&merged)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends the requests of the batch without validating them and returns the parsed job
+    /// results, splitting batches rejected with `413 Payload Too Large`.
+    async fn dispatch_jobs(
+        &mut self,
+        defaults: &SerializedFilters,
+    ) -> Result<Vec<Result<MappingData>>> {
+        let extensions = std::mem::take(&mut self.extensions);
+        dispatch_jobs(
+            &self.client,
+            &self.requests,
+            defaults,
+            extensions,
+            self.max_split_depth,
+        )
+        .await
+    }
+
+    /// Sends the requests in batches of at most the job limit and returns the job results in
+    /// the order of the requests.
+    async fn dispatch_chunks(
+        &mut self,
+        defaults: SerializedFilters,
+    ) -> Result<Vec<Result<MappingData>>> {
+        let extensions = std::mem::take(&mut self.extensions);
+        let defaults = Arc::new(defaults);
+        let requests = Arc::new(std::mem::take(&mut self.requests));
+        let job_limit = self.job_limit();
+        let chunks: Vec<Range<usize>> = (0..requests.len())
+            .step_by(job_limit)
+            .map(|start| start..requests.len().min(start + job_limit))
+            .collect();
+
+        let max_split_depth = self.max_split_depth;
+        let chunk_delay = self.chunk_delay;
+        let last_start = Arc::new(Mutex::new(None));
+        let chunk_results = run_concurrently(chunks, self.chunk_concurrency, |jobs| {
+            let client = self.client.clone();
+            let requests = Arc::clone(&requests);
+            let defaults = Arc::clone(&defaults);
+            let extensions = extensions.clone();
+            let last_start = Arc::clone(&last_start);
+            async move {
+                pace_chunk(&last_start, chunk_delay).await;
+                dispatch_jobs(
+                    &client,
+                    &requests[jobs],
+                    &defaults,
+                    extensions,
+                    max_split_depth,
+                )
+                .await
+            }
+        })
+        .await;
+
+        self.requests = Arc::try_unwrap(requests).unwrap_or_else(|shared| (*shared).clone());
+        let mut results = Vec::with_capacity(self.requests.len());
+        for chunk in chunk_results {
+            results.extend(chunk?);
+        }
+        Ok(results)
     }
 
     /// Sends the mapping request to `/mapping` endpoint and returns parsed results.
     ///
+    /// Requests whose identifier has an invalid format or check digit (see
+    /// [`crate::validation`]) are not sent, and their results are
+    /// [`OtherErrorKind::Validation`] errors, while the other requests of the batch are sent.
+    /// The same applies to [`Self::send_chunked`] and [`Self::send_deduplicated`].
+    ///
     /// # Errors
     ///
     /// Returns an [`crate::error::OpenFIGIError`] if the mapping request is invalid, if the HTTP request fails,
     /// or if the response cannot be parsed.
-    pub async fn send(self) -> Result<MappingResponses> {
-        let client = self.client.clone();
-        let raw_response = self.send_raw().await?;
+    pub async fn send(mut self) -> Result<MappingResponses> {
+        let defaults = self.serialize_default_filters()?;
+        self.validate()?;
+        let results = self.dispatch_jobs(&defaults).await?;
+        Ok(self.into_responses(results))
+    }
+
+    /// Applies the client policy and the expected result counts to the job results, and
+    /// returns them with the requests and default filters of the batch.
+    fn into_responses(self, results: Vec<Result<MappingData>>) -> MappingResponses {
+        let results =
+            apply_job_policy(&self.client, &self.requests, &self.default_filters, results);
+
+        MappingResponses::new(check_job_result_counts(
+            results,
+            self.expected_results.as_ref(),
+        ))
+        .with_requests(self.requests)
+        .with_default_filters(self.default_filters)
+    }
+
+    /// Sends the mapping request like [`Self::send`] and returns each result paired with the
+    /// request that produced it.
+    ///
+    /// Spares callers keeping their own copy of the requests to tell which identifier a failed
+    /// job belonged to. The requests are returned as added, without the default filters of the
+    /// batch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use openfigi_rs::client::OpenFIGIClient;
+    /// # use openfigi_rs::model::enums::IdType;
+    /// # use openfigi_rs::model::request::MappingRequest;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = OpenFIGIClient::new();
+    /// let results = client
+    ///     .bulk_mapping()
+    ///     .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+    ///     .add_request(MappingRequest::new(IdType::ID_ISIN, "US0000000002"))
+    ///     .send_with_requests()
+    ///     .await?;
+    ///
+    /// for (request, result) in results {
+    ///     if let Err(err) = result {
+    ///         eprintln!("{}: {err}", request.id_value);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as [`Self::send`].
+    /// Errors of individual jobs are returned paired with their request.
+    pub async fn send_with_requests(self) -> Result<Vec<(MappingRequest, Result<MappingData>)>> {
+        self.send()
+            .await?
+            .into_results_with_requests()
+            .ok_or_else(|| {
+                OpenFIGIError::other_error(
+                    OtherErrorKind::UnexpectedApiResponse,
+                    "Number of mapping results does not match the number of requests",
+                )
+            })
+    }
+
+    /// Sends any number of mapping requests, split into batches within the job limit, and
+    /// returns the results of all batches in the order of the requests.
+    ///
+    /// Unlike [`Self::send`], the batch may exceed the job limit of 100 requests, or 5 without
+    /// an API key. The requests are sent in consecutive batches of the job limit, each counting
+    /// as one request against a rate limit of the client, and batches rejected with
+    /// `413 Payload Too Large` are split as described in [`Self::max_split_depth`]. Use
+    /// [`Self::chunk_concurrency`] and [`Self::chunk_delay`] to control how the batches are sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use openfigi_rs::client::OpenFIGIClient;
+    /// # use openfigi_rs::model::enums::IdType;
+    /// # use openfigi_rs::model::request::MappingRequest;
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = OpenFIGIClient::new();
+    /// let requests: Vec<MappingRequest> = (0..1_000)
+    ///     .map(|i| MappingRequest::new(IdType::TICKER, format!("T{i}")))
+    ///     .collect();
+    ///
+    /// let results = client
+    ///     .bulk_mapping()
+    ///     .add_requests(requests)
+    ///     .chunk_concurrency(2)
+    ///     .chunk_delay(Duration::from_millis(500))
+    ///     .send_chunked()
+    ///     .await?;
+    /// assert_eq!(results.len(), 1_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if a mapping request is invalid, or if the
+    /// HTTP request or the parsing of any batch fails. Results of other batches are discarded
+    /// in that case.
+    pub async fn send_chunked(mut self) -> Result<MappingResponses> {
+        let defaults = self.serialize_default_filters()?;
+        self.validate_requests()?;
+        let results = self.dispatch_chunks(defaults).await?;
+        Ok(self.into_responses(results))
+    }
+
+    /// Sends only the unique mapping requests and returns results addressable by original position.
+    ///
+    /// Requests with an identical identifier and filter combination are merged before dispatch,
+    /// so repeated inputs neither count against the per-request job limit nor the rate limit.
+    /// Every original position maps to the shared result of its unique request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the deduplicated bulk mapping request is invalid,
+    /// if the HTTP request fails, or if the response cannot be parsed.
+    pub async fn send_deduplicated(mut self) -> Result<Deduplicated<MappingData>> {
+        let defaults = self.serialize_default_filters()?;
+        let (unique, indices) = dedup_requests(std::mem::take(&mut self.requests));
+        self.requests = unique;
+        self.validate()?;
+        let results = self.dispatch_jobs(&defaults).await?;
+        let results =
+            apply_job_policy(&self.client, &self.requests, &self.default_filters, results);
+
+        Ok(Deduplicated::new(
+            check_job_result_counts(results, self.expected_results.as_ref()),
+            indices,
+        ))
+    }
+}
 
-        let results = client.parse_list_response(raw_response).await?;
+impl Sendable for BulkMappingRequestBuilder {
+    type Output = MappingResponses;
 
-        Ok(MappingResponses::new(results))
+    fn send(self) -> impl Future<Output = Result<MappingResponses>> + Send {
+        BulkMappingRequestBuilder::send(self)
     }
+
+    fn send_raw(self) -> impl Future<Output = Result<reqwest::Response>> + Send {
+        BulkMappingRequestBuilder::send_raw(self)
+    }
+}
+
+/// Sends `requests` with the default filters `defaults` merged in to the `/mapping` endpoint
+/// without validating them.
+async fn dispatch(
+    client: &OpenFIGIClient,
+    requests: &[&MappingRequest],
+    defaults: &SerializedFilters,
+    extensions: Extensions,
+) -> Result<reqwest::Response> {
+    client
+        .request(DEFAULT_ENDPOINT_MAPPING, Method::POST)
+        .body(&MappingJobs::new(requests, defaults))
+        .extensions(extensions)
+        .send()
+        .await
+}
+
+/// Sends `requests` without validating them and returns the parsed job results, answering jobs
+/// from the mapping cache of the client and storing the outcomes of the jobs sent in it.
+///
+/// Jobs whose identifier value has an invalid format or check digit are not sent and fail with
+/// an [`OtherErrorKind::Validation`] error, see [`crate::validation`].
+async fn dispatch_jobs(
+    client: &OpenFIGIClient,
+    requests: &[MappingRequest],
+    defaults: &SerializedFilters,
+    extensions: Extensions,
+    max_split_depth: usize,
+) -> Result<Vec<Result<MappingData>>> {
+    let cache = client.mapping_cache();
+    let mut results: Vec<Option<Result<MappingData>>> = requests
+        .iter()
+        .map(|request| {
+            if let Err(error) = validation::validate_id_value(&request.id_type, &request.id_value) {
+                return Some(Err(error));
+            }
+            cache.and_then(|cache| cache.get(&MappingJob::new(request, defaults)))
+        })
+        .collect();
+    let misses: Vec<usize> = (0..requests.len())
+        .filter(|&index| results[index].is_none())
+        .collect();
+    if misses.is_empty() {
+        return Ok(results.into_iter().flatten().collect());
+    }
+
+    let uncached: Vec<&MappingRequest> = misses.iter().map(|&i| &requests[i]).collect();
+    let fetched =
+        dispatch_uncached(client, &uncached, defaults, extensions, max_split_depth).await?;
+    check_job_count(misses.len(), fetched.len())?;
+    for (index, result) in misses.into_iter().zip(fetched) {
+        if let Some(cache) = cache {
+            cache.insert(&MappingJob::new(&requests[index], defaults), &result);
+        }
+        results[index] = Some(result);
+    }
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Sends `requests` without validating them and returns the parsed job results, splitting
+/// batches rejected with `413 Payload Too Large` up to `max_split_depth` times.
+async fn dispatch_uncached(
+    client: &OpenFIGIClient,
+    requests: &[&MappingRequest],
+    defaults: &SerializedFilters,
+    extensions: Extensions,
+    max_split_depth: usize,
+) -> Result<Vec<Result<MappingData>>> {
+    let mut results = Vec::with_capacity(requests.len());
+    // Batches still to send with their split depth, the next one last
+    let mut pending = vec![(0..requests.len(), 0)];
+    while let Some((jobs, depth)) = pending.pop() {
+        let raw_response = dispatch(
+            client,
+            &requests[jobs.clone()],
+            defaults,
+            extensions.clone(),
+        )
+        .await?;
+        if raw_response.status() == StatusCode::PAYLOAD_TOO_LARGE
+            && depth < max_split_depth
+            && jobs.len() > 1
+        {
+            let mid = jobs.start + jobs.len() / 2;
+            pending.push((mid..jobs.end, depth + 1));
+            pending.push((jobs.start..mid, depth + 1));
+            continue;
+        }
+        let batch = client.parse_list_response(raw_response).await?;
+        check_job_count(jobs.len(), batch.len())?;
+        results.extend(batch);
+    }
+    Ok(results)
+}
+
+/// Returns an [`OtherErrorKind::UnexpectedApiResponse`] error unless the API answered with one
+/// result per job, as results are matched to their jobs by position.
+fn check_job_count(jobs: usize, results: usize) -> Result<()> {
+    if jobs == results {
+        Ok(())
+    } else {
+        Err(OpenFIGIError::other_error(
+            OtherErrorKind::UnexpectedApiResponse,
+            format!("Expected {jobs} results for bulk mapping, but got {results}"),
+        ))
+    }
+}
+
+/// Waits until `delay` has passed since the start of the previous batch recorded in
+/// `last_start`, and records the start of this batch.
+async fn pace_chunk(last_start: &Mutex<Option<Instant>>, delay: Duration) {
+    if delay.is_zero() {
+        return;
+    }
+    let start = {
+        let mut last_start = last_start.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        let start = last_start.map_or(now, |last| now.max(last + delay));
+        *last_start = Some(start);
+        start
+    };
+    Delay::new(start.saturating_duration_since(Instant::now())).await;
+}
+
+/// Logs the warnings of every successful job result and applies the client policy to it using
+/// the filters of its job, as merged with the default filters `defaults` of the batch.
+fn apply_job_policy(
+    client: &OpenFIGIClient,
+    requests: &[MappingRequest],
+    defaults: &RequestFilters,
+    results: Vec<Result<MappingData>>,
+) -> Vec<Result<MappingData>> {
+    results
+        .into_iter()
+        .zip(requests)
+        .map(|(result, request)| {
+            result.map(|mut data| {
+                if let Some(warning) = data.warning() {
+                    parse::log_warning(warning);
+                }
+                // The policy only checks the state code of the filters
+                let filters = request.filters.source(FilterField::StateCode, defaults);
+                client.policy().apply(filters, &mut data.data);
+                data
+            })
+        })
+        .collect()
+}
+
+/// Replaces successful job results with a result count outside `expected` by an error.
+fn check_job_result_counts(
+    results: Vec<Result<MappingData>>,
+    expected: Option<&RangeInclusive<usize>>,
+) -> Vec<Result<MappingData>> {
+    results
+        .into_iter()
+        .map(|result| {
+            result.and_then(|data| {
+                OpenFIGIError::check_result_count(expected, data.data().len()).map(|()| data)
+            })
+        })
+        .collect()
 }
 
 impl OpenFIGIClient {
@@ -347,7 +1072,7 @@ impl OpenFIGIClient {
     /// let builder = client.mapping(IdType::ID_ISIN, "US4592001014");
     /// ```
     #[must_use]
-    pub fn mapping<T: Into<serde_json::Value>>(
+    pub fn mapping<T: Into<IdValue>>(
         &self,
         id_type: IdType,
         id_value: T,
@@ -357,7 +1082,362 @@ impl OpenFIGIClient {
             request_builder: MappingRequestBuilder::new()
                 .id_type(id_type)
                 .id_value(id_value),
+            expected_results: None,
+            level: None,
+            validation_rules: self.validation_rules().clone(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Creates a new [`SingleMappingRequestBuilder`] mapping the FIGI `figi` of `level`.
+    ///
+    /// Selects the identifier type matching `level` with [`Level::id_type`], e.g.
+    /// [`IdType::COMPOSITE_ID_BB_GLOBAL`] for a composite FIGI, which returns the composite
+    /// record and all of its listings. Combine with [`SingleMappingRequestBuilder::level`] to
+    /// keep the results on one level only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::Level;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let builder = client.mapping_figi(Level::ShareClass, "BBG001S5S399");
+    /// ```
+    #[must_use]
+    pub fn mapping_figi(
+        &self,
+        level: Level,
+        figi: impl Into<String>,
+    ) -> SingleMappingRequestBuilder {
+        self.mapping(level.id_type(), figi.into())
+    }
+
+    /// Fetches the composite-level record and all venue-level listings of the composite FIGI
+    /// `figi`.
+    ///
+    /// Maps `figi` as a [`IdType::COMPOSITE_ID_BB_GLOBAL`], which returns every FIGI sharing
+    /// the composite FIGI. The client [policy](Self::policy) applies to the listings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the HTTP request fails, if the response
+    /// cannot be parsed, or if the API reports no instrument for `figi`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let expansion = client.expand_composite("BBG000BLNNH6").await?;
+    ///
+    /// for listing in &expansion.listings {
+    ///     println!("{} on {:?}", listing.figi, listing.exch_code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn expand_composite(&self, figi: impl Into<String>) -> Result<CompositeExpansion> {
+        let figi = figi.into();
+        let data = self
+            .mapping(IdType::COMPOSITE_ID_BB_GLOBAL, figi.as_str())
+            .send()
+            .await?;
+        Ok(CompositeExpansion::new(&figi, data.data))
+    }
+
+    /// Fetches all listings of the share class FIGI `share_class_figi` globally, grouped by
+    /// composite FIGI.
+    ///
+    /// Maps `share_class_figi` as an [`IdType::ID_BB_GLOBAL_SHARE_CLASS_LEVEL`]. The mapping
+    /// endpoint returns every match in a single response, so no further pages are requested.
+    /// The client [policy](Self::policy) applies to the listings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the HTTP request fails, if the response
+    /// cannot be parsed, or if the API reports no instrument for `share_class_figi`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// for expansion in client.share_class_listings("BBG001S5S399").await? {
+    ///     if let Some(composite) = &expansion.composite {
+    ///         println!("{}: {} listings", composite.figi, expansion.listings.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn share_class_listings(
+        &self,
+        share_class_figi: impl Into<String>,
+    ) -> Result<Vec<CompositeExpansion>> {
+        let data = self
+            .mapping(
+                IdType::ID_BB_GLOBAL_SHARE_CLASS_LEVEL,
+                share_class_figi.into(),
+            )
+            .send()
+            .await?;
+        Ok(CompositeExpansion::group(data.data))
+    }
+
+    /// Maps a list of ISINs to their FIGI results, keyed by ISIN.
+    ///
+    /// Covers the common "ISIN list in, FIGI map out" case in one call:
+    ///
+    /// - ISINs are trimmed and deduplicated, and ISINs failing the format or check digit
+    ///   validation (see [`crate::validation`]) are reported as failed without being sent
+    /// - the remaining ISINs are sent one batch within the job limit after another, like
+    ///   [`BulkMappingRequestBuilder::send`]
+    /// - the batches are paced by the [rate limit](Self::rate_limit) of the client, or else by
+    ///   a [`crate::rate_limit::RateLimitPolicy::auto`] policy created on the first call and shared by all calls of
+    ///   the client and its clones
+    /// - ISINs the API knows no instrument for map to an empty list in
+    ///   [`IsinMapping::found`], and ISINs whose job failed are reported in
+    ///   [`IsinMapping::failed`]
+    /// - if a whole batch fails, e.g. because its HTTP request fails, its error is reported
+    ///   for every ISIN of the batch, and the results of the other batches are kept
+    ///
+    /// Use [`Self::bulk_mapping`] to map other identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first batch if every batch sent failed as a whole, e.g. because
+    /// the API key is invalid or the API is unreachable. Failures of single ISINs, or of some
+    /// batches only, are reported in [`IsinMapping::failed`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let mapping = client.map_isins(["US4592001014", "US0378331005"]).await?;
+    ///
+    /// for (isin, results) in &mapping.found {
+    ///     println!("{isin}: {} results", results.len());
+    /// }
+    /// for (isin, error) in &mapping.failed {
+    ///     println!("{isin}: {error}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn map_isins<'a>(
+        &self,
+        isins: impl IntoIterator<Item = &'a str>,
+    ) -> Result<IsinMapping> {
+        let mut mapping = IsinMapping::default();
+        let mut seen = HashSet::new();
+        let mut valid = Vec::new();
+        for isin in isins.into_iter().map(str::trim) {
+            if !seen.insert(isin) {
+                continue;
+            }
+            match validation::validate_id_value(&IdType::ID_ISIN, &IdValue::from(isin)) {
+                Ok(()) => valid.push(isin),
+                Err(error) => {
+                    mapping.failed.insert(isin.to_string(), Arc::new(error));
+                }
+            }
+        }
+        if valid.is_empty() {
+            return Ok(mapping);
+        }
+
+        let client = self.clone().with_rate_limit(self.rate_limit_or_auto());
+        let job_limit = client.bulk_mapping().job_limit();
+        let mut failed_batches = Vec::new();
+        for chunk in valid.chunks(job_limit) {
+            let outcomes = match client
+                .bulk_mapping()
+                .add_requests(
+                    chunk
+                        .iter()
+                        .map(|isin| MappingRequest::new(IdType::ID_ISIN, *isin)),
+                )
+                .send()
+                .await
+            {
+                Ok(responses) => responses.into_outcomes(),
+                Err(error) => {
+                    failed_batches.push((chunk, error));
+                    continue;
+                }
+            };
+
+            for (isin, outcome) in chunk.iter().zip(outcomes) {
+                match outcome {
+                    JobOutcome::Found(data) | JobOutcome::Empty(data) => {
+                        mapping.found.insert((*isin).to_string(), data.data);
+                    }
+                    JobOutcome::NotFound { .. } => {
+                        mapping.found.insert((*isin).to_string(), Vec::new());
+                    }
+                    JobOutcome::Error(error) => {
+                        mapping.failed.insert((*isin).to_string(), Arc::new(error));
+                    }
+                }
+            }
+        }
+
+        if failed_batches.len() == valid.len().div_ceil(job_limit) {
+            return Err(failed_batches.swap_remove(0).1);
+        }
+        for (chunk, error) in failed_batches {
+            let error = Arc::new(error);
+            for isin in chunk {
+                mapping
+                    .failed
+                    .insert((*isin).to_string(), Arc::clone(&error));
+            }
+        }
+        Ok(mapping)
+    }
+
+    /// Re-submits only the failed requests of `responses` and merges the new results back by
+    /// index.
+    ///
+    /// Successful and empty results are kept unchanged, and so are the requests the API found
+    /// no instrument for, as a miss is not a transient failure; use
+    /// [`Self::retry_failures_and_not_found_with`] to re-submit those as well, e.g. with relaxed
+    /// filters. The requests are
+    /// sent like [`BulkMappingRequestBuilder::send_chunked`], so any number of failures is
+    /// re-submitted within the job limit. The returned responses retain the requests, so
+    /// retries can be repeated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if `responses` does not retain its original
+    /// requests (see [`MappingResponses::requests`]), if the retry request is invalid, if the
+    /// HTTP request fails, or if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::model::enums::IdType;
+    /// use openfigi_rs::model::request::MappingRequest;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let responses = client
+    ///     .bulk_mapping()
+    ///     .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+    ///     .add_request(MappingRequest::new(IdType::TICKER, "AAPL"))
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let responses = client.retry_failures(&responses).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry_failures(&self, responses: &MappingResponses) -> Result<MappingResponses> {
+        self.retry_failures_with(responses, |request| request).await
+    }
+
+    /// Like [`Self::retry_failures`], but passes each re-submitted request through `modify` before
+    /// re-submitting it.
+    ///
+    /// The returned responses retain the modified requests. The default filters of `responses`
+    /// are not part of the requests passed to `modify`, and apply to the retried requests again.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::retry_failures`].
+    pub async fn retry_failures_with(
+        &self,
+        responses: &MappingResponses,
+        modify: impl FnMut(MappingRequest) -> MappingRequest,
+    ) -> Result<MappingResponses> {
+        self.retry_outcomes(responses, false, modify).await
+    }
+
+    /// Like [`Self::retry_failures_with`], but also re-submits the requests the API found no
+    /// instrument for, e.g. with filters relaxed by `modify`.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::retry_failures`].
+    pub async fn retry_failures_and_not_found_with(
+        &self,
+        responses: &MappingResponses,
+        modify: impl FnMut(MappingRequest) -> MappingRequest,
+    ) -> Result<MappingResponses> {
+        self.retry_outcomes(responses, true, modify).await
+    }
+
+    /// Re-submits the failed requests of `responses`, and the requests the API found no
+    /// instrument for if `retry_not_found` is set, passed through `modify`, and merges the new
+    /// results back by index.
+    async fn retry_outcomes(
+        &self,
+        responses: &MappingResponses,
+        retry_not_found: bool,
+        mut modify: impl FnMut(MappingRequest) -> MappingRequest,
+    ) -> Result<MappingResponses> {
+        let retry = |outcome: &JobOutcome| {
+            outcome.is_error() || (retry_not_found && outcome.is_not_found())
+        };
+        let Some(requests) = responses.requests() else {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "Responses do not retain their original requests",
+            ));
+        };
+
+        let mut requests = requests.to_vec();
+        let mut retried = Vec::new();
+        for (index, outcome) in responses.outcomes().enumerate() {
+            if retry(outcome) {
+                requests[index] = modify(requests[index].clone());
+                retried.push(requests[index].clone());
+            }
         }
+
+        let mut retried = if retried.is_empty() {
+            Vec::new()
+        } else {
+            self.bulk_mapping()
+                .add_requests(retried)
+                .default_filters(responses.default_filters().clone())
+                .send_chunked()
+                .await?
+                .into_results()
+        }
+        .into_iter();
+
+        let results = responses
+            .as_slice()
+            .iter()
+            .map(|outcome| match outcome {
+                JobOutcome::Found(data) | JobOutcome::Empty(data) => Ok(data.clone()),
+                JobOutcome::NotFound { message } if !retry_not_found => JobOutcome::NotFound {
+                    message: message.clone(),
+                }
+                .into_result(),
+                _ => retried.next().unwrap_or_else(|| {
+                    Err(OpenFIGIError::other_error(
+                        OtherErrorKind::UnexpectedApiResponse,
+                        "Missing result for retried mapping request",
+                    ))
+                }),
+            })
+            .collect();
+
+        Ok(MappingResponses::new(results)
+            .with_requests(requests)
+            .with_default_filters(responses.default_filters().clone()))
     }
 
     /// Creates a new [`BulkMappingRequestBuilder`] for batching multiple mapping requests.
@@ -375,6 +1455,13 @@ impl OpenFIGIClient {
         BulkMappingRequestBuilder {
             client: self.clone(),
             requests: Vec::new(),
+            default_filters: RequestFilters::default(),
+            expected_results: None,
+            validation_rules: self.validation_rules().clone(),
+            extensions: Extensions::new(),
+            max_split_depth: DEFAULT_MAX_SPLIT_DEPTH,
+            chunk_concurrency: 1,
+            chunk_delay: Duration::ZERO,
         }
     }
 }
@@ -383,8 +1470,29 @@ impl OpenFIGIClient {
 mod tests {
     use super::*;
     use crate::client::OpenFIGIClient;
+    use crate::model::enums::{
+        Currency, ExchCode, MarketSecDesc, OptionType, SecurityType, StateCode,
+    };
+    use crate::test_utils::{mock_client, mock_client_builder};
+    use chrono::NaiveDate;
     use serde_json::json;
 
+    /// Valid FIGIs echoed back by mock servers answering jobs with their `idValue`
+    const FIGIS: [&str; 12] = [
+        "BBG000000016",
+        "BBG000000025",
+        "BBG000000034",
+        "BBG000000043",
+        "BBG000000052",
+        "BBG000000061",
+        "BBG000000070",
+        "BBG000000089",
+        "BBG000000098",
+        "BBG000000105",
+        "BBG000000114",
+        "BBG000000123",
+    ];
+
     fn create_test_client() -> OpenFIGIClient {
         OpenFIGIClient::new()
     }
@@ -608,6 +1716,19 @@ mod tests {
         assert_eq!(builder.client.has_api_key(), client.has_api_key());
     }
 
+    #[test]
+    fn test_mapping_endpoint_validation() {
+        assert!(MappingEndpoint::validate(&Vec::new()).is_err());
+        assert!(
+            MappingEndpoint::validate(&vec![MappingRequest::new(IdType::TICKER, "IBM")]).is_ok()
+        );
+        // BASE_TICKER requires securityType2
+        assert!(
+            MappingEndpoint::validate(&vec![MappingRequest::new(IdType::BASE_TICKER, "IBM")])
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn test_bulk_mapping_empty_requests_error() {
         let client = create_test_client();
@@ -623,10 +1744,37 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_bulk_mapping_validation_rules() {
+        let client = create_test_client().with_validation_rule(|filters: &RequestFilters| {
+            if filters.exch_code.is_none() && filters.mic_code.is_none() {
+                return Err(OpenFIGIError::other_error(
+                    OtherErrorKind::Validation,
+                    "an exchange is required",
+                ));
+            }
+            Ok(())
+        });
+
+        let result = client
+            .bulk_mapping()
+            .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+            .send()
+            .await;
+
+        match result {
+            Err(OpenFIGIError::OtherError { kind, message }) => {
+                assert_eq!(kind, OtherErrorKind::Validation);
+                assert!(message.contains("an exchange is required"));
+            }
+            _ => panic!("Expected validation error from custom rule"),
+        }
+    }
+
     #[tokio::test]
     async fn test_bulk_mapping_too_many_requests_without_api_key() {
         let client = create_test_client(); // No API key
-        let requests = (0..6)
+        let requests: Vec<_> = (0..6)
             .map(|i| MappingRequest::new(IdType::TICKER, json!(format!("TEST{}", i))))
             .collect();
 
@@ -645,7 +1793,7 @@ mod tests {
     #[tokio::test]
     async fn test_bulk_mapping_too_many_requests_with_api_key() {
         let client = create_test_client_with_api_key();
-        let requests = (0..101)
+        let requests: Vec<_> = (0..101)
             .map(|i| MappingRequest::new(IdType::TICKER, json!(format!("TEST{}", i))))
             .collect();
 
@@ -660,4 +1808,809 @@ mod tests {
             panic!("Expected validation error for too many requests even with API key");
         }
     }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_invalid_identifiers_fail_per_job() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "ID_ISIN", "idValue": "US4592001014" },
+                { "idType": "TICKER", "idValue": "AAPL" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "data": [{ "figi": "BBG000B9XRY4" }] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let builder = || {
+            client
+                .bulk_mapping()
+                .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+                .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001015"))
+                .add_request(MappingRequest::new(IdType::TICKER, "AAPL"))
+        };
+
+        let results = builder().send().await.expect("Valid jobs should be sent");
+        assert_eq!(results.len(), 3);
+        assert!(results.as_slice()[0].is_found());
+        assert!(matches!(
+            results.as_slice()[1].error(),
+            Some(OpenFIGIError::OtherError {
+                kind: OtherErrorKind::Validation,
+                ..
+            })
+        ));
+        assert!(results.as_slice()[2].is_found());
+
+        // The raw response cannot report the invalid job, so the batch is rejected
+        assert!(builder().send_raw().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_default_filters() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "TICKER", "idValue": "IBM", "exchCode": "US", "currency": "USD" },
+                { "idType": "TICKER", "idValue": "VOD", "exchCode": "LN", "currency": "USD" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "data": [{ "figi": "BBG000C6K6G9" }] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let defaults = RequestFilters {
+            exch_code: Some(ExchCode::US),
+            currency: Some(Currency::USD),
+            ..Default::default()
+        };
+        let results = client
+            .bulk_mapping()
+            .default_filters(defaults)
+            .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+            .add_request_with(|j| j.id_type(IdType::TICKER).id_value("VOD").exch_code("LN"))
+            .expect("Request should be valid")
+            .send()
+            .await
+            .expect("Bulk mapping should succeed");
+
+        // The defaults are merged into the body only, and retained once for the batch
+        let requests = results.requests().expect("Requests should be retained");
+        assert_eq!(requests[0].filters.exch_code, None);
+        assert_eq!(requests[1].filters.exch_code, Some(ExchCode::LN));
+        assert_eq!(requests[1].filters.currency, None);
+        assert_eq!(results.default_filters().currency, Some(Currency::USD));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_send_deduplicated() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "TICKER", "idValue": "IBM" },
+                { "idType": "TICKER", "idValue": "AAPL" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "data": [{ "figi": "BBG000B9XRY4" }] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        // Six requests would exceed the limit without an API key, but only two are unique
+        let requests: Vec<_> = ["IBM", "AAPL", "IBM", "IBM", "AAPL", "IBM"]
+            .into_iter()
+            .map(|ticker| MappingRequest::new(IdType::TICKER, ticker))
+            .collect();
+        let results = client
+            .bulk_mapping()
+            .add_requests(requests)
+            .send_deduplicated()
+            .await
+            .expect("Deduplicated request should succeed");
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(results.unique_len(), 2);
+        let figi = |i| {
+            results
+                .get(i)
+                .and_then(|r| r.as_ref().ok())
+                .map(|data| data.data()[0].figi.clone())
+        };
+        assert_eq!(figi(3).as_deref(), Some("BBG000BLNNH6"));
+        assert_eq!(figi(4).as_deref(), Some("BBG000B9XRY4"));
+    }
+
+    #[tokio::test]
+    async fn test_expand_composite() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "COMPOSITE_ID_BB_GLOBAL", "idValue": "BBG000BLNNH6" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [
+                    { "figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6", "exchCode": "UN" },
+                    { "figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6", "exchCode": "US" },
+                    { "figi": "BBG000BLNRM1", "compositeFIGI": "BBG000BLNNH6", "exchCode": "UW" }
+                ] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let expansion = client
+            .expand_composite("BBG000BLNNH6")
+            .await
+            .expect("Expansion should succeed");
+        assert_eq!(
+            expansion
+                .composite
+                .map(|composite| composite.figi)
+                .as_deref(),
+            Some("BBG000BLNNH6")
+        );
+        let listings: Vec<_> = expansion
+            .listings
+            .iter()
+            .map(|listing| listing.figi.as_str())
+            .collect();
+        assert_eq!(listings, ["BBG000BLNQ16", "BBG000BLNRM1"]);
+    }
+
+    #[tokio::test]
+    async fn test_mapping_figi_level() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "COMPOSITE_ID_BB_GLOBAL", "idValue": "BBG000BLNNH6" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [
+                    { "figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6", "exchCode": "UN" },
+                    { "figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6", "exchCode": "US" }
+                ] }
+            ])))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        for (level, figi) in [
+            (Level::Listing, "BBG000BLNQ16"),
+            (Level::Composite, "BBG000BLNNH6"),
+        ] {
+            let data = client
+                .mapping_figi(Level::Composite, "BBG000BLNNH6")
+                .level(level)
+                .expect_results(1..=1)
+                .send()
+                .await
+                .expect("Mapping should succeed");
+            assert_eq!(data.data()[0].figi, figi);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_share_class_listings() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "ID_BB_GLOBAL_SHARE_CLASS_LEVEL", "idValue": "BBG001S5S399" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [
+                    { "figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6", "exchCode": "US" },
+                    { "figi": "BBG000BPD168", "compositeFIGI": "BBG000BPD168", "exchCode": "LN" },
+                    { "figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6", "exchCode": "UN" }
+                ] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let expansions = client
+            .share_class_listings("BBG001S5S399")
+            .await
+            .expect("Share class lookup should succeed");
+        assert_eq!(expansions.len(), 2);
+        assert_eq!(
+            expansions[0].composite.as_ref().map(|c| c.figi.as_str()),
+            Some("BBG000BLNNH6")
+        );
+        assert_eq!(expansions[0].listings.len(), 1);
+        assert!(expansions[1].listings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retry_failures() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "TICKER", "idValue": "IBM" },
+                { "idType": "TICKER", "idValue": "AAPL" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "error": "No identifier found." }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([
+                { "idType": "TICKER", "idValue": "AAPL", "exchCode": "US" }
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000B9XRY4" }] }
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let responses = client
+            .bulk_mapping()
+            .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+            .add_request(MappingRequest::new(IdType::TICKER, "AAPL"))
+            .send()
+            .await
+            .expect("Bulk request should succeed");
+        assert_eq!(responses.requests().map(<[_]>::len), Some(2));
+        assert_eq!(responses.not_found().count(), 1);
+
+        // Misses are not transient, so they are not re-submitted by default
+        let kept = client
+            .retry_failures(&responses)
+            .await
+            .expect("Retry without failures should succeed");
+        assert_eq!(kept.not_found().count(), 1);
+
+        let retried = client
+            .retry_failures_and_not_found_with(&responses, |mut request| {
+                request.filters.exch_code = Some(ExchCode::US);
+                request
+            })
+            .await
+            .expect("Retry should succeed");
+        assert_eq!(retried.failures().count(), 0);
+        let figis: Vec<_> = retried
+            .successes()
+            .map(|(_, data)| data.data()[0].figi.as_str())
+            .collect();
+        assert_eq!(figis, ["BBG000BLNNH6", "BBG000B9XRY4"]);
+        assert_eq!(
+            retried.requests().expect("Requests should be retained")[1]
+                .filters
+                .exch_code,
+            Some(ExchCode::US)
+        );
+
+        // Without failures, nothing is re-submitted
+        client
+            .retry_failures(&retried)
+            .await
+            .expect("Retry without failures should succeed");
+
+        let deserialized: MappingResponses = serde_json::from_value(json!([{ "error": "x" }]))
+            .expect("Failed to deserialize mapping response");
+        assert!(client.retry_failures(&deserialized).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_failures_beyond_job_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use wiremock::{
+            Mock, MockServer, Request, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        // Fails every job of the first two batches, then answers jobs with their idValue
+        let batches = AtomicUsize::new(0);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(move |request: &Request| {
+                let jobs: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).expect("Should be a list of jobs");
+                let failed = batches.fetch_add(1, Ordering::SeqCst) < 2;
+                let results: Vec<_> = jobs
+                    .iter()
+                    .map(|job| {
+                        if failed {
+                            json!({ "error": "Internal server error" })
+                        } else {
+                            json!({ "data": [{ "figi": job["idValue"] }] })
+                        }
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(results)
+            })
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let responses = client
+            .bulk_mapping()
+            .add_requests(
+                FIGIS[..7]
+                    .iter()
+                    .map(|figi| MappingRequest::new(IdType::ID_BB_GLOBAL, *figi)),
+            )
+            .send_chunked()
+            .await
+            .expect("Chunked bulk request should succeed");
+        assert_eq!(responses.failures().count(), 7);
+
+        // 7 failures exceed the job limit of 5 without an API key
+        let retried = client
+            .retry_failures(&responses)
+            .await
+            .expect("Retry should succeed");
+        assert_eq!(retried.successes().count(), 7);
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_expect_results() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "data": [{ "figi": "BBG000B9XRY4" }, { "figi": "BBG000B9Y5X2" }] },
+                { "error": "No identifier found." }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let results = client
+            .bulk_mapping()
+            .add_request(MappingRequest::new(IdType::TICKER, "IBM"))
+            .add_request(MappingRequest::new(IdType::TICKER, "AAPL"))
+            .add_request(MappingRequest::new(IdType::TICKER, "UNKNOWN"))
+            .expect_results(1..=1)
+            .send()
+            .await
+            .expect("Bulk request should succeed");
+
+        let results = results.as_slice();
+        assert!(results[0].is_found());
+        assert!(
+            results[1]
+                .error()
+                .is_some_and(OpenFIGIError::is_unexpected_result_count)
+        );
+        // API misses are passed through unchanged
+        assert!(results[2].is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_splits_payload_too_large() {
+        use wiremock::{
+            Mock, MockServer, Request, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        // Rejects batches of more than two jobs, answering every other job with its idValue
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(|request: &Request| {
+                let jobs: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).expect("Should be a list of jobs");
+                if jobs.len() > 2 {
+                    return ResponseTemplate::new(413);
+                }
+                let results: Vec<_> = jobs
+                    .iter()
+                    .map(|job| json!({ "data": [{ "figi": job["idValue"] }] }))
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(results)
+            })
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let requests: Vec<_> = FIGIS[..5]
+            .iter()
+            .map(|figi| MappingRequest::new(IdType::ID_BB_GLOBAL, *figi))
+            .collect();
+
+        let responses = client
+            .bulk_mapping()
+            .add_requests(requests.clone())
+            .send()
+            .await
+            .expect("Split bulk request should succeed");
+        let figis: Vec<_> = responses
+            .successes()
+            .map(|(_, data)| data.data()[0].figi.as_str())
+            .collect();
+        assert_eq!(figis, FIGIS[..5]);
+        // 5 jobs are rejected, then 2 succeed, then 3 are rejected, then 1 and 2 succeed
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(5));
+
+        let err = client
+            .bulk_mapping()
+            .add_requests(requests)
+            .max_split_depth(0)
+            .send()
+            .await
+            .expect_err("Bulk request without splitting should fail");
+        assert_eq!(err.status(), Some(reqwest::StatusCode::PAYLOAD_TOO_LARGE));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_send_chunked() {
+        use wiremock::{
+            Mock, MockServer, Request, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        // Answers every job with its idValue, delaying larger batches so they finish last
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(|request: &Request| {
+                let jobs: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).expect("Should be a list of jobs");
+                let results: Vec<_> = jobs
+                    .iter()
+                    .map(|job| json!({ "data": [{ "figi": job["idValue"] }] }))
+                    .collect();
+                ResponseTemplate::new(200)
+                    .set_body_json(results)
+                    .set_delay(Duration::from_millis(20 * jobs.len() as u64))
+            })
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let requests: Vec<_> = FIGIS
+            .iter()
+            .map(|figi| MappingRequest::new(IdType::ID_BB_GLOBAL, *figi))
+            .collect();
+
+        for concurrency in [1, 3] {
+            let responses = client
+                .bulk_mapping()
+                .add_requests(&requests)
+                .chunk_concurrency(concurrency)
+                .send_chunked()
+                .await
+                .expect("Chunked bulk request should succeed");
+            let figis: Vec<_> = responses
+                .successes()
+                .map(|(_, data)| data.data()[0].figi.clone())
+                .collect();
+            assert_eq!(figis, FIGIS);
+            assert_eq!(responses.requests().map(<[_]>::len), Some(12));
+        }
+        // 12 jobs without an API key are sent as batches of 5, 5 and 2
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_result_count_mismatch() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{ "data": [{ "figi": "BBG000BLNNH6" }] }])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        for chunked in [false, true] {
+            let builder = client
+                .bulk_mapping()
+                .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+                .add_request(MappingRequest::new(IdType::ID_ISIN, "US0378331005"));
+            let result = if chunked {
+                builder.send_chunked().await
+            } else {
+                builder.send().await
+            };
+            if let Err(OpenFIGIError::OtherError { kind, .. }) = result {
+                assert_eq!(kind, OtherErrorKind::UnexpectedApiResponse);
+            } else {
+                panic!("Expected an error for a missing job result");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_send_with_requests() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "warning": "No identifier found." },
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let results = client
+            .bulk_mapping()
+            .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+            .add_request(MappingRequest::new(IdType::ID_ISIN, "US0000000002"))
+            .send_with_requests()
+            .await
+            .expect("Bulk request should succeed");
+
+        assert_eq!(results.len(), 2);
+        let (found, result) = &results[0];
+        assert_eq!(found.id_value, IdValue::from("US4592001014"));
+        assert!(result.is_ok());
+        let (failed, result) = &results[1];
+        assert_eq!(failed.id_value, IdValue::from("US0000000002"));
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_mapping_uses_mapping_cache() {
+        use crate::cache::MappingCache;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_json, method, path},
+        };
+
+        let found = MappingRequest::new(IdType::ID_ISIN, "US4592001014");
+        let missing = MappingRequest::new(IdType::ID_ISIN, "US0000000002");
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([found, missing])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "warning": "No identifier found." },
+            ])))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .and(body_json(json!([missing])))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{ "warning": "No identifier found." }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let send = |cache: &MappingCache| {
+            let client = mock_client_builder(&server)
+                .mapping_cache(cache.clone())
+                .build()
+                .expect("Failed to create test client");
+            let requests = vec![found.clone(), missing.clone()];
+            async move {
+                client
+                    .bulk_mapping()
+                    .add_requests(requests)
+                    .send()
+                    .await
+                    .expect("Bulk request should succeed")
+            }
+        };
+
+        // Both outcomes are cached, so the second batch is answered without a request
+        let cache = MappingCache::new();
+        send(&cache).await;
+        assert_eq!(cache.len(), 2);
+        let cached = send(&cache).await;
+        assert_eq!(cached.successes().count(), 1);
+        assert_eq!(cached.not_found().count(), 1);
+
+        // Without a negative TTL only the missing identifier is sent again
+        let cache = MappingCache::new().negative_ttl(Duration::ZERO);
+        send(&cache).await;
+        assert_eq!(cache.len(), 1);
+        let resent = send(&cache).await;
+        assert_eq!(resent.len(), 2);
+        assert_eq!(resent.not_found().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_map_isins() {
+        use wiremock::{
+            Mock, MockServer, Request, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(|request: &Request| {
+                let jobs: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).expect("Should be a list of jobs");
+                let results: Vec<_> = jobs
+                    .iter()
+                    .map(|job| match job["idValue"].as_str() {
+                        Some("US4592001014") => json!({ "data": [{ "figi": FIGIS[0] }] }),
+                        Some("US0378331005") => json!({ "warning": "No identifier found." }),
+                        _ => json!({ "error": "Invalid idValue format." }),
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(results)
+            })
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let mapping = client
+            .map_isins([
+                "US4592001014",
+                " US4592001014 ",
+                "US0378331005",
+                "US5949181045",
+                "US4592001015",
+            ])
+            .await
+            .expect("Mapping ISINs should succeed");
+
+        assert_eq!(mapping.found.len(), 2);
+        assert_eq!(mapping.found["US4592001014"][0].figi, FIGIS[0]);
+        assert!(mapping.found["US0378331005"].is_empty());
+        // Failed jobs and ISINs with an invalid check digit are reported as failed
+        assert_eq!(mapping.failed.len(), 2);
+        assert!(
+            mapping.failed["US5949181045"]
+                .to_string()
+                .contains("Invalid idValue format.")
+        );
+        assert!(
+            mapping.failed["US4592001015"]
+                .to_string()
+                .contains("check digit")
+        );
+
+        // Only the three valid, unique ISINs were sent, in a single batch
+        let received = server.received_requests().await.unwrap_or_default();
+        assert_eq!(received.len(), 1);
+        let jobs: Vec<serde_json::Value> =
+            serde_json::from_slice(&received[0].body).expect("Should be a list of jobs");
+        assert_eq!(jobs.len(), 3);
+        assert!(client.rate_limit().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_map_isins_keeps_other_batches_when_one_fails() {
+        use wiremock::{
+            Mock, MockServer, Request, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(|request: &Request| {
+                let jobs: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).expect("Should be a list of jobs");
+                if jobs.iter().any(|job| job["idValue"] == "US88160R1014") {
+                    return ResponseTemplate::new(500);
+                }
+                let results: Vec<_> = jobs
+                    .iter()
+                    .map(|_| json!({ "data": [{ "figi": FIGIS[0] }] }))
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(results)
+            })
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        // Without an API key, the six ISINs are sent in a batch of five and a batch of one
+        let mapping = client
+            .map_isins([
+                "US4592001014",
+                "US0378331005",
+                "US5949181045",
+                "US02079K3059",
+                "US0231351067",
+                "US88160R1014",
+            ])
+            .await
+            .expect("Mapping ISINs should succeed");
+
+        assert_eq!(mapping.found.len(), 5);
+        assert_eq!(mapping.found["US0231351067"][0].figi, FIGIS[0]);
+        assert_eq!(mapping.failed.len(), 1);
+        assert!(mapping.failed.contains_key("US88160R1014"));
+        assert_eq!(
+            server.received_requests().await.unwrap_or_default().len(),
+            2
+        );
+
+        // Without any successful batch, the call fails as a whole
+        let err = client
+            .map_isins(["US88160R1014", "not an ISIN"])
+            .await
+            .expect_err("Mapping should fail if every batch fails");
+        assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    }
 }
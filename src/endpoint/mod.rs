@@ -6,9 +6,22 @@
 //!
 //! ## Available Endpoints
 //!
+//! - [`crate::endpoint::dedup`] - Detection of duplicate requests before bulk dispatch, with results shared across duplicates.
 //! - [`crate::endpoint::filter`] - Filter endpoint for finding FIGIs using key words and other filters. The results are listed alphabetically by FIGI and include the number of results.
 //! - [`crate::endpoint::mapping`] - Mapping endpoint for converting third party identifiers to FIGIs
+//! - [`crate::endpoint::pagination`] - Paginators following `next` tokens across result pages, with optional prefetching.
 //! - [`crate::endpoint::search`] - Search endpoint for finding FIGIs using key words and other filters.
+//! - [`crate::endpoint::values`] - Mapping values endpoint listing the current values of mapping request properties.
+//!
+//! Endpoints are described at compile time by the [`Endpoint`](crate::endpoint::Endpoint) trait, which also allows
+//! custom endpoints to be executed through [`crate::client::OpenFIGIClient::execute`].
+pub mod dedup;
 pub mod filter;
 pub mod mapping;
+pub mod pagination;
 pub mod search;
+pub mod values;
+
+mod concurrent;
+mod traits;
+pub use self::traits::{Endpoint, FilterableRequestBuilder, Sendable};
@@ -0,0 +1,649 @@
+//! # Pagination Helpers
+//!
+//! Paginators for endpoints that return results in pages linked by a `next` token.
+//! Currently this covers the [/filter](https://www.openfigi.com/api/documentation#v3-post-filter)
+//! endpoint, which reports the `total` number of matching instruments alongside each page.
+//!
+//! ## Key Features
+//!
+//! - **Token handling**: Follows the `next` token of each page until the result set is exhausted
+//! - **Total awareness**: Stops as soon as `total` results have been received
+//! - **Result caps**: Optionally stops after a maximum number of results
+//! - **Prefetching**: Optionally fetches upcoming pages in the background while the caller
+//!   processes the current one, with a bounded look-ahead
+//! - **Resumable drains**: Collects all results, keeping the pages fetched before a failure so
+//!   the drain can be resumed from the failed page
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::SecurityType;
+//! use openfigi_rs::model::request::HasFilters;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let mut paginator = client
+//!     .filter()
+//!     .query("ibm")
+//!     .security_type(SecurityType::CommonStock)
+//!     .paginator()?
+//!     .prefetch(2);
+//!
+//! while let Some(page) = paginator.next_page().await {
+//!     let page = page?;
+//!     println!("Received {} of {:?} results", paginator.fetched(), paginator.total());
+//!     for figi_result in page.data() {
+//!         println!("{}", figi_result.figi);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    DEFAULT_ENDPOINT_FILTER,
+    client::OpenFIGIClient,
+    error::{OpenFIGIError, Result},
+    model::{
+        request::FilterRequest,
+        response::{FigiResult, FilterData, page_count},
+    },
+    parse,
+};
+use reqwest::Method;
+use std::{error, fmt};
+use tokio::{runtime::Handle, sync::mpsc};
+
+/// Internal state of a [`FilterPaginator`].
+enum PaginatorState {
+    /// No page has been requested yet.
+    Start,
+    /// The next page is fetched on demand using the contained token.
+    Next(String),
+    /// Pages are fetched by a background task and delivered through the channel.
+    Prefetching(mpsc::Receiver<Result<FilterData>>),
+    /// The result set is exhausted or an error occurred.
+    Done,
+}
+
+/// Paginator over the pages of a `/filter` request.
+///
+/// Created via [`crate::endpoint::filter::SingleFilterRequestBuilder::paginator`]. Each call to
+/// [`Self::next_page`] yields the next [`FilterData`] page until the `next` token is absent or
+/// the `total` reported by the API has been reached.
+///
+/// ## Prefetching
+///
+/// With [`Self::prefetch`] set to a non-zero depth, the paginator spawns a background task once
+/// the first page reports more results than it contains. The task fetches up to `depth` pages
+/// ahead of the caller, hiding request latency during large scans. Because the look-ahead is
+/// bounded, the paginator never issues more than `depth + 1` requests beyond what the caller
+/// has consumed, and it never requests pages past the reported `total`.
+///
+/// Prefetching spawns the task onto the current Tokio runtime. Outside of a Tokio runtime, e.g.
+/// on an `async-std` or `smol` executor, the paginator falls back to fetching each page on
+/// demand. Dropping the paginator stops the background task after its in-flight request.
+pub struct FilterPaginator {
+    client: OpenFIGIClient,
+    request: FilterRequest,
+    prefetch_depth: usize,
+    state: PaginatorState,
+    total: Option<u64>,
+    fetched: usize,
+    /// Size of the largest page received, before applying the policy or the cap.
+    observed_page_size: Option<usize>,
+    /// Maximum number of results to yield, if capped.
+    max_results: Option<usize>,
+    /// Number of results yielded after applying the policy of the client.
+    yielded: usize,
+    /// Token of the page following the last received page, used to resume after an error.
+    resume_token: Option<String>,
+}
+
+impl FilterPaginator {
+    #[doc(hidden)]
+    /// Creates a new paginator for an already validated filter request.
+    ///
+    /// Any `start` token set on the request is used as the starting point of the pagination.
+    pub(crate) fn new(client: OpenFIGIClient, mut request: FilterRequest) -> Self {
+        let resume_token = request.start.take();
+        let state = match &resume_token {
+            Some(token) => PaginatorState::Next(token.clone()),
+            None => PaginatorState::Start,
+        };
+        Self {
+            client,
+            request,
+            prefetch_depth: 0,
+            state,
+            total: None,
+            fetched: 0,
+            observed_page_size: None,
+            max_results: None,
+            yielded: 0,
+            resume_token,
+        }
+    }
+
+    /// Enables background prefetching of up to `depth` pages.
+    ///
+    /// A depth of `0` (the default) disables prefetching, so each page is only requested
+    /// when [`Self::next_page`] is called.
+    #[must_use]
+    pub fn prefetch(mut self, depth: usize) -> Self {
+        self.prefetch_depth = depth;
+        self
+    }
+
+    /// Stops the pagination once `max` results have been yielded.
+    ///
+    /// The page reaching the cap is truncated, so at most `max` results are yielded in total.
+    /// Results discarded by the policy of the client do not count against the cap.
+    #[must_use]
+    pub fn max_results(mut self, max: usize) -> Self {
+        self.max_results = Some(max);
+        if max == 0 {
+            self.state = PaginatorState::Done;
+        }
+        self
+    }
+
+    /// Returns the total number of results reported by the API, once the first page is received.
+    #[must_use]
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns the number of results yielded so far across all pages.
+    #[must_use]
+    pub fn fetched(&self) -> usize {
+        self.fetched
+    }
+
+    /// Returns the page size used by the API, once the first page is received.
+    ///
+    /// The API does not report its page size, so this is the size of the largest page received
+    /// so far, counted before the policy of the client or [`Self::max_results`] discard results.
+    /// Pass it to [`Self::pages_remaining`] to estimate the progress against [`Self::total`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let mut paginator = client.filter().query("ibm").paginator()?;
+    ///
+    /// while let Some(page) = paginator.next_page().await {
+    ///     let page = page?;
+    ///     let remaining = paginator
+    ///         .observed_page_size()
+    ///         .and_then(|size| paginator.pages_remaining(size));
+    ///     println!("{} results, about {remaining:?} pages to go", page.data().len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn observed_page_size(&self) -> Option<usize> {
+        self.observed_page_size
+    }
+
+    /// Estimates the number of pages still to be fetched, assuming pages of `page_size_hint`
+    /// results.
+    ///
+    /// Returns `Some(0)` once the pagination is finished, and `None` before the first page is
+    /// received or if the API reported no total.
+    #[must_use]
+    pub fn pages_remaining(&self, page_size_hint: usize) -> Option<u64> {
+        if self.is_finished() {
+            return Some(0);
+        }
+        let fetched = u64::try_from(self.fetched).unwrap_or(u64::MAX);
+        self.total
+            .map(|total| page_count(total.saturating_sub(fetched), page_size_hint))
+    }
+
+    /// Returns `true` if no further pages will be yielded.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, PaginatorState::Done)
+    }
+
+    /// Fetches the next page of results.
+    ///
+    /// Returns `None` once all pages have been yielded. After an error is returned,
+    /// the paginator is finished and subsequent calls return `None`.
+    ///
+    /// # Errors
+    ///
+    /// Yields an [`crate::error::OpenFIGIError`] if the HTTP request fails or if the
+    /// response cannot be parsed.
+    pub async fn next_page(&mut self) -> Option<Result<FilterData>> {
+        let result = match std::mem::replace(&mut self.state, PaginatorState::Done) {
+            PaginatorState::Start => fetch_page(&self.client, &self.request, None).await,
+            PaginatorState::Next(token) => {
+                fetch_page(&self.client, &self.request, Some(token)).await
+            }
+            PaginatorState::Prefetching(mut receiver) => {
+                let result = receiver.recv().await?;
+                // Keep receiving from the background task for subsequent pages
+                self.state = PaginatorState::Prefetching(receiver);
+                result
+            }
+            PaginatorState::Done => return None,
+        };
+
+        match result {
+            Ok(mut page) => {
+                self.record_page(&page);
+                // Applied after recording so pages emptied by the policy do not end pagination
+                self.client
+                    .policy()
+                    .apply(&self.request.filters, &mut page.data);
+                self.apply_max_results(&mut page);
+                Some(Ok(page))
+            }
+            Err(err) => {
+                self.state = PaginatorState::Done;
+                Some(Err(err))
+            }
+        }
+    }
+
+    /// Fetches all remaining pages and returns their results.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResumableDrain`] if a page request fails. It holds the error, the results of
+    /// the pages received before the failure, and the token of the failed page, so
+    /// [`ResumableDrain::resume`] can continue the drain without requesting those pages again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let paginator = client.filter().query("ibm").paginator()?;
+    ///
+    /// let results = match paginator.drain().await {
+    ///     Ok(results) => results,
+    ///     Err(drain) => {
+    ///         eprintln!("Resuming after {} results: {}", drain.results().len(), drain.error());
+    ///         drain.resume().await?
+    ///     }
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn drain(self) -> std::result::Result<Vec<FigiResult>, ResumableDrain> {
+        drain_into(self, Vec::new()).await
+    }
+
+    /// Truncates `page` to the remaining results allowed by the cap, finishing once it is reached.
+    fn apply_max_results(&mut self, page: &mut FilterData) {
+        let Some(max) = self.max_results else {
+            return;
+        };
+        page.data.truncate(max.saturating_sub(self.yielded));
+        self.yielded += page.data.len();
+        if self.yielded >= max {
+            // Also drops the receiver of a prefetching task, which stops it
+            self.state = PaginatorState::Done;
+        }
+    }
+
+    /// Updates counters with the received page and determines how to fetch the next one.
+    fn record_page(&mut self, page: &FilterData) {
+        self.fetched += page.data.len();
+        if !page.data.is_empty() {
+            self.observed_page_size = self.observed_page_size.max(Some(page.data.len()));
+        }
+        self.resume_token.clone_from(&page.next);
+        if let Some(total) = page.total {
+            self.total = Some(total);
+        }
+
+        // Pages delivered by the background task require no further scheduling
+        if matches!(self.state, PaginatorState::Prefetching(_)) {
+            return;
+        }
+
+        self.state = match next_token(page, self.total, self.fetched) {
+            Some(token) if self.prefetch_depth > 0 && Handle::try_current().is_ok() => {
+                let (sender, receiver) = mpsc::channel(self.prefetch_depth);
+                tokio::spawn(prefetch_pages(
+                    self.client.clone(),
+                    self.request.clone(),
+                    token,
+                    self.total,
+                    self.fetched,
+                    sender,
+                ));
+                PaginatorState::Prefetching(receiver)
+            }
+            Some(token) => PaginatorState::Next(token),
+            None => PaginatorState::Done,
+        };
+    }
+}
+
+/// Fetches all remaining pages of `paginator`, appending their results to `results`.
+async fn drain_into(
+    mut paginator: FilterPaginator,
+    mut results: Vec<FigiResult>,
+) -> std::result::Result<Vec<FigiResult>, ResumableDrain> {
+    while let Some(page) = paginator.next_page().await {
+        match page {
+            Ok(page) => results.extend(page.data),
+            Err(error) => {
+                return Err(ResumableDrain(Box::new(DrainState {
+                    error,
+                    results,
+                    paginator,
+                })));
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// A [`FilterPaginator::drain`] that failed part-way through.
+///
+/// Holds the error and the results of all pages received before it. Since pages count against
+/// the rate limit, [`Self::resume`] continues the drain from the failed page instead of
+/// starting over.
+pub struct ResumableDrain(Box<DrainState>);
+
+/// Contents of a [`ResumableDrain`], boxed to keep the error type small.
+struct DrainState {
+    error: OpenFIGIError,
+    results: Vec<FigiResult>,
+    paginator: FilterPaginator,
+}
+
+impl ResumableDrain {
+    /// Returns the error of the failed page request.
+    #[must_use]
+    pub fn error(&self) -> &OpenFIGIError {
+        &self.0.error
+    }
+
+    /// Returns the results of all pages received before the failure.
+    #[must_use]
+    pub fn results(&self) -> &[FigiResult] {
+        &self.0.results
+    }
+
+    /// Returns the `next` token of the last received page, which requests the failed page.
+    ///
+    /// `None` if the first page failed, in which case resuming starts over.
+    #[must_use]
+    pub fn token(&self) -> Option<&str> {
+        self.0.paginator.resume_token.as_deref()
+    }
+
+    /// Returns the error and the results received before it, abandoning the drain.
+    #[must_use]
+    pub fn into_parts(self) -> (OpenFIGIError, Vec<FigiResult>) {
+        let state = *self.0;
+        (state.error, state.results)
+    }
+
+    /// Continues the drain from the failed page.
+    ///
+    /// Returns the results of all pages, including those received before the failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns another [`ResumableDrain`] if a page request fails again.
+    pub async fn resume(self) -> std::result::Result<Vec<FigiResult>, Self> {
+        let DrainState {
+            results,
+            mut paginator,
+            ..
+        } = *self.0;
+        paginator.state = match paginator.resume_token.clone() {
+            Some(token) => PaginatorState::Next(token),
+            None => PaginatorState::Start,
+        };
+        drain_into(paginator, results).await
+    }
+}
+
+impl fmt::Debug for ResumableDrain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResumableDrain")
+            .field("error", &self.0.error)
+            .field("results", &self.0.results.len())
+            .field("token", &self.token())
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ResumableDrain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pagination failed after {} results: {}",
+            self.0.results.len(),
+            self.0.error
+        )
+    }
+}
+
+impl error::Error for ResumableDrain {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.0.error)
+    }
+}
+
+/// Returns the token for the next page, or `None` if the result set is exhausted.
+fn next_token(page: &FilterData, total: Option<u64>, fetched: usize) -> Option<String> {
+    match (page.next.as_ref(), total) {
+        (Some(_), Some(total)) if u64::try_from(fetched).is_ok_and(|fetched| fetched >= total) => {
+            None
+        }
+        (Some(token), _) if !page.data.is_empty() => Some(token.clone()),
+        _ => None,
+    }
+}
+
+/// Sends a single filter request for the page identified by `start`.
+async fn fetch_page(
+    client: &OpenFIGIClient,
+    request: &FilterRequest,
+    start: Option<String>,
+) -> Result<FilterData> {
+    let mut request = request.clone();
+    request.start = start;
+
+    let raw_response = client
+        .request(DEFAULT_ENDPOINT_FILTER, Method::POST)
+        .body(&request)
+        .send()
+        .await?;
+    let data: FilterData = client.parse_single_response(raw_response).await?;
+    if let Some(warning) = data.warning() {
+        parse::log_warning(warning);
+    }
+    Ok(data)
+}
+
+/// Background task fetching pages ahead of the consumer.
+///
+/// The bounded channel provides back-pressure: once `depth` pages are buffered, the task
+/// waits until the consumer receives a page before requesting the next one.
+async fn prefetch_pages(
+    client: OpenFIGIClient,
+    request: FilterRequest,
+    mut token: String,
+    mut total: Option<u64>,
+    mut fetched: usize,
+    sender: mpsc::Sender<Result<FilterData>>,
+) {
+    loop {
+        match fetch_page(&client, &request, Some(token)).await {
+            Ok(page) => {
+                fetched += page.data.len();
+                total = page.total.or(total);
+                let next = next_token(&page, total, fetched);
+
+                // The consumer dropped the paginator, so stop fetching
+                if sender.send(Ok(page)).await.is_err() {
+                    return;
+                }
+
+                match next {
+                    Some(next) => token = next,
+                    None => return,
+                }
+            }
+            Err(err) => {
+                let _ = sender.send(Err(err)).await;
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::response::Figi;
+    use crate::test_utils::{filter_page, mock_client, mount_filter_pages};
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_partial_json, method, path},
+    };
+
+    async fn collect_figis(paginator: &mut FilterPaginator) -> Vec<Figi> {
+        let mut figis = Vec::new();
+        while let Some(page) = paginator.next_page().await {
+            let page = page.expect("Page request should succeed");
+            figis.extend(page.data.into_iter().map(|r| r.figi));
+        }
+        figis
+    }
+
+    #[test]
+    fn test_next_token_respects_total() {
+        let data: FilterData =
+            serde_json::from_value(filter_page(&["BBG000000016"], Some("p2"), 1))
+                .expect("Should deserialize page");
+        assert_eq!(next_token(&data, Some(1), 1), None);
+        assert_eq!(next_token(&data, Some(2), 1), Some("p2".to_string()));
+        assert_eq!(next_token(&data, None, 1), Some("p2".to_string()));
+    }
+
+    #[test]
+    fn test_prefetch_outside_tokio_runtime_fetches_on_demand() {
+        let request = FilterRequest::builder()
+            .query("ibm")
+            .build()
+            .expect("Should build filter request");
+        let mut paginator = FilterPaginator::new(OpenFIGIClient::new(), request).prefetch(2);
+        let data: FilterData =
+            serde_json::from_value(filter_page(&["BBG000000016"], Some("p2"), 2))
+                .expect("Should deserialize page");
+
+        paginator.record_page(&data);
+        assert!(matches!(paginator.state, PaginatorState::Next(ref token) if token == "p2"));
+    }
+
+    #[tokio::test]
+    async fn test_paginator_follows_tokens_until_total() {
+        let server = MockServer::start().await;
+        mount_filter_pages(&server).await;
+
+        let mut paginator = mock_client(&server)
+            .filter()
+            .query("ibm")
+            .paginator()
+            .expect("Should create paginator");
+
+        let figis = collect_figis(&mut paginator).await;
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+        assert_eq!(paginator.total(), Some(3));
+        assert_eq!(paginator.fetched(), 3);
+        assert_eq!(paginator.observed_page_size(), Some(2));
+        assert_eq!(paginator.pages_remaining(2), Some(0));
+        assert!(paginator.is_finished());
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_paginator_with_prefetch() {
+        let server = MockServer::start().await;
+        mount_filter_pages(&server).await;
+
+        let mut paginator = mock_client(&server)
+            .filter()
+            .query("ibm")
+            .paginator()
+            .expect("Should create paginator")
+            .prefetch(2);
+
+        let figis = collect_figis(&mut paginator).await;
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+        assert!(paginator.is_finished());
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_paginator_stops_after_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/filter"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let mut paginator = mock_client(&server)
+            .filter()
+            .query("ibm")
+            .paginator()
+            .expect("Should create paginator");
+
+        assert!(matches!(paginator.next_page().await, Some(Err(_))));
+        assert!(paginator.next_page().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_drain_resumes_from_failed_page() {
+        let server = MockServer::start().await;
+        let failure = Mock::given(method("POST"))
+            .and(path("/v3/filter"))
+            .and(body_partial_json(json!({ "start": "p2" })))
+            .respond_with(ResponseTemplate::new(500))
+            .mount_as_scoped(&server)
+            .await;
+        mount_filter_pages(&server).await;
+
+        let paginator = mock_client(&server)
+            .filter()
+            .query("ibm")
+            .paginator()
+            .expect("Should create paginator");
+
+        let drain = paginator
+            .drain()
+            .await
+            .expect_err("Second page should fail");
+        assert_eq!(drain.results().len(), 2);
+        assert_eq!(drain.token(), Some("p2"));
+        drop(failure);
+
+        let results = drain.resume().await.expect("Resumed drain should succeed");
+        let figis: Vec<_> = results.iter().map(|r| r.figi.as_str()).collect();
+        assert_eq!(figis, ["BBG000000016", "BBG000000025", "BBG000000034"]);
+        // The first page is not requested again
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(3));
+    }
+}
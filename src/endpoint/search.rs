@@ -6,6 +6,7 @@
 //! ## Key Features
 //!
 //! - **Single Search**: Build and send individual search requests
+//! - **Bulk Search**: Send many search requests with bounded concurrency and per-request results
 //! - **Fluent API**: Chainable method calls for easy configuration
 //! - **Validation**: Automatic validation of request limits and API key requirements
 //!
@@ -16,6 +17,7 @@
 //! ```rust
 //! use openfigi_rs::client::OpenFIGIClient;
 //! use openfigi_rs::model::enums::{Currency, ExchCode};
+//! use openfigi_rs::model::request::HasFilters;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = OpenFIGIClient::new();
@@ -29,23 +31,66 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ### Bulk Search Request
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let results = client
+//!     .bulk_search()
+//!     .add_query("ibm")
+//!     .add_query("apple")
+//!     .concurrency(2)
+//!     .send()
+//!     .await?;
+//! for result in results {
+//!     println!("{:?}", result.map(|data| data.data().len()));
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
+use crate::validation::{ValidationRule, ValidationRules};
 use crate::{
     DEFAULT_ENDPOINT_SEARCH,
     client::OpenFIGIClient,
-    error::Result,
-    impl_filter_builder,
+    endpoint::{
+        Endpoint, FilterableRequestBuilder, Sendable,
+        concurrent::run_concurrently,
+        dedup::{Deduplicated, dedup_requests},
+    },
+    error::{OpenFIGIError, OtherErrorKind, Result},
+    export,
     model::{
-        enums::{
-            Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
-            StateCode,
-        },
-        request::{RequestFilters, SearchRequestBuilder},
+        request::{HasFilters, RequestFilters, SearchRequest, SearchRequestBuilder},
         response::SearchData,
     },
+    parse,
 };
-use chrono::NaiveDate;
+use http::Extensions;
 use reqwest::Method;
+use std::{ops::RangeInclusive, path::Path, sync::Arc};
+
+/// Default number of requests of a [`BulkSearchRequestBuilder`] sent at the same time.
+pub const DEFAULT_SEARCH_CONCURRENCY: usize = 4;
+
+/// Marker type describing the `/search` endpoint for use with [`crate::endpoint::Endpoint`].
+pub struct SearchEndpoint;
+
+impl Endpoint for SearchEndpoint {
+    const PATH: &'static str = DEFAULT_ENDPOINT_SEARCH;
+    const METHOD: Method = Method::POST;
+    type Request = SearchRequest;
+    type Response = SearchData;
+
+    fn validate(request: &Self::Request) -> Result<()> {
+        request.validate()
+    }
+}
 
 /// Builder for constructing single search requests to the `/search` endpoint.
 ///
@@ -57,6 +102,7 @@ use reqwest::Method;
 /// ```rust
 /// use openfigi_rs::client::OpenFIGIClient;
 /// use openfigi_rs::model::enums::Currency;
+/// use openfigi_rs::model::request::HasFilters;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = OpenFIGIClient::new();
@@ -69,9 +115,13 @@ use reqwest::Method;
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct SingleSearchRequestBuilder {
     client: OpenFIGIClient,
     request_builder: SearchRequestBuilder,
+    expected_results: Option<RangeInclusive<usize>>,
+    validation_rules: ValidationRules,
+    extensions: Extensions,
 }
 
 impl SingleSearchRequestBuilder {
@@ -82,6 +132,18 @@ impl SingleSearchRequestBuilder {
         self
     }
 
+    /// Sets the query from structured components, see [`SearchRequestBuilder::query_parts`].
+    #[must_use]
+    pub fn query_parts(
+        mut self,
+        name: Option<&str>,
+        ticker: Option<&str>,
+        keywords: &[&str],
+    ) -> Self {
+        self.request_builder = self.request_builder.query_parts(name, ticker, keywords);
+        self
+    }
+
     /// Sets the optional pagination start value for the search request.
     #[must_use]
     pub fn start(mut self, start: &str) -> Self {
@@ -89,13 +151,38 @@ impl SingleSearchRequestBuilder {
         self
     }
 
-    /// Mutable access to the request filters, delegating to the inner `SearchRequestBuilder`.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        self.request_builder.filters_mut()
+    /// Sets the accepted range for the number of results returned by [`Self::send`].
+    ///
+    /// A response with a result count outside `range` is turned into an
+    /// [`crate::error::OpenFIGIError::UnexpectedResultCount`] error, e.g. `1..=1` for a
+    /// lookup that must resolve to exactly one instrument.
+    #[must_use]
+    pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+        self.expected_results = Some(range);
+        self
+    }
+
+    /// Adds a custom validation rule run for this request only, after the rules of the client.
+    ///
+    /// See [`crate::validation`] for details.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
     }
 
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
+    /// Attaches a typed value to this request, e.g. a job or portfolio id for correlation.
+    ///
+    /// The value is passed to every middleware of the client (see
+    /// [`crate::client_builder::OpenFIGIClientBuilder::middleware_client`]) in its
+    /// [`http::Extensions`], and is returned in the extensions of the response of
+    /// [`Self::send_raw`], together with the values added by middleware. A value of the same type
+    /// replaces the previous one.
+    #[must_use]
+    pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
 
     /// Sends the search request to `/search` endpoint and returns the raw HTTP response.
     ///
@@ -107,9 +194,11 @@ impl SingleSearchRequestBuilder {
     /// Returns an [`crate::error::OpenFIGIError`] if the search request is invalid or if the HTTP request fails.
     pub async fn send_raw(self) -> Result<reqwest::Response> {
         let request = self.request_builder.build()?;
+        self.validation_rules.validate(&request.filters)?;
         self.client
             .request(DEFAULT_ENDPOINT_SEARCH, Method::POST)
             .body(&request)
+            .extensions(self.extensions)
             .send()
             .await
     }
@@ -119,15 +208,242 @@ impl SingleSearchRequestBuilder {
     /// # Errors
     ///
     /// Returns an [`crate::error::OpenFIGIError`] if the search request is invalid, if the HTTP request fails,
-    /// or if the response cannot be parsed.
-    pub async fn send(self) -> Result<SearchData> {
+    /// if the response cannot be parsed, or if the number of results is outside the range set with
+    /// [`Self::expect_results`].
+    pub async fn send(mut self) -> Result<SearchData> {
         let client = self.client.clone();
+        let expected_results = self.expected_results.take();
+        let filters = self.request_builder.filters_mut().clone();
         let raw_response = self.send_raw().await?;
 
-        client.parse_single_response(raw_response).await
+        let mut data: SearchData = client.parse_single_response(raw_response).await?;
+        if let Some(warning) = data.warning() {
+            parse::log_warning(warning);
+        }
+        client.policy().apply(&filters, &mut data.data);
+        OpenFIGIError::check_result_count(expected_results.as_ref(), data.data().len())?;
+        Ok(data)
+    }
+
+    /// Sends the search request and writes the results to the file at `path` as
+    /// newline-delimited JSON, see [`crate::export`].
+    ///
+    /// The file is created or truncated once the response has been parsed. Returns the number
+    /// of results written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as [`Self::send`],
+    /// or if the file cannot be written.
+    pub async fn send_to_ndjson(self, path: impl AsRef<Path>) -> Result<usize> {
+        let data = self.send().await?;
+        export::to_ndjson_file(path, data.data())
+    }
+}
+
+impl HasFilters for SingleSearchRequestBuilder {
+    /// Delegates to the inner `SearchRequestBuilder`.
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        self.request_builder.filters_mut()
     }
 }
 
+impl FilterableRequestBuilder for SingleSearchRequestBuilder {
+    fn expect_results(self, range: RangeInclusive<usize>) -> Self {
+        SingleSearchRequestBuilder::expect_results(self, range)
+    }
+
+    fn validation_rule(self, rule: impl ValidationRule + 'static) -> Self {
+        SingleSearchRequestBuilder::validation_rule(self, rule)
+    }
+
+    fn extension<T: Clone + Send + Sync + 'static>(self, value: T) -> Self {
+        SingleSearchRequestBuilder::extension(self, value)
+    }
+}
+
+impl Sendable for SingleSearchRequestBuilder {
+    type Output = SearchData;
+
+    fn send(self) -> impl Future<Output = Result<SearchData>> + Send {
+        SingleSearchRequestBuilder::send(self)
+    }
+
+    fn send_raw(self) -> impl Future<Output = Result<reqwest::Response>> + Send {
+        SingleSearchRequestBuilder::send_raw(self)
+    }
+}
+
+/// Builder for sending many search requests to the `/search` endpoint.
+///
+/// The API accepts a single query per `/search` request, so each request is sent on its own,
+/// with at most [`Self::concurrency`] requests in flight at once, each counting against a rate
+/// limit of the client. Results are returned per request, in the order the requests were added.
+/// Created via [`OpenFIGIClient::bulk_search`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use openfigi_rs::client::OpenFIGIClient;
+/// use openfigi_rs::model::request::SearchRequest;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OpenFIGIClient::new();
+///
+/// let results = client
+///     .bulk_search()
+///     .add_request(SearchRequest::new("ibm"))
+///     .add_query("apple")
+///     .send()
+///     .await?;
+/// assert_eq!(results.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct BulkSearchRequestBuilder {
+    client: OpenFIGIClient,
+    requests: Vec<SearchRequest>,
+    expected_results: Option<RangeInclusive<usize>>,
+    validation_rules: ValidationRules,
+    extensions: Extensions,
+    concurrency: usize,
+}
+
+impl BulkSearchRequestBuilder {
+    /// Adds a search request.
+    #[must_use]
+    pub fn add_request(mut self, request: SearchRequest) -> Self {
+        self.requests.push(request);
+        self
+    }
+
+    /// Adds multiple search requests.
+    #[must_use]
+    pub fn add_requests(mut self, requests: Vec<SearchRequest>) -> Self {
+        self.requests.extend(requests);
+        self
+    }
+
+    /// Adds a search request for `query` without filters.
+    #[must_use]
+    pub fn add_query(self, query: impl Into<String>) -> Self {
+        self.add_request(SearchRequest::new(query))
+    }
+
+    /// Sets the expected number of results of every request, see
+    /// [`SingleSearchRequestBuilder::expect_results`].
+    #[must_use]
+    pub fn expect_results(mut self, range: RangeInclusive<usize>) -> Self {
+        self.expected_results = Some(range);
+        self
+    }
+
+    /// Adds a custom validation rule run for the filters of every request before any request is
+    /// sent, in addition to the rules of the client.
+    #[must_use]
+    pub fn validation_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.validation_rules.push(rule);
+        self
+    }
+
+    /// Attaches a typed value to the extensions of every request, see
+    /// [`SingleSearchRequestBuilder::extension`].
+    #[must_use]
+    pub fn extension<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
+
+    /// Sets how many requests are sent at the same time.
+    ///
+    /// Defaults to [`DEFAULT_SEARCH_CONCURRENCY`]; `0` is treated as `1`. Concurrent requests
+    /// are sent on background tasks of the current Tokio runtime, and one after another outside
+    /// of one.
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Checks that the batch is not empty and validates every request.
+    fn validate(&self) -> Result<()> {
+        if self.requests.is_empty() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "No requests to send",
+            ));
+        }
+        for request in &self.requests {
+            request.validate()?;
+            self.validation_rules.validate(&request.filters)?;
+        }
+        Ok(())
+    }
+
+    /// Sends the search requests and returns the result of every request in the order of the
+    /// requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the batch is empty or a request is invalid,
+    /// before any request is sent. Errors of individual requests, e.g. HTTP errors or a number
+    /// of results outside the range set with [`Self::expect_results`], are returned in their
+    /// position of the result.
+    pub async fn send(mut self) -> Result<Vec<Result<SearchData>>> {
+        self.validate()?;
+        let requests = std::mem::take(&mut self.requests);
+        Ok(self.dispatch(requests).await)
+    }
+
+    /// Sends only the unique search requests and returns results addressable by original
+    /// position, see [`crate::endpoint::dedup`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] under the same conditions as [`Self::send`].
+    pub async fn send_deduplicated(mut self) -> Result<Deduplicated<SearchData>> {
+        self.validate()?;
+        let (unique, indices) = dedup_requests(std::mem::take(&mut self.requests));
+        Ok(Deduplicated::new(self.dispatch(unique).await, indices))
+    }
+
+    /// Sends `requests` with at most [`Self::concurrency`] in flight and returns their results
+    /// in order.
+    async fn dispatch(self, requests: Vec<SearchRequest>) -> Vec<Result<SearchData>> {
+        let expected = self.expected_results.map(Arc::new);
+        run_concurrently(requests, self.concurrency, |request| {
+            let client = self.client.clone();
+            let extensions = self.extensions.clone();
+            let expected = expected.clone();
+            async move { search(&client, request, extensions, expected.as_deref()).await }
+        })
+        .await
+    }
+}
+
+/// Sends a validated search request and returns its parsed results after applying the policy
+/// of the client.
+async fn search(
+    client: &OpenFIGIClient,
+    request: SearchRequest,
+    extensions: Extensions,
+    expected: Option<&RangeInclusive<usize>>,
+) -> Result<SearchData> {
+    let raw_response = client
+        .request(DEFAULT_ENDPOINT_SEARCH, Method::POST)
+        .body(&request)
+        .extensions(extensions)
+        .send()
+        .await?;
+    let mut data: SearchData = client.parse_single_response(raw_response).await?;
+    if let Some(warning) = data.warning() {
+        parse::log_warning(warning);
+    }
+    client.policy().apply(&request.filters, &mut data.data);
+    OpenFIGIError::check_result_count(expected, data.data().len())?;
+    Ok(data)
+}
+
 impl OpenFIGIClient {
     /// Creates a new [`SingleSearchRequestBuilder`] for configuring and executing a single search request.
     ///
@@ -148,6 +464,31 @@ impl OpenFIGIClient {
         SingleSearchRequestBuilder {
             client: self.clone(),
             request_builder: SearchRequestBuilder::new().query(query),
+            expected_results: None,
+            validation_rules: self.validation_rules().clone(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Creates a new [`BulkSearchRequestBuilder`] for sending many search requests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let builder = client.bulk_search().add_query("ibm").concurrency(2);
+    /// ```
+    #[must_use]
+    pub fn bulk_search(&self) -> BulkSearchRequestBuilder {
+        BulkSearchRequestBuilder {
+            client: self.clone(),
+            requests: Vec::new(),
+            expected_results: None,
+            validation_rules: self.validation_rules().clone(),
+            extensions: Extensions::new(),
+            concurrency: DEFAULT_SEARCH_CONCURRENCY,
         }
     }
 }
@@ -156,6 +497,11 @@ impl OpenFIGIClient {
 mod tests {
     use super::*;
     use crate::client::OpenFIGIClient;
+    use crate::model::enums::{
+        Currency, ExchCode, MarketSecDesc, OptionType, SecurityType, StateCode,
+    };
+    use crate::test_utils::{mock_client, mock_client_builder};
+    use chrono::NaiveDate;
 
     fn create_test_client() -> OpenFIGIClient {
         OpenFIGIClient::new()
@@ -272,4 +618,215 @@ mod tests {
         // Verify client reference is preserved
         assert_eq!(builder.client.base_url(), client.base_url());
     }
+
+    #[tokio::test]
+    async fn test_search_expect_results() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{ "figi": "BBG000BLNNH6" }, { "figi": "BBG000BLNQ16" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let data = client
+            .search("ibm")
+            .expect_results(1..=2)
+            .send()
+            .await
+            .expect("Two results should be accepted");
+        assert_eq!(data.data().len(), 2);
+
+        let err = client
+            .search("ibm")
+            .expect_results(1..=1)
+            .send()
+            .await
+            .expect_err("Two results should be rejected");
+        assert!(err.is_unexpected_result_count());
+        assert!(matches!(
+            err,
+            OpenFIGIError::UnexpectedResultCount { actual: 2, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_validation_rules() {
+        use crate::error::OtherErrorKind;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{ "figi": "BBG000BLNNH6" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .validation_rule(|filters: &RequestFilters| {
+                if filters.include_unlisted_equities == Some(true) {
+                    return Err(OpenFIGIError::other_error(
+                        OtherErrorKind::Validation,
+                        "unlisted equities are not allowed",
+                    ));
+                }
+                Ok(())
+            })
+            .build()
+            .expect("Failed to create test client");
+
+        let err = client
+            .search("ibm")
+            .include_unlisted_equities(true)
+            .send()
+            .await
+            .expect_err("Client rule should reject the request");
+        assert!(err.to_string().contains("unlisted equities"));
+
+        let err = client
+            .search("ibm")
+            .validation_rule(|filters: &RequestFilters| {
+                if filters.currency.is_none() {
+                    return Err(OpenFIGIError::other_error(
+                        OtherErrorKind::Validation,
+                        "currency is required",
+                    ));
+                }
+                Ok(())
+            })
+            .send()
+            .await
+            .expect_err("Builder rule should reject the request");
+        assert!(err.to_string().contains("currency is required"));
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(0));
+
+        assert!(client.search("ibm").send().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_search_policy() {
+        use crate::policy::{AuditRecord, Policy};
+        use std::sync::{Arc, Mutex};
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    { "figi": "BBG000BLNNH6", "exchCode": "US" },
+                    { "figi": "BBG000BLNQ16", "exchCode": "RX" }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&records);
+        let client = mock_client_builder(&server)
+            .policy(
+                Policy::new()
+                    .deny_mic_codes(["MISX".parse().expect("known MIC code")])
+                    .on_audit(move |record: &AuditRecord| {
+                        sink.lock().unwrap().push(record.result.figi.clone());
+                    }),
+            )
+            .build()
+            .expect("Failed to create test client");
+
+        let data = client
+            .search("ibm")
+            .expect_results(1..=1)
+            .send()
+            .await
+            .expect("Policy should discard the denied result");
+        assert_eq!(data.data().len(), 1);
+        assert_eq!(data.data()[0].figi, "BBG000BLNNH6");
+        assert_eq!(*records.lock().unwrap(), vec!["BBG000BLNQ16".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_search() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .and(body_partial_json(json!({ "query": "ibm" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "figi": "BBG000BLNNH6" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .and(body_partial_json(json!({ "query": "apple" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{ "figi": "BBG000B9XRY4" }, { "figi": "BBG000B9Y5X2" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let results = client
+            .bulk_search()
+            .add_query("ibm")
+            .add_query("apple")
+            .add_query("ibm")
+            .expect_results(1..=1)
+            .concurrency(2)
+            .send()
+            .await
+            .expect("Bulk search should be sent");
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().expect("ibm should succeed").data()[0].figi,
+            "BBG000BLNNH6"
+        );
+        assert!(
+            results[1]
+                .as_ref()
+                .is_err_and(OpenFIGIError::is_unexpected_result_count)
+        );
+        assert!(results[2].is_ok());
+
+        let deduplicated = client
+            .bulk_search()
+            .add_query("ibm")
+            .add_query("ibm")
+            .send_deduplicated()
+            .await
+            .expect("Bulk search should be sent");
+        assert_eq!(deduplicated.duplicates(), 1);
+        assert!(deduplicated.get(1).is_some_and(Result::is_ok));
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(4));
+
+        let err = client
+            .bulk_search()
+            .send()
+            .await
+            .expect_err("Empty bulk search should be rejected");
+        assert!(err.to_string().contains("No requests"));
+    }
 }
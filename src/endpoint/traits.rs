@@ -0,0 +1,421 @@
+//! # Endpoint Traits
+//!
+//! Compile-time description of OpenFIGI API endpoints and the generic plumbing to execute them.
+//!
+//! The built-in endpoints ([`crate::endpoint::mapping::MappingEndpoint`],
+//! [`crate::endpoint::search::SearchEndpoint`] and [`crate::endpoint::filter::FilterEndpoint`])
+//! implement [`Endpoint`], and so can endpoints defined outside of this crate. This allows
+//! gateways or forks exposing bespoke endpoints to reuse the client's authentication and
+//! error handling via [`OpenFIGIClient::execute`] without modifying the crate.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::endpoint::Endpoint;
+//! use reqwest::Method;
+//! use serde::{Deserialize, Serialize};
+//!
+//! /// A custom endpoint exposed by an internal gateway.
+//! struct EnrichEndpoint;
+//!
+//! #[derive(Serialize)]
+//! struct EnrichRequest {
+//!     figi: String,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct EnrichResponse {
+//!     sector: String,
+//! }
+//!
+//! impl Endpoint for EnrichEndpoint {
+//!     const PATH: &'static str = "enrich";
+//!     const METHOD: Method = Method::POST;
+//!     type Request = EnrichRequest;
+//!     type Response = EnrichResponse;
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//! let request = EnrichRequest { figi: "BBG000BLNNH6".into() };
+//! let response = client.execute::<EnrichEndpoint>(&request).await?;
+//! println!("Sector: {}", response.sector);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::OpenFIGIClient,
+    error::Result,
+    model::{enums::CountryCode, request::HasFilters},
+    validation::ValidationRule,
+};
+use reqwest::Method;
+use serde::{Serialize, de::DeserializeOwned};
+use std::ops::RangeInclusive;
+
+/// Compile-time description of an API endpoint.
+///
+/// Associates an endpoint path and HTTP method with the request and response types
+/// exchanged with it. Implementations are usually zero-sized marker types.
+///
+/// The response body is parsed with the same OpenFIGI-specific error handling used by
+/// the built-in endpoints: a top-level `{"error": "..."}` body or a non-successful status
+/// code is converted into an [`crate::error::OpenFIGIError`].
+pub trait Endpoint {
+    /// The endpoint path relative to the client's base URL (e.g. `"mapping"`).
+    const PATH: &'static str;
+
+    /// The HTTP method used to call the endpoint.
+    const METHOD: Method;
+
+    /// The request body type, serialized as JSON.
+    type Request: Serialize;
+
+    /// The response body type, deserialized from JSON.
+    type Response: DeserializeOwned;
+
+    /// Validates a request before it is sent.
+    ///
+    /// The default implementation accepts every request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] with
+    /// [`crate::error::OtherErrorKind::Validation`] if the request is invalid.
+    fn validate(request: &Self::Request) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+}
+
+/// Endpoint request builder accepting the common [`crate::model::request::RequestFilters`].
+///
+/// Implemented by [`crate::endpoint::mapping::SingleMappingRequestBuilder`],
+/// [`crate::endpoint::search::SingleSearchRequestBuilder`] and
+/// [`crate::endpoint::filter::SingleFilterRequestBuilder`], so applications can write generic
+/// functions applying a standard filter policy to any of them. The filter setters are provided
+/// by the [`HasFilters`] supertrait.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::client::OpenFIGIClient;
+/// use openfigi_rs::endpoint::FilterableRequestBuilder;
+/// use openfigi_rs::model::enums::{Currency, IdType};
+///
+/// /// Restricts any request to listed instruments traded in US dollars.
+/// fn usd_listed<B: FilterableRequestBuilder>(builder: B) -> B {
+///     builder
+///         .currency(Currency::USD)
+///         .include_unlisted_equities(false)
+/// }
+///
+/// let client = OpenFIGIClient::new();
+/// let search = usd_listed(client.search("ibm"));
+/// let mapping = usd_listed(client.mapping(IdType::TICKER, "IBM"));
+/// ```
+pub trait FilterableRequestBuilder: HasFilters {
+    /// Sets the accepted range for the number of results returned by `send()`.
+    #[must_use]
+    fn expect_results(self, range: RangeInclusive<usize>) -> Self;
+
+    /// Adds a custom validation rule run for this request only, after the rules of the client.
+    #[must_use]
+    fn validation_rule(self, rule: impl ValidationRule + 'static) -> Self;
+
+    /// Attaches a typed value to this request, passed to the middleware of the client.
+    #[must_use]
+    fn extension<T: Clone + Send + Sync + 'static>(self, value: T) -> Self;
+
+    /// Fans this request out into one request per exchange code of `country`.
+    ///
+    /// OpenFIGI filters accept a single exchange code, so all listings of a country require one
+    /// request per venue. The exchange codes come from the bundled reference data, see
+    /// [`CountryCode::exch_codes`], and include the composite codes of the country. Call this
+    /// last, as every returned builder is an independent copy. Returns no builders for
+    /// countries without venues.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::endpoint::FilterableRequestBuilder;
+    /// use openfigi_rs::model::enums::{CountryCode, SecurityType2};
+    /// use openfigi_rs::model::request::HasFilters;
+    ///
+    /// let client = OpenFIGIClient::new();
+    /// let german_listings = client
+    ///     .search("siemens")
+    ///     .security_type2(SecurityType2::CommonStock)
+    ///     .country("DE".parse::<CountryCode>()?);
+    /// assert!(german_listings.len() > 1);
+    /// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+    /// ```
+    #[must_use]
+    fn country(self, country: CountryCode) -> Vec<Self>
+    where
+        Self: Clone + Sized,
+    {
+        country
+            .exch_codes()
+            .into_iter()
+            .map(|exch_code| self.clone().exch_code(exch_code))
+            .collect()
+    }
+}
+
+/// Endpoint request builder sending a single HTTP request.
+///
+/// Implemented by [`crate::endpoint::mapping::SingleMappingRequestBuilder`],
+/// [`crate::endpoint::mapping::BulkMappingRequestBuilder`],
+/// [`crate::endpoint::search::SingleSearchRequestBuilder`] and
+/// [`crate::endpoint::filter::SingleFilterRequestBuilder`], so applications can wrap any request
+/// in generic instrumentation, like timing or caching decorators. The methods behave like the
+/// inherent `send()` and `send_raw()` methods of the builders.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use openfigi_rs::client::OpenFIGIClient;
+/// use openfigi_rs::endpoint::Sendable;
+/// use openfigi_rs::error::Result;
+/// use std::time::Instant;
+///
+/// /// Sends any request and logs how long it took.
+/// async fn timed<S: Sendable>(request: S) -> Result<S::Output> {
+///     let start = Instant::now();
+///     let output = request.send().await;
+///     println!("Request took {:?}", start.elapsed());
+///     output
+/// }
+///
+/// # async fn example() -> Result<()> {
+/// let client = OpenFIGIClient::new();
+/// let search = timed(client.search("ibm")).await?;
+/// let filter = timed(client.filter().query("ibm")).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Sendable: Sized {
+    /// The parsed response returned by [`Self::send`].
+    type Output;
+
+    /// Sends the request and returns the parsed response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the request is invalid, if the HTTP request
+    /// fails, or if the response cannot be parsed.
+    fn send(self) -> impl Future<Output = Result<Self::Output>> + Send;
+
+    /// Sends the request and returns the raw HTTP response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the request is invalid or if the HTTP request
+    /// fails.
+    fn send_raw(self) -> impl Future<Output = Result<reqwest::Response>> + Send;
+}
+
+impl OpenFIGIClient {
+    /// Validates and sends a request to the endpoint `E`, returning the raw HTTP response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if validation fails or if the HTTP request fails.
+    pub async fn execute_raw<E: Endpoint>(
+        &self,
+        request: &E::Request,
+    ) -> Result<reqwest::Response> {
+        E::validate(request)?;
+        self.request(E::PATH, E::METHOD).body(request).send().await
+    }
+
+    /// Validates and sends a request to the endpoint `E`, returning the parsed response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if validation fails, if the HTTP request fails,
+    /// or if the response cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use openfigi_rs::client::OpenFIGIClient;
+    /// use openfigi_rs::endpoint::search::SearchEndpoint;
+    /// use openfigi_rs::model::request::SearchRequest;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenFIGIClient::new();
+    /// let response = client
+    ///     .execute::<SearchEndpoint>(&SearchRequest::new("ibm"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute<E: Endpoint>(&self, request: &E::Request) -> Result<E::Response> {
+        let raw_response = self.execute_raw::<E>(request).await?;
+        self.parse_single_response(raw_response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use crate::{
+        endpoint::{mapping::MappingEndpoint, search::SearchEndpoint},
+        model::{
+            enums::{Currency, IdType},
+            request::{MappingRequest, SearchRequest},
+        },
+    };
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, method, path},
+    };
+
+    #[tokio::test]
+    async fn test_execute_search_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .and(body_json(json!({ "query": "ibm" })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": [{ "figi": "BBG000BLNNH6" }], "next": "p2" })),
+            )
+            .mount(&server)
+            .await;
+
+        let response = mock_client(&server)
+            .execute::<SearchEndpoint>(&SearchRequest::new("ibm"))
+            .await
+            .expect("Search should succeed");
+        assert_eq!(response.data()[0].figi, "BBG000BLNNH6");
+        assert_eq!(response.next_page(), Some("p2"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_mapping_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "error": "No identifier found." }
+            ])))
+            .mount(&server)
+            .await;
+
+        let requests = vec![
+            MappingRequest::new(IdType::TICKER, "IBM"),
+            MappingRequest::new(IdType::TICKER, "UNKNOWN"),
+        ];
+        let responses = mock_client(&server)
+            .execute::<MappingEndpoint>(&requests)
+            .await
+            .expect("Mapping should succeed");
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses.successes().count(), 1);
+        assert_eq!(responses.not_found().next().map(|(i, _)| i), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_execute_validates_before_sending() {
+        let server = MockServer::start().await;
+
+        let result = mock_client(&server)
+            .execute::<MappingEndpoint>(&Vec::new())
+            .await;
+        assert!(result.is_err());
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(0));
+    }
+
+    async fn send_counted<S: Sendable>(request: S, count: &mut usize) -> Result<S::Output> {
+        *count += 1;
+        request.send().await
+    }
+
+    #[tokio::test]
+    async fn test_sendable_generic_send() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": [{ "figi": "BBG000BLNNH6" }] })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{ "data": [{ "figi": "BBG000BLNNH6" }] }])),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let mut count = 0;
+        let search = send_counted(client.search("ibm"), &mut count)
+            .await
+            .expect("Search should succeed");
+        let mapping = send_counted(client.mapping(IdType::TICKER, "IBM"), &mut count)
+            .await
+            .expect("Mapping should succeed");
+        assert_eq!(search.data()[0].figi, "BBG000BLNNH6");
+        assert_eq!(mapping.data()[0].figi, "BBG000BLNNH6");
+        assert_eq!(count, 2);
+
+        let raw = Sendable::send_raw(client.search("ibm"))
+            .await
+            .expect("Raw search should succeed");
+        assert!(raw.status().is_success());
+    }
+
+    fn usd_listed<B: FilterableRequestBuilder>(builder: B) -> B {
+        builder
+            .currency(Currency::USD)
+            .include_unlisted_equities(false)
+    }
+
+    #[test]
+    fn test_filterable_request_builder_generic_policy() {
+        let client = OpenFIGIClient::new();
+
+        let mut search = usd_listed(client.search("ibm"));
+        let mut filter = usd_listed(client.filter());
+        let mut mapping = usd_listed(client.mapping(IdType::TICKER, "IBM"));
+
+        for filters in [
+            search.filters_mut(),
+            filter.filters_mut(),
+            mapping.filters_mut(),
+        ] {
+            assert_eq!(filters.currency, Some(Currency::USD));
+            assert_eq!(filters.include_unlisted_equities, Some(false));
+        }
+    }
+
+    #[test]
+    fn test_country_fans_out_per_exch_code() {
+        let client = OpenFIGIClient::new();
+        let germany: CountryCode = "DE".parse().expect("Should parse");
+
+        let searches = usd_listed(client.search("siemens")).country(germany);
+        let filters = client.filter().query("siemens").country(germany);
+        assert_eq!(searches.len(), germany.exch_codes().len());
+        assert_eq!(filters.len(), germany.exch_codes().len());
+
+        for (mut search, exch_code) in searches.into_iter().zip(germany.exch_codes()) {
+            let filters = search.filters_mut();
+            assert_eq!(filters.exch_code, Some(exch_code));
+            assert_eq!(filters.currency, Some(Currency::USD));
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! # OpenFIGI Mapping Values Endpoint
+//!
+//! Runtime access to the [/mapping/values/:key](https://www.openfigi.com/api/documentation)
+//! endpoint of the OpenFIGI API, which lists the current values of a mapping request property.
+//!
+//! The enums in [`crate::model::enums`] are generated from the same endpoint when the crate is
+//! built, so they only know the values current at that time. Fetching the values at runtime
+//! lets applications offer the current values, e.g. in a dropdown, without a new release.
+//!
+//! ## Key Features
+//!
+//! - **Typed keys**: [`MappingKey`] names every property with a list of values
+//! - **Conditional requests**: With an [`crate::cache::ETagCache`] configured on the client,
+//!   refreshing unchanged values costs a round trip without a response body
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::endpoint::values::MappingKey;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let currencies = client.mapping_values(MappingKey::Currency).await?;
+//! println!("{} currencies", currencies.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    DEFAULT_ENDPOINT_MAPPING,
+    client::OpenFIGIClient,
+    error::{OpenFIGIError, Result},
+    model::enums::unknown_value_expecting,
+};
+use reqwest::Method;
+use serde::Deserialize;
+use std::{fmt, str::FromStr};
+
+/// A mapping request property with a list of values at `/mapping/values/:key`.
+///
+/// Each key corresponds to an enum in [`crate::model::enums`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MappingKey {
+    /// Identifier types, see [`crate::model::enums::IdType`].
+    IdType,
+    /// Exchange codes, see [`crate::model::enums::ExchCode`].
+    ExchCode,
+    /// Market identifier codes, see [`crate::model::enums::MicCode`].
+    MicCode,
+    /// Currencies, see [`crate::model::enums::Currency`].
+    Currency,
+    /// Market sectors, see [`crate::model::enums::MarketSecDesc`].
+    MarketSecDes,
+    /// Security types, see [`crate::model::enums::SecurityType`].
+    SecurityType,
+    /// Alternative security types, see [`crate::model::enums::SecurityType2`].
+    SecurityType2,
+    /// State codes, see [`crate::model::enums::StateCode`].
+    StateCode,
+}
+
+impl MappingKey {
+    /// All keys with a list of values.
+    pub const ALL: [Self; 8] = [
+        Self::IdType,
+        Self::ExchCode,
+        Self::MicCode,
+        Self::Currency,
+        Self::MarketSecDes,
+        Self::SecurityType,
+        Self::SecurityType2,
+        Self::StateCode,
+    ];
+
+    /// Returns the key as used in the path of the endpoint, e.g. `exchCode`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IdType => "idType",
+            Self::ExchCode => "exchCode",
+            Self::MicCode => "micCode",
+            Self::Currency => "currency",
+            Self::MarketSecDes => "marketSecDes",
+            Self::SecurityType => "securityType",
+            Self::SecurityType2 => "securityType2",
+            Self::StateCode => "stateCode",
+        }
+    }
+}
+
+impl fmt::Display for MappingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MappingKey {
+    type Err = OpenFIGIError;
+
+    /// Parses the key as used in the path of the endpoint, e.g. `exchCode`.
+    fn from_str(value: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|key| key.as_str() == value)
+            .ok_or_else(|| {
+                unknown_value_expecting("MappingKey", value, Self::ALL.map(Self::as_str))
+            })
+    }
+}
+
+/// Response body of the `/mapping/values/:key` endpoint.
+#[derive(Deserialize)]
+struct MappingValues {
+    values: Vec<String>,
+}
+
+impl OpenFIGIClient {
+    /// Fetches the current values of the mapping request property `key`.
+    ///
+    /// Sends `GET /mapping/values/:key`, which counts against the rate limit of the mapping
+    /// endpoint. With an [`crate::cache::ETagCache`] configured, unchanged values are served
+    /// from the cache after revalidation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the HTTP request fails or if the response
+    /// cannot be parsed.
+    pub async fn mapping_values(&self, key: MappingKey) -> Result<Vec<String>> {
+        let path = format!("{DEFAULT_ENDPOINT_MAPPING}/values/{key}");
+        let raw_response = self.request(&path, Method::GET).send().await?;
+        let data: MappingValues = self.parse_single_response(raw_response).await?;
+        Ok(data.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+
+    #[tokio::test]
+    async fn test_mapping_values() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v3/mapping/values/currency"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "values": ["EUR", "USD"] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let values = client
+            .mapping_values(MappingKey::Currency)
+            .await
+            .expect("Values should be fetched");
+        assert_eq!(values, ["EUR", "USD"]);
+
+        let err = client
+            .mapping_values(MappingKey::StateCode)
+            .await
+            .expect_err("Unknown path should fail");
+        assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    }
+}
@@ -0,0 +1,125 @@
+//! # Environments
+//!
+//! Named targets of the OpenFIGI API, so deployments select the API by name in their
+//! configuration instead of spreading base URLs through the code.
+//!
+//! [`Environment`](crate::environment::Environment) is either the production API or a custom
+//! base URL, e.g. of a mock server in integration tests or of a proxy in front of the API. It
+//! parses from and serializes to a single string, `production` or the URL, so it can be a
+//! field of a configuration file, and is applied with
+//! [`OpenFIGIClientBuilder::environment`](crate::client_builder::OpenFIGIClientBuilder::environment).
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::environment::Environment;
+//!
+//! let environment: Environment = "http://localhost:8080/v3/".parse()?;
+//! let client = OpenFIGIClient::builder().environment(environment).build()?;
+//! assert_eq!(client.base_url().as_str(), "http://localhost:8080/v3/");
+//! # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+//! ```
+
+use crate::{
+    DEFAULT_BASE_URL,
+    error::{OpenFIGIError, Result},
+    model::enums::FromStrVisitor,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use url::Url;
+
+/// A target of the OpenFIGI API.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Environment {
+    /// The production API at [`crate::DEFAULT_BASE_URL`].
+    #[default]
+    Production,
+    /// A custom base URL, including the version path, e.g. `http://localhost:8080/v3/`.
+    Custom(Url),
+}
+
+impl Environment {
+    /// Returns the base URL of this environment.
+    #[must_use]
+    pub fn base_url(&self) -> &Url {
+        match self {
+            Self::Production => &DEFAULT_BASE_URL,
+            Self::Custom(url) => url,
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Production => f.write_str("production"),
+            Self::Custom(url) => f.write_str(url.as_str()),
+        }
+    }
+}
+
+impl FromStr for Environment {
+    type Err = OpenFIGIError;
+
+    /// Parses `production`, ignoring case, or a custom base URL.
+    fn from_str(value: &str) -> Result<Self> {
+        if value.eq_ignore_ascii_case("production") {
+            Ok(Self::Production)
+        } else {
+            Ok(Self::Custom(Url::parse(value)?))
+        }
+    }
+}
+
+impl From<Url> for Environment {
+    fn from(url: Url) -> Self {
+        Self::Custom(url)
+    }
+}
+
+impl Serialize for Environment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Environment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("Environment"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(
+            "Production".parse::<Environment>().ok(),
+            Some(Environment::Production)
+        );
+        assert_eq!(Environment::Production.to_string(), "production");
+        assert_eq!(Environment::Production.base_url(), &*DEFAULT_BASE_URL);
+
+        let custom: Environment = "http://localhost:8080/v3/"
+            .parse()
+            .expect("Should parse custom URL");
+        assert_eq!(custom.to_string(), "http://localhost:8080/v3/");
+        assert!("staging".parse::<Environment>().is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let environments = vec![
+            Environment::Production,
+            "http://localhost:8080/v3/".parse().expect("Should parse"),
+        ];
+        let json = serde_json::to_string(&environments).expect("Should serialize");
+        assert_eq!(json, r#"["production","http://localhost:8080/v3/"]"#);
+        let parsed: Vec<Environment> = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed, environments);
+    }
+}
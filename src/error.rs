@@ -11,6 +11,8 @@
 //! - **Network errors**: Connection failures, timeouts, DNS resolution issues
 //! - **HTTP errors**: Status codes (400, 401, 404, 429, 500, etc.) with detailed context
 //! - **Parsing errors**: JSON deserialization failures and malformed responses
+//! - **Content type errors**: HTML or other non-JSON pages injected by proxies and gateways
+//! - **Result count errors**: Result counts outside the range set with `expect_results()`
 //! - **Middleware errors**: Retry policy exhaustion, request building failures
 //! - **URL errors**: Invalid URL formation and parsing issues
 //! - **IO errors**: File system operations (for caching, logging, etc.)
@@ -50,7 +52,11 @@
 //! let openfigi_err: OpenFIGIError = json_err.into();
 //! ```
 
-use std::{error, fmt};
+use crate::budget::BudgetPeriod;
+use crate::parse::{API_ERROR_PREFIX, API_WARNING_PREFIX, NOT_FOUND_MESSAGE};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use std::{error, fmt, ops::RangeInclusive};
 use url::Url;
 
 /// Type alias for `Result<T, OpenFIGIError>`.
@@ -139,6 +145,69 @@ pub enum OpenFIGIError {
     /// including status codes and response body content.
     ResponseError(ResponseContent),
 
+    /// Response with a content type other than the expected JSON.
+    ///
+    /// Typically produced by intermediaries such as proxies or load balancers that
+    /// return HTML login portals or gateway error pages instead of forwarding the
+    /// OpenFIGI API response.
+    UnexpectedContentType {
+        /// HTTP status code of the response
+        status: reqwest::StatusCode,
+        /// The `Content-Type` header of the response, empty if absent
+        content_type: String,
+        /// Leading portion of the response body with whitespace collapsed
+        snippet: String,
+        /// Response headers captured by the client, see
+        /// [`crate::client_builder::OpenFIGIClientBuilder::capture_headers`]
+        headers: Box<HeaderMap>,
+        /// Reason registered for the status, see [`ResponseContent::reason`]
+        reason: Option<ReasonCode>,
+    },
+
+    /// Number of results outside the range requested with `expect_results()`.
+    ///
+    /// Produced by the endpoint builders when a response is parsed successfully but
+    /// contains fewer or more results than the caller declared acceptable.
+    UnexpectedResultCount {
+        /// Accepted range of result counts
+        expected: RangeInclusive<usize>,
+        /// Number of results received
+        actual: usize,
+    },
+
+    /// Request refused because the client was paused with
+    /// [`OpenFIGIClient::pause`](crate::client::OpenFIGIClient::pause).
+    ///
+    /// Produced before any network traffic, until the client is resumed.
+    ClientPaused,
+
+    /// Request refused because a cap of the client's
+    /// [`RequestBudget`](crate::budget::RequestBudget) is exhausted.
+    ///
+    /// Produced before any network traffic, until the period of the cap ends.
+    BudgetExceeded {
+        /// Period of the exhausted cap
+        period: BudgetPeriod,
+        /// Maximum number of requests per period
+        limit: u64,
+    },
+
+    /// Request refused during a maintenance window of the client's
+    /// [`MaintenanceSchedule`](crate::maintenance::MaintenanceSchedule).
+    ///
+    /// Produced before any network traffic, until the window ends.
+    MaintenanceWindow {
+        /// End of the active maintenance window
+        until: DateTime<Utc>,
+    },
+
+    /// Request refused because a client in
+    /// [`ReplayMode::Replay`](crate::replay::ReplayMode::Replay) has no recorded response for it.
+    ReplayMiss {
+        /// Canonical key of the request, see [`crate::replay`]
+        request: String,
+    },
+
     /// Miscellaneous application-specific errors.
     ///
     /// Used for validation errors and other issues that don't fit
@@ -163,6 +232,21 @@ pub struct ResponseContent {
     pub message: String,
     /// Raw response body content
     pub content: String,
+    /// Whether the response body was not valid in its declared encoding.
+    ///
+    /// When `true`, invalid byte sequences in `content` were replaced with
+    /// `U+FFFD REPLACEMENT CHARACTER`. This typically happens when an intermediary,
+    /// such as a corporate proxy, injects an error page in a different encoding.
+    pub lossy: bool,
+    /// Response headers captured by the client, e.g. request ids added by gateways.
+    ///
+    /// Only headers listed with
+    /// [`crate::client_builder::OpenFIGIClientBuilder::capture_headers`] are captured.
+    pub headers: Box<HeaderMap>,
+    /// Reason registered for the status with
+    /// [`crate::client_builder::OpenFIGIClientBuilder::status_reason`], taking precedence over
+    /// the built-in classification of [`OpenFIGIError::reason_code`].
+    pub reason: Option<ReasonCode>,
 }
 
 /// Classification for miscellaneous errors that don't fit other categories.
@@ -188,6 +272,142 @@ pub enum OtherErrorKind {
     Other,
 }
 
+/// Typed reason of an error or warning reported by the OpenFIGI API.
+///
+/// Derived from the HTTP status and the message of the API, so programs can handle known
+/// conditions without matching on the exact wording, which may change. Obtain it with
+/// [`OpenFIGIError::reason_code`] or
+/// [`JobOutcome::reason_code`](crate::model::response::JobOutcome::reason_code).
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::error::ReasonCode;
+///
+/// assert_eq!(ReasonCode::from_message("Invalid idValue format."), ReasonCode::InvalidIdValue);
+/// assert_eq!(ReasonCode::from_message("No identifier found."), ReasonCode::NotFound);
+/// assert_eq!(ReasonCode::from_message("Something new."), ReasonCode::Unknown);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReasonCode {
+    /// No instrument matched the request, e.g. `"No identifier found."`.
+    NotFound,
+    /// The identifier value is malformed, e.g. `"Invalid idValue format."`.
+    InvalidIdValue,
+    /// The identifier type is not supported.
+    InvalidIdType,
+    /// Another request parameter has an invalid value, e.g. an unknown exchange code.
+    InvalidParameter,
+    /// The request rate limit was exceeded (`429 Too Many Requests`).
+    RateLimited,
+    /// The request contained more jobs than allowed (`413 Payload Too Large`).
+    TooManyJobs,
+    /// The API key is missing, invalid or lacks permission (`401` or `403`).
+    Unauthorized,
+    /// The API failed to process the request (`5xx`).
+    ServerError,
+    /// The request conflicts with the state of the API or a gateway (`409 Conflict`).
+    Conflict,
+    /// The request was understood but rejected as unprocessable (`422 Unprocessable Entity`).
+    Unprocessable,
+    /// Access was refused for legal or policy reasons, typically by a corporate gateway
+    /// (`451 Unavailable For Legal Reasons`).
+    LegallyBlocked,
+    /// A message without a known reason.
+    Unknown,
+}
+
+/// Known message fragments of the OpenFIGI API, matched case-insensitively in order.
+const REASON_MESSAGES: &[(&str, ReasonCode)] = &[
+    ("no identifier found", ReasonCode::NotFound),
+    ("invalid idvalue", ReasonCode::InvalidIdValue),
+    ("invalid idtype", ReasonCode::InvalidIdType),
+    ("unknown idtype", ReasonCode::InvalidIdType),
+    ("too many requests", ReasonCode::RateLimited),
+    ("rate limit", ReasonCode::RateLimited),
+    ("too many mapping jobs", ReasonCode::TooManyJobs),
+    ("request entity too large", ReasonCode::TooManyJobs),
+    ("payload too large", ReasonCode::TooManyJobs),
+    ("api key", ReasonCode::Unauthorized),
+    ("unauthorized", ReasonCode::Unauthorized),
+    ("invalid", ReasonCode::InvalidParameter),
+    ("unrecognized", ReasonCode::InvalidParameter),
+    ("internal server error", ReasonCode::ServerError),
+];
+
+impl ReasonCode {
+    /// Classifies an error or warning message of the OpenFIGI API.
+    ///
+    /// Matching ignores case and an `OpenFIGI API error: ` or `OpenFIGI API warning: ` prefix.
+    /// Returns [`Self::Unknown`] for messages without a known reason.
+    #[must_use]
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        REASON_MESSAGES
+            .iter()
+            .find(|(fragment, _)| message.contains(fragment))
+            .map_or(Self::Unknown, |&(_, code)| code)
+    }
+
+    /// Classifies an HTTP status code, returning `None` for statuses without a specific reason.
+    #[must_use]
+    pub fn from_status(status: reqwest::StatusCode) -> Option<Self> {
+        match status.as_u16() {
+            401 | 403 => Some(Self::Unauthorized),
+            404 => Some(Self::NotFound),
+            409 => Some(Self::Conflict),
+            413 => Some(Self::TooManyJobs),
+            422 => Some(Self::Unprocessable),
+            429 => Some(Self::RateLimited),
+            451 => Some(Self::LegallyBlocked),
+            500..=599 => Some(Self::ServerError),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the request may succeed when retried later without changes.
+    #[must_use]
+    pub fn is_transient(self) -> bool {
+        matches!(self, Self::RateLimited | Self::ServerError)
+    }
+
+    /// Returns true if the API rejected a value of the request, which fails again unless the
+    /// value is corrected.
+    #[must_use]
+    pub fn is_invalid_value(self) -> bool {
+        matches!(
+            self,
+            Self::InvalidIdValue
+                | Self::InvalidIdType
+                | Self::InvalidParameter
+                | Self::Unprocessable
+        )
+    }
+}
+
+/// Kind of an error reported by the OpenFIGI API, same as [`ReasonCode`].
+pub type ApiErrorKind = ReasonCode;
+
+impl fmt::Display for ReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotFound => "not found",
+            Self::InvalidIdValue => "invalid identifier value",
+            Self::InvalidIdType => "invalid identifier type",
+            Self::InvalidParameter => "invalid parameter",
+            Self::RateLimited => "rate limited",
+            Self::TooManyJobs => "too many jobs",
+            Self::Unauthorized => "unauthorized",
+            Self::ServerError => "server error",
+            Self::Conflict => "conflict",
+            Self::Unprocessable => "unprocessable request",
+            Self::LegallyBlocked => "blocked for legal reasons",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
 impl fmt::Display for OpenFIGIError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -211,6 +431,38 @@ impl fmt::Display for OpenFIGIError {
                 ),
                 (true, true) => write!(f, "error in response: status code {}", e.status),
             },
+            Self::UnexpectedContentType {
+                status,
+                content_type,
+                snippet,
+                ..
+            } => write!(
+                f,
+                "unexpected content type: status code {status}: expected JSON but received '{content_type}' | content: {snippet}"
+            ),
+            Self::UnexpectedResultCount { expected, actual } => write!(
+                f,
+                "unexpected result count: expected {} to {} results but received {actual}",
+                expected.start(),
+                expected.end()
+            ),
+            Self::ClientPaused => write!(
+                f,
+                "client paused: requests are refused until the client is resumed"
+            ),
+            Self::BudgetExceeded { period, limit } => write!(
+                f,
+                "request budget exceeded: at most {limit} requests per {period} are allowed"
+            ),
+            Self::MaintenanceWindow { until } => {
+                write!(f, "maintenance window: requests are refused until {until}")
+            }
+            Self::ReplayMiss { request } => {
+                write!(
+                    f,
+                    "replay miss: no recorded response for request '{request}'"
+                )
+            }
             Self::SerdeError(e) => write!(f, "error in serde: {e}"),
             Self::ReqwestMiddlewareError(e) => {
                 write!(f, "error in reqwest-middleware: {e}")
@@ -398,7 +650,7 @@ impl OpenFIGIError {
         match self {
             Self::ReqwestError(inner) => inner.is_status(),
             Self::ReqwestMiddlewareError(inner) => inner.is_status(),
-            Self::ResponseError(_) => true,
+            Self::ResponseError(_) | Self::UnexpectedContentType { .. } => true,
             // Not applicable for other variants
             _ => false,
         }
@@ -470,12 +722,193 @@ impl OpenFIGIError {
         match self {
             Self::ReqwestError(inner) => inner.is_decode(),
             Self::ReqwestMiddlewareError(inner) => inner.is_decode(),
-            Self::OtherError { .. } => true,
+            Self::SerdeError(_) | Self::OtherError { .. } => true,
             // Not applicable for other variants
             _ => false,
         }
     }
 
+    /// Returns true if the response did not contain JSON.
+    ///
+    /// Indicates that an intermediary (e.g. a proxy login portal or a gateway error page)
+    /// answered instead of the OpenFIGI API. Retrying is unlikely to help until the
+    /// network configuration is fixed.
+    #[must_use]
+    pub fn is_unexpected_content_type(&self) -> bool {
+        matches!(self, Self::UnexpectedContentType { .. })
+    }
+
+    /// Returns true if the number of results was outside the range set with `expect_results()`.
+    #[must_use]
+    pub fn is_unexpected_result_count(&self) -> bool {
+        matches!(self, Self::UnexpectedResultCount { .. })
+    }
+
+    /// Returns true if the request was refused because the client is paused.
+    #[must_use]
+    pub fn is_client_paused(&self) -> bool {
+        matches!(self, Self::ClientPaused)
+    }
+
+    /// Returns true if the request was refused because the request budget is exhausted.
+    #[must_use]
+    pub fn is_budget_exceeded(&self) -> bool {
+        matches!(self, Self::BudgetExceeded { .. })
+    }
+
+    /// Returns true if the request was refused during a maintenance window.
+    #[must_use]
+    pub fn is_maintenance_window(&self) -> bool {
+        matches!(self, Self::MaintenanceWindow { .. })
+    }
+
+    /// Returns true if a replaying client had no recorded response for the request.
+    #[must_use]
+    pub fn is_replay_miss(&self) -> bool {
+        matches!(self, Self::ReplayMiss { .. })
+    }
+
+    /// Returns true if the OpenFIGI API found no instrument for the request.
+    ///
+    /// Such soft errors are reported by the API as `{"warning": ...}` or as
+    /// `{"error": "No identifier found."}` and indicate a miss rather than a failure.
+    /// See also [`crate::model::response::JobOutcome`].
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.not_found_message().is_some()
+    }
+
+    /// Returns true if the OpenFIGI API found no instrument for the request, same as
+    /// [`Self::is_not_found`].
+    #[must_use]
+    pub fn is_no_match(&self) -> bool {
+        self.is_not_found()
+    }
+
+    /// Returns true if the OpenFIGI API rejected a value of the request, e.g. with
+    /// `"Invalid idValue format."`.
+    ///
+    /// Such requests fail again unless the value is corrected, see
+    /// [`ReasonCode::is_invalid_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::MappingResponses;
+    ///
+    /// let responses: MappingResponses =
+    ///     serde_json::from_str(r#"[{"error": "Invalid idValue format."}]"#)?;
+    /// let (_, err) = responses.failures().next().expect("one failure");
+    /// assert!(err.is_invalid_value());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_invalid_value(&self) -> bool {
+        self.reason_code().is_some_and(ReasonCode::is_invalid_value)
+    }
+
+    /// Returns the typed reason of an error reported by the OpenFIGI API.
+    ///
+    /// Specific HTTP statuses, such as `429 Too Many Requests`, take precedence over the
+    /// message of the API. Otherwise the body of the response is classified, or the message of
+    /// a per-job `error` or `warning` entry; messages of the client itself are not. Returns
+    /// `None` for errors that did not originate from an API response, e.g. connection failures
+    /// or validation errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::error::ReasonCode;
+    /// use openfigi_rs::model::response::MappingResponses;
+    ///
+    /// let responses: MappingResponses =
+    ///     serde_json::from_str(r#"[{"error": "Invalid idValue format."}]"#)?;
+    /// let (_, err) = responses.failures().next().expect("one failure");
+    /// assert_eq!(err.reason_code(), Some(ReasonCode::InvalidIdValue));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[must_use]
+    pub fn reason_code(&self) -> Option<ReasonCode> {
+        match self {
+            Self::ResponseError(content) => Some(
+                content
+                    .reason
+                    .or_else(|| ReasonCode::from_status(content.status))
+                    .unwrap_or_else(|| match ReasonCode::from_message(&content.content) {
+                        ReasonCode::Unknown => content
+                            .message
+                            .strip_prefix(API_ERROR_PREFIX)
+                            .or_else(|| content.message.strip_prefix(API_WARNING_PREFIX))
+                            .map_or(ReasonCode::Unknown, ReasonCode::from_message),
+                        code => code,
+                    }),
+            ),
+            Self::UnexpectedContentType { status, reason, .. } => Some(
+                reason
+                    .or_else(|| ReasonCode::from_status(*status))
+                    .unwrap_or(ReasonCode::Unknown),
+            ),
+            Self::ReqwestError(_) | Self::ReqwestMiddlewareError(_) => {
+                self.status().and_then(ReasonCode::from_status)
+            }
+            // Not applicable for other variants
+            _ => None,
+        }
+    }
+
+    /// Returns the API message of a not-found soft error, see [`Self::is_not_found`].
+    pub(crate) fn not_found_message(&self) -> Option<&str> {
+        let Self::ResponseError(content) = self else {
+            return None;
+        };
+        if let Some(warning) = content.message.strip_prefix(API_WARNING_PREFIX) {
+            return Some(warning);
+        }
+        content
+            .message
+            .strip_prefix(API_ERROR_PREFIX)
+            .filter(|message| *message == NOT_FOUND_MESSAGE)
+    }
+
+    /// Returns true if the response body attached to this error was decoded lossily.
+    ///
+    /// Indicates that the body was not valid in its declared encoding (UTF-8 by default)
+    /// and invalid byte sequences were replaced. Such bodies usually originate from
+    /// intermediaries rather than the OpenFIGI API itself.
+    #[must_use]
+    pub fn is_lossy_body(&self) -> bool {
+        match self {
+            Self::ResponseError(content) => content.lossy,
+            // Not applicable for other variants
+            _ => false,
+        }
+    }
+
+    /// Returns the response headers captured for this error, if it originates from a response.
+    ///
+    /// Only headers listed with
+    /// [`crate::client_builder::OpenFIGIClientBuilder::capture_headers`] are captured, e.g. a
+    /// request id to quote in support tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::error::OpenFIGIError;
+    ///
+    /// fn request_id(err: &OpenFIGIError) -> Option<&str> {
+    ///     err.headers()?.get("x-request-id")?.to_str().ok()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        match self {
+            Self::ResponseError(content) => Some(&content.headers),
+            Self::UnexpectedContentType { headers, .. } => Some(headers),
+            // Not applicable for other variants
+            _ => None,
+        }
+    }
+
     /// Returns the HTTP status code associated with this error, if available.
     ///
     /// Extracts the HTTP status code from errors that contain one, such as
@@ -505,6 +938,7 @@ impl OpenFIGIError {
             Self::ReqwestError(inner) => inner.status(),
             Self::ReqwestMiddlewareError(inner) => inner.status(),
             Self::ResponseError(resp) => Some(resp.status),
+            Self::UnexpectedContentType { status, .. } => Some(*status),
             // Not applicable for other variants
             _ => None,
         }
@@ -530,9 +964,105 @@ impl OpenFIGIError {
             status,
             message: message.into(),
             content: content.into(),
+            lossy: false,
+            headers: Box::default(),
+            reason: None,
         })
     }
 
+    #[doc(hidden)]
+    /// Marks the response body of a `ResponseError` as lossily decoded.
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub(crate) fn with_lossy_body(mut self, lossy: bool) -> Self {
+        if let Self::ResponseError(content) = &mut self {
+            content.lossy = lossy;
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    /// Attaches captured response headers to a `ResponseError` or `UnexpectedContentType`.
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub(crate) fn with_headers(mut self, captured: HeaderMap) -> Self {
+        match &mut self {
+            Self::ResponseError(ResponseContent { headers, .. })
+            | Self::UnexpectedContentType { headers, .. } => **headers = captured,
+            _ => {}
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    /// Attaches the reason registered for the status to a `ResponseError` or
+    /// `UnexpectedContentType`.
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub(crate) fn with_reason(mut self, registered: Option<ReasonCode>) -> Self {
+        match &mut self {
+            Self::ResponseError(ResponseContent { reason, .. })
+            | Self::UnexpectedContentType { reason, .. } => *reason = registered,
+            _ => {}
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    /// Creates a new `UnexpectedContentType` error with a snippet of the given body.
+    ///
+    /// The snippet has whitespace collapsed and is limited to 200 characters.
+    pub(crate) fn unexpected_content_type(
+        status: reqwest::StatusCode,
+        content_type: impl Into<String>,
+        body: &str,
+    ) -> Self {
+        const MAX_SNIPPET_CHARS: usize = 200;
+
+        let mut snippet = String::new();
+        for (i, word) in body.split_whitespace().enumerate() {
+            if i > 0 {
+                snippet.push(' ');
+            }
+            snippet.push_str(word);
+            if snippet.chars().count() > MAX_SNIPPET_CHARS {
+                snippet = snippet.chars().take(MAX_SNIPPET_CHARS).collect();
+                snippet.push('…');
+                break;
+            }
+        }
+
+        Self::UnexpectedContentType {
+            status,
+            content_type: content_type.into(),
+            snippet,
+            headers: Box::default(),
+            reason: None,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Checks a result count against the range set with `expect_results()`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `UnexpectedResultCount` error if `actual` lies outside `expected`.
+    pub(crate) fn check_result_count(
+        expected: Option<&RangeInclusive<usize>>,
+        actual: usize,
+    ) -> Result<()> {
+        match expected {
+            Some(expected) if !expected.contains(&actual) => Err(Self::UnexpectedResultCount {
+                expected: expected.clone(),
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     #[doc(hidden)]
     /// Creates a new `OtherError` with the given kind and message.
     ///
@@ -550,3 +1080,58 @@ impl OpenFIGIError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_reason_code() {
+        let err = OpenFIGIError::response_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            "OpenFIGI API error: Invalid idValue format.".to_string(),
+            String::new(),
+        );
+        assert_eq!(err.reason_code(), Some(ReasonCode::RateLimited));
+        assert!(ReasonCode::RateLimited.is_transient());
+
+        let err = OpenFIGIError::response_error(
+            StatusCode::BAD_REQUEST,
+            String::new(),
+            "Invalid exchCode.".to_string(),
+        );
+        assert_eq!(err.reason_code(), Some(ReasonCode::InvalidParameter));
+        assert!(err.is_invalid_value());
+
+        // Messages of the client are not classified, only those of the API
+        let err = OpenFIGIError::response_error(
+            StatusCode::BAD_REQUEST,
+            "Bad request to https://api.openfigi.com/v3/mapping: Invalid request body or parameters."
+                .to_string(),
+            String::new(),
+        );
+        assert_eq!(err.reason_code(), Some(ReasonCode::Unknown));
+        let err = OpenFIGIError::response_error(
+            StatusCode::OK,
+            "OpenFIGI API warning: No identifier found.".to_string(),
+            String::new(),
+        );
+        assert_eq!(err.reason_code(), Some(ReasonCode::NotFound));
+        assert!(err.is_no_match());
+        let kind: Option<ApiErrorKind> = err.reason_code();
+        assert_eq!(kind, Some(ApiErrorKind::NotFound));
+        assert_eq!(OpenFIGIError::ClientPaused.reason_code(), None);
+        assert!(!OpenFIGIError::ClientPaused.is_invalid_value());
+
+        let err = OpenFIGIError::response_error(
+            StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS,
+            String::new(),
+            String::new(),
+        );
+        assert_eq!(err.reason_code(), Some(ReasonCode::LegallyBlocked));
+        let err = err.with_reason(Some(ReasonCode::Unauthorized));
+        assert_eq!(err.reason_code(), Some(ReasonCode::Unauthorized));
+        assert!(ReasonCode::Unprocessable.is_invalid_value());
+    }
+}
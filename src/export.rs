@@ -0,0 +1,96 @@
+//! # Newline-Delimited JSON Export
+//!
+//! Writes FIGI results as newline-delimited JSON (NDJSON), one result per line, the format
+//! accepted by the bulk loaders of data warehouses such as BigQuery and Snowflake.
+//!
+//! Each line is the JSON representation of a [`crate::model::response::FigiResult`] with the
+//! field names of the OpenFIGI API, so exported files load into the same schema as the raw API
+//! responses.
+//! [`to_ndjson`](crate::export::to_ndjson) writes to any [`std::io::Write`], and the
+//! `send_to_ndjson()` methods of the request builders write the results of a request directly
+//! to a file.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::export::to_ndjson;
+//! use openfigi_rs::parse::parse_search_response;
+//!
+//! let body = br#"{"data": [{"figi": "BBG000BLNNH6"}, {"figi": "BBG000BLNQ16"}]}"#;
+//! let data = parse_search_response(body)?;
+//!
+//! let mut out = Vec::new();
+//! let written = to_ndjson(&mut out, data.data())?;
+//!
+//! assert_eq!(written, 2);
+//! assert_eq!(String::from_utf8(out)?.lines().count(), 2);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{error::Result, model::response::FigiResult};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Writes `results` to `writer` as newline-delimited JSON and returns the number of lines.
+///
+/// The writer is flushed after the last result. Wrap unbuffered writers such as a [`File`] in
+/// a [`BufWriter`] to avoid a system call per result.
+///
+/// # Errors
+///
+/// Returns an [`crate::error::OpenFIGIError`] if a result cannot be serialized or if writing
+/// fails. Lines written before the error remain in `writer`.
+pub fn to_ndjson<'a, W: Write>(
+    mut writer: W,
+    results: impl IntoIterator<Item = &'a FigiResult>,
+) -> Result<usize> {
+    let mut count = 0;
+    for result in results {
+        serde_json::to_writer(&mut writer, result)?;
+        writer.write_all(b"\n")?;
+        count += 1;
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+/// Creates or truncates the file at `path` and writes `results` to it as newline-delimited JSON.
+pub(crate) fn to_ndjson_file<'a>(
+    path: impl AsRef<Path>,
+    results: impl IntoIterator<Item = &'a FigiResult>,
+) -> Result<usize> {
+    to_ndjson(BufWriter::new(File::create(path)?), results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ndjson() {
+        let results: Vec<FigiResult> = serde_json::from_value(serde_json::json!([
+            { "figi": "BBG000BLNNH6", "ticker": "IBM" },
+            { "figi": "BBG000BLNQ16", "ticker": "IBM" },
+        ]))
+        .expect("Should deserialize");
+
+        let mut out = Vec::new();
+        let written = to_ndjson(&mut out, &results).expect("Should write");
+        assert_eq!(written, 2);
+
+        let out = String::from_utf8(out).expect("Should be UTF-8");
+        assert!(out.ends_with('\n'));
+        let lines: Vec<FigiResult> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("Each line should be a result"))
+            .collect();
+        assert_eq!(lines, results);
+
+        let mut empty = Vec::new();
+        assert_eq!(to_ndjson(&mut empty, &[]).expect("Should write"), 0);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+//! # Third-Party Re-exports
+//!
+//! Types from dependencies that appear in the public API of this crate, re-exported so
+//! downstream code always uses the exact versions this crate was compiled against.
+//!
+//! Depending on `chrono`, `reqwest` or `url` directly with a different major version results in
+//! distinct, incompatible types. Using the re-exports avoids these mismatches when constructing
+//! date filters, inspecting error status codes or configuring base URLs.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::exports::{NaiveDate, StatusCode};
+//! use openfigi_rs::model::request::HasFilters;
+//!
+//! let client = OpenFIGIClient::new();
+//! let maturity = NaiveDate::from_ymd_opt(2030, 12, 31);
+//! let request = client.filter().query("treasury").maturity([None, maturity]);
+//!
+//! let retryable = |status: StatusCode| status == StatusCode::TOO_MANY_REQUESTS;
+//! assert!(retryable(StatusCode::TOO_MANY_REQUESTS));
+//! ```
+
+pub use chrono::NaiveDate;
+pub use reqwest::header::{HeaderMap, HeaderName};
+pub use reqwest::{Method, StatusCode};
+pub use url::Url;
@@ -1,25 +1,65 @@
 #![doc = include_str!("../README.md")]
 
+/// Archival of every request and response for record keeping
+pub mod archive;
+/// Synchronous client for scripts and codebases without an async runtime
+#[cfg(feature = "blocking")]
+pub mod blocking;
+/// Request caps per calendar day or month
+pub mod budget;
+/// Response caching with `ETag`-based conditional requests
+pub mod cache;
 /// HTTP client for OpenFIGI API operations
 pub mod client;
 /// Client builder with fluent configuration API for custom HTTP settings
 pub mod client_builder;
+/// Post-processing rules resolving FIGI results to a single instrument
+pub mod disambiguation;
 /// API endpoint implementations for mapping, search, and filter operations
 pub mod endpoint;
+/// Named targets of the OpenFIGI API selected by configuration
+pub mod environment;
 /// Comprehensive error types with OpenFIGI-specific context and inspection methods
 pub mod error;
-/// Common utilities and macros for OpenFIGI client
-pub(crate) mod macros;
+/// Export of results as newline-delimited JSON
+pub mod export;
+/// Re-exports of third-party types used in the public API
+pub mod exports;
+/// Known maintenance windows of the API deferring or refusing requests
+pub mod maintenance;
+/// Middleware retrying rate limited requests after the delay announced by the API
+pub mod middleware;
 /// Strongly typed request and response data models for all API operations
 pub mod model;
+/// Normalization and fuzzy matching of instrument names
+pub mod name;
+/// Callbacks notified about every request, e.g. for metrics
+pub mod observer;
+/// Parse-only functions for raw response bodies
+pub mod parse;
+/// Compliance policies discarding results and emitting audit records
+pub mod policy;
+/// Re-exports of the most commonly used types and traits
+pub mod prelude;
+/// Client-side rate limiting per endpoint
+pub mod rate_limit;
+/// Record and replay of responses for deterministic backtests
+pub mod replay;
 /// Internal HTTP request builder utilities (not intended for direct use)
 pub(crate) mod request_builder;
+/// Opt-in traces of the requests and selection decisions of lookups
+pub mod trace;
+/// Custom validation rules run alongside the built-in request validation
+pub mod validation;
+/// Assertions pinning the JSON wire format of requests in downstream tests
+#[cfg(feature = "test-util")]
+pub mod wire_compat;
 /// Test utilities for OpenFIGI client
 #[cfg(test)]
 #[macro_use]
 mod test_utils;
 
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock, PoisonError, RwLock};
 use url::Url;
 
 /// Library version
@@ -49,6 +89,85 @@ pub const DEFAULT_ENDPOINT_FILTER: &str = "filter";
 
 /// API key loaded from the `OPENFIGI_API_KEY` environment variable.
 ///
-/// This is automatically loaded at startup and used by default when creating clients.
-/// `None` if the environment variable is not set.
-static API_KEY: LazyLock<Option<String>> = LazyLock::new(|| std::env::var("OPENFIGI_API_KEY").ok());
+/// Loaded on first use when creating a client, and reloaded by [`init`]. `None` if the
+/// environment variable is not set.
+static API_KEY: LazyLock<RwLock<Option<String>>> =
+    LazyLock::new(|| RwLock::new(std::env::var("OPENFIGI_API_KEY").ok()));
+
+/// Loads the API key from the `OPENFIGI_API_KEY` environment variable.
+///
+/// Clients created without an explicit API key use the value of the variable, which is read
+/// once when the first client is created. Call this function to read it at a defined point
+/// instead, e.g. after loading a `.env` file or setting the variable in a test, and again to
+/// pick up a changed value. Clients created before the call keep their API key.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::client::OpenFIGIClient;
+///
+/// openfigi_rs::init();
+/// let client = OpenFIGIClient::new();
+/// assert_eq!(client.has_api_key(), std::env::var("OPENFIGI_API_KEY").is_ok());
+/// ```
+pub fn init() {
+    let key = std::env::var("OPENFIGI_API_KEY").ok();
+    *API_KEY.write().unwrap_or_else(PoisonError::into_inner) = key;
+}
+
+/// Returns the API key loaded from the `OPENFIGI_API_KEY` environment variable.
+pub(crate) fn env_api_key() -> Option<String> {
+    API_KEY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Client returned by [`global`], set by [`set_global`] or on first use.
+static GLOBAL_CLIENT: OnceLock<client::OpenFIGIClient> = OnceLock::new();
+
+/// Returns the shared default client of the process.
+///
+/// Intended for small applications and examples, so they do not need to pass a client to every
+/// function. The client is created with [`client::OpenFIGIClient::new`] on first use, unless
+/// configured before with [`set_global`]. Larger applications, or those needing several
+/// configurations, should keep creating explicit clients.
+///
+/// # Examples
+///
+/// ```rust
+/// let client = openfigi_rs::global();
+/// assert!(std::ptr::eq(client, openfigi_rs::global()));
+/// ```
+pub fn global() -> &'static client::OpenFIGIClient {
+    GLOBAL_CLIENT.get_or_init(client::OpenFIGIClient::new)
+}
+
+/// Configures the client returned by [`global`].
+///
+/// Can only be called once, before the first call of [`global`]. Threads racing to configure
+/// or use the global client all observe the same client.
+///
+/// # Errors
+///
+/// Returns an [`error::OtherErrorKind::Other`] error if the global client has already been
+/// configured or used.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::client::OpenFIGIClient;
+///
+/// let client = OpenFIGIClient::builder().max_concurrent_requests(2).build()?;
+/// openfigi_rs::set_global(client)?;
+/// assert!(openfigi_rs::set_global(OpenFIGIClient::new()).is_err());
+/// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+/// ```
+pub fn set_global(client: client::OpenFIGIClient) -> error::Result<()> {
+    GLOBAL_CLIENT.set(client).map_err(|_| {
+        error::OpenFIGIError::other_error(
+            error::OtherErrorKind::Other,
+            "Global client is already initialized",
+        )
+    })
+}
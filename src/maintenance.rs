@@ -0,0 +1,286 @@
+//! # Maintenance Windows
+//!
+//! Known maintenance windows of the OpenFIGI API, during which a client defers its requests or
+//! fails fast instead of wasting requests and retries on an unavailable service.
+//!
+//! A [`MaintenanceSchedule`](crate::maintenance::MaintenanceSchedule) attached to a client lists
+//! recurring [`MaintenanceWindow`](crate::maintenance::MaintenanceWindow)s in UTC. Requests sent
+//! during a window either fail with
+//! [`OpenFIGIError::MaintenanceWindow`](crate::error::OpenFIGIError::MaintenanceWindow), the
+//! default, or wait until the window ends with
+//! [`MaintenanceAction::Defer`](crate::maintenance::MaintenanceAction::Defer). Responses served
+//! from a replay store are not affected.
+//!
+//! Windows are written as `<day> <start> <duration>`, where the day is a weekday such as `sun`
+//! or `*` for every day, the start is a UTC time such as `22:00`, and the duration is given in
+//! hours or minutes, e.g. `2h` or `45m`. Windows may extend into the following day.
+//!
+//! If the `tracing` feature is enabled, every deferred request emits a `tracing::debug!` event
+//! with the end of the window.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::maintenance::{MaintenanceAction, MaintenanceSchedule};
+//!
+//! let schedule = MaintenanceSchedule::new()
+//!     .window("sat 22:00 4h".parse()?)
+//!     .window("* 03:30 15m".parse()?)
+//!     .action(MaintenanceAction::Defer);
+//! let client = OpenFIGIClient::builder().maintenance(schedule).build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    error::{OpenFIGIError, OtherErrorKind, Result},
+    model::enums::FromStrVisitor,
+};
+use chrono::{DateTime, Datelike, NaiveTime, TimeDelta, Utc, Weekday};
+use futures_timer::Delay;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr, time::Duration};
+
+/// A recurring maintenance window in UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaintenanceWindow {
+    /// Day of the week the window starts on, or `None` for every day.
+    day: Option<Weekday>,
+    start: NaiveTime,
+    duration: TimeDelta,
+}
+
+impl MaintenanceWindow {
+    /// Creates a window starting every day at `start` UTC and lasting `duration`.
+    #[must_use]
+    pub fn daily(start: NaiveTime, duration: Duration) -> Self {
+        Self::new(None, start, duration)
+    }
+
+    /// Creates a window starting every week on `day` at `start` UTC and lasting `duration`.
+    #[must_use]
+    pub fn weekly(day: Weekday, start: NaiveTime, duration: Duration) -> Self {
+        Self::new(Some(day), start, duration)
+    }
+
+    fn new(day: Option<Weekday>, start: NaiveTime, duration: Duration) -> Self {
+        Self {
+            day,
+            start,
+            duration: TimeDelta::from_std(duration).unwrap_or(TimeDelta::MAX),
+        }
+    }
+
+    /// Returns the end of the occurrence of this window containing `now`, if any.
+    #[must_use]
+    pub fn active_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let (days_back, period) = match self.day {
+            None => (0, 1),
+            Some(day) => {
+                let back =
+                    (7 + now.weekday().num_days_from_monday() - day.num_days_from_monday()) % 7;
+                (i64::from(back), 7)
+            }
+        };
+        let mut start = (now.date_naive() - TimeDelta::days(days_back))
+            .and_time(self.start)
+            .and_utc();
+        if start > now {
+            start -= TimeDelta::days(period);
+        }
+        let end = start.checked_add_signed(self.duration)?;
+        (now < end).then_some(end)
+    }
+}
+
+impl fmt::Display for MaintenanceWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "{} ", day.to_string().to_lowercase())?,
+            None => f.write_str("* ")?,
+        }
+        write!(f, "{} ", self.start.format("%H:%M"))?;
+        let minutes = self.duration.num_minutes();
+        if minutes % 60 == 0 {
+            write!(f, "{}h", minutes / 60)
+        } else {
+            write!(f, "{minutes}m")
+        }
+    }
+}
+
+impl FromStr for MaintenanceWindow {
+    type Err = OpenFIGIError;
+
+    /// Parses a window written as `<day> <start> <duration>`, e.g. `sun 02:00 2h`.
+    fn from_str(value: &str) -> Result<Self> {
+        let invalid = || {
+            OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                format!(
+                    "Invalid maintenance window '{value}', expected '<day> <HH:MM> <duration>', e.g. 'sun 02:00 2h'"
+                ),
+            )
+        };
+        let [day, start, duration] = value.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(invalid());
+        };
+        let day = match day {
+            "*" => None,
+            day => Some(day.parse::<Weekday>().map_err(|_| invalid())?),
+        };
+        let start = NaiveTime::parse_from_str(start, "%H:%M").map_err(|_| invalid())?;
+        let (amount, unit) = duration.split_at(duration.len().saturating_sub(1));
+        let amount: u64 = amount.parse().map_err(|_| invalid())?;
+        let duration = match unit {
+            "h" => Duration::from_hours(amount),
+            "m" => Duration::from_mins(amount),
+            _ => return Err(invalid()),
+        };
+        Ok(Self::new(day, start, duration))
+    }
+}
+
+impl Serialize for MaintenanceWindow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaintenanceWindow {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("MaintenanceWindow"))
+    }
+}
+
+/// How requests sent during a maintenance window are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MaintenanceAction {
+    /// Fail with [`OpenFIGIError::MaintenanceWindow`] before any network traffic.
+    #[default]
+    FailFast,
+    /// Wait until the window ends, then send the request.
+    Defer,
+}
+
+/// Maintenance windows of the OpenFIGI API and how a client handles requests during them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaintenanceSchedule {
+    windows: Vec<MaintenanceWindow>,
+    action: MaintenanceAction,
+}
+
+impl MaintenanceSchedule {
+    /// Creates a schedule without windows that fails requests fast.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a maintenance window.
+    #[must_use]
+    pub fn window(mut self, window: MaintenanceWindow) -> Self {
+        self.windows.push(window);
+        self
+    }
+
+    /// Sets how requests sent during a window are handled.
+    #[must_use]
+    pub fn action(mut self, action: MaintenanceAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Returns the maintenance windows.
+    #[must_use]
+    pub fn windows(&self) -> &[MaintenanceWindow] {
+        &self.windows
+    }
+
+    /// Returns the end of the maintenance window containing `now`, if any.
+    ///
+    /// If several windows contain `now`, the latest end is returned.
+    #[must_use]
+    pub fn active_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.windows
+            .iter()
+            .filter_map(|window| window.active_until(now))
+            .max()
+    }
+
+    /// Waits until no maintenance window is active, or fails if the action is
+    /// [`MaintenanceAction::FailFast`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenFIGIError::MaintenanceWindow`] during a window if the action is
+    /// [`MaintenanceAction::FailFast`].
+    pub(crate) async fn acquire(&self) -> Result<()> {
+        while let Some(until) = self.active_until(Utc::now()) {
+            if self.action == MaintenanceAction::FailFast {
+                return Err(OpenFIGIError::MaintenanceWindow { until });
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(%until, "OpenFIGI request deferred by maintenance window");
+            Delay::new((until - Utc::now()).to_std().unwrap_or_default()).await;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        value.parse().expect("Should parse timestamp")
+    }
+
+    #[test]
+    fn test_window_active_until() {
+        // 2026-10-17 is a Saturday
+        let window: MaintenanceWindow = "sat 22:00 4h".parse().expect("Should parse window");
+        assert_eq!(window.to_string(), "sat 22:00 4h");
+        assert_eq!(window.active_until(at("2026-10-17T21:59:00Z")), None);
+        assert_eq!(
+            window.active_until(at("2026-10-17T23:00:00Z")),
+            Some(at("2026-10-18T02:00:00Z"))
+        );
+        assert_eq!(
+            window.active_until(at("2026-10-18T01:00:00Z")),
+            Some(at("2026-10-18T02:00:00Z"))
+        );
+        assert_eq!(window.active_until(at("2026-10-18T02:00:00Z")), None);
+
+        let daily: MaintenanceWindow = "* 23:45 30m".parse().expect("Should parse window");
+        assert_eq!(daily.to_string(), "* 23:45 30m");
+        assert_eq!(
+            daily.active_until(at("2026-10-20T00:10:00Z")),
+            Some(at("2026-10-20T00:15:00Z"))
+        );
+        assert!("sat 22:00".parse::<MaintenanceWindow>().is_err());
+        assert!("sat 25:00 1h".parse::<MaintenanceWindow>().is_err());
+        assert!("sat 22:00 1d".parse::<MaintenanceWindow>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_fails_fast() {
+        use crate::client::OpenFIGIClient;
+
+        let start = (Utc::now() - TimeDelta::hours(1)).time();
+        let schedule = MaintenanceSchedule::new()
+            .window(MaintenanceWindow::daily(start, Duration::from_hours(2)));
+        let client = OpenFIGIClient::builder()
+            .base_url("http://127.0.0.1:9/v3/")
+            .maintenance(schedule)
+            .build()
+            .expect("Failed to create test client");
+
+        let err = client
+            .search("ibm")
+            .send()
+            .await
+            .expect_err("Request should fail during maintenance");
+        assert!(err.is_maintenance_window());
+    }
+}
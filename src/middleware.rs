@@ -0,0 +1,219 @@
+//! # Middleware
+//!
+//! Middleware for the `reqwest-middleware` stack of the client.
+//!
+//! [`RateLimitRetry`](crate::middleware::RateLimitRetry) retries requests answered with
+//! `429 Too Many Requests` or `503 Service Unavailable` after the delay announced by the
+//! OpenFIGI API in the `ratelimit-reset` or `retry-after` header. Generic exponential backoff,
+//! e.g. from `reqwest-retry`, ignores these headers and either retries too early, wasting
+//! requests of the rate limit, or waits longer than needed.
+//!
+//! Enable it with
+//! [`OpenFIGIClientBuilder::with_default_retries`](crate::client_builder::OpenFIGIClientBuilder::with_default_retries),
+//! or add it to a custom middleware stack passed to
+//! [`OpenFIGIClientBuilder::middleware_client`](crate::client_builder::OpenFIGIClientBuilder::middleware_client).
+//! Delays use a runtime independent timer. If the `tracing` feature is enabled, every retry
+//! emits a `tracing::debug!` event with the `status` and the `delay`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::middleware::RateLimitRetry;
+//! use reqwest_middleware::ClientBuilder;
+//! use std::time::Duration;
+//!
+//! // The default policy
+//! let client = OpenFIGIClient::builder().with_default_retries().build()?;
+//!
+//! // A custom policy in a custom middleware stack
+//! let retry = RateLimitRetry::new()
+//!     .max_retries(5)
+//!     .max_delay(Duration::from_secs(30));
+//! let middleware_client = ClientBuilder::new(reqwest::Client::new()).with(retry).build();
+//! let client = OpenFIGIClient::builder()
+//!     .middleware_client(middleware_client)
+//!     .build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use futures_timer::Delay;
+use http::Extensions;
+use reqwest::{Request, Response, StatusCode, header::HeaderMap};
+use reqwest_middleware::{Middleware, Next};
+use std::time::Duration;
+
+/// Retries requests rejected with `429` or `503` after the delay announced by the API.
+///
+/// The delay is read from the `ratelimit-reset` header, falling back to `retry-after`, both in
+/// seconds. Without either header, or with a value in another format, [`Self::default_delay`]
+/// applies. Delays are capped at [`Self::max_delay`]. Once [`Self::max_retries`] retries are
+/// exhausted, the last response is returned and reported as an error by the client. Requests
+/// with a streaming body, which cannot be cloned, are not retried.
+#[derive(Clone, Debug)]
+pub struct RateLimitRetry {
+    max_retries: u32,
+    default_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RateLimitRetry {
+    /// Three retries, a default delay of 6 seconds and a maximum delay of one minute.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            default_delay: Duration::from_secs(6),
+            max_delay: Duration::from_mins(1),
+        }
+    }
+}
+
+impl RateLimitRetry {
+    /// Creates a retry policy with the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of retries of a request. `0` disables retries.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay used when the response announces no delay.
+    #[must_use]
+    pub fn default_delay(mut self, delay: Duration) -> Self {
+        self.default_delay = delay;
+        self
+    }
+
+    /// Sets the maximum delay before a retry, capping the delays announced by the API.
+    #[must_use]
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Returns the delay before retrying a request answered with `headers`.
+    fn retry_delay(&self, headers: &HeaderMap) -> Duration {
+        ["ratelimit-reset", "retry-after"]
+            .into_iter()
+            .find_map(|name| headers.get(name)?.to_str().ok()?.trim().parse().ok())
+            .map_or(self.default_delay, Duration::from_secs)
+            .min(self.max_delay)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Middleware for RateLimitRetry {
+    async fn handle(
+        &self,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut retries = 0;
+        loop {
+            let Some(attempt) = request.try_clone().filter(|_| retries < self.max_retries) else {
+                return next.run(request, extensions).await;
+            };
+            let response = next.clone().run(attempt, extensions).await?;
+            let status = response.status();
+            if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::SERVICE_UNAVAILABLE
+            {
+                return Ok(response);
+            }
+
+            let delay = self.retry_delay(response.headers());
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                status = status.as_u16(),
+                delay = ?delay,
+                "Retrying OpenFIGI request"
+            );
+            drop(response);
+            Delay::new(delay).await;
+            retries += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client_builder;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_retry_delay() {
+        let retry = RateLimitRetry::new();
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry.retry_delay(&headers), Duration::from_secs(6));
+
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+        assert_eq!(retry.retry_delay(&headers), Duration::from_secs(3));
+
+        headers.insert("ratelimit-reset", HeaderValue::from_static("120"));
+        assert_eq!(retry.retry_delay(&headers), Duration::from_mins(1));
+
+        headers.insert("ratelimit-reset", HeaderValue::from_static("soon"));
+        assert_eq!(retry.retry_delay(&headers), Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_retries_after_rate_limit() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("ratelimit-reset", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": [{ "figi": "BBG000BLNNH6" }] })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .with_default_retries()
+            .build()
+            .expect("Failed to create test client");
+        let data = client
+            .search("ibm")
+            .send()
+            .await
+            .expect("Request should succeed after a retry");
+        assert_eq!(data.data().len(), 1);
+        assert_eq!(server.received_requests().await.map(|r| r.len()), Some(2));
+
+        let limited = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .mount(&limited)
+            .await;
+        let client = mock_client_builder(&limited)
+            .rate_limit_retry(RateLimitRetry::new().max_retries(2))
+            .build()
+            .expect("Failed to create test client");
+        let err = client
+            .search("ibm")
+            .send()
+            .await
+            .expect_err("Request should fail once retries are exhausted");
+        assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(limited.received_requests().await.map(|r| r.len()), Some(3));
+    }
+}
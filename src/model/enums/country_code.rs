@@ -0,0 +1,183 @@
+//! ISO 3166-1 alpha-2 country codes, with the venues listed in each country.
+//!
+//! Unlike the other enums, country codes are not an OpenFIGI request field. They select the
+//! exchange codes and MIC codes of a country from bundled reference data, e.g. to search all
+//! German listings with
+//! [`SingleSearchRequestBuilder::country`](crate::endpoint::search::SingleSearchRequestBuilder::country).
+//!
+//! Example usage:
+//! ```rust
+//! use openfigi_rs::model::enums::{CountryCode, ExchCode, MicCode};
+//!
+//! let germany: CountryCode = "DE".parse()?;
+//! assert!(germany.exch_codes().contains(&"GY".parse::<ExchCode>()?));
+//! assert!(germany.mic_codes().contains(&"XETR".parse::<MicCode>()?));
+//! # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+//! ```
+
+use super::{ExchCode, FromStrVisitor, MicCode, VENUES_CSV, unknown_value};
+use crate::error::{OpenFIGIError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+/// Bundled English short names of the countries with venues in the reference data.
+const COUNTRY_NAMES_CSV: &str = include_str!("../../../resources/others/country_names.csv");
+
+/// An ISO 3166-1 alpha-2 country code, e.g. `DE` for Germany.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CountryCode([u8; 2]);
+
+impl CountryCode {
+    /// Returns the code as two upper-case letters.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Both bytes are ASCII letters, checked on construction
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Parses a code leniently, ignoring case and surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the value is not two ASCII letters.
+    pub fn parse_lenient(value: &str) -> Result<Self> {
+        value.trim().to_ascii_uppercase().parse()
+    }
+
+    /// Returns the English short name of the country, e.g. `Germany` for `DE`.
+    ///
+    /// `None` for countries without venues in the bundled reference data.
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        COUNTRY_NAMES_CSV.lines().skip(1).find_map(|line| {
+            let (code, name) = line.split_once('|')?;
+            (code == self.as_str()).then_some(name)
+        })
+    }
+
+    /// Returns the exchange codes of all venues in this country, in sorted order.
+    ///
+    /// Contains the codes of the individual venues, e.g. `GY` for Xetra, as well as the
+    /// composite codes combining them, e.g. `GR` for Germany. Empty for codes without venues
+    /// in the bundled reference data.
+    #[must_use]
+    pub fn exch_codes(&self) -> Vec<ExchCode> {
+        let codes: BTreeSet<&str> = self
+            .venues()
+            .flat_map(|columns| [columns[4], columns[6]])
+            .filter(|code| is_code(code))
+            .collect();
+        codes
+            .into_iter()
+            .filter_map(|code| code.parse().ok())
+            .collect()
+    }
+
+    /// Returns the MIC codes of all venues in this country, in sorted order.
+    ///
+    /// Empty for codes without venues in the bundled reference data.
+    #[must_use]
+    pub fn mic_codes(&self) -> Vec<MicCode> {
+        let codes: BTreeSet<&str> = self
+            .venues()
+            .map(|columns| columns[0])
+            .filter(|code| is_code(code))
+            .collect();
+        codes
+            .into_iter()
+            .filter_map(|code| code.parse().ok())
+            .collect()
+    }
+
+    /// Returns the columns of the bundled reference data rows of this country.
+    fn venues(&self) -> impl Iterator<Item = Vec<&'static str>> + '_ {
+        VENUES_CSV
+            .lines()
+            .skip(1)
+            .map(|line| line.split('|').map(str::trim).collect::<Vec<_>>())
+            .filter(|columns| columns.len() > 7 && columns[7] == self.as_str())
+    }
+}
+
+/// Returns true if `value` is an exchange or MIC code rather than a placeholder.
+pub(super) fn is_code(value: &str) -> bool {
+    !value.is_empty() && value != "NONE" && !value.contains(' ')
+}
+
+/// Returns the name of a venue in the bundled reference data with runs of whitespace collapsed.
+pub(super) fn venue_name(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = OpenFIGIError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.as_bytes() {
+            &[a, b] if a.is_ascii_uppercase() && b.is_ascii_uppercase() => Ok(Self([a, b])),
+            _ => Err(unknown_value("CountryCode", value)),
+        }
+    }
+}
+
+impl Serialize for CountryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CountryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("CountryCode"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "DE".parse::<CountryCode>().map(|c| c.to_string()).ok(),
+            Some("DE".to_string())
+        );
+        assert!("de".parse::<CountryCode>().is_err());
+        assert!("DEU".parse::<CountryCode>().is_err());
+        assert_eq!(CountryCode::parse_lenient(" de ").ok(), "DE".parse().ok());
+    }
+
+    #[test]
+    fn test_name() {
+        let name = |code: &str| code.parse::<CountryCode>().ok().and_then(|c| c.name());
+        assert_eq!(name("DE"), Some("Germany"));
+        assert_eq!(name("US"), Some("United States"));
+        assert_eq!(name("AQ"), None);
+    }
+
+    #[test]
+    fn test_venues() {
+        let germany: CountryCode = "DE".parse().expect("Should parse");
+        let exch_codes = germany.exch_codes();
+        for code in ["GY", "GF", "GR"] {
+            let code: ExchCode = code.parse().expect("Should parse");
+            assert!(exch_codes.contains(&code), "missing {code}");
+        }
+        assert!(!exch_codes.contains(&ExchCode::US));
+        let mic_codes = germany.mic_codes();
+        for code in ["XETR", "XFRA"] {
+            let code: MicCode = code.parse().expect("Should parse");
+            assert!(mic_codes.contains(&code), "missing {code}");
+        }
+
+        let nowhere: CountryCode = "XX".parse().expect("Should parse");
+        assert!(nowhere.exch_codes().is_empty());
+        assert!(nowhere.mic_codes().is_empty());
+    }
+}
@@ -9,10 +9,16 @@
 //! let currency = Currency::USD; // US Dollar
 //! ```
 //!
+//! Unlike the enums read from responses, `Currency` has no `Other` variant. The API only takes
+//! currencies in requests and never returns them, so the enum stays `Copy` and an unknown code
+//! is reported as an error instead of being sent:
+//! ```rust
+//! use openfigi_rs::model::enums::Currency;
+//! assert!("NOT A CURRENCY".parse::<Currency>().is_err());
+//! ```
+//!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/currency>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported currency codes.
 include!(concat!(env!("OUT_DIR"), "/currency_enum.rs"));
 
@@ -23,4 +29,45 @@ mod tests {
 
     test_enum_serialization!(test_serialize_usd, Currency, USD, "\"USD\"");
     test_enum_serialization!(test_serialize_aud, Currency, AUd, "\"AUd\"");
+
+    #[test]
+    fn test_deserialize_unknown() {
+        let err = serde_json::from_str::<Currency>("\"NOT A CURRENCY\"").unwrap_err();
+        assert!(err.to_string().contains("expected a Currency value"));
+        assert!(serde_json::from_str::<Currency>("42").is_err());
+    }
+
+    #[test]
+    fn test_variants() {
+        assert!(Currency::VARIANTS.contains(&Currency::USD));
+        for currency in Currency::VARIANTS {
+            assert_eq!(
+                currency.as_str().parse::<Currency>().ok().as_ref(),
+                Some(currency)
+            );
+        }
+    }
+
+    #[test]
+    fn test_collection_keys() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let currency = Currency::USD;
+        let copied = currency;
+        assert_eq!(currency, copied);
+
+        let set: HashSet<Currency> = [Currency::USD, Currency::EUR, Currency::USD].into();
+        assert_eq!(set.len(), 2);
+        let sorted: BTreeSet<Currency> = Currency::VARIANTS.iter().copied().collect();
+        assert_eq!(sorted.len(), Currency::VARIANTS.len());
+    }
+
+    #[test]
+    fn test_provenance() {
+        assert_eq!(
+            Currency::SOURCE,
+            "https://api.openfigi.com/v3/mapping/values/currency"
+        );
+        const { assert!(Currency::GENERATED_AT > 0) };
+    }
 }
@@ -0,0 +1,200 @@
+//! Deprecation map of enum values renamed or retired by OpenFIGI.
+//!
+//! When OpenFIGI renames a value, e.g. when exchanges are consolidated, the enums regenerated
+//! from the `/mapping/values` endpoints drop the old value. Requests stored with the old value
+//! would then fail to parse, while clients built before the rename keep sending a value the API
+//! no longer accepts. The deprecation map covers both cases:
+//!
+//! - Parsing a deprecated value with `FromStr`, `parse_lenient()` or `serde` yields its
+//!   replacement, so stored requests keep working after the enums are regenerated.
+//! - Serializing a deprecated value writes its replacement, so clients keep working before.
+//!
+//! Every replacement logs a `tracing::warn!` event once per value if the `tracing` feature is
+//! enabled. The map is bundled with the crate and extended whenever the enums are regenerated;
+//! applications can add their own entries with [`register_deprecation`] and surface all entries,
+//! e.g. in a changelog, with [`deprecations`].
+//!
+//! Example usage:
+//! ```rust
+//! use openfigi_rs::model::enums::{Currency, register_deprecation};
+//!
+//! // Suppose OpenFIGI renamed the currency code `XYZ` to `EUR`
+//! register_deprecation("Currency", "XYZ", "EUR")?;
+//! assert_eq!("XYZ".parse::<Currency>()?, Currency::EUR);
+//! # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+//! ```
+
+use crate::error::{OpenFIGIError, OtherErrorKind, Result};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        LazyLock, PoisonError, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// Bundled deprecations, as `enum|deprecatedValue|replacementValue` rows.
+const DEPRECATIONS_CSV: &str = include_str!("../../../resources/others/deprecated_enum_values.csv");
+
+/// A deprecated enum value and its replacement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deprecation {
+    /// Name of the enum, e.g. `ExchCode`.
+    pub enum_name: String,
+    /// The deprecated API value.
+    pub deprecated_value: String,
+    /// The API value replacing it.
+    pub replacement_value: String,
+}
+
+#[derive(Debug)]
+struct Entry {
+    replacement: String,
+    warned: AtomicBool,
+}
+
+/// Deprecations keyed by enum name and deprecated value.
+type Registry = HashMap<String, HashMap<String, Entry>>;
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| {
+    let mut registry = Registry::new();
+    for line in DEPRECATIONS_CSV.lines().skip(1) {
+        let columns: Vec<&str> = line.split('|').map(str::trim).collect();
+        if let [enum_name, deprecated, replacement] = columns[..] {
+            insert(&mut registry, enum_name, deprecated, replacement);
+        }
+    }
+    RwLock::new(registry)
+});
+
+/// Registers `replacement` as the replacement of the `deprecated` value of the enum `enum_name`.
+///
+/// `enum_name` is the name of a generated enum, e.g. `ExchCode`. Registering a value again
+/// replaces its previous replacement. Replacements may be deprecated themselves, in which case
+/// they are followed to the current value.
+///
+/// # Errors
+///
+/// Returns a validation error if `replacement` is, or resolves to, `deprecated`.
+pub fn register_deprecation(enum_name: &str, deprecated: &str, replacement: &str) -> Result<()> {
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    let resolved = registry
+        .get(enum_name)
+        .and_then(|entries| resolve(entries, replacement));
+    if resolved.as_deref().unwrap_or(replacement) == deprecated {
+        return Err(OpenFIGIError::other_error(
+            OtherErrorKind::Validation,
+            format!("{enum_name} value '{deprecated}' cannot be replaced by itself"),
+        ));
+    }
+    insert(&mut registry, enum_name, deprecated, replacement);
+    Ok(())
+}
+
+/// Returns all registered deprecations, sorted by enum name and deprecated value.
+#[must_use]
+pub fn deprecations() -> Vec<Deprecation> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    let mut deprecations: Vec<Deprecation> = registry
+        .iter()
+        .flat_map(|(enum_name, entries)| {
+            entries.iter().map(|(deprecated, entry)| Deprecation {
+                enum_name: enum_name.clone(),
+                deprecated_value: deprecated.clone(),
+                replacement_value: entry.replacement.clone(),
+            })
+        })
+        .collect();
+    deprecations.sort_by(|a, b| {
+        (&a.enum_name, &a.deprecated_value).cmp(&(&b.enum_name, &b.deprecated_value))
+    });
+    deprecations
+}
+
+/// Returns the current replacement of a deprecated value, logging a warning the first time.
+///
+/// The returned value is never deprecated itself.
+pub(crate) fn replacement_value(enum_name: &str, value: &str) -> Option<String> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    let entries = registry.get(enum_name)?;
+    let entry = entries.get(value)?;
+    let replacement = resolve(entries, value)?;
+    if !entry.warned.swap(true, Ordering::Relaxed) {
+        log_replacement(enum_name, value, &replacement);
+    }
+    Some(replacement)
+}
+
+/// Returns the value to serialize for `value`, which is its replacement if it is deprecated.
+pub(crate) fn serialized_value<'a>(enum_name: &str, value: &'a str) -> Cow<'a, str> {
+    replacement_value(enum_name, value).map_or(Cow::Borrowed(value), Cow::Owned)
+}
+
+fn insert(registry: &mut Registry, enum_name: &str, deprecated: &str, replacement: &str) {
+    registry.entry(enum_name.to_string()).or_default().insert(
+        deprecated.to_string(),
+        Entry {
+            replacement: replacement.to_string(),
+            warned: AtomicBool::new(false),
+        },
+    );
+}
+
+/// Follows the replacements of `value` to a value that is not deprecated.
+///
+/// Returns `None` if `value` is not deprecated. Registration rejects cycles, so this terminates.
+fn resolve(entries: &HashMap<String, Entry>, value: &str) -> Option<String> {
+    let mut current = &entries.get(value)?.replacement;
+    while let Some(entry) = entries.get(current) {
+        current = &entry.replacement;
+    }
+    Some(current.clone())
+}
+
+#[cfg(feature = "tracing")]
+fn log_replacement(enum_name: &str, deprecated: &str, replacement: &str) {
+    tracing::warn!(
+        enum_name,
+        deprecated,
+        replacement,
+        "Deprecated OpenFIGI value replaced"
+    );
+}
+
+#[cfg(not(feature = "tracing"))]
+fn log_replacement(_enum_name: &str, _deprecated: &str, _replacement: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::enums::{ExchCode, MarketSecDesc};
+
+    #[test]
+    fn test_deprecated_values_are_replaced() {
+        register_deprecation("ExchCode", "TEST-OLD", "TEST-NEWER").expect("Should register");
+        register_deprecation("ExchCode", "TEST-NEWER", "US").expect("Should register");
+
+        assert_eq!("TEST-OLD".parse::<ExchCode>().ok(), Some(ExchCode::US));
+        assert_eq!(
+            serde_json::to_string(&ExchCode::Other("TEST-OLD".to_string())).ok(),
+            Some("\"US\"".to_string())
+        );
+        assert!(deprecations().contains(&Deprecation {
+            enum_name: "ExchCode".to_string(),
+            deprecated_value: "TEST-OLD".to_string(),
+            replacement_value: "TEST-NEWER".to_string(),
+        }));
+        assert!(register_deprecation("ExchCode", "US", "TEST-OLD").is_err());
+    }
+
+    #[test]
+    fn test_closed_enum_replacement() {
+        register_deprecation("MarketSecDesc", "Stocks", "Equity").expect("Should register");
+        assert_eq!(
+            serde_json::from_str::<MarketSecDesc>("\"Stocks\"").ok(),
+            Some(MarketSecDesc::Equity)
+        );
+        assert!("Shares".parse::<MarketSecDesc>().is_err());
+    }
+}
@@ -6,26 +6,155 @@
 //! Example usage:
 //! ```rust
 //! use openfigi_rs::model::enums::ExchCode;
-//! let exch_code = ExchCode::FRANKFURT; // Frankfurt Stock Exchange
+//! let exch_code = ExchCode::US; // United States composite
+//! ```
+//!
+//! Unless the `full-enums` feature is enabled, only major venues get a dedicated variant. All other
+//! exchange codes, as well as codes added to the API after this crate was built, are represented
+//! by [`ExchCode::Other`]:
+//! ```rust
+//! use openfigi_rs::model::enums::ExchCode;
+//! let exch_code: ExchCode = "NOT A VENUE".parse()?;
+//! assert_eq!(exch_code, ExchCode::Other("NOT A VENUE".to_string()));
+//! # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
 //! ```
 //!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/exchCode>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported exchange codes.
 include!(concat!(env!("OUT_DIR"), "/exch_code_enum.rs"));
 
+use super::{
+    CountryCode, VENUES_CSV,
+    country_code::{is_code, venue_name},
+};
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Display names of the exchange codes in the bundled reference data.
+///
+/// Venue codes are named after their market, composite codes after their country.
+static DISPLAY_NAMES: LazyLock<HashMap<&'static str, String>> = LazyLock::new(|| {
+    let mut names = HashMap::new();
+    let mut composite_countries = HashMap::new();
+    for line in VENUES_CSV.lines().skip(1) {
+        let columns: Vec<&str> = line.split('|').map(str::trim).collect();
+        if columns.len() < 8 {
+            continue;
+        }
+        if is_code(columns[4]) {
+            names
+                .entry(columns[4])
+                .or_insert_with(|| venue_name(columns[2]));
+        }
+        if is_code(columns[6]) {
+            composite_countries.entry(columns[6]).or_insert(columns[7]);
+        }
+    }
+    for (code, country) in composite_countries {
+        if let Some(country) = country.parse::<CountryCode>().ok().and_then(|c| c.name()) {
+            names
+                .entry(code)
+                .or_insert_with(|| format!("{country} (Composite)"));
+        }
+    }
+    names
+});
+
+impl ExchCode {
+    /// Returns a human-readable name of the exchange code from the bundled reference data.
+    ///
+    /// Venue codes are named after their market in the ISO 10383 register, e.g.
+    /// `NEW YORK STOCK EXCHANGE INC` for `UN`, and composite codes after their country, e.g.
+    /// `United States (Composite)` for `US`. `None` for codes without reference data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::enums::ExchCode;
+    ///
+    /// assert_eq!(ExchCode::US.display_name(), Some("United States (Composite)"));
+    /// ```
+    #[must_use]
+    pub fn display_name(&self) -> Option<&'static str> {
+        DISPLAY_NAMES.get(self.as_str()).map(String::as_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "full-enums")]
     use crate::test_enum_serialization;
 
+    #[cfg(feature = "full-enums")]
     test_enum_serialization!(
         test_serialize_frankfurt,
         ExchCode,
         FRANKFURT,
         "\"FRANKFURT\""
     );
+    #[cfg(feature = "full-enums")]
     test_enum_serialization!(test_serialize_bbox, ExchCode, Bbox, "\"bbox\"");
+
+    #[test]
+    fn test_from_str_exact() {
+        assert_eq!("US".parse::<ExchCode>().ok(), Some(ExchCode::US));
+        assert_eq!(
+            "us".parse::<ExchCode>().ok(),
+            Some(ExchCode::Other("us".to_string()))
+        );
+        assert_eq!(ExchCode::US.to_string(), "US");
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(ExchCode::parse_lenient("us").ok(), Some(ExchCode::US));
+        assert_eq!(ExchCode::parse_lenient("Us").ok(), Some(ExchCode::US));
+        assert_eq!(
+            ExchCode::parse_lenient("not an exchange").ok(),
+            Some(ExchCode::Other("not an exchange".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(
+            ExchCode::US.display_name(),
+            Some("United States (Composite)")
+        );
+        let nyse: ExchCode = "UN".parse().expect("Should parse");
+        assert_eq!(nyse.display_name(), Some("NEW YORK STOCK EXCHANGE INC"));
+        let iex: ExchCode = "VF".parse().expect("Should parse");
+        assert_eq!(iex.display_name(), Some("INVESTORS EXCHANGE"));
+    }
+
+    #[test]
+    fn test_variants() {
+        assert!(ExchCode::VARIANTS.contains(&ExchCode::US));
+        assert!(
+            !ExchCode::VARIANTS
+                .iter()
+                .any(|code| matches!(code, ExchCode::Other(_)))
+        );
+    }
+
+    #[test]
+    fn test_other() {
+        let other = ExchCode::Other("NEW VENUE".to_string());
+        assert_eq!(serde_json::to_string(&other).unwrap(), "\"NEW VENUE\"");
+        assert_eq!(
+            serde_json::from_str::<ExchCode>("\"NEW VENUE\"").unwrap(),
+            other
+        );
+        assert_eq!(
+            serde_json::from_str::<ExchCode>("\"US\"").unwrap(),
+            ExchCode::US
+        );
+        assert_eq!(other.as_str(), "NEW VENUE");
+        assert_eq!(other.display_name(), None);
+
+        let codes: std::collections::BTreeSet<ExchCode> =
+            [other.clone(), ExchCode::US, other].into();
+        assert_eq!(codes.len(), 2);
+    }
 }
@@ -11,8 +11,6 @@
 //!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/idType>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported idType values.
 include!(concat!(env!("OUT_DIR"), "/id_type_enum.rs"));
 
@@ -28,4 +26,19 @@ mod tests {
         VENDOR_INDEX_CODE,
         "\"VENDOR_INDEX_CODE\""
     );
+
+    #[test]
+    fn test_from_str_suggestion() {
+        assert_eq!("ID_ISIN".parse::<IdType>().ok(), Some(IdType::ID_ISIN));
+        let err = "id_isin".parse::<IdType>().unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("unknown IdType value 'id_isin', did you mean 'ID_ISIN'?")
+        );
+        let err = "NOT_AN_ID".parse::<IdType>().unwrap_err();
+        assert!(
+            err.to_string()
+                .ends_with("unknown IdType value 'NOT_AN_ID'")
+        );
+    }
 }
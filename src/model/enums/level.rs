@@ -0,0 +1,139 @@
+//! Levels of the FIGI hierarchy.
+//!
+//! OpenFIGI assigns FIGIs on three levels: a FIGI per listing on a trading venue, a composite
+//! FIGI aggregating the listings of an instrument within one country, and a share class FIGI
+//! aggregating its composites globally. [`Level`] selects the [`IdType`] to map a FIGI of a
+//! given level, and the results of a mapping to keep with
+//! [`crate::endpoint::mapping::SingleMappingRequestBuilder::level`].
+//!
+//! Example usage:
+//! ```rust
+//! use openfigi_rs::model::enums::{IdType, Level};
+//!
+//! assert_eq!(Level::Composite.id_type(), IdType::COMPOSITE_ID_BB_GLOBAL);
+//! ```
+
+use crate::{
+    error::{OpenFIGIError, Result},
+    model::{
+        enums::{IdType, unknown_value_expecting},
+        response::FigiResult,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// A level of the FIGI hierarchy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Level {
+    /// A listing on a single trading venue, e.g. `IBM UN`.
+    Listing,
+    /// The listings of an instrument within one country, e.g. `IBM US`.
+    Composite,
+    /// The composites of an instrument across all countries.
+    ShareClass,
+}
+
+impl Level {
+    /// Returns the identifier type for mapping a FIGI of this level.
+    #[must_use]
+    pub fn id_type(self) -> IdType {
+        match self {
+            Self::Listing => IdType::ID_BB_GLOBAL,
+            Self::Composite => IdType::COMPOSITE_ID_BB_GLOBAL,
+            Self::ShareClass => IdType::ID_BB_GLOBAL_SHARE_CLASS_LEVEL,
+        }
+    }
+
+    /// Returns the FIGI of `result` on this level, if known.
+    ///
+    /// For [`Self::Listing`] this is the FIGI of `result` if it is a listing.
+    #[must_use]
+    pub fn figi(self, result: &FigiResult) -> Option<&str> {
+        match self {
+            Self::Listing => (Self::of(result) == Self::Listing).then_some(result.figi.as_str()),
+            Self::Composite => result.composite_figi.as_deref(),
+            Self::ShareClass => result.share_class_figi.as_deref(),
+        }
+    }
+
+    /// Returns the level of `result`, derived from which of its FIGIs equals its own FIGI.
+    #[must_use]
+    pub fn of(result: &FigiResult) -> Self {
+        let figi = Some(result.figi.as_str());
+        if result.share_class_figi.as_deref() == figi {
+            Self::ShareClass
+        } else if result.composite_figi.as_deref() == figi {
+            Self::Composite
+        } else {
+            Self::Listing
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Listing => "listing",
+            Self::Composite => "composite",
+            Self::ShareClass => "share class",
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = OpenFIGIError;
+
+    /// Parses the displayed name of a level, e.g. `share class`.
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "listing" => Ok(Self::Listing),
+            "composite" => Ok(Self::Composite),
+            "share class" => Ok(Self::ShareClass),
+            _ => Err(unknown_value_expecting(
+                "Level",
+                value,
+                ["listing", "composite", "share class"],
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(figi: &str, composite: &str, share_class: &str) -> FigiResult {
+        serde_json::from_value(serde_json::json!({
+            "figi": figi,
+            "compositeFIGI": composite,
+            "shareClassFIGI": share_class,
+        }))
+        .expect("Should deserialize")
+    }
+
+    #[test]
+    fn test_level_of_result() {
+        let listing = result("BBG000BLNQ16", "BBG000BLNNH6", "BBG001S5S399");
+        let composite = result("BBG000BLNNH6", "BBG000BLNNH6", "BBG001S5S399");
+
+        assert_eq!(Level::of(&listing), Level::Listing);
+        assert_eq!(Level::of(&composite), Level::Composite);
+        assert_eq!(Level::Listing.figi(&listing), Some("BBG000BLNQ16"));
+        assert_eq!(Level::Listing.figi(&composite), None);
+        assert_eq!(Level::Composite.figi(&listing), Some("BBG000BLNNH6"));
+        assert_eq!(Level::ShareClass.figi(&listing), Some("BBG001S5S399"));
+    }
+
+    #[test]
+    fn test_level_from_str() {
+        for level in [Level::Listing, Level::Composite, Level::ShareClass] {
+            assert_eq!(level.to_string().parse::<Level>().ok(), Some(level));
+        }
+        let err = "ShareClass".parse::<Level>().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected one of: listing, composite, share class")
+        );
+    }
+}
@@ -11,8 +11,6 @@
 //!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/marketSecDes>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported market sector description values.
 include!(concat!(env!("OUT_DIR"), "/market_sec_desc_enum.rs"));
 
@@ -9,18 +9,70 @@
 //! let mic = MicCode::XCME; // Chicago Mercantile Exchange (CME)
 //! ```
 //!
+//! Unless the `full-enums` feature is enabled, only major venues get a dedicated variant, all other
+//! codes are represented by [`MicCode::Other`].
+//!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/micCode>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported market identifiers codes.
 include!(concat!(env!("OUT_DIR"), "/mic_code_enum.rs"));
 
+use super::{
+    VENUES_CSV,
+    country_code::{is_code, venue_name},
+};
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Display names of the MIC codes in the bundled reference data.
+static DISPLAY_NAMES: LazyLock<HashMap<&'static str, String>> = LazyLock::new(|| {
+    VENUES_CSV
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut columns = line.split('|').map(str::trim);
+            let mic = columns.next()?;
+            let name = columns.nth(1)?;
+            (is_code(mic) && !name.is_empty()).then(|| (mic, venue_name(name)))
+        })
+        .collect()
+});
+
+impl MicCode {
+    /// Returns the name of the market in the ISO 10383 register from the bundled reference
+    /// data, e.g. `NEW YORK STOCK EXCHANGE INC` for `XNYS`.
+    ///
+    /// `None` for codes without reference data.
+    #[must_use]
+    pub fn display_name(&self) -> Option<&'static str> {
+        DISPLAY_NAMES.get(self.as_str()).map(String::as_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_enum_serialization;
 
     test_enum_serialization!(test_serialize_xcme, MicCode, XCME, "\"XCME\"");
+    #[cfg(feature = "full-enums")]
     test_enum_serialization!(test_serialize_yldx, MicCode, YLDX, "\"YLDX\"");
+
+    #[test]
+    fn test_other() {
+        assert_eq!("XNYS".parse::<MicCode>().ok(), Some(MicCode::XNYS));
+        assert_eq!(
+            serde_json::from_str::<MicCode>("\"ZZZZ\"").unwrap(),
+            MicCode::Other("ZZZZ".to_string())
+        );
+        assert_eq!(MicCode::Other("ZZZZ".to_string()).to_string(), "ZZZZ");
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(
+            MicCode::XNYS.display_name(),
+            Some("NEW YORK STOCK EXCHANGE INC")
+        );
+        assert_eq!(MicCode::Other("NEW".to_string()).display_name(), None);
+    }
 }
@@ -4,6 +4,59 @@
 //! relevant to financial instruments and identifiers, such as exchange codes, currency codes,
 //! market security descriptions, and more. These enums are used for type safety and clarity
 //! when interacting with the [OpenFIGI](https://www.openfigi.com/api/overview) API and related data models.
+//!
+//! Every enum converts to and from its API value with [`std::fmt::Display`] and
+//! [`std::str::FromStr`], which only accepts exact values and suggests the intended value in its
+//! error, e.g. `did you mean 'ID_ISIN'?` for `id_isin`. For human-edited input,
+//! `parse_lenient()` additionally ignores case, whitespace and punctuation.
+//!
+//! [`ExchCode`] and [`MicCode`] never fail to parse; unknown codes become their `Other` variant.
+//!
+//! The enums found in responses, [`ExchCode`], [`MarketSecDesc`], [`MicCode`], [`SecurityType`]
+//! and [`SecurityType2`], never fail to deserialize: values without a variant, e.g. codes
+//! OpenFIGI introduced after this crate was built, become their `Other` variant instead of
+//! failing the whole response. Parsing stays strict for [`MarketSecDesc`], [`SecurityType`] and
+//! [`SecurityType2`], so that typos in requests are still reported; their `from_raw()`
+//! constructor opts into the fallback. The `is_known()` method of these enums detects
+//! fallbacks, and [`FigiResult::unknown_values`](crate::model::response::FigiResult::unknown_values)
+//! lists them for a result:
+//!
+//! ```rust
+//! use openfigi_rs::model::enums::{ExchCode, SecurityType};
+//!
+//! assert_eq!("US".parse::<ExchCode>()?, ExchCode::US);
+//! assert_eq!(ExchCode::parse_lenient("us")?, ExchCode::US);
+//!
+//! let new_type: SecurityType = serde_json::from_str(r#""New Type""#)?;
+//! assert_eq!(new_type, SecurityType::Other("New Type".to_string()));
+//! assert!(!new_type.is_known());
+//! assert!("New Type".parse::<SecurityType>().is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The generated enums list their variants in a `VARIANTS` constant, e.g. to offer the valid values
+//! in a user interface. For the enums with an `Other` variant it excludes that variant:
+//!
+//! ```rust
+//! use openfigi_rs::model::enums::Currency;
+//!
+//! let codes: Vec<&str> = Currency::VARIANTS.iter().map(Currency::as_str).collect();
+//! assert!(codes.contains(&"USD"));
+//! ```
+//!
+//! Values renamed or retired by OpenFIGI are replaced by their successors when parsing and
+//! serializing the generated enums, see [`register_deprecation`].
+//!
+//! The enums generated from the OpenFIGI `/mapping/values` endpoints expose their provenance,
+//! so applications can report how current the compiled value lists are:
+//!
+//! ```rust
+//! use chrono::DateTime;
+//! use openfigi_rs::model::enums::ExchCode;
+//!
+//! let generated_at = DateTime::from_timestamp(ExchCode::GENERATED_AT as i64, 0);
+//! println!("Exchange codes from {} as of {generated_at:?}", ExchCode::SOURCE);
+//! ```
 
 mod exch_code;
 pub use self::exch_code::ExchCode;
@@ -23,3 +76,112 @@ mod state_code;
 pub use self::state_code::StateCode;
 mod option_type;
 pub use self::option_type::OptionType;
+mod country_code;
+pub use self::country_code::CountryCode;
+mod level;
+pub use self::level::Level;
+mod deprecation;
+pub use self::deprecation::{Deprecation, deprecations, register_deprecation};
+pub(crate) use self::deprecation::{replacement_value, serialized_value};
+mod venues;
+pub(crate) use self::venues::{MIC_EXCH_CODES, VENUES_CSV};
+
+use crate::error::{OpenFIGIError, OtherErrorKind};
+use serde::de::{self, Unexpected, Visitor};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// Normalizes a value for lenient enum parsing by keeping only lower-cased alphanumeric characters.
+///
+/// The build script applies the same normalization to the generated enum values.
+pub(crate) fn lenient_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Creates the error returned when a string does not name a value of the given enum.
+pub(crate) fn unknown_value(enum_name: &str, value: &str) -> OpenFIGIError {
+    OpenFIGIError::other_error(
+        OtherErrorKind::Validation,
+        format!("unknown {enum_name} value '{value}'"),
+    )
+}
+
+/// Creates the error returned when a string does not name a value of the given enum, suggesting
+/// the value it matches leniently, if any.
+pub(crate) fn unknown_value_suggesting(
+    enum_name: &str,
+    value: &str,
+    suggestion: Option<&str>,
+) -> OpenFIGIError {
+    match suggestion {
+        Some(suggestion) => OpenFIGIError::other_error(
+            OtherErrorKind::Validation,
+            format!("unknown {enum_name} value '{value}', did you mean '{suggestion}'?"),
+        ),
+        None => unknown_value(enum_name, value),
+    }
+}
+
+/// Creates the error returned when a string does not name a value of an enum with few values,
+/// listing the accepted values.
+pub(crate) fn unknown_value_expecting<'a>(
+    enum_name: &str,
+    value: &str,
+    expected: impl IntoIterator<Item = &'a str>,
+) -> OpenFIGIError {
+    let expected: Vec<&str> = expected.into_iter().collect();
+    OpenFIGIError::other_error(
+        OtherErrorKind::Validation,
+        format!(
+            "unknown {enum_name} value '{value}', expected one of: {}",
+            expected.join(", ")
+        ),
+    )
+}
+
+/// Deserializes a generated enum from its API value using the enum's `FromStr` implementation.
+pub(crate) struct FromStrVisitor<T> {
+    enum_name: &'static str,
+    fallback: Option<fn(&str) -> T>,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    /// Creates a visitor for the enum with the given name, used in error messages.
+    pub(crate) fn new(enum_name: &'static str) -> Self {
+        Self {
+            enum_name,
+            fallback: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a visitor passing values that do not parse to `fallback` instead of failing.
+    pub(crate) fn with_fallback(enum_name: &'static str, fallback: fn(&str) -> T) -> Self {
+        Self {
+            enum_name,
+            fallback: Some(fallback),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr> Visitor<'_> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {} value", self.enum_name)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        if let Some(fallback) = self.fallback {
+            return Ok(fallback(value));
+        }
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
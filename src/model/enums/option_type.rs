@@ -8,7 +8,10 @@
 //! let option_type = OptionType::Call; // Call
 //! ```
 
+use super::{lenient_key, unknown_value, unknown_value_suggesting};
+use crate::error::{OpenFIGIError, Result};
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 
 /// Enum for all supported option types.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -20,6 +23,52 @@ pub enum OptionType {
     Put,
 }
 
+impl OptionType {
+    /// Returns the value of this variant as used by the OpenFIGI API.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "Call",
+            Self::Put => "Put",
+        }
+    }
+
+    /// Parses a value leniently, ignoring case, whitespace and punctuation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the value does not match a variant.
+    pub fn parse_lenient(value: &str) -> Result<Self> {
+        match lenient_key(value).as_str() {
+            "call" => Ok(Self::Call),
+            "put" => Ok(Self::Put),
+            _ => Err(unknown_value("OptionType", value)),
+        }
+    }
+}
+
+impl FromStr for OptionType {
+    type Err = OpenFIGIError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Call" => Ok(Self::Call),
+            "Put" => Ok(Self::Put),
+            _ => Err(unknown_value_suggesting(
+                "OptionType",
+                value,
+                Self::parse_lenient(value).as_ref().ok().map(Self::as_str),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -27,4 +76,14 @@ mod tests {
 
     test_enum_serialization!(test_serialize_call, OptionType, Call, "\"Call\"");
     test_enum_serialization!(test_serialize_put, OptionType, Put, "\"Put\"");
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("Put".parse::<OptionType>().ok(), Some(OptionType::Put));
+        assert!("put".parse::<OptionType>().is_err());
+        assert_eq!(
+            OptionType::parse_lenient(" PUT ").ok(),
+            Some(OptionType::Put)
+        );
+    }
 }
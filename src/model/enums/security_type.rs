@@ -9,13 +9,23 @@
 //! let security_type = SecurityType::ABSCard; // ABS Card
 //! ```
 //!
+//! Each security type can be converted to its coarser [`SecurityType2`] classification with
+//! [`SecurityType::coarse()`]:
+//! ```rust
+//! use openfigi_rs::model::enums::{SecurityType, SecurityType2};
+//! assert_eq!(SecurityType::ADR.coarse(), Some(SecurityType2::DepositaryReceipt));
+//! ```
+//!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/securityType>
 
-use serde::{Deserialize, Serialize};
+use crate::model::enums::SecurityType2;
 
 // Enum for all supported security types.
 include!(concat!(env!("OUT_DIR"), "/security_type_enum.rs"));
 
+// Conversion to security types 2, generated from `resources/others/security_type_to_security_type2.csv`.
+include!(concat!(env!("OUT_DIR"), "/security_type_coarse.rs"));
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,4 +43,82 @@ mod tests {
         ZEROCOUPONOID,
         "\"ZERO COUPON, OID\""
     );
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(
+            SecurityType::parse_lenient("Common Stock").ok(),
+            Some(SecurityType::CommonStock)
+        );
+        assert_eq!(
+            SecurityType::parse_lenient("common-stock").ok(),
+            Some(SecurityType::CommonStock)
+        );
+        assert_eq!(
+            SecurityType::parse_lenient("CommonStock").ok(),
+            Some(SecurityType::CommonStock)
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient_ambiguous() {
+        // "CANADIAN" and "Canadian" are distinct values, so only exact input is accepted
+        assert_eq!(
+            SecurityType::parse_lenient("Canadian").ok(),
+            Some(SecurityType::Canadian)
+        );
+        assert!(SecurityType::parse_lenient("canadian").is_err());
+    }
+
+    #[test]
+    fn test_unknown_value_fallback() {
+        let unknown = serde_json::from_str::<SecurityType>("\"New Type\"")
+            .expect("Unknown values should deserialize");
+        assert_eq!(unknown, SecurityType::Other("New Type".to_string()));
+        assert!(!unknown.is_known());
+        assert_eq!(
+            serde_json::to_string(&unknown).ok().as_deref(),
+            Some("\"New Type\"")
+        );
+
+        // Parsing stays strict unless the fallback is requested explicitly
+        assert!("New Type".parse::<SecurityType>().is_err());
+        assert_eq!(SecurityType::from_raw("New Type"), unknown);
+        assert_eq!(
+            SecurityType::from_raw("Common Stock"),
+            SecurityType::CommonStock
+        );
+        assert!(SecurityType::CommonStock.is_known());
+    }
+
+    #[test]
+    fn test_coarse() {
+        assert_eq!(
+            SecurityType::CommonStock.coarse(),
+            Some(SecurityType2::CommonStock)
+        );
+        assert_eq!(
+            SecurityType::GDR.coarse(),
+            Some(SecurityType2::DepositaryReceipt)
+        );
+        assert_eq!(SecurityType::ETP.coarse(), Some(SecurityType2::MutualFund));
+        assert_eq!(SecurityType::MBS30yr.coarse(), Some(SecurityType2::Pool));
+        assert_eq!(
+            SecurityType::EquityWRT.coarse(),
+            Some(SecurityType2::Warrant)
+        );
+    }
+
+    #[test]
+    fn test_coarse_ambiguous() {
+        // Coupon structures are used for corporate, government and municipal debt alike
+        assert_eq!(SecurityType::FIXED.coarse(), None);
+    }
+
+    #[test]
+    fn test_coarse_round_trip() {
+        let security_type = SecurityType::NYRegShrs;
+        let coarse = security_type.coarse().expect("Should have a coarse type");
+        assert!(coarse.detailed().contains(&security_type));
+    }
 }
@@ -9,12 +9,22 @@
 //! let mic = SecurityType2::BASISSWAP; // Basis Swap
 //! ```
 //!
+//! The detailed [`SecurityType`] values belonging to a security type 2 are available through
+//! [`SecurityType2::detailed()`]:
+//! ```rust
+//! use openfigi_rs::model::enums::{SecurityType, SecurityType2};
+//! assert!(SecurityType2::Warrant.detailed().contains(&SecurityType::IndexWRT));
+//! ```
+//!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/securityType2>
-use serde::{Deserialize, Serialize};
+use crate::model::enums::SecurityType;
 
 // Enum for all supported security types 2 values.
 include!(concat!(env!("OUT_DIR"), "/security_type2_enum.rs"));
 
+// Conversion to detailed security types, generated from `resources/others/security_type_to_security_type2.csv`.
+include!(concat!(env!("OUT_DIR"), "/security_type2_detailed.rs"));
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +42,21 @@ mod tests {
         UnitInvestmentTrust,
         "\"Unit Investment Trust\""
     );
+
+    #[test]
+    fn test_detailed() {
+        let detailed = SecurityType2::DepositaryReceipt.detailed();
+        assert!(detailed.contains(&SecurityType::ADR));
+        assert!(detailed.contains(&SecurityType::GDR));
+        assert!(
+            detailed
+                .iter()
+                .all(|t| t.coarse() == Some(SecurityType2::DepositaryReceipt))
+        );
+    }
+
+    #[test]
+    fn test_detailed_unmapped() {
+        assert!(SecurityType2::BilletQ235.detailed().is_empty());
+    }
 }
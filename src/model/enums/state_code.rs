@@ -11,8 +11,6 @@
 //!
 //! For the full list of values, see: <https://api.openfigi.com/v3/mapping/values/stateCode>
 
-use serde::{Deserialize, Serialize};
-
 // Enum for all supported state codes.
 include!(concat!(env!("OUT_DIR"), "/state_code_enum.rs"));
 
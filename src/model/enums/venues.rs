@@ -0,0 +1,36 @@
+//! Bundled venue reference data shared by the exchange, MIC and country code enums and the
+//! result compliance policies.
+
+use std::{collections::HashMap, sync::LazyLock};
+
+/// Bundled reference data of venues, with their MIC, exchange code, composite code and country.
+pub(crate) const VENUES_CSV: &str =
+    include_str!("../../../resources/others/mapping_mic_figi-exchange-codes.csv");
+
+/// Bundled mapping from MIC codes to the exchange code of the venue.
+pub(crate) static MIC_EXCH_CODES: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| {
+        VENUES_CSV
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let mut columns = line.split('|').map(str::trim);
+                let mic = columns.next()?;
+                let exch_code = columns.nth(3)?;
+                (!mic.is_empty() && !exch_code.is_empty() && exch_code != "NONE")
+                    .then_some((mic, exch_code))
+            })
+            .collect()
+    });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mic_exch_codes() {
+        assert_eq!(MIC_EXCH_CODES.get("XNYS"), Some(&"UN"));
+        assert_eq!(MIC_EXCH_CODES.get("MISX"), Some(&"RX"));
+        assert_eq!(MIC_EXCH_CODES.get("BMTS"), None);
+    }
+}
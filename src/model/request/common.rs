@@ -10,11 +10,13 @@ use crate::{
     error::{OpenFIGIError, OtherErrorKind, Result},
     model::enums::{
         Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
-        StateCode,
+        StateCode, unknown_value_expecting,
     },
 };
 use chrono::NaiveDate;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, ser::SerializeMap};
+use serde_json::Value;
+use std::{collections::BTreeMap, fmt, str::FromStr};
 
 /// Common filter parameters for OpenFIGI API requests.
 ///
@@ -24,43 +26,66 @@ use serde::{Deserialize, Serialize};
 /// # Validation Rules
 ///
 /// - `exch_code` and `mic_code` are mutually exclusive
+/// - A field cannot be set both as a typed filter and with [`Self::set_raw`]
 /// - Numeric ranges (`strike`, `contract_size`, `coupon`) must have start ≤ end
 /// - Date ranges (`expiration`, `maturity`) must have start ≤ end and span ≤ 1 year
 /// - `expiration` is required for Option or Warrant security types
 /// - `maturity` is required for Pool security types
+/// - Enum filters set from strings must name a known value
+///
+/// Parameters not modeled by this crate yet can be set with [`Self::set_raw`].
+///
+/// The struct also keeps private bookkeeping of raw parameters and unrecognized values, so it
+/// cannot be created with a struct literal. Start from [`RequestFilters::default`] instead:
+///
+/// ```rust
+/// use openfigi_rs::model::{enums::Currency, request::RequestFilters};
+///
+/// let mut filters = RequestFilters::default();
+/// filters.currency = Some(Currency::USD);
+/// assert!(filters.validate().is_ok());
+/// ```
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RequestFilters {
     /// Exchange code of the desired instrument.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "exchCode")]
     pub exch_code: Option<ExchCode>,
     /// ISO market identifier code (MIC) of the desired instrument.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "micCode")]
     pub mic_code: Option<MicCode>,
     /// Currency associated to the desired instrument.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     /// Market sector description of the desired instrument.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "marketSecDes")]
     pub market_sec_des: Option<MarketSecDesc>,
     /// Security type of the desired instrument
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "securityType")]
     pub security_type: Option<SecurityType>,
     /// An alternative security type of the desired instrument.
     /// `securityType2` is typically less specific than `securityType`.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "securityType2")]
     pub security_type2: Option<SecurityType2>,
     /// Set to `true` to include equity instruments that are not listed on an exchange.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "includeUnlistedEquities")]
     pub include_unlisted_equities: Option<bool>,
     /// Will filter instruments based on option type
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "optionType")]
     pub option_type: Option<OptionType>,
     /// Will find instruments whose strike price falls in an interval.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strike: Option<[Option<f64>; 2]>,
     /// Will find instruments whose contract size falls in an interval.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "contractSize")]
     pub contract_size: Option<[Option<f64>; 2]>,
     /// Will find instruments whose coupon falls in an interval.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,21 +98,340 @@ pub struct RequestFilters {
     pub maturity: Option<[Option<NaiveDate>; 2]>,
     /// State code of the desired instrument.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "stateCode")]
     pub state_code: Option<StateCode>,
+    /// Unrecognized string values passed to enum filter setters, keyed by field name.
+    #[serde(skip)]
+    pub(crate) unknown_values: Vec<(FilterField, String)>,
+    /// Parameters set with [`Self::set_raw`], serialized as is after the modeled fields.
+    #[serde(flatten)]
+    pub(crate) raw_values: BTreeMap<String, Value>,
+}
+
+/// Value accepted by the enum filter setters of the request builders.
+///
+/// Implemented for each filter enum and for strings holding its API value, so that
+/// `.currency(Currency::USD)` and `.currency("USD")` are equivalent. A string that does not
+/// name a known value is reported as a validation error when the request is built.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::model::request::{HasFilters, SearchRequestBuilder};
+///
+/// let request = SearchRequestBuilder::new().query("ibm").currency("USD").build();
+/// assert!(request.is_ok());
+///
+/// let request = SearchRequestBuilder::new().query("ibm").currency("DOLLAR").build();
+/// assert!(request.is_err());
+/// ```
+pub trait FilterValue<T> {
+    /// Converts the value into the filter enum, returning the original string if it is unknown.
+    ///
+    /// # Errors
+    ///
+    /// Returns the string that does not match any value of `T`.
+    fn into_filter_value(self) -> std::result::Result<T, String>;
+}
+
+/// Implements [`FilterValue`] for the given enums, their string values and themselves.
+macro_rules! impl_filter_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FilterValue<$ty> for $ty {
+                fn into_filter_value(self) -> std::result::Result<$ty, String> {
+                    Ok(self)
+                }
+            }
+
+            impl FilterValue<$ty> for &str {
+                fn into_filter_value(self) -> std::result::Result<$ty, String> {
+                    self.parse().map_err(|_| self.to_string())
+                }
+            }
+
+            impl FilterValue<$ty> for String {
+                fn into_filter_value(self) -> std::result::Result<$ty, String> {
+                    self.as_str().into_filter_value()
+                }
+            }
+        )*
+    };
+}
+
+impl_filter_value!(
+    Currency,
+    ExchCode,
+    MarketSecDesc,
+    MicCode,
+    OptionType,
+    SecurityType,
+    SecurityType2,
+    StateCode,
+);
+
+/// Builder of a request that accepts the common [`RequestFilters`].
+///
+/// All filter setters are provided on top of [`HasFilters::filters_mut`], so builders only
+/// implement that method to opt in. This is implemented by the request builders in this module
+/// and by the endpoint builders of [`crate::endpoint`], and may be implemented by builders
+/// defined outside of this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::model::enums::Currency;
+/// use openfigi_rs::model::request::{HasFilters, RequestFilters};
+///
+/// #[derive(Default)]
+/// struct WatchlistQuery {
+///     filters: RequestFilters,
+/// }
+///
+/// impl HasFilters for WatchlistQuery {
+///     fn filters_mut(&mut self) -> &mut RequestFilters {
+///         &mut self.filters
+///     }
+/// }
+///
+/// let query = WatchlistQuery::default().currency(Currency::USD);
+/// assert_eq!(query.filters.currency, Some(Currency::USD));
+/// ```
+pub trait HasFilters: Sized {
+    /// Mutable access to the request filters.
+    fn filters_mut(&mut self) -> &mut RequestFilters;
+
+    /// Sets the `exch_code` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "exchCode")]
+    fn exch_code(mut self, exch_code: impl FilterValue<ExchCode>) -> Self {
+        let filters = self.filters_mut();
+        filters.exch_code = filters.filter_value(FilterField::ExchCode, exch_code);
+        self
+    }
+
+    /// Sets the `mic_code` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "micCode")]
+    fn mic_code(mut self, mic_code: impl FilterValue<MicCode>) -> Self {
+        let filters = self.filters_mut();
+        filters.mic_code = filters.filter_value(FilterField::MicCode, mic_code);
+        self
+    }
+
+    /// Sets the `currency` for the desired instrument.
+    #[must_use]
+    fn currency(mut self, currency: impl FilterValue<Currency>) -> Self {
+        let filters = self.filters_mut();
+        filters.currency = filters.filter_value(FilterField::Currency, currency);
+        self
+    }
+
+    /// Sets the `market_sec_des` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "marketSecDes")]
+    fn market_sec_des(mut self, market_sec_des: impl FilterValue<MarketSecDesc>) -> Self {
+        let filters = self.filters_mut();
+        filters.market_sec_des = filters.filter_value(FilterField::MarketSecDes, market_sec_des);
+        self
+    }
+
+    /// Sets the `security_type` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "securityType")]
+    fn security_type(mut self, security_type: impl FilterValue<SecurityType>) -> Self {
+        let filters = self.filters_mut();
+        filters.security_type = filters.filter_value(FilterField::SecurityType, security_type);
+        self
+    }
+
+    /// Sets the `security_type2` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "securityType2")]
+    fn security_type2(mut self, security_type2: impl FilterValue<SecurityType2>) -> Self {
+        let filters = self.filters_mut();
+        filters.security_type2 = filters.filter_value(FilterField::SecurityType2, security_type2);
+        self
+    }
+
+    /// Sets whether to include unlisted equities in the filter.
+    #[must_use]
+    #[doc(alias = "includeUnlistedEquities")]
+    fn include_unlisted_equities(mut self, val: bool) -> Self {
+        self.filters_mut().include_unlisted_equities = Some(val);
+        self
+    }
+
+    /// Sets the `option_type` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "optionType")]
+    fn option_type(mut self, option_type: impl FilterValue<OptionType>) -> Self {
+        let filters = self.filters_mut();
+        filters.option_type = filters.filter_value(FilterField::OptionType, option_type);
+        self
+    }
+
+    /// Sets the `strike` price range for the desired instrument.
+    #[must_use]
+    fn strike(mut self, strike: [Option<f64>; 2]) -> Self {
+        self.filters_mut().strike = Some(strike);
+        self
+    }
+
+    /// Sets the `contract_size` range for the desired instrument.
+    #[must_use]
+    #[doc(alias = "contractSize")]
+    fn contract_size(mut self, contract_size: [Option<f64>; 2]) -> Self {
+        self.filters_mut().contract_size = Some(contract_size);
+        self
+    }
+
+    /// Sets the `coupon` range for the desired instrument.
+    #[must_use]
+    fn coupon(mut self, coupon: [Option<f64>; 2]) -> Self {
+        self.filters_mut().coupon = Some(coupon);
+        self
+    }
+
+    /// Sets the `expiration` date range for the desired instrument.
+    #[must_use]
+    fn expiration(mut self, expiration: [Option<NaiveDate>; 2]) -> Self {
+        self.filters_mut().expiration = Some(expiration);
+        self
+    }
+
+    /// Sets the `maturity` date range for the desired instrument.
+    #[must_use]
+    fn maturity(mut self, maturity: [Option<NaiveDate>; 2]) -> Self {
+        self.filters_mut().maturity = Some(maturity);
+        self
+    }
+
+    /// Sets the `state_code` for the desired instrument.
+    #[must_use]
+    #[doc(alias = "stateCode")]
+    fn state_code(mut self, state_code: impl FilterValue<StateCode>) -> Self {
+        let filters = self.filters_mut();
+        filters.state_code = filters.filter_value(FilterField::StateCode, state_code);
+        self
+    }
+
+    /// Unsets the given filter field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::enums::Currency;
+    /// use openfigi_rs::model::request::{FilterField, HasFilters, SearchRequest};
+    ///
+    /// let request = SearchRequest::builder()
+    ///     .query("ibm")
+    ///     .currency(Currency::USD)
+    ///     .unset(FilterField::Currency)
+    ///     .build()?;
+    /// assert!(request.filters.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    fn unset(mut self, field: FilterField) -> Self {
+        self.filters_mut().unset(field);
+        self
+    }
+
+    /// Unsets the `exch_code` filter.
+    #[must_use]
+    fn clear_exch_code(self) -> Self {
+        self.unset(FilterField::ExchCode)
+    }
+
+    /// Unsets the `mic_code` filter.
+    #[must_use]
+    fn clear_mic_code(self) -> Self {
+        self.unset(FilterField::MicCode)
+    }
+
+    /// Unsets the `currency` filter.
+    #[must_use]
+    fn clear_currency(self) -> Self {
+        self.unset(FilterField::Currency)
+    }
+
+    /// Unsets the `market_sec_des` filter.
+    #[must_use]
+    fn clear_market_sec_des(self) -> Self {
+        self.unset(FilterField::MarketSecDes)
+    }
+
+    /// Unsets the `security_type` filter.
+    #[must_use]
+    fn clear_security_type(self) -> Self {
+        self.unset(FilterField::SecurityType)
+    }
+
+    /// Unsets the `security_type2` filter.
+    #[must_use]
+    fn clear_security_type2(self) -> Self {
+        self.unset(FilterField::SecurityType2)
+    }
+
+    /// Unsets the `include_unlisted_equities` flag.
+    #[must_use]
+    fn clear_include_unlisted_equities(self) -> Self {
+        self.unset(FilterField::IncludeUnlistedEquities)
+    }
+
+    /// Unsets the `option_type` filter.
+    #[must_use]
+    fn clear_option_type(self) -> Self {
+        self.unset(FilterField::OptionType)
+    }
+
+    /// Unsets the `strike` range.
+    #[must_use]
+    fn clear_strike(self) -> Self {
+        self.unset(FilterField::Strike)
+    }
+
+    /// Unsets the `contract_size` range.
+    #[must_use]
+    fn clear_contract_size(self) -> Self {
+        self.unset(FilterField::ContractSize)
+    }
+
+    /// Unsets the `coupon` range.
+    #[must_use]
+    fn clear_coupon(self) -> Self {
+        self.unset(FilterField::Coupon)
+    }
+
+    /// Unsets the `expiration` date range.
+    #[must_use]
+    fn clear_expiration(self) -> Self {
+        self.unset(FilterField::Expiration)
+    }
+
+    /// Unsets the `maturity` date range.
+    #[must_use]
+    fn clear_maturity(self) -> Self {
+        self.unset(FilterField::Maturity)
+    }
+
+    /// Unsets the `state_code` filter.
+    #[must_use]
+    fn clear_state_code(self) -> Self {
+        self.unset(FilterField::StateCode)
+    }
 }
 
 impl RequestFilters {
     // Helper function to validate that start <= end for Option<[Option<T>; 2]>
-    fn validate_number_range(
-        field: Option<&[Option<f64>; 2]>,
-        field_name: &'static str,
-    ) -> Result<()> {
+    fn validate_number_range(field: Option<&[Option<f64>; 2]>, name: FilterField) -> Result<()> {
         if let Some([Some(start), Some(end)]) = field
             && start > end
         {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
-                format!("{field_name}: start value cannot be greater than end value"),
+                format!("{name}: start value cannot be greater than end value"),
             ));
         }
         Ok(())
@@ -96,18 +440,18 @@ impl RequestFilters {
     // Validate that the range is valid for Option<[Option<T>; 2]>
     fn validate_date_range(
         field: Option<&[Option<NaiveDate>; 2]>,
-        field_name: &'static str,
+        name: FilterField,
     ) -> Result<()> {
         if let Some([Some(start), Some(end)]) = field {
             if start > end {
                 return Err(OpenFIGIError::other_error(
                     OtherErrorKind::Validation,
-                    format!("{field_name}: start date cannot be after end date"),
+                    format!("{name}: start date cannot be after end date"),
                 ));
             } else if *end > (*start + chrono::Duration::days(365)) {
                 return Err(OpenFIGIError::other_error(
                     OtherErrorKind::Validation,
-                    format!("{field_name}: date range cannot exceed 1 year"),
+                    format!("{name}: date range cannot exceed 1 year"),
                 ));
             }
         }
@@ -116,6 +460,15 @@ impl RequestFilters {
 
     /// Validates that mutually exclusive fields are not used together.
     fn validate_mutual_exclusions(&self) -> Result<()> {
+        if let Some(field) = FilterField::ALL
+            .into_iter()
+            .find(|&field| self.is_set(field) && self.raw_values.contains_key(field.as_api_name()))
+        {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                format!("Cannot set {field} both as a typed filter and a raw value"),
+            ));
+        }
         if self.exch_code.is_some() && self.mic_code.is_some() {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
@@ -129,22 +482,37 @@ impl RequestFilters {
     /// Validates that all numeric and date ranges are ordered correctly.
     fn validate_ranges(&self) -> Result<()> {
         // Validate strike, contract_size, coupon
-        Self::validate_number_range(self.strike.as_ref(), "strike")?;
-        Self::validate_number_range(self.contract_size.as_ref(), "contract_size")?;
-        Self::validate_number_range(self.coupon.as_ref(), "coupon")?;
+        Self::validate_number_range(self.strike.as_ref(), FilterField::Strike)?;
+        Self::validate_number_range(self.contract_size.as_ref(), FilterField::ContractSize)?;
+        Self::validate_number_range(self.coupon.as_ref(), FilterField::Coupon)?;
 
         // Validate expiration and maturity dates
-        Self::validate_date_range(self.expiration.as_ref(), "expiration")?;
-        Self::validate_date_range(self.maturity.as_ref(), "maturity")?;
+        Self::validate_date_range(self.expiration.as_ref(), FilterField::Expiration)?;
+        Self::validate_date_range(self.maturity.as_ref(), FilterField::Maturity)?;
         Ok(())
     }
 
     /// Validates fields that are required only under certain conditions.
     fn validate_conditional_requirements(&self) -> Result<()> {
+        Self::check_conditional_requirements(
+            self.security_type2.as_ref(),
+            self.expiration.is_some(),
+            self.maturity.is_some(),
+        )
+    }
+
+    /// Checks the conditional requirements of [`Self::validate_conditional_requirements`] for
+    /// the given values, which may come from different filters.
+    fn check_conditional_requirements(
+        security_type2: Option<&SecurityType2>,
+        has_expiration: bool,
+        has_maturity: bool,
+    ) -> Result<()> {
         // expiration is required if securityType is Option or Warrant
-        if (self.security_type2 == Some(SecurityType2::Option)
-            || self.security_type2 == Some(SecurityType2::Warrant))
-            && self.expiration.is_none()
+        if matches!(
+            security_type2,
+            Some(SecurityType2::Option | SecurityType2::Warrant)
+        ) && !has_expiration
         {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
@@ -153,7 +521,7 @@ impl RequestFilters {
         }
 
         // maturity is required if securityType is Pool
-        if (self.security_type2 == Some(SecurityType2::Pool)) && self.maturity.is_none() {
+        if security_type2 == Some(&SecurityType2::Pool) && !has_maturity {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
                 "maturity is required for Pool security types",
@@ -162,6 +530,17 @@ impl RequestFilters {
         Ok(())
     }
 
+    /// Rejects unrecognized string values passed to enum filter setters.
+    fn validate_known_values(&self) -> Result<()> {
+        if let Some((field, value)) = self.unknown_values.first() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                format!("unknown {field} value '{value}'"),
+            ));
+        }
+        Ok(())
+    }
+
     /// Validates all filter parameters and their combinations.
     ///
     /// Ensures that:
@@ -174,6 +553,7 @@ impl RequestFilters {
     ///
     /// Returns [`OpenFIGIError`] with [`OtherErrorKind::Validation`] if validation fails.
     pub fn validate(&self) -> Result<()> {
+        self.validate_known_values()?;
         self.validate_mutual_exclusions()?;
         self.validate_ranges()?;
         self.validate_conditional_requirements()?;
@@ -181,32 +561,538 @@ impl RequestFilters {
         Ok(())
     }
 
-    /// Returns `true` if all filter fields are unset.
+    /// Validates these filters as merged with the valid `defaults` by [`Self::with_defaults`],
+    /// without merging them.
+    pub(crate) fn validate_with_defaults(&self, defaults: &Self) -> Result<()> {
+        self.validate_known_values()?;
+        self.validate_mutual_exclusions()?;
+        self.validate_ranges()?;
+        Self::check_conditional_requirements(
+            self.source(FilterField::SecurityType2, defaults)
+                .security_type2
+                .as_ref(),
+            self.source(FilterField::Expiration, defaults)
+                .expiration
+                .is_some(),
+            self.source(FilterField::Maturity, defaults)
+                .maturity
+                .is_some(),
+        )
+    }
+
+    /// Converts a value passed to an enum filter setter, recording unknown strings.
+    ///
+    /// A previously recorded unknown value for the same field is discarded, so the last
+    /// call to a setter wins.
+    pub(crate) fn filter_value<T>(
+        &mut self,
+        field: FilterField,
+        value: impl FilterValue<T>,
+    ) -> Option<T> {
+        self.unknown_values.retain(|(name, _)| *name != field);
+        match value.into_filter_value() {
+            Ok(value) => Some(value),
+            Err(value) => {
+                self.unknown_values.push((field, value));
+                None
+            }
+        }
+    }
+
+    /// Returns the fields whose values differ between `self` and `other`.
+    ///
+    /// Values are represented as in the request JSON, and changes are ordered as
+    /// [`FilterField::ALL`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::enums::{Currency, ExchCode};
+    /// use openfigi_rs::model::request::RequestFilters;
+    ///
+    /// let mut old = RequestFilters::default();
+    /// old.currency = Some(Currency::USD);
+    ///
+    /// let mut new = old.clone();
+    /// new.currency = Some(Currency::EUR);
+    /// new.exch_code = Some(ExchCode::US);
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.len(), 2);
+    /// assert_eq!(diff.to_string(), r#"exchCode: unset -> "US", currency: "USD" -> "EUR""#);
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> FiltersDiff {
+        let (Value::Object(old), Value::Object(mut new)) =
+            (serde_json::json!(self), serde_json::json!(other))
+        else {
+            return FiltersDiff::default();
+        };
+
+        let mut changes: Vec<FilterChange> = old
+            .into_iter()
+            .filter_map(|(name, old)| {
+                let new = new.remove(&name);
+                (new.as_ref() != Some(&old)).then(|| FilterChange::new(name, Some(old), new))
+            })
+            .collect();
+        changes.extend(
+            new.into_iter()
+                .map(|(name, new)| FilterChange::new(name, None, Some(new))),
+        );
+        // Modeled fields in their usual order, then raw parameters by name
+        changes.sort_by(|a, b| {
+            (a.field.is_none(), a.field, &a.name).cmp(&(b.field.is_none(), b.field, &b.name))
+        });
+
+        FiltersDiff(changes)
+    }
+
+    /// Sets the request parameter `field` to `value`, bypassing the typed filters.
+    ///
+    /// This is an escape hatch for parameters added to the API before this crate models them.
+    /// The value is serialized as is into the request JSON and is not validated. Setting a
+    /// modeled field, e.g. `currency`, replaces its typed value, and setting the typed value
+    /// again afterwards fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::request::{HasFilters, SearchRequestBuilder};
+    /// use serde_json::json;
+    ///
+    /// let mut builder = SearchRequestBuilder::new().query("ibm");
+    /// builder.filters_mut().set_raw("listingStatus", json!("active"));
+    /// let request = builder.build()?;
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&request)?,
+    ///     json!({"query": "ibm", "listingStatus": "active"})
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_raw(&mut self, field: &str, value: Value) {
+        if let Some(field) = FilterField::from_api_name(field) {
+            self.unset(field);
+        }
+        self.raw_values.insert(field.to_string(), value);
+    }
+
+    /// Returns the value set with [`Self::set_raw`] for the request parameter `field`, if any.
+    #[must_use]
+    pub fn raw(&self, field: &str) -> Option<&Value> {
+        self.raw_values.get(field)
+    }
+
+    /// Removes and returns the value set with [`Self::set_raw`] for `field`, if any.
+    pub fn remove_raw(&mut self, field: &str) -> Option<Value> {
+        self.raw_values.remove(field)
+    }
+
+    /// Unsets `field`, discarding any unrecognized string value recorded for it.
+    pub fn unset(&mut self, field: FilterField) {
+        match field {
+            FilterField::ExchCode => self.exch_code = None,
+            FilterField::MicCode => self.mic_code = None,
+            FilterField::Currency => self.currency = None,
+            FilterField::MarketSecDes => self.market_sec_des = None,
+            FilterField::SecurityType => self.security_type = None,
+            FilterField::SecurityType2 => self.security_type2 = None,
+            FilterField::IncludeUnlistedEquities => self.include_unlisted_equities = None,
+            FilterField::OptionType => self.option_type = None,
+            FilterField::Strike => self.strike = None,
+            FilterField::ContractSize => self.contract_size = None,
+            FilterField::Coupon => self.coupon = None,
+            FilterField::Expiration => self.expiration = None,
+            FilterField::Maturity => self.maturity = None,
+            FilterField::StateCode => self.state_code = None,
+        }
+        self.unknown_values.retain(|(name, _)| *name != field);
+        self.raw_values.remove(field.as_api_name());
+    }
+
+    /// Returns `true` if `field` is set.
+    #[must_use]
+    pub fn is_set(&self, field: FilterField) -> bool {
+        match field {
+            FilterField::ExchCode => self.exch_code.is_some(),
+            FilterField::MicCode => self.mic_code.is_some(),
+            FilterField::Currency => self.currency.is_some(),
+            FilterField::MarketSecDes => self.market_sec_des.is_some(),
+            FilterField::SecurityType => self.security_type.is_some(),
+            FilterField::SecurityType2 => self.security_type2.is_some(),
+            FilterField::IncludeUnlistedEquities => self.include_unlisted_equities.is_some(),
+            FilterField::OptionType => self.option_type.is_some(),
+            FilterField::Strike => self.strike.is_some(),
+            FilterField::ContractSize => self.contract_size.is_some(),
+            FilterField::Coupon => self.coupon.is_some(),
+            FilterField::Expiration => self.expiration.is_some(),
+            FilterField::Maturity => self.maturity.is_some(),
+            FilterField::StateCode => self.state_code.is_some(),
+        }
+    }
+
+    /// Returns `true` if all filter fields are unset and no raw parameters are set.
     ///
     /// Useful for determining if any filters have been applied to the request.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.exch_code.is_none()
-            && self.mic_code.is_none()
-            && self.currency.is_none()
-            && self.market_sec_des.is_none()
-            && self.security_type.is_none()
-            && self.security_type2.is_none()
-            && self.include_unlisted_equities.is_none()
-            && self.option_type.is_none()
-            && self.strike.is_none()
-            && self.contract_size.is_none()
-            && self.coupon.is_none()
-            && self.expiration.is_none()
-            && self.maturity.is_none()
-            && self.state_code.is_none()
+        !FilterField::ALL.into_iter().any(|field| self.is_set(field)) && self.raw_values.is_empty()
+    }
+
+    /// Returns these filters with every unset field taken from `defaults`.
+    ///
+    /// Fields set in `self`, including unrecognized string values and raw parameters, take
+    /// precedence.
+    /// `exchCode` and `micCode` are mutually exclusive and are therefore only taken from
+    /// `defaults` if neither is set in `self`. Only the inherited values are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::enums::{Currency, ExchCode, MicCode};
+    /// use openfigi_rs::model::request::RequestFilters;
+    ///
+    /// let mut defaults = RequestFilters::default();
+    /// defaults.currency = Some(Currency::USD);
+    /// defaults.exch_code = Some(ExchCode::US);
+    ///
+    /// let mut filters = RequestFilters::default();
+    /// filters.mic_code = Some(MicCode::XNYS);
+    ///
+    /// let filters = filters.with_defaults(&defaults);
+    /// assert_eq!(filters.currency, Some(Currency::USD));
+    /// assert_eq!(filters.exch_code, None);
+    /// ```
+    #[must_use]
+    pub fn with_defaults(mut self, defaults: &Self) -> Self {
+        let inherited: Vec<FilterField> = FilterField::ALL
+            .into_iter()
+            .filter(|&field| self.inherits(field))
+            .collect();
+        for &field in &inherited {
+            self.inherit(field, defaults);
+        }
+        self.unknown_values.extend(
+            defaults
+                .unknown_values
+                .iter()
+                .filter(|(field, _)| inherited.contains(field))
+                .cloned(),
+        );
+        for (field, value) in &defaults.raw_values {
+            let inherited =
+                FilterField::from_api_name(field).is_none_or(|field| inherited.contains(&field));
+            if inherited && !self.raw_values.contains_key(field) {
+                self.raw_values.insert(field.clone(), value.clone());
+            }
+        }
+        self
+    }
+
+    /// Returns the filters the value of `field` is taken from when merging these filters with
+    /// `defaults` by [`Self::with_defaults`].
+    pub(crate) fn source<'a>(&'a self, field: FilterField, defaults: &'a Self) -> &'a Self {
+        if self.inherits(field) { defaults } else { self }
+    }
+
+    /// Returns `true` if `field` is taken from the defaults by [`Self::with_defaults`].
+    fn inherits(&self, field: FilterField) -> bool {
+        let is_set = |field: FilterField| {
+            self.is_set(field)
+                || self.unknown_values.iter().any(|(name, _)| *name == field)
+                || self.raw_values.contains_key(field.as_api_name())
+        };
+        match field {
+            FilterField::ExchCode | FilterField::MicCode => {
+                !is_set(FilterField::ExchCode) && !is_set(FilterField::MicCode)
+            }
+            field => !is_set(field),
+        }
+    }
+
+    /// Copies the value of `field` from `defaults`.
+    fn inherit(&mut self, field: FilterField, defaults: &Self) {
+        match field {
+            FilterField::ExchCode => self.exch_code.clone_from(&defaults.exch_code),
+            FilterField::MicCode => self.mic_code.clone_from(&defaults.mic_code),
+            FilterField::Currency => self.currency.clone_from(&defaults.currency),
+            FilterField::MarketSecDes => self.market_sec_des.clone_from(&defaults.market_sec_des),
+            FilterField::SecurityType => self.security_type.clone_from(&defaults.security_type),
+            FilterField::SecurityType2 => {
+                self.security_type2.clone_from(&defaults.security_type2);
+            }
+            FilterField::IncludeUnlistedEquities => {
+                self.include_unlisted_equities = defaults.include_unlisted_equities;
+            }
+            FilterField::OptionType => self.option_type.clone_from(&defaults.option_type),
+            FilterField::Strike => self.strike = defaults.strike,
+            FilterField::ContractSize => self.contract_size = defaults.contract_size,
+            FilterField::Coupon => self.coupon = defaults.coupon,
+            FilterField::Expiration => self.expiration = defaults.expiration,
+            FilterField::Maturity => self.maturity = defaults.maturity,
+            FilterField::StateCode => self.state_code.clone_from(&defaults.state_code),
+        }
+    }
+}
+
+/// Default filters serialized once and merged into many requests as they are serialized.
+///
+/// Serializing a batch of requests with [`Self::inherited_by`] produces the same JSON as
+/// merging the defaults into every request with [`RequestFilters::with_defaults`], without
+/// cloning the defaults per request.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SerializedFilters(serde_json::Map<String, Value>);
+
+impl SerializedFilters {
+    /// Serializes `defaults`.
+    pub(crate) fn new(defaults: &RequestFilters) -> Result<Self> {
+        match serde_json::to_value(defaults)? {
+            Value::Object(fields) => Ok(Self(fields)),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the defaults inherited by `filters`, serialized as map entries to be flattened
+    /// into the request owning `filters`.
+    pub(crate) fn inherited_by<'a>(&'a self, filters: &'a RequestFilters) -> InheritedFilters<'a> {
+        InheritedFilters {
+            defaults: self,
+            filters,
+        }
+    }
+}
+
+/// The entries of [`SerializedFilters`] inherited by the filters of a request.
+pub(crate) struct InheritedFilters<'a> {
+    defaults: &'a SerializedFilters,
+    filters: &'a RequestFilters,
+}
+
+impl Serialize for InheritedFilters<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (name, value) in &self.defaults.0 {
+            let inherited = match FilterField::from_api_name(name) {
+                Some(field) => self.filters.inherits(field),
+                None => !self.filters.raw_values.contains_key(name),
+            };
+            if inherited {
+                map.serialize_entry(name, value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// A filter field of [`RequestFilters`].
+///
+/// Serializes as, and displays, the API name of the field, e.g. `exchCode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FilterField {
+    /// The `exchCode` filter.
+    ExchCode,
+    /// The `micCode` filter.
+    MicCode,
+    /// The `currency` filter.
+    Currency,
+    /// The `marketSecDes` filter.
+    MarketSecDes,
+    /// The `securityType` filter.
+    SecurityType,
+    /// The `securityType2` filter.
+    SecurityType2,
+    /// The `includeUnlistedEquities` filter.
+    IncludeUnlistedEquities,
+    /// The `optionType` filter.
+    OptionType,
+    /// The `strike` filter.
+    Strike,
+    /// The `contractSize` filter.
+    ContractSize,
+    /// The `coupon` filter.
+    Coupon,
+    /// The `expiration` filter.
+    Expiration,
+    /// The `maturity` filter.
+    Maturity,
+    /// The `stateCode` filter.
+    StateCode,
+}
+
+impl FilterField {
+    /// All filter fields, in serialization order.
+    pub const ALL: [Self; 14] = [
+        Self::ExchCode,
+        Self::MicCode,
+        Self::Currency,
+        Self::MarketSecDes,
+        Self::SecurityType,
+        Self::SecurityType2,
+        Self::IncludeUnlistedEquities,
+        Self::OptionType,
+        Self::Strike,
+        Self::ContractSize,
+        Self::Coupon,
+        Self::Expiration,
+        Self::Maturity,
+        Self::StateCode,
+    ];
+
+    /// Returns the name of the field in API requests, e.g. `exchCode`.
+    #[must_use]
+    pub const fn as_api_name(self) -> &'static str {
+        match self {
+            Self::ExchCode => "exchCode",
+            Self::MicCode => "micCode",
+            Self::Currency => "currency",
+            Self::MarketSecDes => "marketSecDes",
+            Self::SecurityType => "securityType",
+            Self::SecurityType2 => "securityType2",
+            Self::IncludeUnlistedEquities => "includeUnlistedEquities",
+            Self::OptionType => "optionType",
+            Self::Strike => "strike",
+            Self::ContractSize => "contractSize",
+            Self::Coupon => "coupon",
+            Self::Expiration => "expiration",
+            Self::Maturity => "maturity",
+            Self::StateCode => "stateCode",
+        }
+    }
+
+    /// Returns the field with the given API name, if any.
+    #[must_use]
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_api_name() == name)
+    }
+}
+
+impl fmt::Display for FilterField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_api_name())
+    }
+}
+
+impl FromStr for FilterField {
+    type Err = OpenFIGIError;
+
+    /// Parses the API name of a field, e.g. `exchCode`.
+    fn from_str(value: &str) -> Result<Self> {
+        Self::from_api_name(value).ok_or_else(|| {
+            unknown_value_expecting("FilterField", value, Self::ALL.map(Self::as_api_name))
+        })
+    }
+}
+
+/// A single field that differs between two [`RequestFilters`].
+///
+/// Parameters set with [`RequestFilters::set_raw`] that this crate does not model are included
+/// with a `field` of `None`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FilterChange {
+    /// The changed field, `None` for a raw parameter this crate does not model.
+    #[serde(skip)]
+    pub field: Option<FilterField>,
+    /// API name of the changed field, e.g. `exchCode` or `listingStatus`.
+    #[serde(rename = "field")]
+    pub name: String,
+    /// JSON value of the field in the original filters, `None` if unset.
+    pub old: Option<Value>,
+    /// JSON value of the field in the compared filters, `None` if unset.
+    pub new: Option<Value>,
+}
+
+impl FilterChange {
+    fn new(name: String, old: Option<Value>, new: Option<Value>) -> Self {
+        Self {
+            field: FilterField::from_api_name(&name),
+            name,
+            old,
+            new,
+        }
+    }
+}
+
+impl fmt::Display for FilterChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |value: &Option<Value>| {
+            value
+                .as_ref()
+                .map_or_else(|| "unset".to_string(), Value::to_string)
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.name,
+            value(&self.old),
+            value(&self.new)
+        )
+    }
+}
+
+/// Fields that differ between two [`RequestFilters`], as returned by [`RequestFilters::diff`].
+///
+/// Displays as a comma-separated list of changes, and serializes as an array of
+/// [`FilterChange`]s.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct FiltersDiff(Vec<FilterChange>);
+
+impl FiltersDiff {
+    /// Returns `true` if the filters are equal.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of changed fields.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the changed fields.
+    #[must_use]
+    pub fn changes(&self) -> &[FilterChange] {
+        &self.0
+    }
+
+    /// Returns the change of `field`, if it changed.
+    #[must_use]
+    pub fn get(&self, field: FilterField) -> Option<&FilterChange> {
+        self.0.iter().find(|change| change.field == Some(field))
+    }
+}
+
+impl fmt::Display for FiltersDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes");
+        }
+        for (i, change) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::enums::{Currency, ExchCode, MicCode, SecurityType2};
+    use crate::model::enums::{
+        Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
+        StateCode,
+    };
+    use crate::model::request::SearchRequestBuilder;
     use chrono::NaiveDate;
 
     fn create_sample_filters() -> RequestFilters {
@@ -224,6 +1110,78 @@ mod tests {
         assert!(filters.validate().is_ok());
     }
 
+    #[test]
+    fn test_with_defaults() {
+        let defaults = RequestFilters {
+            exch_code: Some(ExchCode::US),
+            currency: Some(Currency::USD),
+            security_type2: Some(SecurityType2::CommonStock),
+            ..Default::default()
+        };
+
+        let filters = RequestFilters {
+            mic_code: Some(MicCode::XNYS),
+            currency: Some(Currency::EUR),
+            ..Default::default()
+        };
+        let filters = filters.with_defaults(&defaults);
+
+        assert_eq!(filters.exch_code, None);
+        assert_eq!(filters.mic_code, Some(MicCode::XNYS));
+        assert_eq!(filters.currency, Some(Currency::EUR));
+        assert_eq!(filters.security_type2, Some(SecurityType2::CommonStock));
+        assert!(filters.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_defaults_keeps_unknown_values() {
+        let defaults = RequestFilters {
+            currency: Some(Currency::USD),
+            ..Default::default()
+        };
+
+        let mut filters = RequestFilters::default();
+        filters.currency = filters.filter_value(FilterField::Currency, "DOLLAR");
+        let filters = filters.with_defaults(&defaults);
+
+        assert_eq!(filters.currency, None);
+        assert!(filters.validate().is_err());
+    }
+
+    #[test]
+    fn test_set_raw() {
+        let mut filters = create_sample_filters();
+        filters.set_raw("currency", serde_json::json!("EUR"));
+        filters.set_raw("newFilter", serde_json::json!([1, 2]));
+
+        assert_eq!(filters.currency, None);
+        assert_eq!(filters.raw("currency"), Some(&serde_json::json!("EUR")));
+        assert_eq!(
+            serde_json::to_value(&filters).ok(),
+            Some(serde_json::json!({"exchCode": "US", "currency": "EUR", "newFilter": [1, 2]}))
+        );
+        let parsed: RequestFilters =
+            serde_json::from_value(serde_json::json!(filters)).expect("filters deserialize");
+        assert_eq!(parsed.currency, Some(Currency::EUR));
+        assert_eq!(parsed.raw("newFilter"), Some(&serde_json::json!([1, 2])));
+
+        filters.currency = Some(Currency::USD);
+        assert!(filters.validate().is_err());
+        filters.unset(FilterField::Currency);
+        assert_eq!(filters.raw("currency"), None);
+        assert_eq!(
+            filters.remove_raw("newFilter"),
+            Some(serde_json::json!([1, 2]))
+        );
+        assert_eq!(
+            filters,
+            RequestFilters {
+                exch_code: Some(ExchCode::US),
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn test_is_empty_with_single_field() {
         let mut filters = RequestFilters::default();
@@ -470,8 +1428,141 @@ mod tests {
             expiration: None,
             maturity: None,
             state_code: None,
+            unknown_values: Vec::new(),
+            raw_values: BTreeMap::new(),
         };
 
         assert!(filters.is_empty());
     }
+
+    #[test]
+    fn test_filter_value_from_str() {
+        let mut filters = RequestFilters::default();
+        filters.currency = filters.filter_value(FilterField::Currency, "USD");
+        assert_eq!(filters.currency, Some(Currency::USD));
+        assert!(filters.validate().is_ok());
+
+        filters.currency = filters.filter_value(FilterField::Currency, "DOLLAR");
+        assert_eq!(filters.currency, None);
+        let err = filters
+            .validate()
+            .expect_err("Unknown value should fail validation");
+        assert!(err.to_string().contains("unknown currency value 'DOLLAR'"));
+
+        // A later valid value replaces the unknown one
+        filters.currency = filters.filter_value(FilterField::Currency, Currency::EUR);
+        assert!(filters.validate().is_ok());
+    }
+
+    #[test]
+    fn test_clear_filters() {
+        let mut request = SearchRequestBuilder::new()
+            .query("ibm")
+            .exch_code(ExchCode::US)
+            .currency("DOLLAR")
+            .strike([Some(1.0), None])
+            .clear_exch_code()
+            .clear_currency()
+            .clear_strike();
+        assert!(request.filters_mut().is_empty());
+        assert!(request.build().is_ok());
+    }
+
+    #[test]
+    fn test_filter_field_api_names() {
+        for field in FilterField::ALL {
+            assert_eq!(FilterField::from_api_name(field.as_api_name()), Some(field));
+            assert_eq!(field.to_string().parse::<FilterField>().ok(), Some(field));
+            assert_eq!(
+                serde_json::to_value(field).expect("field serializes"),
+                serde_json::json!(field.as_api_name())
+            );
+        }
+        let err = "exch_code".parse::<FilterField>().unwrap_err();
+        assert!(err.to_string().contains(
+            "unknown FilterField value 'exch_code', expected one of: exchCode, micCode,"
+        ));
+
+        // Every field name used in the request JSON is covered
+        let filters = RequestFilters {
+            exch_code: Some(ExchCode::US),
+            mic_code: Some(MicCode::XNYS),
+            currency: Some(Currency::USD),
+            market_sec_des: Some(MarketSecDesc::Equity),
+            security_type: Some(SecurityType::CommonStock),
+            security_type2: Some(SecurityType2::CommonStock),
+            include_unlisted_equities: Some(true),
+            option_type: Some(OptionType::Call),
+            strike: Some([Some(1.0), None]),
+            contract_size: Some([Some(1.0), None]),
+            coupon: Some([Some(1.0), None]),
+            expiration: Some([None, None]),
+            maturity: Some([None, None]),
+            state_code: Some(StateCode::CA),
+            unknown_values: Vec::new(),
+            raw_values: BTreeMap::new(),
+        };
+        let json = serde_json::to_value(&filters).expect("filters serialize");
+        let mut names: Vec<&str> = json
+            .as_object()
+            .expect("filters serialize to an object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let mut expected: Vec<&str> = FilterField::ALL
+            .into_iter()
+            .map(FilterField::as_api_name)
+            .collect();
+        names.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(names, expected);
+
+        for field in FilterField::ALL {
+            let mut filters = filters.clone();
+            assert!(filters.is_set(field));
+            filters.unset(field);
+            assert!(!filters.is_set(field));
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = create_sample_filters();
+        assert!(old.diff(&old.clone()).is_empty());
+        assert_eq!(old.diff(&old).to_string(), "no changes");
+
+        let new = RequestFilters {
+            currency: Some(Currency::USD),
+            strike: Some([Some(100.0), None]),
+            ..Default::default()
+        };
+        let diff = old.diff(&new);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff.to_string(),
+            r#"exchCode: "US" -> unset, strike: unset -> [100.0,null]"#
+        );
+        assert!(diff.get(FilterField::Currency).is_none());
+        assert_eq!(
+            serde_json::to_value(&diff).expect("diff serializes"),
+            serde_json::json!([
+                { "field": "exchCode", "old": "US", "new": null },
+                { "field": "strike", "old": null, "new": [100.0, null] }
+            ])
+        );
+
+        // Raw parameters are compared by name after the modeled fields
+        let mut raw = new.clone();
+        raw.set_raw("listingStatus", serde_json::json!("active"));
+        let diff = new.diff(&raw);
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff.changes()[0].field, None);
+        assert_eq!(diff.changes()[0].name, "listingStatus");
+        assert_eq!(diff.to_string(), r#"listingStatus: unset -> "active""#);
+        let diff = old.diff(&raw);
+        assert_eq!(
+            diff.to_string(),
+            r#"exchCode: "US" -> unset, strike: unset -> [100.0,null], listingStatus: unset -> "active""#
+        );
+    }
 }
@@ -19,7 +19,7 @@
 //! ### Filter request with additional parameters
 //!
 //! ```rust
-//! use openfigi_rs::model::request::FilterRequest;
+//! use openfigi_rs::model::request::{FilterRequest, HasFilters};
 //! use openfigi_rs::model::enums::{Currency, ExchCode};
 //!
 //! let request = FilterRequest::builder()
@@ -34,16 +34,8 @@
 
 use crate::{
     error::{OpenFIGIError, OtherErrorKind, Result},
-    impl_filter_builder,
-    model::{
-        enums::{
-            Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
-            StateCode,
-        },
-        request::common::RequestFilters,
-    },
+    model::request::common::{HasFilters, RequestFilters},
 };
-use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// Request structure for the OpenFIGI `/filter` endpoint.
@@ -60,7 +52,7 @@ use serde::{Deserialize, Serialize};
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::FilterRequest;
+/// use openfigi_rs::model::request::{FilterRequest, HasFilters};
 /// use openfigi_rs::model::enums::Currency;
 ///
 /// // Simple query-based filter
@@ -124,7 +116,7 @@ impl FilterRequest {
     /// # Examples
     ///
     /// ```rust
-    /// use openfigi_rs::model::request::FilterRequest;
+    /// use openfigi_rs::model::request::{FilterRequest, HasFilters};
     /// use openfigi_rs::model::enums::Currency;
     ///
     /// let request = FilterRequest::builder()
@@ -187,7 +179,7 @@ impl FilterRequest {
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::FilterRequestBuilder;
+/// use openfigi_rs::model::request::{FilterRequestBuilder, HasFilters};
 /// use openfigi_rs::model::enums::{Currency, ExchCode};
 ///
 /// let request = FilterRequestBuilder::new()
@@ -197,7 +189,7 @@ impl FilterRequest {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct FilterRequestBuilder {
     query: Option<String>,
     start: Option<String>,
@@ -254,14 +246,6 @@ impl FilterRequestBuilder {
         self
     }
 
-    /// Mutable access to the request filters.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        &mut self.filters
-    }
-
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
-
     /// Builds and validates the `FilterRequest`.
     ///
     /// Constructs the final request object and performs validation to ensure
@@ -295,6 +279,12 @@ impl FilterRequestBuilder {
     }
 }
 
+impl HasFilters for FilterRequestBuilder {
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        &mut self.filters
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,7 +17,7 @@
 //! ### Mapping request with additional filters
 //!
 //! ```rust
-//! use openfigi_rs::model::request::MappingRequest;
+//! use openfigi_rs::model::request::{HasFilters, MappingRequest};
 //! use openfigi_rs::model::enums::{IdType, Currency, ExchCode};
 //!
 //! let request = MappingRequest::builder()
@@ -33,17 +33,17 @@
 
 use crate::{
     error::{OpenFIGIError, OtherErrorKind, Result},
-    impl_filter_builder,
     model::{
-        enums::{
-            Currency, ExchCode, IdType, MarketSecDesc, MicCode, OptionType, SecurityType,
-            SecurityType2, StateCode,
+        enums::IdType,
+        request::common::{
+            FilterField, HasFilters, InheritedFilters, RequestFilters, SerializedFilters,
         },
-        request::common::RequestFilters,
     },
+    validation,
 };
-use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+use std::{borrow::Cow, fmt};
 
 /// Request structure for the OpenFIGI `/mapping` endpoint.
 ///
@@ -67,7 +67,7 @@ use serde::{Deserialize, Serialize};
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::MappingRequest;
+/// use openfigi_rs::model::request::{HasFilters, MappingRequest};
 /// use openfigi_rs::model::enums::{IdType, Currency};
 ///
 /// // Simple identifier mapping
@@ -85,9 +85,11 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct MappingRequest {
     /// Type of third party identifier.
+    #[doc(alias = "idType")]
     pub id_type: IdType,
     /// Value of the third party identifier.
-    pub id_value: serde_json::Value, // String or Number
+    #[doc(alias = "idValue")]
+    pub id_value: IdValue,
 
     /// Additional filtering criteria applied to the mapping request.
     ///
@@ -118,7 +120,7 @@ impl MappingRequest {
     /// assert_eq!(request.id_type, IdType::ID_ISIN);
     /// ```
     #[must_use]
-    pub fn new<T: Into<serde_json::Value>>(id_type: IdType, id_value: T) -> Self {
+    pub fn new<T: Into<IdValue>>(id_type: IdType, id_value: T) -> Self {
         Self {
             id_type,
             id_value: id_value.into(),
@@ -133,7 +135,7 @@ impl MappingRequest {
     /// # Examples
     ///
     /// ```rust
-    /// use openfigi_rs::model::request::MappingRequest;
+    /// use openfigi_rs::model::request::{HasFilters, MappingRequest};
     /// use openfigi_rs::model::enums::{IdType, Currency};
     ///
     /// let request = MappingRequest::builder()
@@ -153,6 +155,7 @@ impl MappingRequest {
     /// Ensures that:
     /// - Required fields (`id_type`, `id_value`) are present
     /// - `security_type2` is provided when required by certain identifier types
+    /// - ISINs, CUSIPs, SEDOLs and FIGIs have a valid check digit (see [`crate::validation`])
     /// - All filter validation rules are satisfied
     /// - No mutually exclusive parameters are set
     ///
@@ -175,12 +178,27 @@ impl MappingRequest {
     /// assert!(request.validate().is_ok());
     /// ```
     pub fn validate(&self) -> Result<()> {
+        self.validate_filters(&RequestFilters::default())?;
+
+        // Reject identifiers with an invalid format or check digit
+        validation::validate_id_value(&self.id_type, &self.id_value)
+    }
+
+    /// Validates the filters of the request as merged with the valid default filters
+    /// `defaults` of its batch by [`RequestFilters::with_defaults`], without merging them.
+    ///
+    /// The identifier value is not checked.
+    pub(crate) fn validate_filters(&self, defaults: &RequestFilters) -> Result<()> {
         // Validate the `RequestFilters` fields
-        self.filters.validate()?;
+        self.filters.validate_with_defaults(defaults)?;
 
         // securityType2 is required when idType is BASE_TICKER or ID_EXCH_SYMBOL
         if (self.id_type == IdType::BASE_TICKER || self.id_type == IdType::ID_EXCH_SYMBOL)
-            && self.filters.security_type2.is_none()
+            && self
+                .filters
+                .source(FilterField::SecurityType2, defaults)
+                .security_type2
+                .is_none()
         {
             return Err(OpenFIGIError::other_error(
                 OtherErrorKind::Validation,
@@ -191,6 +209,226 @@ impl MappingRequest {
     }
 }
 
+/// A mapping request serialized with the default filters of its batch merged in.
+#[derive(Serialize)]
+pub(crate) struct MappingJob<'a> {
+    #[serde(flatten)]
+    request: &'a MappingRequest,
+    #[serde(flatten)]
+    defaults: InheritedFilters<'a>,
+}
+
+impl<'a> MappingJob<'a> {
+    pub(crate) fn new(request: &'a MappingRequest, defaults: &'a SerializedFilters) -> Self {
+        Self {
+            request,
+            defaults: defaults.inherited_by(&request.filters),
+        }
+    }
+}
+
+/// The mapping requests of a batch, serialized as the JSON array sent to the API with the
+/// default filters of the batch merged into every request.
+pub(crate) struct MappingJobs<'a> {
+    requests: &'a [&'a MappingRequest],
+    defaults: &'a SerializedFilters,
+}
+
+impl<'a> MappingJobs<'a> {
+    pub(crate) fn new(requests: &'a [&'a MappingRequest], defaults: &'a SerializedFilters) -> Self {
+        Self { requests, defaults }
+    }
+}
+
+impl Serialize for MappingJobs<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.requests
+                .iter()
+                .map(|request| MappingJob::new(request, self.defaults)),
+        )
+    }
+}
+
+impl From<&MappingRequest> for MappingRequest {
+    fn from(request: &MappingRequest) -> Self {
+        request.clone()
+    }
+}
+
+/// Value of the third party identifier of a [`MappingRequest`], either a string or a number.
+///
+/// Strings are kept as [`Cow<'static, str>`]. Identifiers from string literals or other
+/// `'static` data are not copied when created with [`Self::borrowed`] or from a
+/// `Cow::Borrowed`, and owned strings are moved in. Conversions from `&str` with a shorter
+/// lifetime, e.g. fields of a CSV record buffer, copy the string once, as the request outlives
+/// the buffer. Conversions exist from the string types, the integer types, and
+/// [`serde_json::Value`], which is converted as follows:
+///
+/// - JSON strings and numbers keep their type
+/// - Any other JSON value becomes a string holding its JSON text
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::model::request::IdValue;
+/// use std::borrow::Cow;
+///
+/// let isin = IdValue::borrowed("US4592001014");
+/// assert_eq!(isin.as_str(), Some("US4592001014"));
+///
+/// let cusip: IdValue = Cow::Borrowed("459200101").into();
+/// assert_eq!(cusip.to_string(), "459200101");
+///
+/// let id = IdValue::from(12345);
+/// assert_eq!(id.as_u64(), Some(12345));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdValue {
+    /// A string identifier, e.g. an ISIN or ticker.
+    Str(Cow<'static, str>),
+    /// A numeric identifier.
+    Number(Number),
+}
+
+impl IdValue {
+    /// Creates a string identifier borrowing `value` without copying it.
+    #[must_use]
+    pub const fn borrowed(value: &'static str) -> Self {
+        Self::Str(Cow::Borrowed(value))
+    }
+
+    /// Returns the identifier if it is a string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Str(value) => Some(value),
+            Self::Number(_) => None,
+        }
+    }
+
+    /// Returns the identifier if it is a number representable as `u64`.
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Str(_) => None,
+            Self::Number(value) => value.as_u64(),
+        }
+    }
+}
+
+impl fmt::Display for IdValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Str(value) => f.write_str(value),
+            Self::Number(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<Cow<'static, str>> for IdValue {
+    fn from(value: Cow<'static, str>) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<String> for IdValue {
+    fn from(value: String) -> Self {
+        Self::Str(Cow::Owned(value))
+    }
+}
+
+/// Copies `value`, see [`IdValue::borrowed`] for `'static` strings.
+impl From<&str> for IdValue {
+    fn from(value: &str) -> Self {
+        Self::Str(Cow::Owned(value.to_owned()))
+    }
+}
+
+impl From<&String> for IdValue {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<Box<str>> for IdValue {
+    fn from(value: Box<str>) -> Self {
+        Self::from(String::from(value))
+    }
+}
+
+impl From<&Cow<'static, str>> for IdValue {
+    fn from(value: &Cow<'static, str>) -> Self {
+        Self::Str(value.clone())
+    }
+}
+
+impl From<char> for IdValue {
+    fn from(value: char) -> Self {
+        Self::from(String::from(value))
+    }
+}
+
+impl From<Number> for IdValue {
+    fn from(value: Number) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<Value> for IdValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(value) => Self::from(value),
+            Value::Number(value) => Self::Number(value),
+            value => Self::from(value.to_string()),
+        }
+    }
+}
+
+impl From<&Value> for IdValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::String(value) => Self::from(value),
+            Value::Number(value) => Self::Number(value.clone()),
+            value => Self::from(value.to_string()),
+        }
+    }
+}
+
+impl From<&IdValue> for IdValue {
+    fn from(value: &IdValue) -> Self {
+        value.clone()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for IdValue {
+                fn from(value: $ty) -> Self {
+                    Self::Number(Number::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl PartialEq<Value> for IdValue {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Self::Str(value), Value::String(other)) => value == other,
+            (Self::Number(value), Value::Number(other)) => value == other,
+            _ => false,
+        }
+    }
+}
+
 /// Builder for constructing [`MappingRequest`] instances.
 ///
 /// Provides a fluent API for setting identifier information and filter parameters.
@@ -204,7 +442,7 @@ impl MappingRequest {
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::MappingRequestBuilder;
+/// use openfigi_rs::model::request::{HasFilters, MappingRequestBuilder};
 /// use openfigi_rs::model::enums::{IdType, Currency, ExchCode};
 ///
 /// let request = MappingRequestBuilder::new()
@@ -218,7 +456,7 @@ impl MappingRequest {
 #[derive(Default)]
 pub struct MappingRequestBuilder {
     id_type: Option<IdType>,
-    id_value: Option<serde_json::Value>,
+    id_value: Option<IdValue>,
     filters: RequestFilters,
 }
 
@@ -251,6 +489,7 @@ impl MappingRequestBuilder {
     /// let builder = MappingRequestBuilder::new().id_type(IdType::ID_ISIN);
     /// ```
     #[must_use]
+    #[doc(alias = "idType")]
     pub fn id_type(mut self, id_type: IdType) -> Self {
         self.id_type = Some(id_type);
         self
@@ -259,32 +498,63 @@ impl MappingRequestBuilder {
     /// Sets the identifier value for the mapping request.
     ///
     /// This field is required and contains the actual identifier value to be mapped.
-    /// Can accept strings, numbers, or any value that converts to `serde_json::Value`.
+    /// Accepts anything that converts into an [`IdValue`], i.e. strings, `Cow<'static, str>`,
+    /// integers, and `serde_json::Value`. A `&str` is copied; pass a `String` to move it in, or
+    /// [`IdValue::borrowed`] for a `'static` string.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use openfigi_rs::model::request::MappingRequestBuilder;
     /// use serde_json::json;
+    /// use std::borrow::Cow;
     ///
     /// let builder = MappingRequestBuilder::new()
-    ///     .id_value("US4592001014")      // String
+    ///     .id_value("US4592001014")      // String, copied
+    ///     .id_value(Cow::Borrowed("IBM")) // Borrowed string, never copied
     ///     .id_value(json!("AAPL"))       // JSON string
     ///     .id_value(12345);              // Number
     /// ```
     #[must_use]
-    pub fn id_value<T: Into<serde_json::Value>>(mut self, id_value: T) -> Self {
+    #[doc(alias = "idValue")]
+    pub fn id_value<T: Into<IdValue>>(mut self, id_value: T) -> Self {
         self.id_value = Some(id_value.into());
         self
     }
 
-    /// Mutable access to the request filters.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        &mut self.filters
+    /// Sets the identifier value for the mapping request to a string.
+    ///
+    /// Equivalent to [`Self::id_value`] with a `&str`, without relying on type inference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::request::MappingRequestBuilder;
+    ///
+    /// let builder = MappingRequestBuilder::new().id_value_str("US4592001014");
+    /// ```
+    #[must_use]
+    pub fn id_value_str(self, id_value: &str) -> Self {
+        self.id_value(id_value)
     }
 
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
+    /// Sets the identifier value for the mapping request to a number.
+    ///
+    /// Equivalent to [`Self::id_value`] with a `u64`. The value is sent as a JSON number;
+    /// use [`Self::id_value_str`] for numeric identifiers the API expects as strings, such as
+    /// CUSIPs with leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::request::MappingRequestBuilder;
+    ///
+    /// let builder = MappingRequestBuilder::new().id_value_num(12345);
+    /// ```
+    #[must_use]
+    pub fn id_value_num(self, id_value: u64) -> Self {
+        self.id_value(id_value)
+    }
 
     /// Builds and validates the `MappingRequest`.
     ///
@@ -328,6 +598,12 @@ impl MappingRequestBuilder {
     }
 }
 
+impl HasFilters for MappingRequestBuilder {
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        &mut self.filters
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,9 +613,9 @@ mod tests {
 
     #[test]
     fn test_mapping_request_new_minimal() {
-        let request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         assert_eq!(request.id_type, IdType::ID_ISIN);
-        assert_eq!(request.id_value, json!("US1234567890"));
+        assert_eq!(request.id_value, json!("US1234567899"));
         assert!(request.filters.exch_code.is_none());
         assert!(request.filters.mic_code.is_none());
     }
@@ -348,18 +624,121 @@ mod tests {
     fn test_mapping_request_builder_minimal() {
         let request = MappingRequest::builder()
             .id_type(IdType::ID_ISIN)
-            .id_value("US1234567890")
+            .id_value("US1234567899")
             .build()
             .expect("Failed to build a valid mapping request");
         assert_eq!(request.id_type, IdType::ID_ISIN);
-        assert_eq!(request.id_value, json!("US1234567890"));
+        assert_eq!(request.id_value, json!("US1234567899"));
+    }
+
+    #[test]
+    fn test_mapping_jobs_merge_serialized_defaults() {
+        let mut defaults = RequestFilters {
+            exch_code: Some(ExchCode::US),
+            currency: Some(Currency::USD),
+            ..Default::default()
+        };
+        defaults.set_raw("listingStatus", json!("active"));
+
+        let plain = MappingRequest::new(IdType::TICKER, "IBM");
+        let mut custom = MappingRequest::new(IdType::TICKER, "VOD");
+        custom.filters.mic_code = Some(MicCode::XLON);
+        custom.filters.set_raw("listingStatus", json!("all"));
+        custom.filters.set_raw("currency", json!("GBP"));
+
+        let serialized = SerializedFilters::new(&defaults).expect("Should serialize defaults");
+        let jobs = [&plain, &custom];
+        let merged: Vec<MappingRequest> = jobs
+            .iter()
+            .map(|request| {
+                let mut request = (*request).clone();
+                request.filters = request.filters.with_defaults(&defaults);
+                request
+            })
+            .collect();
+        assert_eq!(
+            serde_json::to_value(MappingJobs::new(&jobs, &serialized))
+                .expect("Should serialize jobs"),
+            serde_json::to_value(&merged).expect("Should serialize requests")
+        );
+        assert_eq!(plain.filters, RequestFilters::default());
+    }
+
+    #[test]
+    fn test_validate_with_defaults() {
+        let defaults = RequestFilters {
+            security_type2: Some(SecurityType2::Option),
+            expiration: Some([
+                NaiveDate::from_ymd_opt(2025, 1, 1),
+                NaiveDate::from_ymd_opt(2025, 6, 1),
+            ]),
+            ..Default::default()
+        };
+        let request = MappingRequest::new(IdType::BASE_TICKER, "IBM");
+        assert!(request.validate().is_err());
+        assert!(request.validate_filters(&defaults).is_ok());
+
+        let mut request = MappingRequest::new(IdType::TICKER, "IBM");
+        request.filters.expiration = None;
+        request
+            .filters
+            .set_raw("expiration", json!(["2025-01-01", null]));
+        assert!(request.validate_filters(&defaults).is_err());
+    }
+
+    #[test]
+    fn test_id_value_serialization() {
+        let request = MappingRequest::new(IdType::ID_CUSIP, Cow::Borrowed("459200101"));
+        assert!(matches!(request.id_value, IdValue::Str(Cow::Borrowed(_))));
+        let request = MappingRequest::new(IdType::ID_CUSIP, IdValue::borrowed("459200101"));
+        assert!(matches!(request.id_value, IdValue::Str(Cow::Borrowed(_))));
+        assert_eq!(
+            serde_json::to_value(&request).expect("Should serialize request"),
+            json!({ "idType": "ID_CUSIP", "idValue": "459200101" })
+        );
+
+        let request = MappingRequest::new(IdType::ID_BB_UNIQUE, 12345);
+        assert_eq!(
+            serde_json::to_value(&request).expect("Should serialize request"),
+            json!({ "idType": "ID_BB_UNIQUE", "idValue": 12345 })
+        );
+
+        let request: MappingRequest =
+            serde_json::from_value(json!({ "idType": "ID_BB_UNIQUE", "idValue": 12345 }))
+                .expect("Should deserialize request");
+        assert_eq!(request.id_value.as_u64(), Some(12345));
+    }
+
+    #[test]
+    fn test_id_value_from_json() {
+        assert_eq!(IdValue::from(json!("IBM")), IdValue::from("IBM"));
+        assert_eq!(IdValue::from(json!(42)), IdValue::from(42_u64));
+        assert_eq!(IdValue::from(&json!(42)), IdValue::from(42));
+        assert_eq!(IdValue::from(json!(true)).as_str(), Some("true"));
+    }
+
+    #[test]
+    fn test_mapping_request_builder_typed_id_value() {
+        let request = MappingRequest::builder()
+            .id_type(IdType::ID_CUSIP)
+            .id_value_str("037833100")
+            .build()
+            .expect("Failed to build a valid mapping request");
+        assert_eq!(request.id_value, json!("037833100"));
+
+        let request = MappingRequest::builder()
+            .id_type(IdType::ID_BB_UNIQUE)
+            .id_value_num(12345)
+            .build()
+            .expect("Failed to build a valid mapping request");
+        assert_eq!(request.id_value, json!(12345));
     }
 
     #[test]
     fn test_mapping_request_builder_with_currency() {
         let request = MappingRequest::builder()
             .id_type(IdType::ID_ISIN)
-            .id_value("US1234567890")
+            .id_value("US1234567899")
             .currency(Currency::USD)
             .build()
             .expect("Failed to build a valid mapping request");
@@ -368,7 +747,7 @@ mod tests {
 
     #[test]
     fn test_mapping_request_validate_exch_and_mic_code_conflict() {
-        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         request.filters.exch_code = Some(ExchCode::A0);
         request.filters.mic_code = Some(MicCode::XCME);
         let result = request.validate();
@@ -389,7 +768,7 @@ mod tests {
 
     #[test]
     fn test_mapping_request_validate_strike_range() {
-        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         request.filters.strike = Some([Some(10.0), Some(5.0)]);
         let result = request.validate();
         assert!(result.is_err());
@@ -399,7 +778,7 @@ mod tests {
 
     #[test]
     fn test_mapping_request_validate_expiration_required_for_option() {
-        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         request.filters.security_type2 = Some(SecurityType2::Option);
         request.filters.expiration = None;
         let result = request.validate();
@@ -410,7 +789,7 @@ mod tests {
 
     #[test]
     fn test_mapping_request_validate_maturity_required_for_pool() {
-        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         request.filters.security_type2 = Some(SecurityType2::Pool);
         let result = request.validate();
         assert!(result.is_err());
@@ -420,7 +799,7 @@ mod tests {
 
     #[test]
     fn test_mapping_request_validate_date_range_too_long() {
-        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567890"));
+        let mut request = MappingRequest::new(IdType::ID_ISIN, json!("US1234567899"));
         let start = NaiveDate::from_ymd_opt(2025, 1, 1).expect("Should create a valid date");
         let end = NaiveDate::from_ymd_opt(2026, 2, 1).expect("Should create a valid date");
         request.filters.expiration = Some([Some(start), Some(end)]);
@@ -434,7 +813,7 @@ mod tests {
     fn test_serialize_deserialize_mapping_request() {
         let request = MappingRequest::builder()
             .id_type(IdType::ID_ISIN)
-            .id_value("US1234567890")
+            .id_value("US1234567899")
             .currency(Currency::USD)
             .build()
             .expect("Failed to build a valid mapping request");
@@ -10,10 +10,14 @@
 //! - [`SearchRequest`] - For `/search` endpoint requests
 
 mod common;
-pub(crate) use self::common::RequestFilters;
+pub(crate) use self::common::SerializedFilters;
+pub use self::common::{
+    FilterChange, FilterField, FilterValue, FiltersDiff, HasFilters, RequestFilters,
+};
 
 mod mapping_request;
-pub use self::mapping_request::{MappingRequest, MappingRequestBuilder};
+pub use self::mapping_request::{IdValue, MappingRequest, MappingRequestBuilder};
+pub(crate) use self::mapping_request::{MappingJob, MappingJobs};
 
 mod search_request;
 pub use self::search_request::{SearchRequest, SearchRequestBuilder};
@@ -16,7 +16,7 @@
 //! ### Search request with additional filters
 //!
 //! ```rust
-//! use openfigi_rs::model::request::SearchRequest;
+//! use openfigi_rs::model::request::{HasFilters, SearchRequest};
 //! use openfigi_rs::model::enums::{Currency, ExchCode};
 //!
 //! let request = SearchRequest::builder()
@@ -31,16 +31,8 @@
 
 use crate::{
     error::{OpenFIGIError, OtherErrorKind, Result},
-    impl_filter_builder,
-    model::{
-        enums::{
-            Currency, ExchCode, MarketSecDesc, MicCode, OptionType, SecurityType, SecurityType2,
-            StateCode,
-        },
-        request::common::RequestFilters,
-    },
+    model::request::common::{HasFilters, RequestFilters},
 };
-use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// Request structure for the OpenFIGI `/search` endpoint.
@@ -60,7 +52,7 @@ use serde::{Deserialize, Serialize};
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::SearchRequest;
+/// use openfigi_rs::model::request::{HasFilters, SearchRequest};
 /// use openfigi_rs::model::enums::Currency;
 ///
 /// // Simple keyword search
@@ -128,7 +120,7 @@ impl SearchRequest {
     /// # Examples
     ///
     /// ```rust
-    /// use openfigi_rs::model::request::SearchRequest;
+    /// use openfigi_rs::model::request::{HasFilters, SearchRequest};
     /// use openfigi_rs::model::enums::Currency;
     ///
     /// let request = SearchRequest::builder()
@@ -180,7 +172,7 @@ impl SearchRequest {
 /// # Examples
 ///
 /// ```rust
-/// use openfigi_rs::model::request::SearchRequestBuilder;
+/// use openfigi_rs::model::request::{HasFilters, SearchRequestBuilder};
 /// use openfigi_rs::model::enums::{Currency, ExchCode};
 ///
 /// let request = SearchRequestBuilder::new()
@@ -190,7 +182,7 @@ impl SearchRequest {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct SearchRequestBuilder {
     query: Option<String>,
     start: Option<String>,
@@ -230,6 +222,43 @@ impl SearchRequestBuilder {
         self
     }
 
+    /// Sets the search keywords from structured query components.
+    ///
+    /// The `/search` endpoint performs a keyword match, so verbose inputs tend to
+    /// reduce the quality of the results. The components are combined into a single
+    /// query using the following heuristics:
+    ///
+    /// - The ticker comes first, upper-cased, and only its first token is kept
+    ///   (`"IBM US Equity"` becomes `"IBM"`)
+    /// - Punctuation is removed from the name, as are a leading `"The"` and legal-form
+    ///   suffixes such as `Inc`, `Corp` or `Ltd`
+    /// - Additional keywords are appended as given
+    /// - Repeated words are dropped, ignoring case
+    ///
+    /// Replaces any query previously set via [`query()`](Self::query).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::request::SearchRequestBuilder;
+    ///
+    /// let request = SearchRequestBuilder::new()
+    ///     .query_parts(Some("International Business Machines Corp."), Some("ibm"), &["bond"])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(request.query, "IBM International Business Machines bond");
+    /// ```
+    #[must_use]
+    pub fn query_parts(
+        mut self,
+        name: Option<&str>,
+        ticker: Option<&str>,
+        keywords: &[&str],
+    ) -> Self {
+        self.query = Some(compose_query(name, ticker, keywords));
+        self
+    }
+
     /// Sets the pagination start token.
     ///
     /// Used for retrieving subsequent pages of results when the response
@@ -250,14 +279,6 @@ impl SearchRequestBuilder {
         self
     }
 
-    /// Mutable access to the request filters.
-    pub fn filters_mut(&mut self) -> &mut RequestFilters {
-        &mut self.filters
-    }
-
-    // Bring in common builder methods for filtering logic
-    impl_filter_builder!();
-
     /// Builds and validates the `SearchRequest`.
     ///
     /// Constructs the final request object and performs validation to ensure
@@ -294,6 +315,77 @@ impl SearchRequestBuilder {
     }
 }
 
+impl HasFilters for SearchRequestBuilder {
+    fn filters_mut(&mut self) -> &mut RequestFilters {
+        &mut self.filters
+    }
+}
+
+/// Legal-form suffixes that add noise to keyword searches on company names.
+const LEGAL_FORM_SUFFIXES: &[&str] = &[
+    "AG",
+    "CO",
+    "CORP",
+    "CORPORATION",
+    "INC",
+    "INCORPORATED",
+    "LIMITED",
+    "LLC",
+    "LP",
+    "LTD",
+    "NV",
+    "PLC",
+    "SA",
+    "SE",
+];
+
+/// Combines structured query components into a single `/search` query string.
+///
+/// See [`SearchRequestBuilder::query_parts`] for the applied heuristics.
+fn compose_query(name: Option<&str>, ticker: Option<&str>, keywords: &[&str]) -> String {
+    let ticker = ticker
+        .and_then(|ticker| ticker.split_whitespace().next())
+        .map(str::to_uppercase);
+
+    let mut name_words: Vec<String> = name
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || matches!(c, '&' | '-'))
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+    if name_words
+        .first()
+        .is_some_and(|word| word.eq_ignore_ascii_case("THE"))
+    {
+        name_words.remove(0);
+    }
+    while name_words.last().is_some_and(|word| {
+        LEGAL_FORM_SUFFIXES
+            .iter()
+            .any(|suffix| word.eq_ignore_ascii_case(suffix))
+    }) {
+        name_words.pop();
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    let candidates = ticker.into_iter().chain(name_words).chain(
+        keywords
+            .iter()
+            .flat_map(|k| k.split_whitespace())
+            .map(String::from),
+    );
+    for word in candidates {
+        if !words.iter().any(|w| w.eq_ignore_ascii_case(&word)) {
+            words.push(word);
+        }
+    }
+    words.join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -382,6 +474,30 @@ mod tests {
         assert!(msg.contains("date range cannot exceed 1 year"));
     }
 
+    #[test]
+    fn test_compose_query_heuristics() {
+        assert_eq!(
+            compose_query(Some("The Coca-Cola Co."), Some("ko us equity"), &[]),
+            "KO Coca-Cola"
+        );
+        assert_eq!(
+            compose_query(Some("Apple Inc"), Some("AAPL"), &["apple", "common stock"]),
+            "AAPL Apple common stock"
+        );
+        assert_eq!(compose_query(None, None, &["bond"]), "bond");
+        assert_eq!(compose_query(Some("Siemens AG"), None, &[]), "Siemens");
+    }
+
+    #[test]
+    fn test_search_request_builder_query_parts_replaces_query() {
+        let request = SearchRequest::builder()
+            .query("ignored")
+            .query_parts(Some("Intl Business Machines Corp"), Some("IBM"), &[])
+            .build()
+            .expect("Failed to build search request");
+        assert_eq!(request.query, "IBM Intl Business Machines");
+    }
+
     #[test]
     fn test_serialize_deserialize_search_request() {
         let request = SearchRequest::builder()
@@ -6,10 +6,21 @@
 //! individual financial instrument data, and the [`ResponseResult`] enum for handling
 //! success/error responses.
 //!
+//! The OpenFIGI API distinguishes between fields that are omitted and fields that are
+//! explicitly set to `null`. [`FigiResult`] records the latter in [`FigiResult::null_fields`],
+//! which can be queried with [`FigiResult::presence`].
+//!
 //! Note: This module is not intended for direct use by consumers of the OpenFIGI API.
 
-use crate::model::enums::{ExchCode, MarketSecDesc, SecurityType, SecurityType2};
-use serde::{Deserialize, Serialize};
+use crate::{
+    error::{self, OpenFIGIError},
+    model::{
+        enums::{ExchCode, MarketSecDesc, SecurityType, SecurityType2, unknown_value_expecting},
+        response::Figi,
+    },
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{fmt, str::FromStr};
 
 /// Represents the result of an OpenFIGI API request, which can either succeed with data or fail with an error.
 ///
@@ -26,6 +37,8 @@ pub enum ResponseResult<T> {
     Success(T),
     /// Error result when the API request fails.
     Error(ResponseError),
+    /// Warning result when the API request yields no data, e.g. for an unknown identifier.
+    Warning(ResponseWarning),
 }
 
 /// Error information returned by the OpenFIGI API when a request fails.
@@ -42,6 +55,17 @@ pub struct ResponseError {
     pub error: String,
 }
 
+/// Warning information returned by the OpenFIGI API instead of data, typically
+/// `"No identifier found."` for identifiers without a match.
+///
+/// Warnings other than misses are logged with `tracing::warn!` when parsed if the `tracing`
+/// feature is enabled.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResponseWarning {
+    /// Human-readable warning message provided by the OpenFIGI API.
+    pub warning: String,
+}
+
 /// Detailed information about a single financial instrument returned by the OpenFIGI API.
 ///
 /// This structure represents the core data about a financial instrument, including its
@@ -64,8 +88,9 @@ pub struct ResponseError {
 /// - `exch_code`: Exchange where the instrument is primarily traded
 /// - `security_description`: Detailed description of the security
 /// - `metadata`: Additional information when other fields are unavailable
+/// - `null_fields`: Optional fields that the API explicitly set to `null`
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(from = "RawFigiResult", into = "RawFigiResult")]
 pub struct FigiResult {
     /// The Financial Instrument Global Identifier (FIGI) - a unique 12-character identifier.
     ///
@@ -75,32 +100,33 @@ pub struct FigiResult {
     ///
     /// This is the primary identifier for the specific financial instrument and is
     /// always present in every FIGI result.
-    pub figi: String,
+    ///
+    /// FIGIs of responses are not validated, so a single malformed FIGI does not fail a whole
+    /// response. Use [`Figi::is_valid`] to check them.
+    pub figi: Figi,
 
     /// Security type of the instrument.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "securityType")]
     pub security_type: Option<SecurityType>,
 
     /// Market sector of the instrument.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "marketSector")]
     pub market_sector: Option<MarketSecDesc>,
 
     /// Trading symbol or ticker used on the exchange.
     ///
     /// This is the abbreviated symbol used for trading the instrument.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub ticker: Option<String>,
 
     /// Full legal name of the financial instrument.
     ///
     /// The complete, official name of the instrument as registered.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// Exchange code where the instrument is primarily traded.
     ///
     /// Identifies the specific exchange or trading venue.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "exchCode")]
     pub exch_code: Option<ExchCode>,
 
     /// FIGI identifier for the share class level.
@@ -108,38 +134,228 @@ pub struct FigiResult {
     /// A Share Class level Financial Instrument Global Identifier is assigned to Equities
     /// and Funds. This enables users to link multiple Composite FIGIs for the same instrument
     /// in order to obtain an aggregated view for that instrument across all countries globally.
-    #[serde(rename = "shareClassFIGI", skip_serializing_if = "Option::is_none")]
-    pub share_class_figi: Option<String>,
+    #[doc(alias = "shareClassFIGI")]
+    pub share_class_figi: Option<Figi>,
 
     /// FIGI identifier for the composite level.
     ///
     /// The Composite Financial Instrument Global Identifier (FIGI) enables users to
     /// link multiple FIGIs at the Trading Venue-level within the same country or market
     /// in order to obtain an aggregated view for that instrument.
-    #[serde(rename = "compositeFIGI", skip_serializing_if = "Option::is_none")]
-    pub composite_figi: Option<String>,
+    #[doc(alias = "compositeFIGI")]
+    pub composite_figi: Option<Figi>,
 
     /// Alternative security type of the instrument.
     ///
     /// Offers a secondary classification that is less specific than the primary security type.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "securityType2")]
     pub security_type2: Option<SecurityType2>,
 
     /// Detailed textual description of the security.
     ///
     /// Provides additional context and details about the financial instrument.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[doc(alias = "securityDescription")]
     pub security_description: Option<String>,
 
     /// Additional metadata provided when other fields are unavailable.
     ///
     /// This field may contain supplementary information when the API cannot
     /// populate the standard fields due to data limitations or restrictions.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<String>,
+
+    /// Optional fields that were explicitly set to `null` in the response.
+    ///
+    /// Fields listed here are `None` just like omitted fields, but data-quality checks
+    /// may treat an explicit `null` differently from a field the API did not send.
+    pub null_fields: Vec<FigiField>,
+}
+
+/// Optional fields of a FIGI result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FigiField {
+    /// The `securityType` field
+    SecurityType,
+    /// The `marketSector` field
+    MarketSector,
+    /// The `ticker` field
+    Ticker,
+    /// The `name` field
+    Name,
+    /// The `exchCode` field
+    ExchCode,
+    /// The `shareClassFIGI` field
+    ShareClassFigi,
+    /// The `compositeFIGI` field
+    CompositeFigi,
+    /// The `securityType2` field
+    SecurityType2,
+    /// The `securityDescription` field
+    SecurityDescription,
+    /// The `metadata` field
+    Metadata,
+}
+
+impl FigiField {
+    /// All optional fields, in the order of [`FigiResult`].
+    pub const ALL: [Self; 10] = [
+        Self::SecurityType,
+        Self::MarketSector,
+        Self::Ticker,
+        Self::Name,
+        Self::ExchCode,
+        Self::ShareClassFigi,
+        Self::CompositeFigi,
+        Self::SecurityType2,
+        Self::SecurityDescription,
+        Self::Metadata,
+    ];
+
+    /// Returns the name of the field in API responses, e.g. `compositeFIGI`.
+    #[must_use]
+    pub const fn as_api_name(self) -> &'static str {
+        match self {
+            Self::SecurityType => "securityType",
+            Self::MarketSector => "marketSector",
+            Self::Ticker => "ticker",
+            Self::Name => "name",
+            Self::ExchCode => "exchCode",
+            Self::ShareClassFigi => "shareClassFIGI",
+            Self::CompositeFigi => "compositeFIGI",
+            Self::SecurityType2 => "securityType2",
+            Self::SecurityDescription => "securityDescription",
+            Self::Metadata => "metadata",
+        }
+    }
+}
+
+impl fmt::Display for FigiField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_api_name())
+    }
+}
+
+impl FromStr for FigiField {
+    type Err = OpenFIGIError;
+
+    /// Parses the API name of a field, e.g. `compositeFIGI`.
+    fn from_str(value: &str) -> error::Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_api_name() == value)
+            .ok_or_else(|| {
+                unknown_value_expecting("FigiField", value, Self::ALL.map(Self::as_api_name))
+            })
+    }
+}
+
+/// Whether an optional field of a FIGI result was sent by the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldPresence {
+    /// The field was omitted from the response.
+    Missing,
+    /// The field was present with an explicit `null` value.
+    Null,
+    /// The field was present with a value.
+    Present,
 }
 
 impl FigiResult {
+    /// Returns whether the given field was present, explicitly `null`, or missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::{FieldPresence, FigiField, SearchData};
+    ///
+    /// let data: SearchData = serde_json::from_str(
+    ///     r#"{"data": [{"figi": "BBG000BLNNH6", "ticker": null}]}"#,
+    /// )?;
+    /// let result = &data.data()[0];
+    ///
+    /// assert_eq!(result.presence(FigiField::Ticker), FieldPresence::Null);
+    /// assert_eq!(result.presence(FigiField::Name), FieldPresence::Missing);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[must_use]
+    pub fn presence(&self, field: FigiField) -> FieldPresence {
+        let is_some = match field {
+            FigiField::SecurityType => self.security_type.is_some(),
+            FigiField::MarketSector => self.market_sector.is_some(),
+            FigiField::Ticker => self.ticker.is_some(),
+            FigiField::Name => self.name.is_some(),
+            FigiField::ExchCode => self.exch_code.is_some(),
+            FigiField::ShareClassFigi => self.share_class_figi.is_some(),
+            FigiField::CompositeFigi => self.composite_figi.is_some(),
+            FigiField::SecurityType2 => self.security_type2.is_some(),
+            FigiField::SecurityDescription => self.security_description.is_some(),
+            FigiField::Metadata => self.metadata.is_some(),
+        };
+        if is_some {
+            FieldPresence::Present
+        } else if self.null_fields.contains(&field) {
+            FieldPresence::Null
+        } else {
+            FieldPresence::Missing
+        }
+    }
+
+    /// Returns the enum fields holding values without a dedicated variant, with their values.
+    ///
+    /// Values the API introduced after this crate was built deserialize into the `Other`
+    /// variant of their enum instead of failing; this lists them, e.g. to log that the crate
+    /// should be updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::{FigiField, SearchData};
+    ///
+    /// let data: SearchData = serde_json::from_str(
+    ///     r#"{"data": [{"figi": "BBG000BLNNH6", "securityType": "Common Stock", "exchCode": "NEW"}]}"#,
+    /// )?;
+    /// let result = &data.data()[0];
+    ///
+    /// assert_eq!(result.unknown_values(), [(FigiField::ExchCode, "NEW")]);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[must_use]
+    pub fn unknown_values(&self) -> Vec<(FigiField, &str)> {
+        [
+            (
+                FigiField::SecurityType,
+                self.security_type
+                    .as_ref()
+                    .filter(|value| !value.is_known())
+                    .map(SecurityType::as_str),
+            ),
+            (
+                FigiField::MarketSector,
+                self.market_sector
+                    .as_ref()
+                    .filter(|value| !value.is_known())
+                    .map(MarketSecDesc::as_str),
+            ),
+            (
+                FigiField::ExchCode,
+                self.exch_code
+                    .as_ref()
+                    .filter(|value| !value.is_known())
+                    .map(ExchCode::as_str),
+            ),
+            (
+                FigiField::SecurityType2,
+                self.security_type2
+                    .as_ref()
+                    .filter(|value| !value.is_known())
+                    .map(SecurityType2::as_str),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| Some((field, value?)))
+        .collect()
+    }
+
     /// Returns `true` if this result includes a composite FIGI identifier.
     #[must_use]
     pub fn has_composite_figi(&self) -> bool {
@@ -166,4 +382,341 @@ impl FigiResult {
             .as_deref()
             .unwrap_or_else(|| self.ticker.as_deref().unwrap_or(&self.figi))
     }
+
+    /// Returns a stable 64-bit hash of the instrument data, for detecting changed results.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the fields in declaration order, from `figi` to
+    /// `metadata`. Each field is encoded as a `0x00` byte if absent, or as a `0x01` byte followed
+    /// by the length of its API string value as a little-endian `u64` and the UTF-8 bytes of the
+    /// value. Enum fields use their API names, e.g. `Common Stock`.
+    ///
+    /// The algorithm does not depend on the Rust version or the platform, so hashes can be stored
+    /// and compared across releases. [`Self::null_fields`] does not contribute: an explicit `null`
+    /// hashes like an omitted field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::SearchData;
+    ///
+    /// let stored: SearchData = serde_json::from_str(
+    ///     r#"{"data": [{"figi": "BBG000BLNNH6", "ticker": "IBM"}]}"#,
+    /// )?;
+    /// let refreshed: SearchData = serde_json::from_str(
+    ///     r#"{"data": [{"figi": "BBG000BLNNH6", "ticker": "IBM", "name": null}]}"#,
+    /// )?;
+    ///
+    /// assert_eq!(stored.data()[0].content_hash(), refreshed.data()[0].content_hash());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = ContentHasher::new();
+        hasher.write_field(Some(self.figi.as_str()));
+        hasher.write_field(self.security_type.as_ref().map(SecurityType::as_str));
+        hasher.write_field(self.market_sector.as_ref().map(MarketSecDesc::as_str));
+        hasher.write_field(self.ticker.as_deref());
+        hasher.write_field(self.name.as_deref());
+        hasher.write_field(self.exch_code.as_ref().map(ExchCode::as_str));
+        hasher.write_field(self.share_class_figi.as_deref());
+        hasher.write_field(self.composite_figi.as_deref());
+        hasher.write_field(self.security_type2.as_ref().map(SecurityType2::as_str));
+        hasher.write_field(self.security_description.as_deref());
+        hasher.write_field(self.metadata.as_deref());
+        hasher.finish()
+    }
+}
+
+/// 64-bit FNV-1a hasher backing the documented `content_hash` methods.
+///
+/// Unlike [`std::hash::DefaultHasher`], the output is guaranteed to be stable.
+pub(crate) struct ContentHasher(u64);
+
+impl ContentHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub(crate) fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Writes an optional string field as an absent marker or a length-prefixed value.
+    fn write_field(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.write(&[1]);
+                self.write_u64(value.len() as u64);
+                self.write(value.as_bytes());
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Wire representation of [`FigiResult`] that keeps explicit `null` values apart from omitted fields.
+///
+/// An omitted field deserializes to `None` and a `null` field to `Some(None)`.
+#[expect(clippy::option_option)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFigiResult {
+    #[serde(deserialize_with = "Figi::deserialize_lenient")]
+    figi: Figi,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    security_type: Option<Option<SecurityType>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    market_sector: Option<Option<MarketSecDesc>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    ticker: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    name: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    exch_code: Option<Option<ExchCode>>,
+    #[serde(
+        rename = "shareClassFIGI",
+        default,
+        deserialize_with = "nullable_figi",
+        skip_serializing_if = "Option::is_none"
+    )]
+    share_class_figi: Option<Option<Figi>>,
+    #[serde(
+        rename = "compositeFIGI",
+        default,
+        deserialize_with = "nullable_figi",
+        skip_serializing_if = "Option::is_none"
+    )]
+    composite_figi: Option<Option<Figi>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    security_type2: Option<Option<SecurityType2>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    security_description: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    metadata: Option<Option<String>>,
+}
+
+/// Deserializes a present field, mapping `null` to `Some(None)`.
+#[expect(clippy::option_option)]
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+/// Deserializes a present FIGI field without validation, mapping `null` to `Some(None)`.
+#[expect(clippy::option_option)]
+fn nullable_figi<'de, D>(deserializer: D) -> Result<Option<Option<Figi>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(|value| Some(value.map(Figi::new_unchecked)))
+}
+
+impl From<RawFigiResult> for FigiResult {
+    fn from(raw: RawFigiResult) -> Self {
+        let mut nulls = Vec::new();
+        Self {
+            figi: raw.figi,
+            security_type: split_null(raw.security_type, FigiField::SecurityType, &mut nulls),
+            market_sector: split_null(raw.market_sector, FigiField::MarketSector, &mut nulls),
+            ticker: split_null(raw.ticker, FigiField::Ticker, &mut nulls),
+            name: split_null(raw.name, FigiField::Name, &mut nulls),
+            exch_code: split_null(raw.exch_code, FigiField::ExchCode, &mut nulls),
+            share_class_figi: split_null(
+                raw.share_class_figi,
+                FigiField::ShareClassFigi,
+                &mut nulls,
+            ),
+            composite_figi: split_null(raw.composite_figi, FigiField::CompositeFigi, &mut nulls),
+            security_type2: split_null(raw.security_type2, FigiField::SecurityType2, &mut nulls),
+            security_description: split_null(
+                raw.security_description,
+                FigiField::SecurityDescription,
+                &mut nulls,
+            ),
+            metadata: split_null(raw.metadata, FigiField::Metadata, &mut nulls),
+            null_fields: nulls,
+        }
+    }
+}
+
+impl From<FigiResult> for RawFigiResult {
+    fn from(result: FigiResult) -> Self {
+        let nulls = &result.null_fields;
+        Self {
+            figi: result.figi,
+            security_type: join_null(result.security_type, FigiField::SecurityType, nulls),
+            market_sector: join_null(result.market_sector, FigiField::MarketSector, nulls),
+            ticker: join_null(result.ticker, FigiField::Ticker, nulls),
+            name: join_null(result.name, FigiField::Name, nulls),
+            exch_code: join_null(result.exch_code, FigiField::ExchCode, nulls),
+            share_class_figi: join_null(result.share_class_figi, FigiField::ShareClassFigi, nulls),
+            composite_figi: join_null(result.composite_figi, FigiField::CompositeFigi, nulls),
+            security_type2: join_null(result.security_type2, FigiField::SecurityType2, nulls),
+            security_description: join_null(
+                result.security_description,
+                FigiField::SecurityDescription,
+                nulls,
+            ),
+            metadata: join_null(result.metadata, FigiField::Metadata, nulls),
+        }
+    }
+}
+
+/// Flattens a wire value, recording the field if it was explicitly `null`.
+#[expect(clippy::option_option)]
+fn split_null<T>(
+    value: Option<Option<T>>,
+    field: FigiField,
+    nulls: &mut Vec<FigiField>,
+) -> Option<T> {
+    if matches!(value, Some(None)) {
+        nulls.push(field);
+    }
+    value.flatten()
+}
+
+/// Restores the wire value of a field, emitting `null` for fields recorded as explicitly `null`.
+#[expect(clippy::option_option)]
+fn join_null<T>(value: Option<T>, field: FigiField, nulls: &[FigiField]) -> Option<Option<T>> {
+    match value {
+        Some(value) => Some(Some(value)),
+        None if nulls.contains(&field) => Some(None),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_figi_field_from_str() {
+        for field in FigiField::ALL {
+            assert_eq!(field.to_string().parse::<FigiField>().ok(), Some(field));
+        }
+        assert!("compositeFigi".parse::<FigiField>().is_err());
+    }
+
+    #[test]
+    fn test_figi_result_null_fields() {
+        let result: FigiResult = serde_json::from_value(json!({
+            "figi": "BBG000BLNNH6",
+            "ticker": null,
+            "name": "INTL BUSINESS MACHINES CORP",
+            "exchCode": null
+        }))
+        .expect("Should deserialize FIGI result");
+
+        assert_eq!(result.ticker, None);
+        assert_eq!(result.null_fields, [FigiField::Ticker, FigiField::ExchCode]);
+        assert_eq!(result.presence(FigiField::Ticker), FieldPresence::Null);
+        assert_eq!(result.presence(FigiField::Name), FieldPresence::Present);
+        assert_eq!(result.presence(FigiField::Metadata), FieldPresence::Missing);
+    }
+
+    #[test]
+    fn test_figi_result_content_hash() {
+        let result: FigiResult = serde_json::from_value(json!({
+            "figi": "BBG000BLNNH6",
+            "securityType": "Common Stock",
+            "ticker": "IBM"
+        }))
+        .expect("Should deserialize FIGI result");
+        assert_eq!(result.content_hash(), 0xad8e_e451_0b40_6ca7);
+
+        let mut changed = result.clone();
+        changed.ticker = Some("IBM2".to_string());
+        assert_ne!(changed.content_hash(), result.content_hash());
+
+        // The same value in a different field must not collide.
+        let mut moved = result.clone();
+        moved.ticker = None;
+        moved.name = Some("IBM".to_string());
+        assert_ne!(moved.content_hash(), result.content_hash());
+
+        let mut null = result.clone();
+        null.null_fields.push(FigiField::Name);
+        assert_eq!(null.content_hash(), result.content_hash());
+    }
+
+    #[test]
+    fn test_figi_result_round_trip_preserves_nulls() {
+        let value = json!({
+            "figi": "BBG000BLNNH6",
+            "securityType": "Common Stock",
+            "compositeFIGI": null
+        });
+        let result: FigiResult =
+            serde_json::from_value(value.clone()).expect("Should deserialize FIGI result");
+
+        assert_eq!(
+            serde_json::to_value(&result).expect("Should serialize FIGI result"),
+            value
+        );
+    }
+
+    #[test]
+    fn test_figi_result_accepts_malformed_figis() {
+        let result: FigiResult = serde_json::from_value(json!({
+            "figi": "BBG000BLNNH7",
+            "compositeFIGI": "BBG000BLNNH6",
+            "shareClassFIGI": "placeholder"
+        }))
+        .expect("Malformed FIGIs should not fail a FIGI result");
+
+        assert!(!result.figi.is_valid());
+        assert_eq!(result.figi, "BBG000BLNNH7");
+        assert!(result.composite_figi.as_ref().is_some_and(Figi::is_valid));
+        assert!(!result.share_class_figi.as_ref().is_some_and(Figi::is_valid));
+    }
 }
@@ -0,0 +1,265 @@
+//! Validated Financial Instrument Global Identifiers.
+
+use crate::{
+    error::{OpenFIGIError, Result},
+    model::enums::FromStrVisitor,
+    validation,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Borrow, fmt, ops::Deref, str::FromStr};
+
+/// A Financial Instrument Global Identifier (FIGI), e.g. `BBG000BLNNH6`.
+///
+/// A FIGI has twelve uppercase characters: two consonants not forming an excluded prefix,
+/// `G`, eight digits or consonants and a check digit. [`Self::new`], [`FromStr`] and
+/// deserialization validate the format and the check digit, so a `Figi` cannot be confused with
+/// other strings, like tickers or ISINs, passed where FIGIs are expected downstream.
+/// FIGIs of API responses are the exception, see below.
+///
+/// A `Figi` dereferences to [`str`] and compares equal to strings with the same value.
+///
+/// # Lenient Mode
+///
+/// The FIGIs of [`crate::model::response::FigiResult`] are deserialized leniently, so a
+/// single malformed FIGI does not fail a response with many jobs. Applications processing
+/// fixtures or third party data with placeholder FIGIs can skip validation for their own fields
+/// with [`Self::deserialize_lenient`] as well, or create FIGIs with [`Self::new_unchecked`], and
+/// check individual FIGIs with [`Self::is_valid`].
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::model::response::Figi;
+///
+/// let figi: Figi = "BBG000BLNNH6".parse()?;
+/// assert_eq!(figi, "BBG000BLNNH6");
+/// assert_eq!(Figi::parse_lenient(" bbg000blnnh6 ")?, figi);
+/// assert!("BBG000BLNNH7".parse::<Figi>().is_err());
+/// # Ok::<(), openfigi_rs::error::OpenFIGIError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Figi(String);
+
+impl Figi {
+    /// Creates a FIGI after validating its format and check digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OtherErrorKind::Validation`] error naming the problem if
+    /// `value` is not a valid uppercase FIGI.
+    pub fn new(value: impl Into<String>) -> Result<Self> {
+        let value = value.into();
+        validation::validate_figi(&value)?;
+        Ok(Self(value))
+    }
+
+    /// Creates a FIGI leniently, ignoring case and surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OtherErrorKind::Validation`] error if the normalized `value`
+    /// is not a valid FIGI.
+    pub fn parse_lenient(value: &str) -> Result<Self> {
+        Self::new(value.trim().to_ascii_uppercase())
+    }
+
+    /// Creates a FIGI without validation, so [`Self::is_valid`] may return `false`.
+    #[must_use]
+    pub fn new_unchecked(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Deserializes a FIGI without validation, for use with `#[serde(deserialize_with)]`.
+    ///
+    /// Accepts any string, so [`Self::is_valid`] may return `false`, like the FIGIs of
+    /// [`crate::model::response::FigiResult`]. The [`Deserialize`] implementation always
+    /// validates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the deserializer if the value is not a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::Figi;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Position {
+    ///     #[serde(deserialize_with = "Figi::deserialize_lenient")]
+    ///     figi: Figi,
+    /// }
+    ///
+    /// let position: Position = serde_json::from_str(r#"{ "figi": "BBG000000001" }"#)?;
+    /// assert!(!position.figi.is_valid());
+    /// assert!(serde_json::from_str::<Figi>(r#""BBG000000001""#).is_err());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize_lenient<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+
+    /// Returns `true` if the FIGI has a valid format and check digit.
+    ///
+    /// Always `true` unless the FIGI was created by [`Self::new_unchecked`] or
+    /// [`Self::deserialize_lenient`], e.g. as part of a response.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        validation::validate_figi(&self.0).is_ok()
+    }
+
+    /// Returns the FIGI as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the FIGI as a string.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for Figi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Figi {
+    type Err = OpenFIGIError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for Figi {
+    type Error = OpenFIGIError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for Figi {
+    type Error = OpenFIGIError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<Figi> for String {
+    fn from(figi: Figi) -> Self {
+        figi.0
+    }
+}
+
+impl Deref for Figi {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Figi {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Figi {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Figi {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Figi {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Figi {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Figi> for str {
+    fn eq(&self, other: &Figi) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<Figi> for &str {
+    fn eq(&self, other: &Figi) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<Figi> for String {
+    fn eq(&self, other: &Figi) -> bool {
+        *self == other.0
+    }
+}
+
+impl Serialize for Figi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Figi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("Figi"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_figi_validation() {
+        let figi = Figi::new("BBG000BLNNH6").expect("Valid FIGI");
+        assert_eq!(figi.to_string(), "BBG000BLNNH6");
+        assert_eq!("BBG000BLNNH6", figi);
+        assert!(figi.is_valid());
+
+        for invalid in [
+            "BBG000BLNNH7",
+            "bbg000blnnh6",
+            "BBG000BLNNH",
+            "US4592001014",
+        ] {
+            assert!(Figi::new(invalid).is_err(), "{invalid} should be rejected");
+        }
+        assert_eq!(
+            Figi::parse_lenient(" bbg000blnnh6").ok(),
+            Some(figi.clone())
+        );
+
+        let json = serde_json::to_string(&figi).expect("Should serialize");
+        assert_eq!(json, r#""BBG000BLNNH6""#);
+        assert_eq!(serde_json::from_str::<Figi>(&json).ok(), Some(figi));
+        assert!(serde_json::from_str::<Figi>(r#""BBG000BLNNH7""#).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""BBG000BLNNH7""#);
+        let lenient = Figi::deserialize_lenient(&mut deserializer).expect("Should deserialize");
+        assert_eq!(lenient, Figi::new_unchecked("BBG000BLNNH7"));
+        assert!(!lenient.is_valid());
+    }
+}
@@ -30,7 +30,10 @@
 //!
 //! Note: This module is not intended for direct use by consumers of the OpenFIGI API.
 
-use crate::model::response::common::FigiResult;
+use crate::{
+    error::{OpenFIGIError, OtherErrorKind, Result},
+    model::response::common::FigiResult,
+};
 use serde::{Deserialize, Serialize};
 
 /// Successful filter result data containing FIGI results and pagination metadata.
@@ -72,8 +75,17 @@ pub struct FilterData {
     /// This count represents the complete result set size, not just the
     /// number of results in the current page. Useful for implementing
     /// pagination controls and progress indicators.
+    ///
+    /// Unfiltered queries report totals of tens of millions, so the count is a `u64` to stay
+    /// exact on 32-bit targets. Use [`Self::total_len`] for a checked conversion to `usize`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total: Option<usize>,
+    pub total: Option<u64>,
+
+    /// Warning the OpenFIGI API attached to the results, e.g. about truncated results.
+    ///
+    /// Logged with `tracing::warn!` when parsed if the `tracing` feature is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 impl FilterData {
@@ -85,6 +97,12 @@ impl FilterData {
         &self.data
     }
 
+    /// Returns the warning the OpenFIGI API attached to the results, if any.
+    #[must_use]
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
     /// Returns the pagination token for retrieving the next page of results.
     ///
     /// Returns `Some(token)` if more results are available, `None` if this is the last page.
@@ -99,9 +117,68 @@ impl FilterData {
     /// The filter endpoint almost always provides the total count, so this method
     /// returns `Some(total)` rather than `None`.
     #[must_use]
-    pub fn total_results(&self) -> Option<&usize> {
+    pub fn total_results(&self) -> Option<&u64> {
         self.total.as_ref()
     }
+
+    /// Returns the total number of results as a `usize`, if reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the total does not fit into a `usize`,
+    /// which can happen on 32-bit targets such as `wasm32`.
+    pub fn total_len(&self) -> Result<Option<usize>> {
+        self.total
+            .map(|total| {
+                usize::try_from(total).map_err(|_| {
+                    OpenFIGIError::other_error(
+                        OtherErrorKind::Other,
+                        format!(
+                            "Total of {total} results exceeds the address space of this target"
+                        ),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Estimates the number of pages following this page, if it is the first page of the
+    /// result set.
+    ///
+    /// Assumes pages of `page_size_hint` results, e.g. the size of this page. Returns `Some(0)`
+    /// if there is no next page, and `None` if more pages follow but the API reported no total.
+    /// For later pages, use
+    /// [`FilterPaginator::pages_remaining`](crate::endpoint::pagination::FilterPaginator::pages_remaining),
+    /// which accounts for the results already fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::FilterData;
+    ///
+    /// let page: FilterData = serde_json::from_str(
+    ///     r#"{"data": [{"figi": "BBG000BLNNH6"}, {"figi": "BBG000BLNQ16"}], "next": "p2", "total": 7}"#,
+    /// )?;
+    /// assert_eq!(page.pages_remaining(2), Some(3));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn pages_remaining(&self, page_size_hint: usize) -> Option<u64> {
+        if self.next.is_none() {
+            return Some(0);
+        }
+        let fetched = u64::try_from(self.data.len()).unwrap_or(u64::MAX);
+        self.total
+            .map(|total| page_count(total.saturating_sub(fetched), page_size_hint))
+    }
+}
+
+/// Returns the number of pages of `page_size_hint` results needed for `results` results.
+///
+/// A hint of `0` is treated as `1`.
+pub(crate) fn page_count(results: u64, page_size_hint: usize) -> u64 {
+    let page_size = u64::try_from(page_size_hint.max(1)).unwrap_or(u64::MAX);
+    results.div_ceil(page_size)
 }
 
 #[cfg(test)]
@@ -119,7 +196,7 @@ mod tests {
 
         let filter_data = match filter_response {
             ResponseResult::Success(ref data) => data,
-            ResponseResult::Error(ref err) => panic!("Expected success, got error: {err:?}"),
+            ref other => panic!("Expected success, got {other:?}"),
         };
         let figi_result = filter_data.data();
         assert!(!figi_result.is_empty());
@@ -135,7 +212,7 @@ mod tests {
         assert!(!first_entry.has_share_class_figi());
 
         // Verify actual field values from real data
-        assert_eq!(first_entry.composite_figi, Some("BBG0001RT9P0".to_string()));
+        assert_eq!(first_entry.composite_figi.as_deref(), Some("BBG0001RT9P0"));
         assert_eq!(first_entry.share_class_figi, None);
 
         // Verify pagination exists
@@ -157,11 +234,46 @@ mod tests {
 
         let filter_data = match filter_response {
             ResponseResult::Success(ref data) => data,
-            ResponseResult::Error(ref err) => panic!("Expected success, got error: {err:?}"),
+            ref other => panic!("Expected success, got {other:?}"),
         };
         let figi_result = filter_data.data();
         assert!(figi_result.is_empty());
         assert!(filter_data.next_page().is_none());
         assert_eq!(filter_data.total_results(), Some(0).as_ref());
     }
+
+    #[test]
+    fn test_total_exceeding_u32() {
+        let data: FilterData = serde_json::from_str(r#"{"data": [], "total": 5000000000}"#)
+            .expect("Failed to deserialize filter response");
+        assert_eq!(data.total, Some(5_000_000_000));
+
+        let total_len = data.total_len();
+        if usize::BITS >= 64 {
+            assert_eq!(total_len.expect("Total should fit"), Some(5_000_000_000));
+        } else {
+            assert!(total_len.is_err());
+        }
+    }
+
+    #[test]
+    fn test_pages_remaining() {
+        let page = |next: Option<&str>, total: Option<u64>| FilterData {
+            data: vec![
+                serde_json::from_str(r#"{"figi": "BBG000BLNNH6"}"#).expect("Should deserialize"),
+            ],
+            next: next.map(str::to_string),
+            total,
+            warning: None,
+        };
+
+        assert_eq!(
+            page(Some("p2"), Some(59_884_674)).pages_remaining(100),
+            Some(598_847)
+        );
+        assert_eq!(page(Some("p2"), Some(3)).pages_remaining(0), Some(2));
+        assert_eq!(page(Some("p2"), None).pages_remaining(100), None);
+        assert_eq!(page(None, Some(3)).pages_remaining(100), Some(0));
+        assert_eq!(page_count(0, 100), 0);
+    }
 }
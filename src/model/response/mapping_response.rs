@@ -38,24 +38,44 @@
 //!
 //! Note: This module is not intended for direct use by consumers of the OpenFIGI API.
 
-use crate::error::{OpenFIGIError, Result};
-use crate::model::response::common::FigiResult;
-use serde::{Deserialize, Serialize};
+use crate::error::{OpenFIGIError, ReasonCode, Result};
+use crate::model::request::{MappingRequest, RequestFilters};
+use crate::model::response::{
+    Figi,
+    common::{ContentHasher, FigiResult, ResponseResult},
+};
+use crate::parse::{API_ERROR_PREFIX, API_WARNING_PREFIX, into_result};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt,
+    sync::Arc,
+};
 
 /// Ergonomic wrapper for batch responses from the OpenFIGI mapping endpoint (POST /v3/mapping).
 ///
 /// This type represents the complete response from the mapping endpoint, which returns
 /// an array of results corresponding to each mapping request submitted in the batch.
 /// Each mapping request in the batch gets its own result in the response array, which is
-/// either a successful [`MappingData`] or an [`OpenFIGIError`] describing why the mapping failed.
+/// a [`JobOutcome`]: the matched [`MappingData`], a response without results, a miss reported by
+/// the API, or an [`OpenFIGIError`] describing why the mapping failed.
 ///
 /// # Usage
 ///
 /// - Use [`MappingResponses::successes()`] to iterate over all successful mapping results.
+/// - Use [`MappingResponses::empty()`] to iterate over all requests answered without results.
+/// - Use [`MappingResponses::not_found()`] to iterate over all requests the API found no instrument for.
 /// - Use [`MappingResponses::failures()`] to iterate over all errors that occurred for individual requests.
+/// - Use [`MappingResponses::outcomes()`] to handle all cases in order.
 /// - Use [`MappingResponses::len()`] and [`MappingResponses::is_empty()`] for batch size checks.
+/// - Use [`MappingResponses::conflicts()`] to flag duplicate FIGIs and share class conflicts.
+/// - Use [`crate::client::OpenFIGIClient::retry_failures`] to re-submit only the failed requests.
 #[derive(Debug)]
-pub struct MappingResponses(Vec<Result<MappingData>>);
+pub struct MappingResponses {
+    outcomes: Vec<JobOutcome>,
+    requests: Option<Vec<MappingRequest>>,
+    default_filters: RequestFilters,
+}
 
 impl MappingResponses {
     #[doc(hidden)]
@@ -63,44 +83,541 @@ impl MappingResponses {
     /// This constructor is primarily for internal use
     /// and testing purposes.
     pub(crate) fn new(results: Vec<Result<MappingData>>) -> Self {
-        Self(results)
+        Self {
+            outcomes: results.into_iter().map(JobOutcome::from).collect(),
+            requests: None,
+            default_filters: RequestFilters::default(),
+        }
+    }
+
+    /// Retains the default filters merged into the requests when they were sent, for
+    /// [`Self::default_filters`].
+    pub(crate) fn with_default_filters(mut self, default_filters: RequestFilters) -> Self {
+        self.default_filters = default_filters;
+        self
+    }
+
+    /// Retains the original requests, one per result, for [`Self::requests`].
+    pub(crate) fn with_requests(mut self, requests: Vec<MappingRequest>) -> Self {
+        if requests.len() == self.outcomes.len() {
+            self.requests = Some(requests);
+        }
+        self
     }
 
     /// Returns an iterator over all successful mapping results in the batch, with their indices.
     ///
-    /// Each item is a tuple `(index, &MappingData)` for a request that was successfully mapped.
+    /// Each item is a tuple `(index, &MappingData)` for a request that matched at least one
+    /// instrument. Requests answered without results are not included, see [`Self::empty`].
     pub fn successes(&self) -> impl Iterator<Item = (usize, &MappingData)> {
-        self.0
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, outcome)| outcome.found().map(|data| (i, data)))
+    }
+
+    /// Returns an iterator over the warnings the API attached to successful mapping results,
+    /// with the indices of their requests.
+    ///
+    /// Misses reported as warnings are not included, see [`Self::not_found`].
+    pub fn warnings(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.outcomes.iter().enumerate().filter_map(|(i, outcome)| {
+            outcome
+                .data()
+                .and_then(MappingData::warning)
+                .map(|warning| (i, warning))
+        })
+    }
+
+    /// Returns an iterator over the mapping requests answered with an empty `data` array, with
+    /// their indices.
+    ///
+    /// These are neither misses reported by the API nor failures, e.g. because the client
+    /// policy discarded every result. Use [`MappingData::require_non_empty`] to treat them as
+    /// errors.
+    pub fn empty(&self) -> impl Iterator<Item = (usize, &MappingData)> {
+        self.outcomes
             .iter()
             .enumerate()
-            .filter_map(|(i, r)| r.as_ref().ok().map(|data| (i, data)))
+            .filter_map(|(i, outcome)| match outcome {
+                JobOutcome::Empty(data) => Some((i, data)),
+                _ => None,
+            })
     }
 
     /// Returns an iterator over all errors for failed mapping requests in the batch, with their indices.
     ///
-    /// Each item is a tuple `(index, &OpenFIGIError)` for a request that failed to map.
+    /// Each item is a tuple `(index, &OpenFIGIError)` for a request that failed to map. Requests
+    /// the API found no instrument for are not failures, see [`Self::not_found`].
     pub fn failures(&self) -> impl Iterator<Item = (usize, &OpenFIGIError)> {
-        self.0
+        self.outcomes
             .iter()
             .enumerate()
-            .filter_map(|(i, r)| r.as_ref().err().map(|err| (i, err)))
+            .filter_map(|(i, outcome)| outcome.error().map(|err| (i, err)))
     }
 
-    /// Returns the total number of mapping results (successes + failures) in the batch.
+    /// Returns an iterator over the outcome of every mapping request in the batch, in order.
+    ///
+    /// Misses reported by the API are distinguished from genuine failures, so pipelines can
+    /// route them differently.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::{JobOutcome, MappingResponses};
+    ///
+    /// let json = r#"[
+    ///     {"data": [{"figi": "BBG000BLNNH6"}]},
+    ///     {"warning": "No identifier found."},
+    ///     {"error": "Invalid idValue format."}
+    /// ]"#;
+    /// let responses: MappingResponses = serde_json::from_str(json)?;
+    ///
+    /// for (index, outcome) in responses.outcomes().enumerate() {
+    ///     match outcome {
+    ///         JobOutcome::Found(data) => println!("{index}: {} FIGIs", data.data().len()),
+    ///         JobOutcome::Empty(_) => println!("{index}: no results"),
+    ///         JobOutcome::NotFound { message } => println!("{index}: miss ({message})"),
+    ///         JobOutcome::Error(err) => eprintln!("{index}: {err}"),
+    ///     }
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn outcomes(&self) -> impl Iterator<Item = &JobOutcome> {
+        self.outcomes.iter()
+    }
+
+    /// Returns an iterator over the mapping requests the API found no instrument for, with
+    /// their indices and the API message.
+    pub fn not_found(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, outcome)| match outcome {
+                JobOutcome::NotFound { message } => Some((i, message.as_str())),
+                _ => None,
+            })
+    }
+
+    /// Returns the total number of mapping results (successes, empty results, misses and
+    /// failures) in the batch.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.outcomes.len()
     }
 
     /// Returns true if there are no mapping results in the batch.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.outcomes.is_empty()
+    }
+
+    /// Returns a reference to the underlying vector of outcomes, preserving order and index.
+    #[must_use]
+    pub fn as_slice(&self) -> &[JobOutcome] {
+        &self.outcomes
+    }
+
+    /// Consumes the batch and returns the underlying vector of outcomes.
+    #[must_use]
+    pub fn into_outcomes(self) -> Vec<JobOutcome> {
+        self.outcomes
+    }
+
+    /// Consumes the batch and returns the outcomes converted to results, see
+    /// [`JobOutcome::into_result`].
+    #[must_use]
+    pub fn into_results(self) -> Vec<Result<MappingData>> {
+        self.outcomes
+            .into_iter()
+            .map(JobOutcome::into_result)
+            .collect()
+    }
+
+    /// Returns the original mapping requests, with the request at each index producing the
+    /// result at the same index.
+    ///
+    /// Requests are retained by [`crate::endpoint::mapping::BulkMappingRequestBuilder::send`] and
+    /// [`crate::client::OpenFIGIClient::retry_failures`], but not when deserializing a response body.
+    #[must_use]
+    pub fn requests(&self) -> Option<&[MappingRequest]> {
+        self.requests.as_deref()
+    }
+
+    /// Returns the default filters merged into every request when the batch was sent, see
+    /// [`crate::endpoint::mapping::BulkMappingRequestBuilder::default_filters`].
+    ///
+    /// The requests returned by [`Self::requests`] do not include them.
+    #[must_use]
+    pub fn default_filters(&self) -> &RequestFilters {
+        &self.default_filters
+    }
+
+    /// Consumes the batch and returns each result paired with the request that produced it.
+    ///
+    /// Returns `None` if the requests were not retained, see [`Self::requests`].
+    #[must_use]
+    pub fn into_results_with_requests(self) -> Option<Vec<(MappingRequest, Result<MappingData>)>> {
+        let requests = self.requests?;
+        Some(
+            requests
+                .into_iter()
+                .zip(self.outcomes.into_iter().map(JobOutcome::into_result))
+                .collect(),
+        )
+    }
+
+    /// Returns aggregate statistics of the batch, e.g. for logging a one-line outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::MappingResponses;
+    ///
+    /// let json = r#"[
+    ///     {"data": [{"figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6"}]},
+    ///     {"warning": "No identifier found."},
+    ///     {"error": "Invalid idValue format."}
+    /// ]"#;
+    /// let responses: MappingResponses = serde_json::from_str(json)?;
+    ///
+    /// let summary = responses.summary();
+    /// assert_eq!(summary.successes, 1);
+    /// assert_eq!(summary.not_found, 1);
+    /// assert_eq!(summary.failure_reasons["Invalid idValue format."], 1);
+    /// println!("{summary}");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> MappingSummary {
+        let mut summary = MappingSummary::default();
+        let mut composites = HashSet::new();
+
+        for outcome in &self.outcomes {
+            match outcome {
+                JobOutcome::Found(data) => {
+                    summary.successes += 1;
+                    summary.total_figis += data.data.len();
+                    composites.extend(
+                        data.data
+                            .iter()
+                            .filter_map(|result| result.composite_figi.as_deref()),
+                    );
+                }
+                JobOutcome::Empty(_) => summary.empty += 1,
+                JobOutcome::NotFound { .. } => summary.not_found += 1,
+                JobOutcome::Error(err) => {
+                    summary.failures += 1;
+                    *summary
+                        .failure_reasons
+                        .entry(failure_reason(err))
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        summary.unique_composites = composites.len();
+        summary
+    }
+
+    /// Returns the data-quality conflicts of the batch for review.
+    ///
+    /// Flags FIGIs returned for different input identifiers, which may indicate duplicate
+    /// positions, and jobs whose results span several share class FIGIs. Jobs are compared by
+    /// the `idType` and `idValue` of their requests if the requests were retained, see
+    /// [`Self::requests`], otherwise every job counts as a different input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::MappingResponses;
+    ///
+    /// let json = r#"[
+    ///     {"data": [{"figi": "BBG000BLNNH6", "shareClassFIGI": "BBG001S5S399"}]},
+    ///     {"data": [{"figi": "BBG000BLNNH6", "shareClassFIGI": "BBG001S5S399"}]}
+    /// ]"#;
+    /// let responses: MappingResponses = serde_json::from_str(json)?;
+    ///
+    /// let report = responses.conflicts();
+    /// assert_eq!(report.duplicate_figis["BBG000BLNNH6"], [0, 1]);
+    /// assert!(report.share_class_conflicts.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn conflicts(&self) -> ConflictReport {
+        let mut report = ConflictReport::default();
+        let mut jobs_by_figi: BTreeMap<&Figi, Vec<usize>> = BTreeMap::new();
+
+        for (index, data) in self.successes() {
+            for figi in data.data.iter().map(|result| &result.figi) {
+                let jobs = jobs_by_figi.entry(figi).or_default();
+                if jobs.last() != Some(&index) {
+                    jobs.push(index);
+                }
+            }
+            let share_classes: BTreeSet<Figi> = data
+                .data
+                .iter()
+                .filter_map(|result| result.share_class_figi.clone())
+                .collect();
+            if share_classes.len() > 1 {
+                report.share_class_conflicts.insert(index, share_classes);
+            }
+        }
+
+        let identifier = |index: usize| {
+            self.requests
+                .as_ref()
+                .and_then(|requests| requests.get(index))
+                .map(|request| (&request.id_type, &request.id_value))
+        };
+        for (figi, jobs) in jobs_by_figi {
+            let mut identifiers = Vec::new();
+            for &index in &jobs {
+                match identifier(index) {
+                    Some(id) if identifiers.contains(&Some(id)) => {}
+                    id => identifiers.push(id),
+                }
+            }
+            if identifiers.len() > 1 {
+                report.duplicate_figis.insert(figi.clone(), jobs);
+            }
+        }
+        report
+    }
+}
+
+/// Returns the API message of a failed job, or the full error description for other errors.
+fn failure_reason(err: &OpenFIGIError) -> String {
+    match err {
+        OpenFIGIError::ResponseError(content) if !content.message.is_empty() => content
+            .message
+            .strip_prefix(API_ERROR_PREFIX)
+            .unwrap_or(&content.message)
+            .to_string(),
+        err => err.to_string(),
+    }
+}
+
+/// Outcome of a single mapping request within [`MappingResponses`].
+///
+/// Distinguishes misses, which the API reports as `{"warning": ...}` or
+/// `{"error": "No identifier found."}`, from genuine failures. Convert to a [`Result`] with
+/// [`Self::into_result`] where the binary success/error model is sufficient.
+#[derive(Debug)]
+pub enum JobOutcome {
+    /// The request matched at least one instrument.
+    Found(MappingData),
+    /// The API answered the request with an empty `data` array, or the client policy discarded
+    /// all of its results.
+    Empty(MappingData),
+    /// The API found no instrument for the request.
+    NotFound {
+        /// The API message, typically `"No identifier found."`.
+        message: String,
+    },
+    /// The request failed, e.g. due to an invalid identifier.
+    Error(OpenFIGIError),
+}
+
+impl JobOutcome {
+    /// Returns the mapping data if the request matched at least one instrument.
+    #[must_use]
+    pub fn found(&self) -> Option<&MappingData> {
+        match self {
+            Self::Found(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the mapping data if the request was answered with data, even if empty.
+    #[must_use]
+    pub fn data(&self) -> Option<&MappingData> {
+        match self {
+            Self::Found(data) | Self::Empty(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns the error if the request failed.
+    #[must_use]
+    pub fn error(&self) -> Option<&OpenFIGIError> {
+        match self {
+            Self::Error(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the typed reason of a miss or failure, or `None` if the request matched.
+    ///
+    /// See [`ReasonCode`] for details.
+    #[must_use]
+    pub fn reason_code(&self) -> Option<ReasonCode> {
+        match self {
+            Self::Found(_) | Self::Empty(_) => None,
+            Self::NotFound { .. } => Some(ReasonCode::NotFound),
+            Self::Error(err) => err.reason_code(),
+        }
+    }
+
+    /// Returns true if the request matched at least one instrument.
+    #[must_use]
+    pub fn is_found(&self) -> bool {
+        matches!(self, Self::Found(_))
+    }
+
+    /// Returns true if the request was answered without results.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty(_))
+    }
+
+    /// Returns true if the API found no instrument for the request.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+
+    /// Returns true if the request failed.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+
+    /// Converts the outcome into a [`Result`], representing a miss as an
+    /// [`OpenFIGIError::ResponseError`] for which [`OpenFIGIError::is_not_found`] returns true.
+    ///
+    /// An empty result is returned as `Ok`, see [`MappingData::require_non_empty`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpenFIGIError`] for [`Self::NotFound`] and [`Self::Error`] outcomes.
+    pub fn into_result(self) -> Result<MappingData> {
+        match self {
+            Self::Found(data) | Self::Empty(data) => Ok(data),
+            Self::NotFound { message } => Err(OpenFIGIError::response_error(
+                reqwest::StatusCode::OK,
+                format!("{API_WARNING_PREFIX}{message}"),
+                String::new(),
+            )),
+            Self::Error(err) => Err(err),
+        }
+    }
+}
+
+impl From<Result<MappingData>> for JobOutcome {
+    fn from(result: Result<MappingData>) -> Self {
+        match result {
+            Ok(data) if data.data.is_empty() => Self::Empty(data),
+            Ok(data) => Self::Found(data),
+            Err(err) => match err.not_found_message() {
+                Some(message) => Self::NotFound {
+                    message: message.to_string(),
+                },
+                None => Self::Error(err),
+            },
+        }
+    }
+}
+
+impl From<JobOutcome> for Result<MappingData> {
+    fn from(outcome: JobOutcome) -> Self {
+        outcome.into_result()
+    }
+}
+
+/// Aggregate statistics of a [`MappingResponses`] batch, created by [`MappingResponses::summary`].
+///
+/// The [`fmt::Display`] implementation renders a single line suitable for logging.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MappingSummary {
+    /// Number of successful jobs.
+    pub successes: usize,
+    /// Number of jobs answered without results.
+    pub empty: usize,
+    /// Number of jobs the API found no instrument for.
+    pub not_found: usize,
+    /// Number of failed jobs.
+    pub failures: usize,
+    /// Number of FIGIs across all successful jobs.
+    pub total_figis: usize,
+    /// Number of distinct composite FIGIs across all successful jobs.
+    pub unique_composites: usize,
+    /// Number of failed jobs per failure reason, e.g. `"Invalid idValue format."`.
+    pub failure_reasons: BTreeMap<String, usize>,
+}
+
+impl fmt::Display for MappingSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} jobs: {} succeeded, {} empty, {} not found, {} failed, {} FIGIs, {} unique composites",
+            self.successes + self.empty + self.not_found + self.failures,
+            self.successes,
+            self.empty,
+            self.not_found,
+            self.failures,
+            self.total_figis,
+            self.unique_composites
+        )?;
+
+        for (i, (reason, count)) in self.failure_reasons.iter().enumerate() {
+            let separator = if i == 0 { " (" } else { ", " };
+            write!(f, "{separator}{count}x {reason}")?;
+        }
+        if !self.failure_reasons.is_empty() {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Data-quality conflicts of a [`MappingResponses`] batch, created by
+/// [`MappingResponses::conflicts`].
+///
+/// Jobs are identified by their index in the batch. The [`fmt::Display`] implementation renders
+/// a single line suitable for logging.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConflictReport {
+    /// Jobs of different input identifiers per FIGI they all returned.
+    pub duplicate_figis: BTreeMap<Figi, Vec<usize>>,
+    /// Distinct share class FIGIs per job whose results span more than one.
+    pub share_class_conflicts: BTreeMap<usize, BTreeSet<Figi>>,
+}
+
+impl ConflictReport {
+    /// Returns true if no conflicts were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.duplicate_figis.is_empty() && self.share_class_conflicts.is_empty()
     }
+}
 
-    /// Returns a reference to the underlying vector of results, preserving order and index.
-    pub fn as_slice(&self) -> &[Result<MappingData>] {
-        &self.0
+impl fmt::Display for ConflictReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} FIGIs returned for different inputs, {} jobs with conflicting share classes",
+            self.duplicate_figis.len(),
+            self.share_class_conflicts.len()
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for MappingResponses {
+    /// Deserializes a raw `/mapping` response body (an array of per-job results).
+    ///
+    /// Jobs carrying an `error` message are converted into [`OpenFIGIError::ResponseError`]
+    /// values with status `200 OK`, matching the status the API uses for batch responses.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = Vec::<ResponseResult<MappingData>>::deserialize(deserializer)?;
+        let results = raw
+            .into_iter()
+            .map(|item| into_result(reqwest::StatusCode::OK, item))
+            .collect();
+        Ok(Self::new(results))
     }
 }
 
@@ -128,10 +645,17 @@ pub struct MappingData {
     /// identifier. The array may contain:
     /// - A single result for unique identifiers
     /// - Multiple results when the identifier matches several instruments
-    /// - An empty array if no matches are found (though this typically results in an error instead)
+    /// - An empty array if no matches are found (though this typically results in an error
+    ///   instead), reported as [`JobOutcome::Empty`] in a batch
     ///
     /// Each FIGI result provides detailed information about the matched financial instrument.
     pub data: Vec<FigiResult>,
+
+    /// Warning the OpenFIGI API attached to the results, e.g. about truncated results.
+    ///
+    /// Logged with `tracing::warn!` when parsed if the `tracing` feature is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 impl MappingData {
@@ -142,12 +666,128 @@ impl MappingData {
     pub fn data(&self) -> &[FigiResult] {
         &self.data
     }
+
+    /// Returns the warning the OpenFIGI API attached to the results, if any.
+    #[must_use]
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
+    /// Returns the mapping data if it contains at least one result.
+    ///
+    /// The API may answer a request with an empty `data` array instead of a miss, which
+    /// otherwise looks like a success without results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpenFIGIError::UnexpectedResultCount`] error if there are no results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::MappingData;
+    ///
+    /// let data: MappingData = serde_json::from_str(r#"{"data": []}"#)?;
+    /// assert!(data.require_non_empty().is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn require_non_empty(self) -> Result<Self> {
+        OpenFIGIError::check_result_count(Some(&(1..=usize::MAX)), self.data.len()).map(|()| self)
+    }
+
+    /// Returns a stable 64-bit hash of the results, for detecting changed mappings.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the number of results followed by the
+    /// [`FigiResult::content_hash`] of each result, all as little-endian `u64`s. It depends on the
+    /// order of the results. The [`Self::warning`] does not contribute.
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = ContentHasher::new();
+        hasher.write_u64(self.data.len() as u64);
+        for result in &self.data {
+            hasher.write_u64(result.content_hash());
+        }
+        hasher.finish()
+    }
+}
+
+/// All listings of a composite FIGI, created by
+/// [`crate::client::OpenFIGIClient::expand_composite`] and
+/// [`crate::client::OpenFIGIClient::share_class_listings`].
+///
+/// The composite FIGI aggregates the venue-level listings of an instrument within one country,
+/// e.g. the `US` composite of the `UN` and `UW` listings.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CompositeExpansion {
+    /// The composite-level record, whose FIGI is its own composite FIGI, if returned.
+    pub composite: Option<FigiResult>,
+    /// The venue-level listings, in response order.
+    pub listings: Vec<FigiResult>,
+}
+
+impl CompositeExpansion {
+    /// Splits the mapping results for the composite FIGI `figi` into its composite-level record
+    /// and venue-level listings.
+    pub(crate) fn new(figi: &str, results: Vec<FigiResult>) -> Self {
+        let mut composite = None;
+        let mut listings = Vec::with_capacity(results.len());
+        for result in results {
+            if composite.is_none() && result.figi == figi {
+                composite = Some(result);
+            } else {
+                listings.push(result);
+            }
+        }
+        Self {
+            composite,
+            listings,
+        }
+    }
+
+    /// Groups results by their composite FIGI, in order of first occurrence.
+    ///
+    /// Results without a composite FIGI form a group of their own.
+    pub(crate) fn group(results: Vec<FigiResult>) -> Vec<Self> {
+        let mut groups: Vec<(Figi, Vec<FigiResult>)> = Vec::new();
+        let mut indices: HashMap<Figi, usize> = HashMap::new();
+        for result in results {
+            let key = result
+                .composite_figi
+                .clone()
+                .unwrap_or_else(|| result.figi.clone());
+            let index = *indices.entry(key.clone()).or_insert_with(|| {
+                groups.push((key, Vec::new()));
+                groups.len() - 1
+            });
+            groups[index].1.push(result);
+        }
+        groups
+            .into_iter()
+            .map(|(figi, results)| Self::new(&figi, results))
+            .collect()
+    }
+}
+
+/// FIGI results of a list of ISINs, created by [`crate::client::OpenFIGIClient::map_isins`].
+#[derive(Debug, Default)]
+pub struct IsinMapping {
+    /// The results of each ISIN the API answered, empty if it knows no instrument for it.
+    pub found: HashMap<String, Vec<FigiResult>>,
+    /// The error of each ISIN that failed validation or whose job the API rejected.
+    ///
+    /// Errors are shared, as a batch that failed as a whole reports its error for each of its
+    /// ISINs.
+    pub failed: HashMap<String, Arc<OpenFIGIError>>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{model::response::common::ResponseResult, test_utils::load_test_data};
+    use crate::test_utils::load_test_data;
+
+    fn figi(value: &str) -> Figi {
+        Figi::new(value).expect("Valid FIGI")
+    }
 
     /// Helper function to convert raw response results into a `MappingResponses` instance
     fn from_response_results(raw: Vec<ResponseResult<MappingData>>) -> MappingResponses {
@@ -160,6 +800,11 @@ mod tests {
                         err.error,
                         String::new(),
                     )),
+                    ResponseResult::Warning(warning) => Err(OpenFIGIError::response_error(
+                        reqwest::StatusCode::OK,
+                        warning.warning,
+                        String::new(),
+                    )),
                 })
                 .collect(),
         )
@@ -176,7 +821,7 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) => {
                 let figi_result = mapping_data.data();
                 assert!(!figi_result.is_empty());
 
@@ -191,16 +836,39 @@ mod tests {
                 assert!(first_entry.has_share_class_figi());
 
                 // Verify actual field values from real data
-                assert_eq!(first_entry.composite_figi, Some("BBG000BLNNH6".to_string()));
+                assert_eq!(first_entry.composite_figi.as_deref(), Some("BBG000BLNNH6"));
                 assert_eq!(
-                    first_entry.share_class_figi,
-                    Some("BBG001S5S399".to_string())
+                    first_entry.share_class_figi.as_deref(),
+                    Some("BBG001S5S399")
                 );
             }
-            Err(e) => panic!("Expected success, got error: {e}"),
+            other => panic!("Expected success, got {other:?}"),
         }
     }
 
+    #[test]
+    fn test_mapping_data_content_hash() {
+        let data: MappingData = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "figi": "BBG000BLNNH6", "exchCode": "US" },
+                { "figi": "BBG000BLNQ16", "exchCode": "UN" }
+            ]
+        }))
+        .expect("Failed to deserialize mapping data");
+
+        let mut warned = data.clone();
+        warned.warning = Some("Too many results".to_string());
+        assert_eq!(warned.content_hash(), data.content_hash());
+
+        let mut reordered = data.clone();
+        reordered.data.reverse();
+        assert_ne!(reordered.content_hash(), data.content_hash());
+
+        let mut truncated = data.clone();
+        truncated.data.pop();
+        assert_ne!(truncated.content_hash(), data.content_hash());
+    }
+
     #[test]
     fn test_deserialize_invalid_identifier() {
         let json_str = load_test_data("mapping", "invalid_identifier.json");
@@ -211,14 +879,136 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(_) => panic!("Expected error, got success"),
-            Err(OpenFIGIError::ResponseError(resp)) => {
+            JobOutcome::Error(OpenFIGIError::ResponseError(resp)) => {
                 assert!(resp.message.contains("Invalid idValue format."));
             }
-            Err(e) => panic!("Unexpected error variant: {e}"),
+            other => panic!("Unexpected outcome: {other:?}"),
         }
     }
 
+    #[test]
+    fn test_outcomes() {
+        let responses: MappingResponses = serde_json::from_value(serde_json::json!([
+            { "data": [{ "figi": "BBG000BLNNH6" }] },
+            { "warning": "No identifier found." },
+            { "error": "No identifier found." },
+            { "error": "Invalid idValue format." }
+        ]))
+        .expect("Failed to deserialize mapping response");
+
+        let outcomes = responses.as_slice();
+        assert!(outcomes[0].is_found());
+        assert!(
+            matches!(&outcomes[1], JobOutcome::NotFound { message } if message == "No identifier found.")
+        );
+        assert!(outcomes[2].is_not_found());
+        assert!(outcomes[3].error().is_some_and(|err| !err.is_not_found()));
+        let codes: Vec<_> = outcomes.iter().map(JobOutcome::reason_code).collect();
+        assert_eq!(
+            codes,
+            [
+                None,
+                Some(ReasonCode::NotFound),
+                Some(ReasonCode::NotFound),
+                Some(ReasonCode::InvalidIdValue)
+            ]
+        );
+
+        let not_found: Vec<_> = responses.not_found().map(|(i, _)| i).collect();
+        assert_eq!(not_found, [1, 2]);
+        assert_eq!(
+            responses.failures().map(|(i, _)| i).collect::<Vec<_>>(),
+            [3]
+        );
+
+        // Conversion to results keeps misses recognizable
+        let results = responses.into_results();
+        assert!(results[1].as_ref().is_err_and(OpenFIGIError::is_not_found));
+        assert!(matches!(
+            JobOutcome::from(results.into_iter().nth(1).expect("four results")),
+            JobOutcome::NotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn test_summary() {
+        let responses: MappingResponses = serde_json::from_value(serde_json::json!([
+            { "data": [
+                { "figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6" },
+                { "figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6" }
+            ] },
+            { "data": [{ "figi": "BBG000B9XRY4", "compositeFIGI": "BBG000B9XRY4" }] },
+            { "data": [] },
+            { "error": "No identifier found." },
+            { "error": "No identifier found." },
+            { "error": "Invalid idValue format." }
+        ]))
+        .expect("Failed to deserialize mapping response");
+
+        let summary = responses.summary();
+        assert_eq!(summary.successes, 2);
+        assert_eq!(summary.empty, 1);
+        assert_eq!(responses.empty().map(|(i, _)| i).collect::<Vec<_>>(), [2]);
+        assert_eq!(summary.not_found, 2);
+        assert_eq!(summary.failures, 1);
+        assert_eq!(summary.total_figis, 3);
+        assert_eq!(summary.unique_composites, 2);
+        assert_eq!(summary.failure_reasons["Invalid idValue format."], 1);
+        assert_eq!(
+            summary.to_string(),
+            "6 jobs: 2 succeeded, 1 empty, 2 not found, 1 failed, 3 FIGIs, 2 unique composites \
+             (1x Invalid idValue format.)"
+        );
+    }
+
+    #[test]
+    fn test_conflicts() {
+        use crate::model::enums::IdType;
+
+        let json = serde_json::json!([
+            { "data": [
+                { "figi": "BBG000BLNNH6", "shareClassFIGI": "BBG001S5S399" },
+                { "figi": "BBG000BLNQ16", "shareClassFIGI": "BBG001S5S399" }
+            ] },
+            { "data": [{ "figi": "BBG000BLNNH6", "shareClassFIGI": "BBG001S5S399" }] },
+            { "data": [{ "figi": "BBG000BLNNH6", "shareClassFIGI": "BBG001S5S399" }] },
+            { "data": [
+                { "figi": "BBG000B9XRY4", "shareClassFIGI": "BBG001S5N8V8" },
+                { "figi": "BBG000B9Y5X2", "shareClassFIGI": "BBG001S6RT82" }
+            ] },
+            { "warning": "No identifier found." }
+        ]);
+        let responses: MappingResponses =
+            serde_json::from_value(json.clone()).expect("Failed to deserialize mapping response");
+
+        let report = responses.conflicts();
+        assert_eq!(report.duplicate_figis.len(), 1);
+        assert_eq!(report.duplicate_figis["BBG000BLNNH6"], [0, 1, 2]);
+        assert_eq!(
+            report.share_class_conflicts[&3],
+            BTreeSet::from([figi("BBG001S5N8V8"), figi("BBG001S6RT82")])
+        );
+        assert_eq!(
+            report.to_string(),
+            "1 FIGIs returned for different inputs, 1 jobs with conflicting share classes"
+        );
+
+        // Jobs of the same identifier are not reported as duplicates of each other
+        let responses = serde_json::from_value::<MappingResponses>(json)
+            .expect("Failed to deserialize mapping response")
+            .with_requests(vec![
+                MappingRequest::new(IdType::TICKER, "IBM"),
+                MappingRequest::new(IdType::TICKER, "IBM"),
+                MappingRequest::new(IdType::TICKER, "IBM"),
+                MappingRequest::new(IdType::TICKER, "AAPL"),
+                MappingRequest::new(IdType::TICKER, "XYZ"),
+            ]);
+        let report = responses.conflicts();
+        assert!(report.duplicate_figis.is_empty());
+        assert!(!report.is_empty());
+        assert!(MappingResponses::new(Vec::new()).conflicts().is_empty());
+    }
+
     #[test]
     fn test_deserialize_bulk_request() {
         let json_str = load_test_data("mapping", "bulk_request.json");
@@ -231,23 +1021,23 @@ mod tests {
         // First result should be IBM success
         let ibm_response_result = &mapping_response.as_slice()[0];
         match ibm_response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) => {
                 let ibm_figi_result = mapping_data.data();
                 assert!(!ibm_figi_result.is_empty());
                 assert_eq!(ibm_figi_result[0].ticker, Some("IBM".to_string()));
             }
-            Err(e) => panic!("Expected IBM success, got error: {e}"),
+            other => panic!("Expected IBM success, got {other:?}"),
         }
 
         // Second result should be AAPL success
         let aapl_response_result = &mapping_response.as_slice()[1];
         match aapl_response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) => {
                 let aapl_figi_result = mapping_data.data();
                 assert!(!aapl_figi_result.is_empty());
                 assert_eq!(aapl_figi_result[0].ticker, Some("AAPL".to_string()));
             }
-            Err(e) => panic!("Expected AAPL success, got error: {e}"),
+            other => panic!("Expected AAPL success, got {other:?}"),
         }
     }
 
@@ -261,7 +1051,7 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) => {
                 let figi_result = mapping_data.data();
                 assert!(!figi_result.is_empty());
 
@@ -271,7 +1061,7 @@ mod tests {
                     assert!(data.ticker.is_some());
                 }
             }
-            Err(e) => panic!("Expected success, got error: {e}"),
+            other => panic!("Expected success, got {other:?}"),
         }
     }
 
@@ -285,7 +1075,7 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) => {
                 let figi_result = mapping_data.data();
                 assert!(!figi_result.is_empty());
 
@@ -295,7 +1085,7 @@ mod tests {
                     assert!(data.market_sector.is_some());
                 }
             }
-            Err(e) => panic!("Expected success, got error: {e}"),
+            other => panic!("Expected success, got {other:?}"),
         }
     }
 
@@ -309,13 +1099,14 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) | JobOutcome::Empty(mapping_data) => {
                 let figi_result = mapping_data.data();
                 for data in figi_result {
                     assert!(!data.figi.is_empty());
                 }
             }
-            Err(e) => {
+            JobOutcome::NotFound { message } => assert!(!message.is_empty()),
+            JobOutcome::Error(e) => {
                 // This could be either success or error depending on the option data
                 assert!(!e.to_string().is_empty());
             }
@@ -332,24 +1123,66 @@ mod tests {
         assert_eq!(mapping_response.len(), 1);
         let response_result = &mapping_response.as_slice()[0];
         match response_result {
-            Ok(mapping_data) => {
+            JobOutcome::Found(mapping_data) | JobOutcome::Empty(mapping_data) => {
                 let figi_result = mapping_data.data();
                 for data in figi_result {
                     assert!(!data.figi.is_empty());
                 }
             }
-            Err(e) => {
+            JobOutcome::NotFound { message } => assert!(!message.is_empty()),
+            JobOutcome::Error(e) => {
                 // This could be either success or error depending on the currency data
                 assert!(!e.to_string().is_empty());
             }
         }
     }
 
+    #[test]
+    fn test_deserialize_mapping_responses_directly() {
+        let json_str = load_test_data("mapping", "bulk_request.json");
+        let mapping_response: MappingResponses =
+            serde_json::from_str(&json_str).expect("Failed to deserialize mapping response");
+
+        assert_eq!(mapping_response.len(), 2);
+        assert_eq!(mapping_response.successes().count(), 2);
+
+        let json_str = load_test_data("mapping", "invalid_identifier.json");
+        let mapping_response: MappingResponses =
+            serde_json::from_str(&json_str).expect("Failed to deserialize mapping response");
+        let (index, err) = mapping_response
+            .failures()
+            .next()
+            .expect("Should contain a failure");
+        assert_eq!(index, 0);
+        assert!(err.to_string().contains("Invalid idValue format."));
+    }
+
+    #[test]
+    fn test_deserialize_warnings() {
+        let json = r#"[
+            {"data": [{"figi": "BBG000BLNNH6"}], "warning": "Results were truncated."},
+            {"data": [{"figi": "BBG000BLNQ16"}]},
+            {"warning": "No identifier found."}
+        ]"#;
+        let responses: MappingResponses =
+            serde_json::from_str(json).expect("Failed to deserialize mapping response");
+
+        let warnings: Vec<_> = responses.warnings().collect();
+        assert_eq!(warnings, vec![(0, "Results were truncated.")]);
+        assert_eq!(responses.not_found().count(), 1);
+        assert_eq!(
+            responses.as_slice()[1]
+                .found()
+                .and_then(MappingData::warning),
+            None
+        );
+    }
+
     #[test]
     fn test_figi_result_display_name_fallback() {
         // Test with only ticker
         let figi_with_ticker = FigiResult {
-            figi: "BBG000BLNNH6".to_string(),
+            figi: figi("BBG000BLNNH6"),
             name: None,
             ticker: Some("IBM".to_string()),
             security_type: None,
@@ -360,12 +1193,13 @@ mod tests {
             security_type2: None,
             security_description: None,
             metadata: None,
+            null_fields: Vec::new(),
         };
         assert_eq!(figi_with_ticker.display_name(), "IBM");
 
         // Test with only FIGI
         let figi_only = FigiResult {
-            figi: "BBG000BLNNH6".to_string(),
+            figi: figi("BBG000BLNNH6"),
             name: None,
             ticker: None,
             security_type: None,
@@ -376,6 +1210,7 @@ mod tests {
             security_type2: None,
             security_description: None,
             metadata: None,
+            null_fields: Vec::new(),
         };
         assert_eq!(figi_only.display_name(), "BBG000BLNNH6");
     }
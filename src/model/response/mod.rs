@@ -34,13 +34,23 @@
 
 mod common;
 pub(crate) use self::common::ResponseResult;
+pub use self::common::{FieldPresence, FigiField, FigiResult};
+
+mod figi;
+pub use self::figi::Figi;
 
 mod mapping_response;
+pub use self::mapping_response::CompositeExpansion;
+pub use self::mapping_response::ConflictReport;
+pub use self::mapping_response::IsinMapping;
+pub use self::mapping_response::JobOutcome;
 pub use self::mapping_response::MappingData;
 pub use self::mapping_response::MappingResponses;
+pub use self::mapping_response::MappingSummary;
 
 mod search_response;
 pub use self::search_response::SearchData;
 
 mod filter_response;
 pub use self::filter_response::FilterData;
+pub(crate) use self::filter_response::page_count;
@@ -33,6 +33,7 @@
 //! Note: This module is not intended for direct use by consumers of the OpenFIGI API.
 
 use crate::model::response::common::FigiResult;
+use crate::name::name_similarity;
 use serde::{Deserialize, Serialize};
 
 /// Successful search result containing FIGI data and optional pagination information.
@@ -68,6 +69,12 @@ pub struct SearchData {
     /// additional results. When `None`, this indicates the last page of results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next: Option<String>,
+
+    /// Warning the OpenFIGI API attached to the results, e.g. about truncated results.
+    ///
+    /// Logged with `tracing::warn!` when parsed if the `tracing` feature is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 impl SearchData {
@@ -79,6 +86,12 @@ impl SearchData {
         &self.data
     }
 
+    /// Returns the warning the OpenFIGI API attached to the results, if any.
+    #[must_use]
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
     /// Returns the pagination token for retrieving the next page of search results.
     ///
     /// Returns `Some(token)` if more search results are available, `None` if this is the last page.
@@ -87,6 +100,44 @@ impl SearchData {
     pub fn next_page(&self) -> Option<&str> {
         self.next.as_deref()
     }
+
+    /// Returns the results whose name matches `candidate`, best match first.
+    ///
+    /// Each result is paired with the [`name_similarity`] of its name to `candidate`, e.g. the
+    /// name of an instrument in an internal security master. Results without a name or without
+    /// any shared word are omitted, and results with equal scores keep their response order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openfigi_rs::model::response::SearchData;
+    ///
+    /// let search_data: SearchData = serde_json::from_str(
+    ///     r#"{"data": [
+    ///         {"figi": "BBG000000016", "name": "PINEAPPLE EXPRESS INC"},
+    ///         {"figi": "BBG000B9XRY4", "name": "APPLE INC"}
+    ///     ]}"#,
+    /// )?;
+    ///
+    /// let matches = search_data.match_name("Apple Inc.");
+    /// assert_eq!(matches.len(), 1);
+    /// assert_eq!(matches[0].0.name.as_deref(), Some("APPLE INC"));
+    /// assert_eq!(matches[0].1, 1.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn match_name(&self, candidate: &str) -> Vec<(&FigiResult, f64)> {
+        let mut matches: Vec<_> = self
+            .data
+            .iter()
+            .filter_map(|result| {
+                let score = name_similarity(result.name.as_deref()?, candidate);
+                (score > 0.0).then_some((result, score))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+        matches
+    }
 }
 
 #[cfg(test)]
@@ -105,7 +156,7 @@ mod tests {
 
         let search_data = match search_response {
             ResponseResult::Success(ref data) => data,
-            ResponseResult::Error(ref err) => panic!("Expected success, got error: {err:?}"),
+            ref other => panic!("Expected success, got {other:?}"),
         };
         let figi_result = search_data.data();
         assert!(!figi_result.is_empty());
@@ -121,7 +172,7 @@ mod tests {
         assert!(!first_entry.has_share_class_figi());
 
         // Verify actual field values from real data
-        assert_eq!(first_entry.composite_figi, Some("BBG0002ZTPP5".to_string()));
+        assert_eq!(first_entry.composite_figi.as_deref(), Some("BBG0002ZTPP5"));
         assert_eq!(first_entry.share_class_figi, None);
 
         // Verify pagination exists
@@ -136,8 +187,19 @@ mod tests {
 
         let figi_result = match search_response {
             ResponseResult::Success(ref data) => data.data(),
-            ResponseResult::Error(ref err) => panic!("Expected success, got error: {err:?}"),
+            ref other => panic!("Expected success, got {other:?}"),
         };
         assert!(figi_result.is_empty());
     }
+
+    #[test]
+    fn test_deserialize_warning() {
+        let json = r#"{"data": [], "warning": "Query is deprecated."}"#;
+        let search_data: SearchData =
+            serde_json::from_str(json).expect("Failed to deserialize search response");
+        assert_eq!(search_data.warning(), Some("Query is deprecated."));
+
+        let json = serde_json::to_string(&search_data).expect("Failed to serialize");
+        assert!(json.contains("\"warning\":\"Query is deprecated.\""));
+    }
 }
@@ -0,0 +1,173 @@
+//! # Name Normalization
+//!
+//! Normalization and fuzzy matching of instrument names, for linking OpenFIGI names to the names
+//! of an internal security master.
+//!
+//! [`normalize_name`](crate::name::normalize_name) applies Unicode NFKC normalization and case
+//! folding, removes punctuation, and strips trailing legal form suffixes such as `INC`, `PLC`
+//! or `AG`. [`name_similarity`](crate::name::name_similarity) scores two names by the overlap of
+//! their normalized words, and
+//! [`SearchData::match_name`](crate::model::response::SearchData::match_name) ranks search
+//! results by the similarity of their names to a candidate.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::name::{name_similarity, normalize_name};
+//!
+//! assert_eq!(normalize_name("International Business Machines Corp."), "international business machines");
+//! assert_eq!(normalize_name("ＳＩＥＭＥＮＳ AG"), "siemens");
+//! assert_eq!(name_similarity("Nestlé S.A.", "NESTLÉ SA-REG"), 0.5);
+//! ```
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Legal form suffixes stripped from the end of normalized names.
+const LEGAL_SUFFIXES: &[&str] = &[
+    "ab",
+    "ag",
+    "as",
+    "asa",
+    "bhd",
+    "bv",
+    "co",
+    "company",
+    "corp",
+    "corporation",
+    "gmbh",
+    "inc",
+    "incorporated",
+    "kg",
+    "kgaa",
+    "kk",
+    "limited",
+    "llc",
+    "lp",
+    "ltd",
+    "nv",
+    "oyj",
+    "plc",
+    "pte",
+    "pty",
+    "sa",
+    "sab",
+    "sas",
+    "se",
+    "spa",
+    "tbk",
+];
+
+/// Returns the normalized form of an instrument or company name.
+///
+/// The name is normalized to Unicode NFKC and case folded, periods and apostrophes are
+/// removed, other punctuation is replaced by spaces, and runs of whitespace are collapsed.
+/// Trailing legal form suffixes like `INC`, `CORP`, `PLC`, `AG` or `S.A.` are stripped, as
+/// long as at least one word remains.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::name::normalize_name;
+///
+/// assert_eq!(normalize_name("Apple Inc."), "apple");
+/// assert_eq!(normalize_name("TOYOTA MOTOR CO LTD"), "toyota motor");
+/// assert_eq!(normalize_name("Straße & Co. KGaA"), "strasse");
+/// assert_eq!(normalize_name("AG"), "ag");
+/// ```
+#[must_use]
+pub fn normalize_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.nfkc().flat_map(char::to_lowercase) {
+        match c {
+            '.' | '\'' | '’' => {}
+            'ß' => folded.push_str("ss"),
+            'ς' => folded.push('σ'),
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+    while words.len() > 1
+        && words
+            .last()
+            .is_some_and(|word| LEGAL_SUFFIXES.contains(word))
+    {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+/// Returns the similarity of two names between `0.0` and `1.0`.
+///
+/// Both names are normalized with [`normalize_name`]. Identical normalized names score `1.0`,
+/// otherwise the score is the Dice coefficient of their sets of words: twice the number of
+/// shared words divided by the total number of distinct words in both names.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::name::name_similarity;
+///
+/// assert_eq!(name_similarity("Alphabet Inc.", "ALPHABET INC-CL A"), 0.4);
+/// assert_eq!(name_similarity("Apple Inc.", "Microsoft Corp"), 0.0);
+/// ```
+#[must_use]
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let a = normalize_name(a);
+    let b = normalize_name(b);
+    if a == b {
+        return if a.is_empty() { 0.0 } else { 1.0 };
+    }
+
+    let mut a_words: Vec<&str> = a.split_whitespace().collect();
+    let mut b_words: Vec<&str> = b.split_whitespace().collect();
+    a_words.sort_unstable();
+    a_words.dedup();
+    b_words.sort_unstable();
+    b_words.dedup();
+    let shared = a_words
+        .iter()
+        .filter(|word| b_words.binary_search(word).is_ok())
+        .count();
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "word counts of names are far below 2^52"
+    )]
+    let score = (2 * shared) as f64 / (a_words.len() + b_words.len()) as f64;
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("  Siemens   AG "), "siemens");
+        assert_eq!(normalize_name("HSBC Holdings PLC"), "hsbc holdings");
+        assert_eq!(normalize_name("Nestlé S.A."), "nestlé");
+        assert_eq!(normalize_name("NESTLE\u{301} SA"), "nestlé");
+        assert_eq!(normalize_name("McDonald's Corp"), "mcdonalds");
+        assert_eq!(normalize_name("ＳＯＮＹ ＧＲＯＵＰ ＣＯＲＰ"), "sony group");
+        assert_eq!(normalize_name("AT&T INC"), "at t");
+        assert_eq!(normalize_name("Inc."), "inc");
+        assert_eq!(normalize_name(""), "");
+    }
+
+    #[test]
+    #[expect(
+        clippy::float_cmp,
+        reason = "scores are exact ratios of small integers"
+    )]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("Siemens AG", "SIEMENS AG-REG"), 0.5);
+        assert_eq!(
+            name_similarity("Siemens AG", "Siemens Aktiengesellschaft"),
+            2.0 / 3.0
+        );
+        assert_eq!(name_similarity("", ""), 0.0);
+        assert_eq!(name_similarity("Apple Inc", "APPLE INC"), 1.0);
+    }
+}
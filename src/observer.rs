@@ -0,0 +1,227 @@
+//! # Request Observers
+//!
+//! Callbacks for every request sent through a client, e.g. to feed Prometheus counters and
+//! histograms with the success rate and latency of mappings without wrapping every call site.
+//!
+//! A [`RequestObserver`](crate::observer::RequestObserver) registered with
+//! [`OpenFIGIClientBuilder::observer`](crate::client_builder::OpenFIGIClientBuilder::observer)
+//! is notified before each request and once it completes, with the endpoint, the method and the
+//! number of jobs of the request in a [`RequestInfo`](crate::observer::RequestInfo). Each page of
+//! a paginated lookup and each batch of a bulk mapping is a request of its own. Observers run on
+//! the task sending the request, so they should return quickly.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::error::OpenFIGIError;
+//! use openfigi_rs::exports::StatusCode;
+//! use openfigi_rs::observer::{RequestInfo, RequestObserver};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::time::Duration;
+//!
+//! #[derive(Default)]
+//! struct Metrics {
+//!     succeeded: AtomicU64,
+//!     failed: AtomicU64,
+//! }
+//!
+//! impl RequestObserver for Metrics {
+//!     fn on_response(&self, request: &RequestInfo, status: StatusCode, elapsed: Duration) {
+//!         self.succeeded.fetch_add(1, Ordering::Relaxed);
+//!         println!("{} {status} in {elapsed:?}", request.endpoint);
+//!     }
+//!
+//!     fn on_error(&self, _request: &RequestInfo, _error: &OpenFIGIError, _elapsed: Duration) {
+//!         self.failed.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! let client = OpenFIGIClient::builder()
+//!     .observer(Metrics::default())
+//!     .build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::error::OpenFIGIError;
+use reqwest::{Method, StatusCode};
+use std::{fmt, sync::Arc, time::Duration};
+
+/// A request passed to the callbacks of a [`RequestObserver`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestInfo {
+    /// Endpoint path of the request relative to the base URL, e.g. `mapping`.
+    pub endpoint: String,
+    /// HTTP method of the request.
+    pub method: Method,
+    /// Number of jobs in the body of the request, `1` for a single query and `0` without a body.
+    pub batch_size: usize,
+}
+
+impl RequestInfo {
+    pub(crate) fn new(endpoint: &str, method: &Method, batch_size: usize) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            method: method.clone(),
+            batch_size,
+        }
+    }
+}
+
+/// Callbacks notified about every request sent through a client.
+///
+/// All callbacks do nothing by default, so implementations only override the ones they need.
+/// A request is answered either by [`Self::on_response`], with the status of any response
+/// including error responses, or by [`Self::on_error`] if no response was received or the
+/// request was refused before sending it, e.g. by a paused client or an exhausted budget.
+pub trait RequestObserver: Send + Sync {
+    /// Called before the request is sent.
+    fn on_request(&self, request: &RequestInfo) {
+        let _ = request;
+    }
+
+    /// Called once a response was received, `elapsed` after [`Self::on_request`].
+    fn on_response(&self, request: &RequestInfo, status: StatusCode, elapsed: Duration) {
+        let _ = (request, status, elapsed);
+    }
+
+    /// Called if the request failed without a response, `elapsed` after [`Self::on_request`].
+    fn on_error(&self, request: &RequestInfo, error: &OpenFIGIError, elapsed: Duration) {
+        let _ = (request, error, elapsed);
+    }
+}
+
+/// The observers registered on a client, notified in order of registration.
+#[derive(Clone, Default)]
+pub(crate) struct RequestObservers {
+    observers: Vec<Arc<dyn RequestObserver>>,
+}
+
+impl RequestObservers {
+    pub(crate) fn push(&mut self, observer: impl RequestObserver + 'static) {
+        self.observers.push(Arc::new(observer));
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    pub(crate) fn on_request(&self, request: &RequestInfo) {
+        for observer in &self.observers {
+            observer.on_request(request);
+        }
+    }
+
+    pub(crate) fn on_response(&self, request: &RequestInfo, status: StatusCode, elapsed: Duration) {
+        for observer in &self.observers {
+            observer.on_response(request, status, elapsed);
+        }
+    }
+
+    pub(crate) fn on_error(&self, request: &RequestInfo, error: &OpenFIGIError, elapsed: Duration) {
+        for observer in &self.observers {
+            observer.on_error(request, error, elapsed);
+        }
+    }
+}
+
+impl fmt::Debug for RequestObservers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestObservers")
+            .field("len", &self.observers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client_builder;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl RequestObserver for Arc<Recorder> {
+        fn on_request(&self, request: &RequestInfo) {
+            self.record(format!(
+                "request {} {}",
+                request.endpoint, request.batch_size
+            ));
+        }
+
+        fn on_response(&self, request: &RequestInfo, status: StatusCode, _elapsed: Duration) {
+            self.record(format!("response {} {}", request.endpoint, status.as_u16()));
+        }
+
+        fn on_error(&self, request: &RequestInfo, error: &OpenFIGIError, _elapsed: Duration) {
+            self.record(format!(
+                "error {} {}",
+                request.endpoint,
+                error.is_client_paused()
+            ));
+        }
+    }
+
+    impl Recorder {
+        fn record(&self, event: String) {
+            self.events
+                .lock()
+                .expect("Lock should not be poisoned")
+                .push(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observers_are_notified() {
+        use crate::model::{enums::IdType, request::MappingRequest};
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [{ "figi": "BBG000BLNNH6" }] },
+                { "warning": "No identifier found." },
+            ])))
+            .mount(&server)
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let client = mock_client_builder(&server)
+            .observer(Arc::clone(&recorder))
+            .build()
+            .expect("Failed to create test client");
+        client
+            .bulk_mapping()
+            .add_request(MappingRequest::new(IdType::ID_ISIN, "US4592001014"))
+            .add_request(MappingRequest::new(IdType::ID_ISIN, "US0000000002"))
+            .send()
+            .await
+            .expect("Bulk request should succeed");
+        client.pause();
+        client
+            .search("ibm")
+            .send()
+            .await
+            .expect_err("Paused client should refuse requests");
+
+        let events = recorder.events.lock().expect("Lock should not be poisoned");
+        assert_eq!(
+            *events,
+            [
+                "request mapping 2",
+                "response mapping 200",
+                "request search 1",
+                "error search true",
+            ]
+        );
+    }
+}
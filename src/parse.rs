@@ -0,0 +1,257 @@
+//! # Response Parsing
+//!
+//! Parse-only functions for OpenFIGI API response bodies that were obtained without
+//! [`crate::client::OpenFIGIClient`], for example archived payloads or responses received
+//! through another HTTP stack.
+//!
+//! The functions return the same types as the corresponding `send()` methods and apply the
+//! same handling: byte order marks are stripped, invalid UTF-8 is decoded lossily, HTML pages
+//! are reported as [`crate::error::OpenFIGIError::UnexpectedContentType`], and a top-level
+//! `{"error": "..."}` or `{"warning": "..."}` body is converted into a
+//! [`crate::error::OpenFIGIError::ResponseError`].
+//!
+//! Responses received with the `send_raw()` methods of the request builders are parsed with
+//! [`OpenFIGIClient::parse_single_response`](crate::client::OpenFIGIClient::parse_single_response)
+//! and [`OpenFIGIClient::parse_list_response`](crate::client::OpenFIGIClient::parse_list_response)
+//! instead, which also map error statuses and capture headers like the `send()` methods.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::parse::parse_mapping_response;
+//!
+//! let body = br#"[{"data": [{"figi": "BBG000BLNNH6"}]}, {"error": "Invalid idValue format."}]"#;
+//! let responses = parse_mapping_response(body)?;
+//!
+//! assert_eq!(responses.len(), 2);
+//! assert_eq!(responses.successes().count(), 1);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    error::{OpenFIGIError, Result},
+    model::response::{FilterData, MappingResponses, ResponseResult, SearchData},
+};
+use encoding_rs::{Encoding, UTF_8};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+/// Parses a raw `/mapping` response body.
+///
+/// # Errors
+///
+/// Returns an [`OpenFIGIError`] if the body is not a valid mapping response. Errors for
+/// individual mapping jobs are reported within the returned [`MappingResponses`].
+pub fn parse_mapping_response(body: &[u8]) -> Result<MappingResponses> {
+    let responses: MappingResponses = parse_json(StatusCode::OK, "", body)?;
+    for (_, warning) in responses.warnings() {
+        log_warning(warning);
+    }
+    Ok(responses)
+}
+
+/// Parses a raw `/search` response body.
+///
+/// # Errors
+///
+/// Returns an [`OpenFIGIError`] if the body is not a valid search response or if it
+/// contains an error message.
+pub fn parse_search_response(body: &[u8]) -> Result<SearchData> {
+    let data: SearchData = into_result(StatusCode::OK, parse_json(StatusCode::OK, "", body)?)?;
+    if let Some(warning) = data.warning() {
+        log_warning(warning);
+    }
+    Ok(data)
+}
+
+/// Parses a raw `/filter` response body.
+///
+/// # Errors
+///
+/// Returns an [`OpenFIGIError`] if the body is not a valid filter response or if it
+/// contains an error message.
+pub fn parse_filter_response(body: &[u8]) -> Result<FilterData> {
+    let data: FilterData = into_result(StatusCode::OK, parse_json(StatusCode::OK, "", body)?)?;
+    if let Some(warning) = data.warning() {
+        log_warning(warning);
+    }
+    Ok(data)
+}
+
+/// Prefix of the message of errors created from per-job `error` entries.
+pub(crate) const API_ERROR_PREFIX: &str = "OpenFIGI API error: ";
+
+/// Prefix of the message of errors created from per-job `warning` entries.
+pub(crate) const API_WARNING_PREFIX: &str = "OpenFIGI API warning: ";
+
+/// Message the OpenFIGI API reports for identifiers without a match.
+pub(crate) const NOT_FOUND_MESSAGE: &str = "No identifier found.";
+
+/// Converts a parsed response into a result, mapping an error payload to an `OpenFIGIError`.
+pub(crate) fn into_result<T>(status: StatusCode, response: ResponseResult<T>) -> Result<T> {
+    match response {
+        ResponseResult::Success(data) => Ok(data),
+        ResponseResult::Error(err) => Err(OpenFIGIError::response_error(
+            status,
+            format!("{API_ERROR_PREFIX}{}", err.error),
+            String::new(),
+        )),
+        ResponseResult::Warning(warning) => {
+            if warning.warning != NOT_FOUND_MESSAGE {
+                log_warning(&warning.warning);
+            }
+            Err(OpenFIGIError::response_error(
+                status,
+                format!("{API_WARNING_PREFIX}{}", warning.warning),
+                String::new(),
+            ))
+        }
+    }
+}
+
+/// Logs a warning returned by the OpenFIGI API if the `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+pub(crate) fn log_warning(warning: &str) {
+    tracing::warn!(warning, "OpenFIGI API returned a warning");
+}
+
+/// Logs a warning returned by the OpenFIGI API if the `tracing` feature is enabled.
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn log_warning(_warning: &str) {}
+
+/// Decodes and deserializes a JSON response body.
+///
+/// The body is decoded with [`decode_body`], so byte order marks and invalid byte sequences
+/// do not cause an opaque decode error. HTML bodies, as well as bodies with a non-JSON
+/// content type that fail to parse, result in an `UnexpectedContentType` error. If a lossily
+/// decoded body cannot be parsed, a `ResponseError` carrying the decoded body is returned
+/// instead of the bare deserialization error.
+///
+/// Runs in a `tracing` span with the size of the body if the `tracing` feature is enabled.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "openfigi.parse",
+        level = "debug",
+        skip_all,
+        fields(status = status.as_u16(), bytes = body.len()),
+        err(level = "debug")
+    )
+)]
+pub(crate) fn parse_json<T: DeserializeOwned>(
+    status: StatusCode,
+    content_type: &str,
+    body: &[u8],
+) -> Result<T> {
+    let (text, lossy) = decode_body(content_type, body);
+
+    // Detect proxy login portals and gateway pages before attempting to parse
+    if is_html(content_type, &text) {
+        return Err(OpenFIGIError::unexpected_content_type(
+            status,
+            content_type,
+            &text,
+        ));
+    }
+
+    serde_json::from_str(&text).map_err(|err| {
+        if !content_type.is_empty() && !content_type.to_ascii_lowercase().contains("json") {
+            OpenFIGIError::unexpected_content_type(status, content_type, &text)
+        } else if lossy {
+            OpenFIGIError::response_error(
+                status,
+                format!(
+                    "Failed to parse response body that is not valid in its declared encoding: {err}"
+                ),
+                text,
+            )
+            .with_lossy_body(true)
+        } else {
+            OpenFIGIError::from(err)
+        }
+    })
+}
+
+/// Decodes a response body to text.
+///
+/// Uses the charset declared in the `Content-Type` value, falling back to UTF-8.
+/// A leading byte order mark takes precedence over the declared charset and is
+/// stripped. Invalid byte sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// # Returns
+///
+/// Returns the decoded body and whether any byte sequences had to be replaced.
+pub(crate) fn decode_body(content_type: &str, body: &[u8]) -> (String, bool) {
+    let encoding = content_type
+        .split(';')
+        .filter_map(|param| param.trim().split_once('='))
+        .find(|(name, _)| name.eq_ignore_ascii_case("charset"))
+        .and_then(|(_, charset)| Encoding::for_label(charset.trim_matches('"').as_bytes()))
+        .unwrap_or(UTF_8);
+
+    let (text, _, lossy) = encoding.decode(body);
+    (text.into_owned(), lossy)
+}
+
+/// Returns true if the body is an HTML page, judged by content type or body.
+pub(crate) fn is_html(content_type: &str, body: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+    if content_type.contains("html") {
+        return true;
+    }
+
+    // Some intermediaries omit or mislabel the content type of their error pages
+    let start = body.trim_start().as_bytes();
+    let starts_with = |prefix: &[u8]| {
+        start
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    };
+    !content_type.contains("json") && (starts_with(b"<!doctype html") || starts_with(b"<html"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::load_test_data;
+
+    #[test]
+    fn test_parse_search_response() {
+        let body = load_test_data("search", "query_example.json");
+        let data = parse_search_response(body.as_bytes()).expect("Should parse search response");
+        assert!(!data.data().is_empty());
+    }
+
+    #[test]
+    fn test_parse_filter_response_with_bom() {
+        let mut body = b"\xEF\xBB\xBF".to_vec();
+        body.extend_from_slice(br#"{"data": [{"figi": "BBG000BLNNH6"}], "total": 1}"#);
+        let data = parse_filter_response(&body).expect("Should parse filter response");
+        assert_eq!(data.total, Some(1));
+    }
+
+    #[test]
+    fn test_parse_search_response_error_payload() {
+        let err = parse_search_response(br#"{"error": "Invalid query."}"#)
+            .expect_err("Error payload should be an error");
+        assert!(err.to_string().contains("Invalid query."));
+    }
+
+    #[test]
+    fn test_parse_mapping_response_html() {
+        let err = parse_mapping_response(b"<html><body>Login required</body></html>")
+            .expect_err("HTML should be rejected");
+        assert!(err.is_unexpected_content_type());
+    }
+
+    #[test]
+    fn test_decode_body_declared_charset() {
+        let (text, lossy) = decode_body("text/plain; charset=ISO-8859-1", b"f\xFCr");
+        assert_eq!(text, "für");
+        assert!(!lossy);
+
+        let (text, lossy) = decode_body("", b"f\xFCr");
+        assert_eq!(text, "f\u{FFFD}r");
+        assert!(lossy);
+    }
+}
@@ -0,0 +1,282 @@
+//! # Result Compliance Policies
+//!
+//! Declarative rules discarding instruments from API responses, e.g. to keep instruments listed
+//! on sanctioned exchanges out of downstream systems.
+//!
+//! A [`Policy`](crate::policy::Policy) configured on a client via [`crate::client_builder::OpenFIGIClientBuilder::policy`]
+//! is applied to the results of every request sent through the mapping, search and filter
+//! endpoint builders, including all pages of a [`crate::endpoint::pagination::FilterPaginator`].
+//! Every discarded instrument is reported to the audit sink registered with
+//! [`Policy::on_audit`](crate::policy::Policy::on_audit) as an
+//! [`AuditRecord`](crate::policy::AuditRecord).
+//!
+//! ## Matching
+//!
+//! - **Exchange codes** are matched against the `exchCode` of each result.
+//! - **MIC codes** are translated to the exchange code of the venue using the bundled
+//!   MIC to exchange code mapping, then matched against the `exchCode` of each result. Composite
+//!   exchange codes spanning several venues are not matched.
+//! - **State codes** are not part of the results, so all results of a request filtering on a
+//!   denied `stateCode` are discarded.
+//!
+//! Result counts reported by the API, such as [`crate::model::response::FilterData::total`],
+//! are left unchanged.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::{ExchCode, MicCode};
+//! use openfigi_rs::policy::{AuditRecord, Policy};
+//!
+//! let policy = Policy::new()
+//!     .deny_exch_codes(["RX".parse::<ExchCode>()?])
+//!     .deny_mic_codes(["MISX".parse::<MicCode>()?])
+//!     .on_audit(|record: &AuditRecord| {
+//!         eprintln!("discarded {}: {}", record.result.figi, record.violation);
+//!     });
+//!
+//! let client = OpenFIGIClient::builder().policy(policy).build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::model::{
+    enums::{ExchCode, MIC_EXCH_CODES, MicCode, StateCode},
+    request::RequestFilters,
+    response::FigiResult,
+};
+use std::{fmt, sync::Arc};
+
+/// A receiver of [`AuditRecord`]s for instruments discarded by a [`Policy`].
+///
+/// Implemented for every thread-safe closure or function taking `&AuditRecord`.
+pub trait AuditSink: Fn(&AuditRecord) + Send + Sync {}
+
+impl<F> AuditSink for F where F: Fn(&AuditRecord) + Send + Sync {}
+
+/// The rule of a [`Policy`] an instrument violated.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PolicyViolation {
+    /// The instrument is listed on a denied exchange.
+    ExchCode(ExchCode),
+    /// The instrument is listed on the exchange of a denied MIC.
+    MicCode(MicCode),
+    /// The request filtered on a denied state.
+    StateCode(StateCode),
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExchCode(code) => write!(f, "denied exchange code '{code}'"),
+            Self::MicCode(code) => write!(f, "denied MIC code '{code}'"),
+            Self::StateCode(code) => write!(f, "denied state code '{code}'"),
+        }
+    }
+}
+
+/// Record of an instrument discarded by a [`Policy`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct AuditRecord {
+    /// The discarded instrument.
+    pub result: FigiResult,
+    /// The rule the instrument violated.
+    pub violation: PolicyViolation,
+}
+
+/// A set of compliance rules applied to API results, cheap to clone.
+///
+/// See the [module documentation](crate::policy) for how the rules are matched.
+#[derive(Clone, Default)]
+pub struct Policy {
+    exch_codes: Vec<ExchCode>,
+    mic_codes: Vec<MicCode>,
+    state_codes: Vec<StateCode>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+}
+
+impl Policy {
+    /// Creates a policy without any rules, which keeps all results.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards instruments listed on any of the given exchanges.
+    #[must_use]
+    pub fn deny_exch_codes(mut self, codes: impl IntoIterator<Item = ExchCode>) -> Self {
+        self.exch_codes.extend(codes);
+        self
+    }
+
+    /// Discards instruments listed on the exchanges of any of the given MICs.
+    #[must_use]
+    pub fn deny_mic_codes(mut self, codes: impl IntoIterator<Item = MicCode>) -> Self {
+        self.mic_codes.extend(codes);
+        self
+    }
+
+    /// Discards all results of requests filtering on any of the given states.
+    #[must_use]
+    pub fn deny_state_codes(mut self, codes: impl IntoIterator<Item = StateCode>) -> Self {
+        self.state_codes.extend(codes);
+        self
+    }
+
+    /// Sets the sink receiving an [`AuditRecord`] for every discarded instrument.
+    #[must_use]
+    pub fn on_audit(mut self, sink: impl AuditSink + 'static) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Returns `true` if the policy has no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.exch_codes.is_empty() && self.mic_codes.is_empty() && self.state_codes.is_empty()
+    }
+
+    /// Returns the rule violated by `result` of a request with the given `filters`, if any.
+    #[must_use]
+    pub fn check(&self, filters: &RequestFilters, result: &FigiResult) -> Option<PolicyViolation> {
+        if let Some(state_code) = &filters.state_code
+            && self.state_codes.contains(state_code)
+        {
+            return Some(PolicyViolation::StateCode(*state_code));
+        }
+
+        let exch_code = result.exch_code.as_ref()?.as_str();
+        if let Some(code) = self
+            .exch_codes
+            .iter()
+            .find(|code| code.as_str() == exch_code)
+        {
+            return Some(PolicyViolation::ExchCode(code.clone()));
+        }
+        self.mic_codes
+            .iter()
+            .find(|code| MIC_EXCH_CODES.get(code.as_str()) == Some(&exch_code))
+            .map(|code| PolicyViolation::MicCode(code.clone()))
+    }
+
+    /// Removes all results violating the policy and reports them to the audit sink.
+    ///
+    /// Returns the number of removed results.
+    pub fn apply(&self, filters: &RequestFilters, results: &mut Vec<FigiResult>) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let before = results.len();
+        results.retain(|result| match self.check(filters, result) {
+            Some(violation) => {
+                if let Some(sink) = &self.audit_sink {
+                    sink(&AuditRecord {
+                        result: result.clone(),
+                        violation,
+                    });
+                }
+                false
+            }
+            None => true,
+        });
+        before - results.len()
+    }
+}
+
+impl fmt::Debug for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Policy")
+            .field("exch_codes", &self.exch_codes)
+            .field("mic_codes", &self.mic_codes)
+            .field("state_codes", &self.state_codes)
+            .field("audit_sink", &self.audit_sink.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    const A: &str = "BBG000000016";
+    const B: &str = "BBG000000025";
+    const C: &str = "BBG000000034";
+
+    fn result(figi: &str, exch_code: &str) -> FigiResult {
+        serde_json::from_value(serde_json::json!({ "figi": figi, "exchCode": exch_code }))
+            .expect("valid FIGI result")
+    }
+
+    #[test]
+    fn test_empty_policy_keeps_everything() {
+        let mut results = vec![result(A, "US")];
+        assert_eq!(
+            Policy::new().apply(&RequestFilters::default(), &mut results),
+            0
+        );
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_deny_exch_and_mic_codes() {
+        let policy = Policy::new()
+            .deny_exch_codes([ExchCode::US])
+            .deny_mic_codes([MicCode::XNYS]);
+        let mut results = vec![result(A, "US"), result(B, "UN"), result(C, "UW")];
+
+        let filters = RequestFilters::default();
+        assert_eq!(
+            policy.check(&filters, &results[0]),
+            Some(PolicyViolation::ExchCode(ExchCode::US))
+        );
+        assert_eq!(
+            policy.check(&filters, &results[1]),
+            Some(PolicyViolation::MicCode(MicCode::XNYS))
+        );
+        assert_eq!(policy.apply(&filters, &mut results), 2);
+        assert_eq!(results[0].figi, C);
+    }
+
+    #[test]
+    fn test_deny_state_codes_discards_all_results() {
+        let state_code: StateCode = "NY".parse().expect("known state code");
+        let policy = Policy::new().deny_state_codes([state_code]);
+        let mut results = vec![result(A, "US"), result(B, "UN")];
+
+        assert_eq!(policy.apply(&RequestFilters::default(), &mut results), 0);
+
+        let filters = RequestFilters {
+            state_code: Some(state_code),
+            ..RequestFilters::default()
+        };
+        assert_eq!(policy.apply(&filters, &mut results), 2);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_audit_records() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&records);
+        let policy =
+            Policy::new()
+                .deny_exch_codes([ExchCode::US])
+                .on_audit(move |record: &AuditRecord| {
+                    sink.lock().unwrap().push(record.clone());
+                });
+
+        let mut results = vec![result(A, "US"), result(B, "UW")];
+        policy.apply(&RequestFilters::default(), &mut results);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].result.figi, A);
+        assert_eq!(
+            records[0].violation.to_string(),
+            "denied exchange code 'US'"
+        );
+    }
+}
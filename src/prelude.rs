@@ -0,0 +1,46 @@
+//! # Prelude
+//!
+//! Re-exports the types and traits needed by typical programs, so a single glob import replaces
+//! the nested module paths:
+//!
+//! ```rust,no_run
+//! use openfigi_rs::prelude::*;
+//!
+//! # async fn example() -> Result<()> {
+//! let client = OpenFIGIClient::new();
+//!
+//! let mapping = client
+//!     .mapping(IdType::ID_ISIN, "US4592001014")
+//!     .currency(Currency::USD)
+//!     .exch_code(ExchCode::US)
+//!     .send()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The glob import brings the [`Result`](crate::error::Result) alias into scope, which shadows
+//! [`std::result::Result`]. Programs mixing both can import the prelude items they need by name.
+
+pub use crate::{
+    client::OpenFIGIClient,
+    client_builder::OpenFIGIClientBuilder,
+    endpoint::{
+        FilterableRequestBuilder, Sendable,
+        filter::SingleFilterRequestBuilder,
+        mapping::{BulkMappingRequestBuilder, SingleMappingRequestBuilder},
+        pagination::{FilterPaginator, ResumableDrain},
+        search::SingleSearchRequestBuilder,
+    },
+    error::{OpenFIGIError, Result},
+    model::{
+        enums::{
+            Currency, ExchCode, IdType, MarketSecDesc, MicCode, OptionType, SecurityType,
+            SecurityType2, StateCode,
+        },
+        request::{
+            FilterField, FilterRequest, HasFilters, MappingRequest, RequestFilters, SearchRequest,
+        },
+        response::{FigiResult, FilterData, MappingData, MappingResponses, SearchData},
+    },
+};
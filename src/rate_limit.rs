@@ -0,0 +1,810 @@
+//! # Rate Limiting
+//!
+//! Client-side rate limiting, delaying requests so that batch jobs stay within the request rate
+//! limits of the OpenFIGI API instead of running into `429 Too Many Requests` errors.
+//!
+//! A [`RateLimitPolicy`](crate::rate_limit::RateLimitPolicy) attached to a client tracks the
+//! requests sent through it in sliding windows, and delays a request until every window it
+//! counts against has room. Concurrent requests queue up behind each other. Each page of a
+//! paginated lookup and each batch of a bulk mapping counts as a request, while responses served
+//! from a replay store do not.
+//!
+//! [`RateLimitPolicy::auto`](crate::rate_limit::RateLimitPolicy::auto) applies the published
+//! limits per endpoint, depending on whether the client has an API key:
+//!
+//! | Limitation              | Without API Key   | With API Key         |
+//! | ----------------------- | ----------------- | -------------------- |
+//! | `/mapping`              | 25 per 60 seconds | 25 per 6 seconds     |
+//! | `/search` and `/filter` | 5 per 60 seconds  | 20 per 60 seconds    |
+//! | Across all endpoints    | -                 | 250,000 per 24 hours |
+//!
+//! Independently of any policy, every client captures the rate limit state announced by the API
+//! in the `ratelimit-policy`, `ratelimit-remaining` and `ratelimit-reset` headers of its last
+//! response as a [`RateLimitStatus`](crate::rate_limit::RateLimitStatus), available via
+//! [`OpenFIGIClient::rate_limit_status`](crate::client::OpenFIGIClient::rate_limit_status), so
+//! callers can throttle proactively instead of learning about the limits from `429` errors.
+//!
+//! The windows of a policy live in memory, so a process starting right after a previous run
+//! would otherwise burst past limits the previous run already used up. With
+//! [`RateLimitPolicy::persist`](crate::rate_limit::RateLimitPolicy::persist), a policy restores
+//! the send times still inside its windows from a file on first use, and saves them when the
+//! last clone of the policy is dropped or on
+//! [`RateLimitPolicy::save`](crate::rate_limit::RateLimitPolicy::save), e.g. for frequent
+//! cron-style jobs sharing an API key.
+//!
+//! Delays use a runtime independent timer, so rate limited clients work on any executor. If the
+//! `tracing` feature is enabled, every delayed request emits a `tracing::debug!` event with the
+//! `endpoint` and the `delay`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::rate_limit::RateLimitPolicy;
+//! use std::time::Duration;
+//!
+//! // The published limits of the API
+//! let client = OpenFIGIClient::builder()
+//!     .rate_limit(RateLimitPolicy::auto())
+//!     .build()?;
+//!
+//! // Custom limits, e.g. a share of the limits of an API key used by several jobs
+//! let policy = RateLimitPolicy::new()
+//!     .endpoint_limit("mapping", 10, Duration::from_secs(6))
+//!     .limit(100_000, Duration::from_hours(24));
+//! let client = OpenFIGIClient::builder().rate_limit(policy).build()?;
+//!
+//! // Limits carried over between runs of a job
+//! let path = std::env::temp_dir().join("openfigi-rate-limit.json");
+//! let policy = RateLimitPolicy::auto().persist(path);
+//! let client = OpenFIGIClient::builder().rate_limit(policy).build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    DEFAULT_ENDPOINT_FILTER, DEFAULT_ENDPOINT_MAPPING, DEFAULT_ENDPOINT_SEARCH,
+    error::{OpenFIGIError, Result},
+};
+use futures_timer::Delay;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Published limits applied by [`RateLimitPolicy::auto`], as the endpoint, the number of
+/// requests without and with an API key, and the period without and with an API key.
+const ENDPOINT_LIMITS: [(&str, usize, usize, Duration, Duration); 3] = [
+    (
+        DEFAULT_ENDPOINT_MAPPING,
+        25,
+        25,
+        Duration::from_mins(1),
+        Duration::from_secs(6),
+    ),
+    (
+        DEFAULT_ENDPOINT_SEARCH,
+        5,
+        20,
+        Duration::from_mins(1),
+        Duration::from_mins(1),
+    ),
+    (
+        DEFAULT_ENDPOINT_FILTER,
+        5,
+        20,
+        Duration::from_mins(1),
+        Duration::from_mins(1),
+    ),
+];
+
+/// A limit on the number of requests in a sliding window.
+#[derive(Debug)]
+struct Window {
+    /// Endpoint the limit applies to, or `None` for all endpoints.
+    endpoint: Option<String>,
+    max: usize,
+    period: Duration,
+    /// Send times of the requests in the current window, oldest first.
+    sent: VecDeque<Instant>,
+}
+
+impl Window {
+    fn new(endpoint: Option<&str>, max: usize, period: Duration) -> Self {
+        Self {
+            endpoint: endpoint.map(str::to_string),
+            max: max.max(1),
+            period,
+            sent: VecDeque::new(),
+        }
+    }
+
+    /// Returns true if requests to `path` count against this window.
+    ///
+    /// Subpaths belong to their endpoint, e.g. `mapping/values/idType` to `mapping`.
+    fn applies_to(&self, path: &str) -> bool {
+        self.endpoint.as_deref().is_none_or(|endpoint| {
+            path.strip_prefix(endpoint)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+
+    /// Returns how long a request at `now` has to wait for room in this window.
+    fn delay_at(&mut self, now: Instant) -> Duration {
+        while self
+            .sent
+            .front()
+            .is_some_and(|&sent| sent + self.period <= now)
+        {
+            self.sent.pop_front();
+        }
+        match self.sent.front() {
+            Some(&oldest) if self.sent.len() >= self.max => {
+                (oldest + self.period).saturating_duration_since(now)
+            }
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// The send times of a [`Window`] as saved to a file, in milliseconds since the Unix epoch.
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedWindow {
+    endpoint: Option<String>,
+    period_ms: u64,
+    sent: Vec<u64>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the published limits are installed once the client is known.
+    auto: bool,
+    windows: Vec<Window>,
+    /// File the send times are restored from and saved to.
+    persist: Option<PathBuf>,
+    /// Whether the send times were restored from [`Self::persist`].
+    restored: bool,
+}
+
+impl State {
+    /// Restores the send times from the persisted file on first use, once the limits are known.
+    fn restore(&mut self, now: Instant) {
+        if self.restored || self.auto {
+            return;
+        }
+        self.restored = true;
+        let Some(path) = self.persist.as_deref() else {
+            return;
+        };
+        let saved = match load(path) {
+            Ok(saved) => saved,
+            Err(e) => {
+                log_persist_error("restore", &e);
+                return;
+            }
+        };
+        let system_now = unix_millis(SystemTime::now());
+        for saved in saved {
+            let period = Duration::from_millis(saved.period_ms);
+            for window in self
+                .windows
+                .iter_mut()
+                .filter(|w| w.endpoint == saved.endpoint && w.period == period)
+            {
+                let restored = saved.sent.iter().filter_map(|&sent| {
+                    let age = Duration::from_millis(system_now.saturating_sub(sent));
+                    (age < period).then(|| now.checked_sub(age)).flatten()
+                });
+                window.sent.extend(restored);
+                window.sent.make_contiguous().sort_unstable();
+            }
+        }
+    }
+
+    /// Writes the send times still inside their windows to the persisted file, if any.
+    fn save(&self, now: Instant) -> Result<()> {
+        let Some(path) = self.persist.as_deref() else {
+            return Ok(());
+        };
+        let system_now = SystemTime::now();
+        let saved: Vec<SavedWindow> = self
+            .windows
+            .iter()
+            .map(|window| SavedWindow {
+                endpoint: window.endpoint.clone(),
+                period_ms: duration_millis(window.period),
+                sent: window
+                    .sent
+                    .iter()
+                    .filter(|&&sent| sent + window.period > now)
+                    .map(|&sent| unix_millis(system_now - now.saturating_duration_since(sent)))
+                    .collect(),
+            })
+            .collect();
+        write_atomically(path, &serde_json::to_vec(&saved)?)
+    }
+}
+
+impl Drop for State {
+    /// Saves the send times, unless they were never restored, e.g. because the policy was not
+    /// used, which would replace the saved history with empty windows.
+    fn drop(&mut self) {
+        if !self.restored {
+            return;
+        }
+        if let Err(e) = self.save(Instant::now()) {
+            log_persist_error("save", &e);
+        }
+    }
+}
+
+/// Reads the saved windows from `path`, or none if the file does not exist.
+fn load(path: &Path) -> Result<Vec<SavedWindow>> {
+    match fs::read(path) {
+        Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Logs a failure to restore or save the persisted state if the `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+fn log_persist_error(operation: &str, error: &OpenFIGIError) {
+    tracing::warn!(operation, %error, "Failed to persist OpenFIGI rate limit state");
+}
+
+/// Logs a failure to restore or save the persisted state if the `tracing` feature is enabled.
+#[cfg(not(feature = "tracing"))]
+fn log_persist_error(_operation: &str, _error: &OpenFIGIError) {}
+
+/// Writes `contents` to a temporary file next to `path` and renames it, so concurrent readers
+/// never see a partially written file.
+///
+/// The temporary file is unique per process and call, so concurrent writers do not overwrite
+/// each other's temporary files.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<()> {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+    let write = WRITES.fetch_add(1, Ordering::Relaxed);
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(format!(".{}.{write}.tmp", std::process::id()));
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)?;
+    Ok(())
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    duration_millis(time.duration_since(UNIX_EPOCH).unwrap_or_default())
+}
+
+fn duration_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Shared limits on the request rate of clients, per endpoint and across all endpoints.
+///
+/// Enable rate limiting for a client via
+/// [`OpenFIGIClient::with_rate_limit`](crate::client::OpenFIGIClient::with_rate_limit) or
+/// [`crate::client_builder::OpenFIGIClientBuilder::rate_limit`]. Clones share the same windows,
+/// so one policy can limit several clients sharing an API key together. A policy without limits
+/// never delays requests.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitPolicy {
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimitPolicy {
+    /// Creates a new policy without limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy applying the published limits of the OpenFIGI API.
+    ///
+    /// The limits are chosen when the policy is attached to a client, depending on whether the
+    /// client has an API key, see [`crate::rate_limit`]. Limits added to the policy apply in
+    /// addition to them.
+    #[must_use]
+    pub fn auto() -> Self {
+        let policy = Self::new();
+        policy.lock().auto = true;
+        policy
+    }
+
+    /// Creates a policy with the published limits for clients without an API key.
+    #[must_use]
+    pub fn unauthenticated() -> Self {
+        ENDPOINT_LIMITS
+            .into_iter()
+            .fold(Self::new(), |policy, (endpoint, max, _, period, _)| {
+                policy.endpoint_limit(endpoint, max, period)
+            })
+    }
+
+    /// Creates a policy with the published limits for clients with an API key.
+    #[must_use]
+    pub fn authenticated() -> Self {
+        ENDPOINT_LIMITS
+            .into_iter()
+            .fold(Self::new(), |policy, (endpoint, _, max, _, period)| {
+                policy.endpoint_limit(endpoint, max, period)
+            })
+            .limit(250_000, Duration::from_hours(24))
+    }
+
+    /// Limits the requests to all endpoints together to `max` per `period`.
+    ///
+    /// A `max` of zero is treated as one.
+    #[must_use]
+    pub fn limit(self, max: usize, period: Duration) -> Self {
+        self.lock().windows.push(Window::new(None, max, period));
+        self
+    }
+
+    /// Limits the requests to `endpoint`, e.g. `"mapping"`, to `max` per `period`.
+    ///
+    /// Requests to subpaths of `endpoint`, like `mapping/values/idType`, count against the
+    /// limit as well. A `max` of zero is treated as one.
+    #[must_use]
+    pub fn endpoint_limit(self, endpoint: &str, max: usize, period: Duration) -> Self {
+        let endpoint = endpoint.trim_matches('/');
+        self.lock()
+            .windows
+            .push(Window::new(Some(endpoint), max, period));
+        self
+    }
+
+    /// Persists the send times of the policy in the file at `path` across restarts.
+    ///
+    /// The send times still inside a window are restored from the file when the policy is first
+    /// used, and saved to it when the last clone of the policy is dropped or on [`Self::save`]. A
+    /// policy dropped without being used leaves the file unchanged.
+    /// Saved windows apply to limits of the same endpoint and period, so changed limits start
+    /// empty. A missing file starts all windows empty, like an unreadable one, which is logged
+    /// with `tracing::warn!` if the `tracing` feature is enabled.
+    #[must_use]
+    pub fn persist(self, path: impl Into<PathBuf>) -> Self {
+        self.lock().persist = Some(path.into());
+        self
+    }
+
+    /// Saves the send times to the file set with [`Self::persist`], e.g. before a process exits
+    /// without dropping the policy.
+    ///
+    /// Does nothing if the policy is not persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`crate::error::OpenFIGIError`] if the file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.restore(now);
+        state.save(now)
+    }
+
+    /// Returns how long a request to `endpoint` sent now would be delayed.
+    #[must_use]
+    pub fn delay(&self, endpoint: &str) -> Duration {
+        self.delay_at(endpoint, Instant::now())
+    }
+
+    /// Installs the published limits if the policy was created with [`Self::auto`].
+    pub(crate) fn resolve(&self, authenticated: bool) {
+        let mut state = self.lock();
+        if !std::mem::take(&mut state.auto) {
+            return;
+        }
+        let preset = if authenticated {
+            Self::authenticated()
+        } else {
+            Self::unauthenticated()
+        };
+        let windows = std::mem::take(&mut preset.lock().windows);
+        state.windows.extend(windows);
+    }
+
+    /// Waits until every window limiting `path` has room, then counts the request against them.
+    pub(crate) async fn acquire(&self, path: &str) {
+        loop {
+            let delay = self.try_acquire_at(path, Instant::now());
+            if delay.is_zero() {
+                return;
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(endpoint = path, ?delay, "OpenFIGI request rate limited");
+            Delay::new(delay).await;
+        }
+    }
+
+    /// Counts a request to `path` at `now` and returns zero, or returns how long to wait.
+    fn try_acquire_at(&self, path: &str, now: Instant) -> Duration {
+        let path = path.trim_matches('/');
+        let mut state = self.lock();
+        state.restore(now);
+        let delay = Self::max_delay(&mut state, path, now);
+        if delay.is_zero() {
+            for window in state.windows.iter_mut().filter(|w| w.applies_to(path)) {
+                window.sent.push_back(now);
+            }
+        }
+        delay
+    }
+
+    fn delay_at(&self, path: &str, now: Instant) -> Duration {
+        let mut state = self.lock();
+        state.restore(now);
+        Self::max_delay(&mut state, path.trim_matches('/'), now)
+    }
+
+    fn max_delay(state: &mut State, path: &str, now: Instant) -> Duration {
+        state
+            .windows
+            .iter_mut()
+            .filter(|window| window.applies_to(path))
+            .map(|window| window.delay_at(now))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The rate limit state announced by the OpenFIGI API in the headers of a response.
+///
+/// Captured from every response received by a client, see
+/// [`OpenFIGIClient::rate_limit_status`](crate::client::OpenFIGIClient::rate_limit_status).
+/// Headers missing from the response, or with values in another format, are `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    policy: Option<String>,
+    remaining: Option<u64>,
+    reset: Option<Duration>,
+    received_at: Instant,
+}
+
+impl RateLimitStatus {
+    /// Returns the policy from the `ratelimit-policy` header, e.g. `25;w=6`.
+    #[must_use]
+    pub fn policy(&self) -> Option<&str> {
+        self.policy.as_deref()
+    }
+
+    /// Returns the number of requests remaining in the current window from the
+    /// `ratelimit-remaining` header.
+    #[must_use]
+    pub fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Returns the time until the current window resets from the `ratelimit-reset` header, as
+    /// announced when the response was received.
+    #[must_use]
+    pub fn reset(&self) -> Option<Duration> {
+        self.reset
+    }
+
+    /// Returns the instant the current window resets.
+    #[must_use]
+    pub fn reset_at(&self) -> Option<Instant> {
+        self.reset.map(|reset| self.received_at + reset)
+    }
+
+    /// Returns true if no requests remain and the window has not reset yet.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.is_exhausted_at(Instant::now())
+    }
+
+    fn is_exhausted_at(&self, now: Instant) -> bool {
+        self.remaining == Some(0) && self.reset_at().is_none_or(|reset_at| reset_at > now)
+    }
+
+    /// Parses the rate limit headers of a response, or returns `None` if it has none.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name| headers.get(name)?.to_str().ok().map(str::trim);
+        let status = Self {
+            policy: header("ratelimit-policy").map(str::to_string),
+            remaining: header("ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset: header("ratelimit-reset")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs),
+            received_at: Instant::now(),
+        };
+        (status.policy.is_some() || status.remaining.is_some() || status.reset.is_some())
+            .then_some(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, mock_client_builder};
+
+    #[test]
+    fn test_window_delays_until_oldest_request_expires() {
+        let policy = RateLimitPolicy::new().endpoint_limit("mapping", 2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert_eq!(policy.try_acquire_at("mapping", start), Duration::ZERO);
+        let later = start + Duration::from_secs(4);
+        assert_eq!(policy.try_acquire_at("mapping", later), Duration::ZERO);
+        assert_eq!(
+            policy.try_acquire_at("mapping", later),
+            Duration::from_secs(6)
+        );
+        assert_eq!(policy.try_acquire_at("search", later), Duration::ZERO);
+        assert_eq!(
+            policy.try_acquire_at("mapping", start + Duration::from_secs(10)),
+            Duration::ZERO
+        );
+        assert_eq!(
+            policy.delay_at("mapping", start + Duration::from_secs(10)),
+            Duration::from_secs(4)
+        );
+    }
+
+    #[test]
+    fn test_limits_apply_to_subpaths_and_all_endpoints() {
+        let policy = RateLimitPolicy::new()
+            .endpoint_limit("/mapping/", 1, Duration::from_secs(10))
+            .limit(2, Duration::from_mins(1));
+        let now = Instant::now();
+
+        assert_eq!(
+            policy.try_acquire_at("mapping/values/idType", now),
+            Duration::ZERO
+        );
+        assert_eq!(
+            policy.try_acquire_at("mapping", now),
+            Duration::from_secs(10)
+        );
+        assert_eq!(
+            policy.try_acquire_at("/mapping", now),
+            Duration::from_secs(10)
+        );
+        assert_eq!(policy.delay_at("mappings", now), Duration::ZERO);
+        assert_eq!(policy.try_acquire_at("filter", now), Duration::ZERO);
+        assert_eq!(policy.delay_at("search", now), Duration::from_mins(1));
+    }
+
+    #[test]
+    fn test_auto_resolves_once() {
+        let policy = RateLimitPolicy::auto();
+        assert_eq!(policy.lock().windows.len(), 0);
+
+        policy.resolve(true);
+        policy.resolve(false);
+        assert_eq!(policy.lock().windows.len(), ENDPOINT_LIMITS.len() + 1);
+        assert_eq!(RateLimitPolicy::new().delay("mapping"), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_presets_limit_endpoints_separately() {
+        let now = Instant::now();
+        let exhaust = |policy: &RateLimitPolicy, endpoint: &str, max: usize| {
+            for _ in 0..max {
+                assert_eq!(policy.try_acquire_at(endpoint, now), Duration::ZERO);
+            }
+            policy.delay_at(endpoint, now)
+        };
+
+        let unauthenticated = RateLimitPolicy::unauthenticated();
+        assert_eq!(
+            exhaust(&unauthenticated, "search", 5),
+            Duration::from_mins(1)
+        );
+        assert_eq!(
+            exhaust(&unauthenticated, "filter", 5),
+            Duration::from_mins(1)
+        );
+        assert_eq!(
+            exhaust(&unauthenticated, "mapping", 25),
+            Duration::from_mins(1)
+        );
+
+        let authenticated = RateLimitPolicy::authenticated();
+        assert_eq!(
+            exhaust(&authenticated, "search", 20),
+            Duration::from_mins(1)
+        );
+        assert_eq!(
+            exhaust(&authenticated, "mapping", 25),
+            Duration::from_secs(6)
+        );
+    }
+
+    #[test]
+    fn test_persisted_windows_survive_restarts() {
+        let path =
+            std::env::temp_dir().join(format!("openfigi-rate-limit-{}.json", std::process::id()));
+        let policy = || {
+            RateLimitPolicy::new()
+                .endpoint_limit("mapping", 2, Duration::from_mins(1))
+                .limit(100, Duration::from_secs(1))
+                .persist(&path)
+        };
+
+        let first = policy();
+        assert_eq!(
+            first.try_acquire_at("mapping", Instant::now()),
+            Duration::ZERO
+        );
+        assert_eq!(
+            first.try_acquire_at("mapping", Instant::now()),
+            Duration::ZERO
+        );
+        drop(first);
+
+        let restarted = policy();
+        assert!(restarted.delay("mapping") > Duration::from_secs(50));
+        assert_eq!(restarted.delay("search"), Duration::ZERO);
+        restarted.save().expect("State should be saved");
+        drop(restarted);
+
+        let changed = RateLimitPolicy::new()
+            .endpoint_limit("mapping", 2, Duration::from_secs(30))
+            .persist(&path);
+        assert_eq!(changed.delay("mapping"), Duration::ZERO);
+        drop(changed);
+        std::fs::remove_file(&path).expect("State file should be removed");
+    }
+
+    #[test]
+    fn test_unused_policies_keep_persisted_windows() {
+        let path = std::env::temp_dir().join(format!(
+            "openfigi-rate-limit-unused-{}.json",
+            std::process::id()
+        ));
+        let policy = RateLimitPolicy::new()
+            .endpoint_limit("mapping", 1, Duration::from_mins(1))
+            .persist(&path);
+        assert_eq!(
+            policy.try_acquire_at("mapping", Instant::now()),
+            Duration::ZERO
+        );
+        drop(policy);
+        let saved = std::fs::read(&path).expect("State should be saved");
+
+        // Neither a policy that was never used nor an unresolved automatic policy overwrite it
+        drop(
+            RateLimitPolicy::new()
+                .endpoint_limit("mapping", 1, Duration::from_mins(1))
+                .persist(&path),
+        );
+        drop(RateLimitPolicy::auto().persist(&path));
+        assert_eq!(
+            std::fs::read(&path).expect("State should still exist"),
+            saved
+        );
+        std::fs::remove_file(&path).expect("State file should be removed");
+    }
+
+    #[test]
+    fn test_concurrent_saves_to_the_same_file() {
+        let path = std::env::temp_dir().join(format!(
+            "openfigi-rate-limit-concurrent-{}.json",
+            std::process::id()
+        ));
+        let policies: Vec<_> = (0..4)
+            .map(|_| {
+                RateLimitPolicy::new()
+                    .endpoint_limit("mapping", 10, Duration::from_mins(1))
+                    .persist(&path)
+            })
+            .collect();
+        std::thread::scope(|scope| {
+            for policy in &policies {
+                scope.spawn(|| {
+                    for _ in 0..20 {
+                        policy.save().expect("State should be saved");
+                    }
+                });
+            }
+        });
+        drop(policies);
+        std::fs::remove_file(&path).expect("State file should be removed");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_requests_are_delayed() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": []})))
+            .mount(&server)
+            .await;
+        let client = mock_client_builder(&server)
+            .rate_limit(RateLimitPolicy::new().endpoint_limit(
+                "search",
+                2,
+                Duration::from_millis(300),
+            ))
+            .build()
+            .expect("Client build should succeed");
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            client
+                .search("ibm")
+                .send()
+                .await
+                .expect("Search should succeed");
+        }
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_rate_limit_status_from_headers() {
+        use reqwest::header::HeaderValue;
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimitStatus::from_headers(&headers), None);
+
+        headers.insert("ratelimit-policy", HeaderValue::from_static("25;w=6"));
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("ratelimit-reset", HeaderValue::from_static("4"));
+        let status = RateLimitStatus::from_headers(&headers).expect("Should parse headers");
+        assert_eq!(status.policy(), Some("25;w=6"));
+        assert_eq!(status.remaining(), Some(0));
+        assert_eq!(status.reset(), Some(Duration::from_secs(4)));
+        assert!(status.is_exhausted());
+        assert!(!status.is_exhausted_at(status.received_at + Duration::from_secs(4)));
+
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("many"));
+        let status = RateLimitStatus::from_headers(&headers).expect("Should parse headers");
+        assert_eq!(status.remaining(), None);
+        assert!(!status.is_exhausted());
+    }
+
+    #[tokio::test]
+    async fn test_client_captures_rate_limit_status() {
+        use serde_json::json;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ratelimit-policy", "25;w=6")
+                    .insert_header("ratelimit-remaining", "24")
+                    .insert_header("ratelimit-reset", "6")
+                    .set_body_json(json!({"data": []})),
+            )
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+        assert_eq!(client.rate_limit_status(), None);
+
+        client
+            .clone()
+            .search("ibm")
+            .send()
+            .await
+            .expect("Search should succeed");
+        let status = client
+            .rate_limit_status()
+            .expect("Status should be captured by clones");
+        assert_eq!(status.remaining(), Some(24));
+        assert_eq!(status.policy(), Some("25;w=6"));
+    }
+}
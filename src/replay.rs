@@ -0,0 +1,313 @@
+//! # Response Replay
+//!
+//! Record and replay of API responses, giving research backtests deterministic symbology
+//! resolution.
+//!
+//! A client in [`ReplayMode::Record`](crate::replay::ReplayMode::Record) sends its requests as
+//! usual and stores every response in a [`ReplayStore`](crate::replay::ReplayStore), keyed by
+//! the canonical request: the HTTP method, the path relative to the base URL, and the JSON body
+//! with sorted keys. A later session in [`ReplayMode::Replay`](crate::replay::ReplayMode::Replay)
+//! serves every request from the store without network traffic, and fails with
+//! [`OpenFIGIError::ReplayMiss`](crate::error::OpenFIGIError::ReplayMiss) for requests that were
+//! not recorded. A later recording of the same request replaces the earlier one.
+//!
+//! Every entry records when it was received, and [`ReplayStore::window`](crate::replay::ReplayStore::window)
+//! reports the time window a session was recorded in. Stores serialize to JSON, so they can be
+//! saved alongside the backtest results.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::IdType;
+//! use openfigi_rs::replay::{ReplayMode, ReplayStore};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Record a session
+//! let store = ReplayStore::new();
+//! let client = OpenFIGIClient::new().with_replay(ReplayMode::Record(store.clone()));
+//! client.mapping(IdType::TICKER, "IBM").send().await?;
+//! let saved = serde_json::to_string(&store)?;
+//!
+//! // Replay it later
+//! let store: ReplayStore = serde_json::from_str(&saved)?;
+//! let client = OpenFIGIClient::new().with_replay(ReplayMode::Replay(store));
+//! let data = client.mapping(IdType::TICKER, "IBM").send().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{OpenFIGIError, OtherErrorKind, Result};
+use chrono::{DateTime, Utc};
+use reqwest::{Method, ResponseBuilderExt, StatusCode, header::CONTENT_TYPE};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+use url::Url;
+
+/// Whether a client records responses into, or replays responses from, a [`ReplayStore`].
+#[derive(Clone, Debug)]
+pub enum ReplayMode {
+    /// Send requests as usual and record every response.
+    Record(ReplayStore),
+    /// Serve every request from the store, failing on requests that were not recorded.
+    Replay(ReplayStore),
+}
+
+/// A recorded response.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The `Content-Type` header, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// The response body.
+    pub body: String,
+    /// When the response was received.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// A shared store of recorded responses, keyed by canonical request.
+///
+/// Attach a store to a client via
+/// [`OpenFIGIClient::with_replay`](crate::client::OpenFIGIClient::with_replay) or
+/// [`crate::client_builder::OpenFIGIClientBuilder::replay`]. Clones share the same underlying
+/// storage. The store serializes as a JSON object mapping request keys to responses.
+#[derive(Clone, Debug, Default)]
+pub struct ReplayStore {
+    entries: Arc<Mutex<BTreeMap<String, RecordedResponse>>>,
+}
+
+impl ReplayStore {
+    /// Creates a new, empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of recorded responses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no responses were recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the recorded response for the request `key`, if any.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<RecordedResponse> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Returns the keys of all recorded requests, in sorted order.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        self.lock().keys().cloned().collect()
+    }
+
+    /// Returns the times of the earliest and latest recorded responses, or `None` if the store
+    /// is empty.
+    #[must_use]
+    pub fn window(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let entries = self.lock();
+        let times = entries.values().map(|entry| entry.recorded_at);
+        Some((times.clone().min()?, times.max()?))
+    }
+
+    /// Removes all recorded responses.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the canonical key of a request, e.g. `POST mapping [{"idType":"TICKER","idValue":"IBM"}]`.
+    pub(crate) fn key(method: &Method, path: &str, body: Option<&serde_json::Value>) -> String {
+        match body {
+            Some(body) => format!("{method} {path} {body}"),
+            None => format!("{method} {path}"),
+        }
+    }
+
+    /// Reads the body of `response`, records it under `key`, and returns an equivalent response
+    /// with the same URL.
+    pub(crate) async fn record(
+        &self,
+        key: String,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let url = response.url().clone();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let recorded = RecordedResponse {
+            status: response.status().as_u16(),
+            content_type,
+            body: String::from_utf8_lossy(&response.bytes().await?).into_owned(),
+            recorded_at: Utc::now(),
+        };
+        let replayed = replayed_response(&recorded, url)?;
+        self.lock().insert(key, recorded);
+        Ok(replayed)
+    }
+
+    /// Returns the recorded response for `key`, as if received from `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenFIGIError::ReplayMiss`] if the request was not recorded.
+    pub(crate) fn replay(&self, key: &str, url: Url) -> Result<reqwest::Response> {
+        let recorded = self.get(key).ok_or_else(|| OpenFIGIError::ReplayMiss {
+            request: key.to_string(),
+        })?;
+        replayed_response(&recorded, url)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, RecordedResponse>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Serialize for ReplayStore {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.lock().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReplayStore {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let entries = BTreeMap::deserialize(deserializer)?;
+        Ok(Self {
+            entries: Arc::new(Mutex::new(entries)),
+        })
+    }
+}
+
+/// Builds a response from a recorded entry, received from `url`.
+fn replayed_response(recorded: &RecordedResponse, url: Url) -> Result<reqwest::Response> {
+    let mut response = http::Response::builder()
+        .status(StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::OK))
+        .url(url)
+        .body(recorded.body.clone())
+        .map_err(|error| OpenFIGIError::other_error(OtherErrorKind::Other, error.to_string()))?;
+    if let Some(value) = recorded
+        .content_type
+        .as_deref()
+        .and_then(|value| value.parse().ok())
+    {
+        response.headers_mut().insert(CONTENT_TYPE, value);
+    }
+    Ok(reqwest::Response::from(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client_builder;
+    use crate::{client::OpenFIGIClient, model::enums::IdType};
+    use serde_json::json;
+
+    #[test]
+    fn test_key_is_canonical() {
+        let body = json!([{ "idValue": "IBM", "idType": "TICKER" }]);
+        assert_eq!(
+            ReplayStore::key(&Method::POST, "mapping", Some(&body)),
+            r#"POST mapping [{"idType":"TICKER","idValue":"IBM"}]"#
+        );
+        assert_eq!(
+            ReplayStore::key(&Method::GET, "mapping/values/exchCode", None),
+            "GET mapping/values/exchCode"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([{ "data": [{ "figi": "BBG000BLNNH6" }] }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let store = ReplayStore::new();
+        let client = mock_client_builder(&server)
+            .replay(ReplayMode::Record(store.clone()))
+            .build()
+            .expect("Failed to create test client");
+        let recorded = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect("Recorded mapping should succeed");
+        assert_eq!(store.len(), 1);
+        assert!(store.window().is_some());
+
+        let saved = serde_json::to_string(&store).expect("Should serialize store");
+        let store: ReplayStore = serde_json::from_str(&saved).expect("Should deserialize store");
+        let client = client.with_replay(ReplayMode::Replay(store));
+
+        let replayed = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect("Replayed mapping should succeed");
+        assert_eq!(replayed, recorded);
+
+        let err = client
+            .mapping(IdType::TICKER, "AAPL")
+            .send()
+            .await
+            .expect_err("Unrecorded request should fail");
+        assert!(err.is_replay_miss());
+    }
+
+    #[tokio::test]
+    async fn test_replayed_error_reports_the_request_url() {
+        let store = ReplayStore::new();
+        let key = ReplayStore::key(
+            &Method::POST,
+            "mapping",
+            Some(&json!([{ "idType": "TICKER", "idValue": "IBM" }])),
+        );
+        store.lock().insert(
+            key,
+            RecordedResponse {
+                status: 400,
+                content_type: None,
+                body: "Invalid idType.".to_string(),
+                recorded_at: Utc::now(),
+            },
+        );
+        let client = OpenFIGIClient::builder()
+            .base_url("https://example.com/v3/")
+            .replay(ReplayMode::Replay(store))
+            .build()
+            .expect("Failed to create test client");
+
+        let err = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect_err("Replayed bad request should fail");
+        assert!(
+            err.to_string().contains("https://example.com/v3/mapping"),
+            "{err}"
+        );
+    }
+}
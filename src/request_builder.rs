@@ -15,10 +15,21 @@
 //!
 //! Note: This module is not intended for direct use by consumers of the OpenFIGI API.
 
+use crate::archive::RequestPlan;
+use crate::cache::CachedResponse;
 use crate::client::OpenFIGIClient;
 use crate::error::{OpenFIGIError, Result};
-use reqwest::Method;
+use crate::observer::RequestInfo;
+use crate::replay::{ReplayMode, ReplayStore};
+use crate::trace::TraceEvent;
+use chrono::Utc;
+use http::Extensions;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Method, StatusCode};
 use serde::Serialize;
+use std::time::Instant;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 
 /// HTTP request builder with fluent interface for OpenFIGI API operations.
 ///
@@ -48,6 +59,7 @@ pub(crate) struct OpenFIGIRequestBuilder {
     method: Method,
     path: String,
     body: Option<serde_json::Value>,
+    extensions: Extensions,
 }
 
 impl OpenFIGIRequestBuilder {
@@ -67,6 +79,7 @@ impl OpenFIGIRequestBuilder {
             method,
             path: path.into(),
             body: None,
+            extensions: Extensions::new(),
         }
     }
 
@@ -81,11 +94,20 @@ impl OpenFIGIRequestBuilder {
     /// # Panics
     ///
     /// Panics if JSON serialization fails.
-    pub(crate) fn body<T: Serialize>(mut self, body: &T) -> Self {
+    pub(crate) fn body<T: Serialize + ?Sized>(mut self, body: &T) -> Self {
         self.body = Some(serde_json::to_value(body).expect("Failed to serialize body"));
         self
     }
 
+    /// Sets the extensions passed to the middleware of the client.
+    ///
+    /// The extensions, including values added by middleware, are merged into the extensions
+    /// of the returned response.
+    pub(crate) fn extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
     /// Executes the HTTP request and returns the raw response.
     ///
     /// This method constructs the full URL by joining the path with the client's base URL,
@@ -95,11 +117,27 @@ impl OpenFIGIRequestBuilder {
     ///
     /// # Process
     ///
-    /// 1. Constructs the full URL from base URL and path
-    /// 2. Builds the HTTP request with the specified method
-    /// 3. Adds JSON body if provided via [`body()`](Self::body)
-    /// 4. Adds `X-OPENFIGI-APIKEY` header if API key is configured
-    /// 5. Executes the request and returns the response
+    /// 0. Notifies the observers of the client (see [`crate::observer`]) and, if the `tracing`
+    ///    feature is enabled, opens a span with the `endpoint`, `method` and `batch_size` of the
+    ///    request, recording its `status` and `latency_ms` once it completes
+    /// 1. Fails with [`OpenFIGIError::ClientPaused`] if the client is paused, serves the
+    ///    response from the store if the client replays responses (see [`crate::replay`]),
+    ///    waits or fails with [`OpenFIGIError::MaintenanceWindow`] during a maintenance window
+    ///    (see [`crate::maintenance`]), or fails with [`OpenFIGIError::BudgetExceeded`] if its
+    ///    request budget is exhausted, and waits for the rate limit of the client (see
+    ///    [`crate::rate_limit`]) and for a slot below its maximum number of requests in flight
+    /// 2. Constructs the full URL from base URL and path
+    /// 3. Builds the HTTP request with the specified method
+    /// 4. Adds JSON body if provided via [`body()`](Self::body)
+    /// 5. Adds `X-OPENFIGI-APIKEY` header if API key is configured
+    /// 6. Adds `If-None-Match` for `GET` requests with a cached response (see [`crate::cache`])
+    /// 7. Executes the request with the [`extensions()`](Self::extensions) and returns the
+    ///    response, serving `304 Not Modified` from the cache
+    /// 8. Passes the request and the body of the response to the archiver of the client, if
+    ///    any (see [`crate::archive`]), failing if it cannot be archived
+    /// 9. Records the response if the client records responses
+    /// 10. Merges the extensions into the extensions of the response, including the in-flight
+    ///     slot, which is released once the response is consumed
     ///
     /// # Errors
     ///
@@ -109,6 +147,150 @@ impl OpenFIGIRequestBuilder {
     /// - HTTP errors (will not automatically handle status codes)
     /// - Request building failures
     pub(crate) async fn send(self) -> Result<reqwest::Response> {
+        let observers = self.client.observers().clone();
+        let info = RequestInfo::new(&self.path, &self.method, self.batch_size());
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "openfigi.request",
+            endpoint = %info.endpoint,
+            method = %info.method,
+            batch_size = info.batch_size,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+
+        observers.on_request(&info);
+        let start = Instant::now();
+        #[cfg(feature = "tracing")]
+        let response = self.dispatch().instrument(span.clone()).await;
+        #[cfg(not(feature = "tracing"))]
+        let response = self.dispatch().await;
+        let elapsed = start.elapsed();
+        match &response {
+            Ok(response) => observers.on_response(&info, response.status(), elapsed),
+            Err(err) => observers.on_error(&info, err, elapsed),
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record(
+                "latency_ms",
+                u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+            );
+            let status = match &response {
+                Ok(response) => Some(response.status()),
+                Err(err) => err.status(),
+            };
+            if let Some(status) = status {
+                span.record("status", status.as_u16());
+            }
+            span.in_scope(|| match &response {
+                Ok(_) => tracing::debug!("OpenFIGI request completed"),
+                Err(err) => tracing::debug!(error = %err, "OpenFIGI request failed"),
+            });
+        }
+        response
+    }
+
+    /// Returns the number of jobs in the body of the request, `1` for a single object and `0`
+    /// without a body.
+    fn batch_size(&self) -> usize {
+        match &self.body {
+            Some(serde_json::Value::Array(jobs)) => jobs.len(),
+            Some(_) => 1,
+            None => 0,
+        }
+    }
+
+    /// Runs steps 1 to 10 of [`Self::send`].
+    async fn dispatch(mut self) -> Result<reqwest::Response> {
+        if self.client.is_paused() {
+            return Err(OpenFIGIError::ClientPaused);
+        }
+        let mut extensions = std::mem::take(&mut self.extensions);
+        let mut response = match self.client.replay().cloned() {
+            Some(ReplayMode::Replay(store)) => {
+                let url = self.client.base_url().join(&self.path)?;
+                store.replay(&self.replay_key(), url)?
+            }
+            Some(ReplayMode::Record(store)) => {
+                let key = self.replay_key();
+                self.acquire_maintenance().await?;
+                self.acquire_budget()?;
+                self.acquire_rate_limit().await;
+                let _permit = self.client.acquire_in_flight().await;
+                let response = self.execute_archived(&mut extensions).await?;
+                store.record(key, response).await?
+            }
+            None => {
+                self.acquire_maintenance().await?;
+                self.acquire_budget()?;
+                self.acquire_rate_limit().await;
+                if let Some(permit) = self.client.acquire_in_flight().await {
+                    extensions.insert(permit);
+                }
+                self.execute_archived(&mut extensions).await?
+            }
+        };
+        response.extensions_mut().extend(extensions);
+        Ok(response)
+    }
+
+    /// Executes the request and passes it to the archiver of the client, if any, together with
+    /// the body of its response.
+    async fn execute_archived(self, extensions: &mut Extensions) -> Result<reqwest::Response> {
+        let Some(archiver) = self.client.shared_archiver().cloned() else {
+            return self.execute(extensions).await;
+        };
+        let url = self
+            .client
+            .base_url()
+            .join(&self.path)
+            .map_err(OpenFIGIError::from)?;
+        let sent_at = Utc::now();
+        let plan = RequestPlan {
+            endpoint: self.path.clone(),
+            url,
+            method: self.method.clone(),
+            body: self.body.clone(),
+            batch_size: self.batch_size(),
+            sent_at,
+            received_at: sent_at,
+            status: StatusCode::OK,
+        };
+        let response = self.execute(extensions).await?;
+        archiver.archive(plan, response).await
+    }
+
+    /// Returns the canonical key of the request in a [`ReplayStore`].
+    fn replay_key(&self) -> String {
+        ReplayStore::key(&self.method, &self.path, self.body.as_ref())
+    }
+
+    /// Waits until no maintenance window of the client is active, or fails during a window.
+    async fn acquire_maintenance(&self) -> Result<()> {
+        match self.client.maintenance() {
+            Some(schedule) => schedule.acquire().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Counts the request against the budget of the client, if any.
+    fn acquire_budget(&self) -> Result<()> {
+        match self.client.budget() {
+            Some(budget) => budget.acquire(),
+            None => Ok(()),
+        }
+    }
+
+    /// Waits until the rate limit of the client, if any, allows sending the request.
+    async fn acquire_rate_limit(&self) {
+        if let Some(policy) = self.client.rate_limit() {
+            policy.acquire(&self.path).await;
+        }
+    }
+
+    async fn execute(self, extensions: &mut Extensions) -> Result<reqwest::Response> {
         // Construct the full URL - this is fallible
         let url = self
             .client
@@ -116,8 +298,24 @@ impl OpenFIGIRequestBuilder {
             .join(&self.path)
             .map_err(OpenFIGIError::from)?;
 
+        // Conditional requests only apply to cacheable `GET` requests
+        let cache = match self.method {
+            Method::GET => self.client.etag_cache().cloned(),
+            _ => None,
+        };
+        let cached = cache.as_ref().and_then(|cache| cache.get(&url));
+
+        let trace = self.client.resolution_trace();
+        if let Some(trace) = trace {
+            trace.record(TraceEvent::Request {
+                method: self.method.to_string(),
+                url: url.to_string(),
+                body: self.body.clone(),
+            });
+        }
+
         // Build the request with optimal method chaining
-        let mut request_builder = self.client.client().request(self.method, url);
+        let mut request_builder = self.client.client().request(self.method, url.clone());
 
         // Add JSON body if provided (most efficient path)
         if let Some(body) = self.body {
@@ -130,15 +328,72 @@ impl OpenFIGIRequestBuilder {
             request_builder = request_builder.header("X-OPENFIGI-APIKEY", api_key);
         }
 
-        // Execute the request with proper error conversion
-        request_builder.send().await.map_err(OpenFIGIError::from)
+        // Revalidate a previously cached response
+        if let Some(cached) = &cached {
+            request_builder = request_builder.header(IF_NONE_MATCH, cached.etag.clone());
+        }
+
+        // Execute the request with proper error conversion, letting middleware see the extensions
+        let (client, request) = request_builder.build_split();
+        let response = match request {
+            Ok(request) => client
+                .execute_with_extensions(request, extensions)
+                .await
+                .map_err(OpenFIGIError::from),
+            Err(err) => Err(OpenFIGIError::from(err)),
+        };
+        if let Some(trace) = trace {
+            trace.record(match &response {
+                Ok(response) => TraceEvent::Response {
+                    url: url.to_string(),
+                    status: response.status().as_u16(),
+                },
+                Err(err) => TraceEvent::Failed {
+                    url: url.to_string(),
+                    message: err.to_string(),
+                },
+            });
+        }
+        let response = response?;
+        self.client.update_rate_limit_status(response.headers());
+
+        let Some(cache) = cache else {
+            return Ok(response);
+        };
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            return Ok(cached_response(cached));
+        }
+        match response.headers().get(ETAG).cloned() {
+            Some(etag) if response.status().is_success() => {
+                let headers = response.headers().clone();
+                let body = response.bytes().await?.to_vec();
+                let cached = CachedResponse {
+                    etag,
+                    headers,
+                    body,
+                };
+                cache.insert(url, cached.clone());
+                Ok(cached_response(cached))
+            }
+            _ => Ok(response),
+        }
     }
 }
 
+/// Builds a `200 OK` response from a cached entry.
+fn cached_response(cached: CachedResponse) -> reqwest::Response {
+    let mut response = http::Response::new(cached.body);
+    *response.headers_mut() = cached.headers;
+    reqwest::Response::from(response)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::client::OpenFIGIClient;
+    use crate::test_utils::mock_client_builder;
     use reqwest::Method;
     use serde_json::json;
 
@@ -167,6 +422,19 @@ mod tests {
         OpenFIGIClient::new()
     }
 
+    #[test]
+    fn test_batch_size() {
+        let client = create_test_client();
+        let request = OpenFIGIRequestBuilder::new(client.clone(), Method::GET, "mapping/values/x");
+        assert_eq!(request.batch_size(), 0);
+        let request = OpenFIGIRequestBuilder::new(client.clone(), Method::POST, "search")
+            .body(&json!({"query": "ibm"}));
+        assert_eq!(request.batch_size(), 1);
+        let request = OpenFIGIRequestBuilder::new(client, Method::POST, "mapping")
+            .body(&json!([{"idType": "TICKER"}, {"idType": "TICKER"}]));
+        assert_eq!(request.batch_size(), 2);
+    }
+
     #[test]
     fn test_request_builder_creation() {
         let client = create_test_client();
@@ -207,4 +475,109 @@ mod tests {
         assert_eq!(builder.path(), "test");
         assert!(builder.has_body());
     }
+
+    #[tokio::test]
+    async fn test_get_revalidates_with_etag_cache() {
+        use crate::cache::ETagCache;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v3/mapping/values/exchCode"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v3/mapping/values/exchCode"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(json!({ "values": ["US", "LN"] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let cache = ETagCache::new();
+        let client = mock_client_builder(&server)
+            .etag_cache(cache.clone())
+            .build()
+            .expect("Failed to create test client");
+
+        for _ in 0..2 {
+            let response =
+                OpenFIGIRequestBuilder::new(client.clone(), Method::GET, "mapping/values/exchCode")
+                    .send()
+                    .await
+                    .expect("Request should succeed");
+            assert_eq!(response.status(), StatusCode::OK);
+            let body: serde_json::Value = response.json().await.expect("Body should be JSON");
+            assert_eq!(body, json!({ "values": ["US", "LN"] }));
+        }
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_extensions_reach_middleware_and_response() {
+        use http::Extensions;
+        use reqwest_middleware::{ClientBuilder, Next};
+        use std::{future::Future, pin::Pin};
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct JobId(u32);
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct SeenJob(Option<u32>);
+
+        fn record_job<'a>(
+            request: reqwest::Request,
+            extensions: &'a mut Extensions,
+            next: Next<'a>,
+        ) -> Pin<Box<dyn Future<Output = reqwest_middleware::Result<reqwest::Response>> + Send + 'a>>
+        {
+            Box::pin(async move {
+                let job = extensions.get::<JobId>().map(|job| job.0);
+                extensions.insert(SeenJob(job));
+                next.run(request, extensions).await
+            })
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client_builder(&server)
+            .middleware_client(
+                ClientBuilder::new(reqwest::Client::new())
+                    .with(record_job)
+                    .build(),
+            )
+            .build()
+            .expect("Failed to create test client");
+
+        let response = client
+            .search("ibm")
+            .extension(JobId(7))
+            .send_raw()
+            .await
+            .expect("Search should succeed");
+
+        assert_eq!(response.extensions().get::<JobId>(), Some(&JobId(7)));
+        assert_eq!(
+            response.extensions().get::<SeenJob>(),
+            Some(&SeenJob(Some(7)))
+        );
+    }
 }
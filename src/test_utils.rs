@@ -19,7 +19,13 @@
 //! - **Maintainability**: Centralize common testing logic for easier updates
 //! - **Type Safety**: Provide compile-time guarantees for test correctness
 
+use crate::{client::OpenFIGIClient, client_builder::OpenFIGIClientBuilder};
+use serde_json::json;
 use std::fs;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path},
+};
 
 /// Utility functions to load test data from files
 pub fn load_test_data(folder: &str, filename: &str) -> String {
@@ -43,3 +49,47 @@ macro_rules! test_enum_serialization {
         }
     };
 }
+
+/// Returns a client builder sending requests to the `/v3/` API of a mock server
+pub fn mock_client_builder(server: &MockServer) -> OpenFIGIClientBuilder {
+    OpenFIGIClient::builder().base_url(format!("{}/v3/", server.uri()))
+}
+
+/// Returns a client sending requests to the `/v3/` API of a mock server
+pub fn mock_client(server: &MockServer) -> OpenFIGIClient {
+    mock_client_builder(server)
+        .build()
+        .expect("Failed to create test client")
+}
+
+/// Returns a `/filter` response page with the given FIGIs, `next` token and total
+pub fn filter_page(figis: &[&str], next: Option<&str>, total: usize) -> serde_json::Value {
+    let data: Vec<_> = figis.iter().map(|figi| json!({ "figi": figi })).collect();
+    match next {
+        Some(next) => json!({ "data": data, "next": next, "total": total }),
+        None => json!({ "data": data, "total": total }),
+    }
+}
+
+/// Mounts three `/filter` results on a mock server, split across pages `p1` to `p3`
+pub async fn mount_filter_pages(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/v3/filter"))
+        .and(body_partial_json(json!({ "start": "p2" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(filter_page(
+            &["BBG000000034"],
+            Some("p3"),
+            3,
+        )))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v3/filter"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(filter_page(
+            &["BBG000000016", "BBG000000025"],
+            Some("p2"),
+            3,
+        )))
+        .mount(server)
+        .await;
+}
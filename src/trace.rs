@@ -0,0 +1,246 @@
+//! # Resolution Traces
+//!
+//! An opt-in record of how a lookup was resolved, for debugging instruments that unexpectedly
+//! map to the wrong FIGI.
+//!
+//! A [`ResolutionTrace`](crate::trace::ResolutionTrace) attached to a client records every
+//! HTTP request sent through it, including fan-outs and retries, with its JSON body and the
+//! response status. Disambiguators created by
+//! [`OpenFIGIClient::disambiguator`](crate::client::OpenFIGIClient::disambiguator) additionally
+//! record their selection decisions. The recorded events serialize to JSON.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::model::enums::IdType;
+//! use openfigi_rs::trace::ResolutionTrace;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let trace = ResolutionTrace::new();
+//! let client = OpenFIGIClient::new().with_resolution_trace(trace.clone());
+//!
+//! let data = client.mapping(IdType::TICKER, "IBM").send().await?;
+//! let best = client.disambiguator().prefer_composite().best_match(data.data());
+//!
+//! println!("{}", trace.to_json());
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A single event recorded by a [`ResolutionTrace`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TraceEvent {
+    /// An HTTP request was sent.
+    Request {
+        /// The HTTP method, e.g. `POST`.
+        method: String,
+        /// The request URL.
+        url: String,
+        /// The JSON request body, including all filters, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        body: Option<serde_json::Value>,
+    },
+    /// A response was received for the preceding request.
+    Response {
+        /// The request URL.
+        url: String,
+        /// The HTTP status code.
+        status: u16,
+    },
+    /// The preceding request failed without a response.
+    Failed {
+        /// The request URL.
+        url: String,
+        /// The error message.
+        message: String,
+    },
+    /// A disambiguator selected among the results of a request.
+    Selection {
+        /// FIGIs of all candidates, in response order.
+        candidates: Vec<String>,
+        /// FIGIs of the candidates remaining after applying all rules.
+        remaining: Vec<String>,
+        /// FIGI of the selected result, if one was selected.
+        selected: Option<String>,
+    },
+}
+
+/// A shared, append-only record of [`TraceEvent`]s.
+///
+/// Attach a trace to a client via
+/// [`OpenFIGIClient::with_resolution_trace`](crate::client::OpenFIGIClient::with_resolution_trace)
+/// or [`crate::client_builder::OpenFIGIClientBuilder::resolution_trace`]. Clones share the same
+/// underlying storage.
+#[derive(Clone, Debug, Default)]
+pub struct ResolutionTrace {
+    events: Arc<Mutex<Vec<TraceEvent>>>,
+}
+
+impl ResolutionTrace {
+    /// Creates a new, empty trace.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the recorded events, in order.
+    #[must_use]
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.lock().clone()
+    }
+
+    /// Returns the number of recorded events.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if no events were recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all recorded events, e.g. before the next lookup.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the recorded events as a JSON array.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(&*self.lock()).unwrap_or_default()
+    }
+
+    /// Appends an event.
+    pub(crate) fn record(&self, event: TraceEvent) {
+        self.lock().push(event);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<TraceEvent>> {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Serialize for ResolutionTrace {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.lock().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client_builder;
+    use serde_json::json;
+
+    #[test]
+    fn test_clones_share_events() {
+        let trace = ResolutionTrace::new();
+        let clone = trace.clone();
+        clone.record(TraceEvent::Response {
+            url: "https://api.openfigi.com/v3/mapping".to_string(),
+            status: 200,
+        });
+        assert_eq!(trace.len(), 1);
+
+        trace.clear();
+        assert!(clone.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_records_requests_and_selections() {
+        use crate::model::enums::IdType;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v3/mapping"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "data": [
+                    { "figi": "BBG000BLNQ16", "compositeFIGI": "BBG000BLNNH6" },
+                    { "figi": "BBG000BLNNH6", "compositeFIGI": "BBG000BLNNH6" }
+                ] }
+            ])))
+            .mount(&server)
+            .await;
+
+        let trace = ResolutionTrace::new();
+        let client = mock_client_builder(&server)
+            .resolution_trace(trace.clone())
+            .build()
+            .expect("Failed to create test client");
+
+        let data = client
+            .mapping(IdType::TICKER, "IBM")
+            .send()
+            .await
+            .expect("Mapping should succeed");
+        let best = client
+            .disambiguator()
+            .prefer_composite()
+            .best_match(data.data())
+            .expect("Should select a result");
+        assert_eq!(best.figi, "BBG000BLNNH6");
+
+        let url = format!("{}/v3/mapping", server.uri());
+        assert_eq!(
+            trace.events(),
+            vec![
+                TraceEvent::Request {
+                    method: "POST".to_string(),
+                    url: url.clone(),
+                    body: Some(json!([{ "idType": "TICKER", "idValue": "IBM" }])),
+                },
+                TraceEvent::Response { url, status: 200 },
+                TraceEvent::Selection {
+                    candidates: vec!["BBG000BLNQ16".to_string(), "BBG000BLNNH6".to_string()],
+                    remaining: vec!["BBG000BLNNH6".to_string()],
+                    selected: Some("BBG000BLNNH6".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let trace = ResolutionTrace::new();
+        trace.record(TraceEvent::Request {
+            method: "POST".to_string(),
+            url: "https://api.openfigi.com/v3/mapping".to_string(),
+            body: Some(json!([{ "idType": "TICKER", "idValue": "IBM" }])),
+        });
+        trace.record(TraceEvent::Selection {
+            candidates: vec!["A".to_string(), "B".to_string()],
+            remaining: vec!["A".to_string()],
+            selected: Some("A".to_string()),
+        });
+
+        assert_eq!(
+            trace.to_json(),
+            json!([
+                {
+                    "event": "request",
+                    "method": "POST",
+                    "url": "https://api.openfigi.com/v3/mapping",
+                    "body": [{ "idType": "TICKER", "idValue": "IBM" }]
+                },
+                {
+                    "event": "selection",
+                    "candidates": ["A", "B"],
+                    "remaining": ["A"],
+                    "selected": "A"
+                }
+            ])
+        );
+    }
+}
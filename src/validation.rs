@@ -0,0 +1,432 @@
+//! # Custom Validation Rules
+//!
+//! Extension point for organization-specific request validation.
+//!
+//! Rules are run against the [`RequestFilters`](crate::model::request::RequestFilters) of every request sent through the endpoint
+//! builders, after the built-in [`RequestFilters::validate`](crate::model::request::RequestFilters::validate) checks succeeded. They can be
+//! registered for all requests of a client via
+//! [`crate::client_builder::OpenFIGIClientBuilder::validation_rule`] or for a single request via
+//! the `validation_rule()` method of the endpoint builders.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::client::OpenFIGIClient;
+//! use openfigi_rs::error::{OpenFIGIError, OtherErrorKind};
+//! use openfigi_rs::model::request::{HasFilters, RequestFilters};
+//!
+//! // Organization rule: unlisted equities must never be requested.
+//! fn no_unlisted_equities(filters: &RequestFilters) -> openfigi_rs::error::Result<()> {
+//!     if filters.include_unlisted_equities == Some(true) {
+//!         return Err(OpenFIGIError::OtherError {
+//!             kind: OtherErrorKind::Validation,
+//!             message: "unlisted equities are not allowed".into(),
+//!         });
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let client = OpenFIGIClient::builder()
+//!     .validation_rule(no_unlisted_equities)
+//!     .build()?;
+//!
+//! let request = client.search("ibm").include_unlisted_equities(true);
+//! // `request.send()` fails before any network request is made.
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Identifier Check Digits
+//!
+//! Mapping requests for ISINs, CUSIPs and CINS codes, SEDOLs and FIGIs are checked for their
+//! format and check digit before they are sent, so typos fail with an
+//! [`OtherErrorKind::Validation`](crate::error::OtherErrorKind::Validation) error instead of
+//! using up API quota. In a bulk mapping, the error is the result of the invalid request only,
+//! and the other requests of the batch are still sent. Letters are accepted in either case, and numeric identifier values are
+//! not checked. The checks are available on their own as well:
+//!
+//! ```rust
+//! use openfigi_rs::validation::{is_valid_figi, is_valid_isin};
+//!
+//! assert!(is_valid_isin("US4592001014"));
+//! assert!(!is_valid_isin("US4592001015"));
+//! assert!(is_valid_figi("BBG000BLNNH6"));
+//! ```
+
+use crate::{
+    error::{OpenFIGIError, OtherErrorKind, Result},
+    model::{
+        enums::IdType,
+        request::{IdValue, RequestFilters},
+    },
+};
+use std::{borrow::Cow, fmt, sync::Arc};
+
+/// A custom validation rule for request filters.
+///
+/// Implemented for every thread-safe closure or function taking `&RequestFilters` and returning
+/// [`crate::error::Result<()>`]. Rules should report violations as
+/// [`crate::error::OtherErrorKind::Validation`] errors.
+pub trait ValidationRule: Fn(&RequestFilters) -> Result<()> + Send + Sync {}
+
+impl<F> ValidationRule for F where F: Fn(&RequestFilters) -> Result<()> + Send + Sync {}
+
+/// An ordered set of [`ValidationRule`]s, cheap to clone.
+#[derive(Clone, Default)]
+pub struct ValidationRules {
+    rules: Vec<Arc<dyn ValidationRule>>,
+}
+
+impl ValidationRules {
+    /// Creates an empty set of rules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a rule, run after all previously added rules.
+    pub fn push(&mut self, rule: impl ValidationRule + 'static) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Returns the number of rules.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns `true` if no rules are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Runs all rules in order against `filters`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first rule rejecting `filters`.
+    pub fn validate(&self, filters: &RequestFilters) -> Result<()> {
+        self.rules.iter().try_for_each(|rule| rule(filters))
+    }
+}
+
+impl fmt::Debug for ValidationRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationRules")
+            .field("len", &self.rules.len())
+            .finish()
+    }
+}
+
+/// Checks the format and check digit of `id_value` if `id_type` is an identifier with one.
+///
+/// Covers [`IdType::ID_ISIN`], [`IdType::ID_CUSIP`], [`IdType::ID_CINS`], [`IdType::ID_SEDOL`]
+/// and the FIGI types [`IdType::ID_BB_GLOBAL`], [`IdType::COMPOSITE_ID_BB_GLOBAL`] and
+/// [`IdType::ID_BB_GLOBAL_SHARE_CLASS_LEVEL`]. Other identifier types and numeric values are
+/// accepted unchecked.
+///
+/// # Errors
+///
+/// Returns an [`OtherErrorKind::Validation`] error naming the identifier and the problem.
+pub fn validate_id_value(id_type: &IdType, id_value: &IdValue) -> Result<()> {
+    let Some(value) = id_value.as_str() else {
+        return Ok(());
+    };
+    let scheme = match id_type {
+        IdType::ID_ISIN => &ISIN,
+        IdType::ID_CUSIP | IdType::ID_CINS => &CUSIP,
+        IdType::ID_SEDOL => &SEDOL,
+        IdType::ID_BB_GLOBAL
+        | IdType::COMPOSITE_ID_BB_GLOBAL
+        | IdType::ID_BB_GLOBAL_SHARE_CLASS_LEVEL => &FIGI,
+        _ => return Ok(()),
+    };
+    scheme.check(value).map_err(|problem| {
+        OpenFIGIError::other_error(
+            OtherErrorKind::Validation,
+            format!("'{value}' is not a valid {}: {problem}", scheme.name),
+        )
+    })
+}
+
+/// Returns `true` if `value` is an ISIN with a valid check digit, e.g. `US4592001014`.
+#[must_use]
+pub fn is_valid_isin(value: &str) -> bool {
+    ISIN.check(value).is_ok()
+}
+
+/// Returns `true` if `value` is a CUSIP or CINS code with a valid check digit, e.g. `459200101`.
+#[must_use]
+pub fn is_valid_cusip(value: &str) -> bool {
+    CUSIP.check(value).is_ok()
+}
+
+/// Returns `true` if `value` is a SEDOL with a valid check digit, e.g. `2005973`.
+#[must_use]
+pub fn is_valid_sedol(value: &str) -> bool {
+    SEDOL.check(value).is_ok()
+}
+
+/// Returns `true` if `value` is a FIGI with a valid check digit, e.g. `BBG000BLNNH6`.
+#[must_use]
+pub fn is_valid_figi(value: &str) -> bool {
+    FIGI.check(value).is_ok()
+}
+
+/// Checks the format and check digit of a FIGI, which must be uppercase.
+pub(crate) fn validate_figi(value: &str) -> Result<()> {
+    let problem = if value.bytes().any(|c| c.is_ascii_lowercase()) {
+        Cow::Borrowed("must be uppercase")
+    } else {
+        match FIGI.check(value) {
+            Ok(()) => return Ok(()),
+            Err(problem) => problem,
+        }
+    };
+    Err(OpenFIGIError::other_error(
+        OtherErrorKind::Validation,
+        format!("'{value}' is not a valid FIGI: {problem}"),
+    ))
+}
+
+/// Outcome of a check, with the problem found in an invalid identifier.
+type CheckResult = std::result::Result<(), &'static str>;
+
+/// An identifier with a fixed length and a check digit as its last character.
+struct Scheme {
+    name: &'static str,
+    length: usize,
+    /// Checks an uppercase value of the right length.
+    check: fn(&[u8]) -> CheckResult,
+}
+
+const ISIN: Scheme = Scheme {
+    name: "ISIN",
+    length: 12,
+    check: check_isin,
+};
+
+const CUSIP: Scheme = Scheme {
+    name: "CUSIP",
+    length: 9,
+    check: check_cusip,
+};
+
+const SEDOL: Scheme = Scheme {
+    name: "SEDOL",
+    length: 7,
+    check: check_sedol,
+};
+
+const FIGI: Scheme = Scheme {
+    name: "FIGI",
+    length: 12,
+    check: check_figi,
+};
+
+impl Scheme {
+    fn check(&self, value: &str) -> std::result::Result<(), Cow<'static, str>> {
+        let value = value.to_ascii_uppercase();
+        if value.len() != self.length {
+            return Err(format!("expected {} characters", self.length).into());
+        }
+        (self.check)(value.as_bytes()).map_err(Cow::Borrowed)
+    }
+}
+
+/// Two letter country code, nine alphanumeric characters and a Luhn check digit over the
+/// digits of all characters, with letters counted as `A = 10` to `Z = 35`.
+fn check_isin(value: &[u8]) -> CheckResult {
+    if !value[..2].iter().all(u8::is_ascii_uppercase) {
+        return Err("must start with a two letter country code");
+    }
+    let mut digits = Vec::with_capacity(24);
+    for &c in &value[..11] {
+        match char_value(c) {
+            Some(v) if v >= 10 => digits.extend([v / 10, v % 10]),
+            Some(v) => digits.push(v),
+            None => return Err("contains invalid characters"),
+        }
+    }
+    // Double every second digit, starting with the one left of the check digit
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 0 { digit_sum(d * 2) } else { d })
+        .sum();
+    expect_check_digit(value[11], sum)
+}
+
+/// Eight alphanumeric characters, where `*`, `@` and `#` count as 36 to 38, and a check digit.
+fn check_cusip(value: &[u8]) -> CheckResult {
+    let mut sum = 0;
+    for (i, &c) in value[..8].iter().enumerate() {
+        let v = match c {
+            b'*' => 36,
+            b'@' => 37,
+            b'#' => 38,
+            c => char_value(c).ok_or("contains invalid characters")?,
+        };
+        sum += digit_sum(if i % 2 == 1 { v * 2 } else { v });
+    }
+    expect_check_digit(value[8], sum)
+}
+
+/// Six digits or consonants weighted with 1, 3, 1, 7, 3 and 9, and a check digit.
+fn check_sedol(value: &[u8]) -> CheckResult {
+    const WEIGHTS: [u32; 6] = [1, 3, 1, 7, 3, 9];
+    let mut sum = 0;
+    for (&c, weight) in value[..6].iter().zip(WEIGHTS) {
+        if b"AEIOU".contains(&c) {
+            return Err("contains vowels");
+        }
+        sum += char_value(c).ok_or("contains invalid characters")? * weight;
+    }
+    expect_check_digit(value[6], sum)
+}
+
+/// Eleven digits or consonants, the third being `G`, and a check digit computed like the one
+/// of a CUSIP.
+fn check_figi(value: &[u8]) -> CheckResult {
+    /// Prefixes excluded to avoid confusion with ISINs.
+    const EXCLUDED_PREFIXES: [&[u8]; 7] = [b"BS", b"BM", b"GG", b"GB", b"GH", b"KY", b"VG"];
+    if value[2] != b'G' {
+        return Err("third character must be 'G'");
+    }
+    if EXCLUDED_PREFIXES.contains(&&value[..2]) {
+        return Err("starts with an excluded prefix");
+    }
+    let mut sum = 0;
+    for (i, &c) in value[..11].iter().enumerate() {
+        if b"AEIOU".contains(&c) {
+            return Err("contains vowels");
+        }
+        let v = char_value(c).ok_or("contains invalid characters")?;
+        sum += digit_sum(if i % 2 == 1 { v * 2 } else { v });
+    }
+    expect_check_digit(value[11], sum)
+}
+
+/// Returns the value of a digit or uppercase letter, counting letters as `A = 10` to `Z = 35`.
+fn char_value(c: u8) -> Option<u32> {
+    match c {
+        b'0'..=b'9' => Some(u32::from(c - b'0')),
+        b'A'..=b'Z' => Some(u32::from(c - b'A') + 10),
+        _ => None,
+    }
+}
+
+fn digit_sum(value: u32) -> u32 {
+    value / 10 + value % 10
+}
+
+/// Checks that `check` is the digit completing `sum` to a multiple of ten.
+fn expect_check_digit(check: u8, sum: u32) -> CheckResult {
+    let expected = (10 - sum % 10) % 10;
+    if char_value(check) == Some(expected) {
+        Ok(())
+    } else {
+        Err("invalid check digit")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{OpenFIGIError, OtherErrorKind},
+        model::enums::Currency,
+    };
+
+    fn require_currency(filters: &RequestFilters) -> Result<()> {
+        if filters.currency.is_none() {
+            return Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "currency is required",
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_rules_accept_everything() {
+        let rules = ValidationRules::new();
+        assert!(rules.is_empty());
+        assert!(rules.validate(&RequestFilters::default()).is_ok());
+    }
+
+    #[test]
+    fn test_rules_run_in_order() {
+        let mut rules = ValidationRules::new();
+        rules.push(require_currency);
+        rules.push(|_: &RequestFilters| {
+            Err(OpenFIGIError::other_error(
+                OtherErrorKind::Validation,
+                "always rejected",
+            ))
+        });
+        assert_eq!(rules.len(), 2);
+
+        let err = rules.validate(&RequestFilters::default()).unwrap_err();
+        assert!(err.to_string().contains("currency is required"));
+
+        let filters = RequestFilters {
+            currency: Some(Currency::USD),
+            ..RequestFilters::default()
+        };
+        let err = rules.validate(&filters).unwrap_err();
+        assert!(err.to_string().contains("always rejected"));
+    }
+
+    #[test]
+    fn test_check_digits() {
+        assert!(is_valid_isin("US4592001014"));
+        assert!(is_valid_isin("us0378331005"));
+        assert!(!is_valid_isin("US0378331006"));
+        assert!(!is_valid_isin("1S0378331005"));
+        assert!(is_valid_cusip("459200101"));
+        assert!(is_valid_cusip("037833100"));
+        assert!(!is_valid_cusip("037833101"));
+        assert!(is_valid_sedol("2005973"));
+        assert!(is_valid_sedol("B0YBKJ7"));
+        assert!(!is_valid_sedol("B0YBKJ8"));
+        assert!(!is_valid_sedol("A0YBKJ7"));
+        assert!(is_valid_figi("BBG000BLNNH6"));
+        assert!(is_valid_figi("BBG001S5S399"));
+        assert!(!is_valid_figi("BBG000BLNNH7"));
+        assert!(!is_valid_figi("BBX000BLNNH6"));
+    }
+
+    #[test]
+    fn test_validate_id_value() {
+        assert!(validate_id_value(&IdType::ID_ISIN, &"US4592001014".into()).is_ok());
+        assert!(validate_id_value(&IdType::TICKER, &"US4592001015".into()).is_ok());
+        assert!(validate_id_value(&IdType::ID_CUSIP, &IdValue::from(459_200_101)).is_ok());
+
+        let err = validate_id_value(&IdType::ID_ISIN, &"US459200101".into()).unwrap_err();
+        assert!(matches!(
+            err,
+            OpenFIGIError::OtherError {
+                kind: OtherErrorKind::Validation,
+                ..
+            }
+        ));
+        assert!(
+            err.to_string()
+                .contains("'US459200101' is not a valid ISIN: expected 12 characters")
+        );
+        let err =
+            validate_id_value(&IdType::COMPOSITE_ID_BB_GLOBAL, &"BBG000BLNNH7".into()).unwrap_err();
+        assert!(err.to_string().contains("invalid check digit"));
+    }
+
+    #[test]
+    fn test_clone_keeps_rules() {
+        let mut rules = ValidationRules::new();
+        rules.push(require_currency);
+        let cloned = rules.clone();
+        assert_eq!(cloned.len(), 1);
+        assert!(cloned.validate(&RequestFilters::default()).is_err());
+    }
+}
@@ -0,0 +1,324 @@
+//! # Wire Format Compatibility Assertions
+//!
+//! Helpers for downstream integration tests pinning the JSON wire format of requests they send,
+//! so changes in serialization are caught when upgrading this crate, and comparing responses
+//! with snapshots via [`assert_figi_results_eq!`](crate::assert_figi_results_eq). Requires the
+//! `test-util` feature, which is intended for `[dev-dependencies]` only.
+//!
+//! Both sides are normalized before comparison:
+//!
+//! - Object keys are compared regardless of their order.
+//! - Object entries with a `null` value are treated as absent.
+//! - Numbers are compared by value, so `100` matches `100.0`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use openfigi_rs::model::enums::{Currency, IdType};
+//! use openfigi_rs::model::request::{HasFilters, MappingRequest};
+//! use openfigi_rs::wire_compat::assert_request_matches;
+//!
+//! let request = MappingRequest::builder()
+//!     .id_type(IdType::ID_ISIN)
+//!     .id_value("US4592001014")
+//!     .currency(Currency::USD)
+//!     .build()?;
+//!
+//! assert_request_matches(
+//!     &request,
+//!     r#"{ "idValue": "US4592001014", "idType": "ID_ISIN", "currency": "USD" }"#,
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use serde::Serialize;
+use serde_json::{Number, Value};
+use std::fmt;
+
+/// Serializes `value` and returns its normalized JSON representation.
+///
+/// # Panics
+///
+/// Panics if `value` cannot be serialized to JSON.
+#[must_use]
+#[track_caller]
+pub fn to_normalized_json<T: Serialize + ?Sized>(value: &T) -> Value {
+    let json = serde_json::to_value(value)
+        .unwrap_or_else(|err| panic!("failed to serialize value to JSON: {err}"));
+    normalize(json)
+}
+
+/// Parses `json` and returns its normalized representation.
+///
+/// # Panics
+///
+/// Panics if `json` is not valid JSON.
+#[must_use]
+#[track_caller]
+pub fn parse_normalized_json(json: &str) -> Value {
+    let json = serde_json::from_str(json)
+        .unwrap_or_else(|err| panic!("failed to parse expected JSON: {err}"));
+    normalize(json)
+}
+
+/// Asserts that `request` serializes to the same JSON as `expected_json` after normalization.
+///
+/// # Panics
+///
+/// Panics with both normalized documents if they differ, or if either side is not valid JSON.
+#[track_caller]
+pub fn assert_request_matches<T: Serialize + ?Sized>(request: &T, expected_json: &str) {
+    let actual = to_normalized_json(request);
+    let expected = parse_normalized_json(expected_json);
+    assert!(
+        actual == expected,
+        "request wire format mismatch\n  actual: {actual}\nexpected: {expected}"
+    );
+}
+
+/// Returns the differences between `actual` and `expected` after normalization.
+///
+/// Each difference is reported on its own line with the JSON pointer of the differing value,
+/// e.g. `/0/ticker: "IBM" != "IBMX"`. Returns an empty list if both sides are equal.
+///
+/// # Panics
+///
+/// Panics if either side cannot be serialized to JSON.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::wire_compat::json_diff;
+/// use serde_json::json;
+///
+/// let diff = json_diff(
+///     &json!({ "figi": "BBG000BLNNH6", "ticker": "IBM" }),
+///     &json!({ "ticker": "IBMX", "figi": "BBG000BLNNH6", "name": null }),
+/// );
+/// assert_eq!(diff, [r#"/ticker: "IBM" != "IBMX""#]);
+/// ```
+#[must_use]
+#[track_caller]
+pub fn json_diff<A: Serialize + ?Sized, E: Serialize + ?Sized>(
+    actual: &A,
+    expected: &E,
+) -> Vec<String> {
+    let mut diff = Vec::new();
+    collect_diff(
+        "",
+        &to_normalized_json(actual),
+        &to_normalized_json(expected),
+        &mut diff,
+    );
+    diff
+}
+
+/// Asserts that `actual` and `expected` serialize to the same JSON after normalization.
+///
+/// Intended for comparing FIGI results or whole responses with snapshots, see
+/// [`assert_figi_results_eq!`](crate::assert_figi_results_eq).
+///
+/// # Panics
+///
+/// Panics with the differences if both sides differ, or if either side cannot be serialized.
+#[track_caller]
+pub fn assert_figi_results_eq<A: Serialize + ?Sized, E: Serialize + ?Sized>(
+    actual: &A,
+    expected: &E,
+    message: Option<fmt::Arguments<'_>>,
+) {
+    let diff = json_diff(actual, expected);
+    if !diff.is_empty() {
+        let message = message
+            .map(|message| format!(": {message}"))
+            .unwrap_or_default();
+        panic!("FIGI results differ{message}\n  {}", diff.join("\n  "));
+    }
+}
+
+/// Asserts that two FIGI results, lists of results or responses are equal, printing a diff.
+///
+/// Both sides are serialized and normalized as described in [`crate::wire_compat`], so field
+/// order and fields that are `null` on one side and absent on the other do not matter. The
+/// order of results does. An optional message with format arguments is included on failure.
+///
+/// # Examples
+///
+/// ```rust
+/// use openfigi_rs::assert_figi_results_eq;
+/// use openfigi_rs::model::response::FigiResult;
+/// use serde_json::json;
+///
+/// let results: Vec<FigiResult> = serde_json::from_value(json!([
+///     { "figi": "BBG000BLNNH6", "ticker": "IBM", "name": null }
+/// ]))?;
+/// let snapshot: Vec<FigiResult> =
+///     serde_json::from_value(json!([{ "ticker": "IBM", "figi": "BBG000BLNNH6" }]))?;
+///
+/// assert_figi_results_eq!(results, snapshot);
+/// assert_figi_results_eq!(results, snapshot, "lookup of {}", "IBM");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[macro_export]
+macro_rules! assert_figi_results_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::wire_compat::assert_figi_results_eq(&$actual, &$expected, None)
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        $crate::wire_compat::assert_figi_results_eq(
+            &$actual,
+            &$expected,
+            Some(format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Appends the differences between two normalized JSON values at `path` to `diff`.
+fn collect_diff(path: &str, actual: &Value, expected: &Value, diff: &mut Vec<String>) {
+    let location = if path.is_empty() { "/" } else { path };
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            let mut keys: Vec<&String> = actual.keys().chain(expected.keys()).collect();
+            keys.sort_unstable();
+            keys.dedup();
+            for key in keys {
+                let path = format!("{path}/{key}");
+                match (actual.get(key), expected.get(key)) {
+                    (Some(actual), Some(expected)) => collect_diff(&path, actual, expected, diff),
+                    (Some(actual), None) => diff.push(format!("{path}: unexpected {actual}")),
+                    (None, Some(expected)) => diff.push(format!("{path}: missing {expected}")),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) => {
+            for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+                collect_diff(&format!("{path}/{index}"), actual, expected, diff);
+            }
+            if actual.len() != expected.len() {
+                diff.push(format!(
+                    "{location}: {} items != {} items",
+                    actual.len(),
+                    expected.len()
+                ));
+            }
+        }
+        (actual, expected) if actual != expected => {
+            diff.push(format!("{location}: {actual} != {expected}"));
+        }
+        _ => {}
+    }
+}
+
+/// Recursively normalizes a JSON value for comparison.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, normalize(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+        Value::Number(number) => Value::Number(normalize_number(number)),
+        other => other,
+    }
+}
+
+/// Represents integral floating point numbers as integers.
+#[expect(clippy::cast_possible_truncation)]
+fn normalize_number(number: Number) -> Number {
+    match number.as_f64() {
+        Some(float)
+            if !number.is_i64()
+                && !number.is_u64()
+                && float.fract() == 0.0
+                && float.abs() < 9_007_199_254_740_992.0 =>
+        {
+            Number::from(float as i64)
+        }
+        _ => number,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        enums::{Currency, IdType},
+        request::{HasFilters, MappingRequest},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize() {
+        let normalized = normalize(json!({
+            "b": [{ "c": null, "d": 100.0 }],
+            "a": 1.5,
+            "e": null
+        }));
+        assert_eq!(normalized, json!({ "a": 1.5, "b": [{ "d": 100 }] }));
+    }
+
+    #[test]
+    fn test_assert_request_matches() {
+        let request = MappingRequest::builder()
+            .id_type(IdType::ID_ISIN)
+            .id_value("US4592001014")
+            .currency(Currency::USD)
+            .strike([Some(100.0), None])
+            .build()
+            .expect("valid request");
+
+        assert_request_matches(
+            &request,
+            r#"{
+                "strike": [100, null],
+                "currency": "USD",
+                "idValue": "US4592001014",
+                "idType": "ID_ISIN"
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "request wire format mismatch")]
+    fn test_assert_request_matches_mismatch() {
+        let request = MappingRequest::builder()
+            .id_type(IdType::ID_ISIN)
+            .id_value("US4592001014")
+            .build()
+            .expect("valid request");
+
+        assert_request_matches(
+            &request,
+            r#"{ "idType": "ID_CUSIP", "idValue": "US4592001014" }"#,
+        );
+    }
+
+    #[test]
+    fn test_json_diff() {
+        let diff = json_diff(
+            &json!([{ "figi": "A", "ticker": "IBM", "name": null }, { "figi": "B" }]),
+            &json!([{ "ticker": "IBM", "figi": "A", "exchCode": "US" }]),
+        );
+        assert_eq!(
+            diff,
+            [r#"/0/exchCode: missing "US""#, "/: 2 items != 1 items"]
+        );
+        assert!(json_diff(&json!({ "a": 1.0 }), &json!({ "a": 1 })).is_empty());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "FIGI results differ: lookup of IBM\n  /0/ticker: \"IBM\" != \"IBMX\""
+    )]
+    fn test_assert_figi_results_eq_mismatch() {
+        crate::assert_figi_results_eq!(
+            json!([{ "figi": "A", "ticker": "IBM" }]),
+            json!([{ "figi": "A", "ticker": "IBMX" }]),
+            "lookup of {}",
+            "IBM"
+        );
+    }
+}
@@ -25,5 +25,5 @@ pub fn create_test_client() -> OpenFIGIClient {
 /// This function should be called after each API request in integration tests
 /// to ensure compliance with OpenFIGI's rate limiting policies.
 pub async fn rate_limit_delay() {
-    sleep(Duration::from_millis(10000)).await;
+    sleep(Duration::from_secs(10)).await;
 }
@@ -17,7 +17,10 @@
 //! - Empty result handling for non-existent filter terms
 //! - Pagination information validation
 
-use openfigi_rs::model::enums::{Currency, ExchCode, MarketSecDesc, SecurityType};
+use openfigi_rs::model::{
+    enums::{Currency, ExchCode, MarketSecDesc, SecurityType},
+    request::HasFilters,
+};
 use serial_test::serial;
 
 mod common;
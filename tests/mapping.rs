@@ -19,7 +19,8 @@
 
 use openfigi_rs::model::{
     enums::{Currency, ExchCode, IdType, MarketSecDesc, SecurityType},
-    request::MappingRequest,
+    request::{HasFilters, MappingRequest},
+    response::JobOutcome,
 };
 use serde_json::json;
 use serial_test::serial;
@@ -134,17 +135,17 @@ async fn test_mapping_bulk_request() {
 
     // Should have one result for each request
     assert_eq!(mapping_responses.len(), 3);
-    for (i, result) in mapping_responses.as_slice().iter().enumerate() {
-        match result {
-            Ok(data) => {
+    for (i, outcome) in mapping_responses.as_slice().iter().enumerate() {
+        match outcome {
+            JobOutcome::Found(data) => {
                 // Success: data present
                 assert!(
                     !data.data().is_empty(),
                     "Expected non-empty data for index {i}"
                 );
             }
-            Err(e) => {
-                panic!("Expected successful mapping for index {i}, but got error: {e}");
+            other => {
+                panic!("Expected successful mapping for index {i}, but got {other:?}");
             }
         }
     }
@@ -155,10 +156,10 @@ async fn test_mapping_bulk_request() {
 
 /// Tests error handling for invalid identifiers
 ///
-/// Validates that the API properly handles invalid input by:
+/// Validates that malformed identifiers are rejected by:
 /// - Returning appropriate error messages
 /// - Not returning data for invalid requests
-/// - Maintaining proper response structure even with errors
+/// - Failing before the request is sent
 #[tokio::test]
 #[serial]
 async fn test_mapping_invalid_identifier() {
@@ -174,10 +175,7 @@ async fn test_mapping_invalid_identifier() {
         Ok(_) => panic!("Expected error, got MappingData for invalid identifier"),
         Err(e) => {
             // Check that the error message is as expected
-            assert!(e.to_string().contains("Invalid idValue format."));
+            assert!(e.to_string().contains("is not a valid ISIN"));
         }
     }
-
-    // Add delay to avoid rate limiting
-    rate_limit_delay().await;
 }
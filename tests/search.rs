@@ -16,7 +16,10 @@
 //! - Search with filter criteria (currency, market sector, security type)
 //! - Empty result handling for non-existent search terms
 
-use openfigi_rs::model::enums::{Currency, MarketSecDesc, SecurityType};
+use openfigi_rs::model::{
+    enums::{Currency, MarketSecDesc, SecurityType},
+    request::HasFilters,
+};
 use serial_test::serial;
 
 mod common;